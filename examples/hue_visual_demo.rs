@@ -47,6 +47,10 @@ fn main() -> Result<()> {
         border_color: "white".to_string(),
         header_text: None,
         vectorized_text: false,
+        find_duplicates: None,
+        min_chroma: None,
+        sort: vec![color_rs::cli::CollectionSortKey::Hue],
+        dry_run: false,
     };
 
     match execute_hue_analysis(&warm_args, None) {
@@ -75,6 +79,10 @@ fn main() -> Result<()> {
         border_color: "white".to_string(),
         header_text: None,
         vectorized_text: false,
+        find_duplicates: None,
+        min_chroma: None,
+        sort: vec![color_rs::cli::CollectionSortKey::Hue],
+        dry_run: false,
     };
 
     match execute_hue_analysis(&cool_args, None) {
@@ -106,6 +114,10 @@ fn main() -> Result<()> {
         border_color: "white".to_string(),
         header_text: None,
         vectorized_text: false,
+        find_duplicates: None,
+        min_chroma: None,
+        sort: vec![color_rs::cli::CollectionSortKey::Hue],
+        dry_run: false,
     };
 
     match execute_hue_analysis(&wraparound_args, None) {
@@ -134,6 +146,10 @@ fn main() -> Result<()> {
         border_color: "white".to_string(),
         header_text: None,
         vectorized_text: false,
+        find_duplicates: None,
+        min_chroma: None,
+        sort: vec![color_rs::cli::CollectionSortKey::Hue],
+        dry_run: false,
     };
 
     match execute_hue_analysis(&complex_args, None) {
@@ -186,6 +202,10 @@ mod tests {
             border_color: "white".to_string(),
             header_text: None,
             vectorized_text: false,
+            find_duplicates: None,
+            min_chroma: None,
+            sort: vec![color_rs::cli::CollectionSortKey::Hue],
+            dry_run: false,
         };
 
         // Validate that our demo arguments are valid
@@ -7,7 +7,7 @@
 
 use color_rs::{
     ColorRs, Result,
-    cli::GradientArgs,
+    cli::{GradientArgs, ImageWidth},
     gradient::{GradientCalculator, cubic_bezier_ease},
 };
 use palette::{IntoColor, Lab, Mix, Srgb};
@@ -68,16 +68,10 @@ fn main() -> Result<()> {
         ease_in: 0.42,
         ease_out: 0.58,
         svg: Some("example-gradient.svg".to_string()),
-        png: None,
-        no_legend: false,
-        width: 800,
-        step: None,
-        stops: 5,
+        width: ImageWidth::Pixels(800),
         stops_simple: true,
-        output_format: None,
-        output_file: None,
-        func_filter: None,
         vectorized_text: false,
+        ..Default::default()
     };
 
     // This will generate the gradient and save SVG file
@@ -3,10 +3,13 @@
 use crate::config::{
     APP_AUTHOR, APP_DESCRIPTION, APP_NAME, APP_VERSION, BEZIER_MAX, BEZIER_MIN,
     DEFAULT_BORDER_COLOR, DEFAULT_BORDER_WIDTH, DEFAULT_EASE_IN, DEFAULT_EASE_OUT,
-    DEFAULT_END_POSITION, DEFAULT_FONT_SIZE, DEFAULT_START_POSITION, DEFAULT_WIDTH, MAX_PERCENTAGE,
+    DEFAULT_END_POSITION, DEFAULT_FONT_SIZE, DEFAULT_RAMP_SHADES, DEFAULT_RAMP_TINTS,
+    DEFAULT_START_POSITION, DEFAULT_STOP_SAMPLES, DEFAULT_WIDTH, MAX_PERCENTAGE,
+    MIN_STOP_SAMPLE_POINTS,
 };
 use crate::error::{ColorError, Result};
-use clap::{Args, Parser, Subcommand, ValueEnum};
+use clap::builder::{PossibleValuesParser, TypedValueParser};
+use clap::{Args, FromArgMatches, Parser, Subcommand, ValueEnum};
 use std::default::Default;
 
 /// Output format for file export
@@ -19,6 +22,188 @@ pub enum OutputFormat {
     #[clap(alias = "y")]
     #[default]
     Yaml,
+    /// Flat KEY=#RRGGBB lines for build systems
+    #[clap(alias = "e")]
+    Env,
+    /// Rust `const` array of gradient stops
+    #[clap(alias = "rs")]
+    Rust,
+    /// Markdown table of the main results
+    #[clap(alias = "md")]
+    Markdown,
+    /// CSS `:root` block of `--color-<relationship>` custom properties
+    #[value(name = "css-vars", alias = "css")]
+    CssVars,
+}
+
+impl OutputFormat {
+    /// The formats every command with structured output writes
+    pub const STRUCTURED: &'static [Self] = &[Self::Toml, Self::Yaml];
+
+    /// Value parser for a command's `--output` that accepts only `supported`
+    ///
+    /// Each command declares the formats it writes once and passes them here,
+    /// so `--help` lists only those and any other value is a parse error.
+    #[must_use]
+    pub fn parser(supported: &'static [Self]) -> impl TypedValueParser<Value = Self> {
+        PossibleValuesParser::new(supported.iter().filter_map(ValueEnum::to_possible_value))
+            .map(|name| <Self as ValueEnum>::from_str(&name, false).unwrap_or_default())
+    }
+
+    /// Check that `command` can write this format
+    ///
+    /// Covers arguments built in code rather than parsed by [`Self::parser`].
+    ///
+    /// # Errors
+    /// Returns [`Self::unsupported_by`] if `supported` does not contain this format
+    pub fn check_supported(&self, command: &str, supported: &[Self]) -> Result<()> {
+        if supported.contains(self) {
+            Ok(())
+        } else {
+            Err(self.unsupported_by(command, supported))
+        }
+    }
+
+    /// Error for a `command` that cannot write this format, naming the ones it can
+    #[must_use]
+    pub fn unsupported_by(&self, command: &str, supported: &[Self]) -> ColorError {
+        let name = |format: &Self| {
+            format
+                .to_possible_value()
+                .map_or_else(String::new, |value| value.get_name().to_string())
+        };
+        ColorError::InvalidArguments(format!(
+            "{} output is not supported by {command} (supported: {})",
+            name(self),
+            supported.iter().map(name).collect::<Vec<_>>().join(", ")
+        ))
+    }
+}
+
+/// Text size category used to pick WCAG contrast thresholds
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum TextSize {
+    /// Normal text (AA 4.5:1, AAA 7:1)
+    #[default]
+    Normal,
+    /// Large text, 18pt+ or 14pt+ bold (AA 3:1, AAA 4.5:1)
+    Large,
+}
+
+impl TextSize {
+    /// Whether WCAG large-text thresholds apply
+    #[must_use]
+    pub const fn is_large(self) -> bool {
+        matches!(self, Self::Large)
+    }
+}
+
+/// How RGB values are written in structured output
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum RgbFormat {
+    /// 0-255 integer channels, e.g. rgb(255, 0, 0)
+    #[default]
+    #[value(name = "u8")]
+    U8,
+    /// 0.0-1.0 float channels, e.g. rgb(1.0, 0.0, 0.0), as used by shaders
+    Float,
+}
+
+/// Reference white of LAB values read and written by the color command
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum LabVariant {
+    /// CIELAB relative to D65, the sRGB white point
+    #[default]
+    D65,
+    /// CIELAB relative to D50, as used by ICC profiles and print workflows
+    D50,
+}
+
+/// Top-level block of the color command's structured report
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum ReportSection {
+    /// The input as given and the parsed base color
+    Input,
+    /// The color in every supported color space
+    #[value(alias = "color-spaces")]
+    Conversion,
+    /// Luminance, brightness and WCAG contrast
+    #[value(alias = "accessibility")]
+    Contrast,
+    /// Grayscale variations
+    Grayscale,
+    /// Nearest matches in the color collections
+    Collections,
+    /// Harmony and luminance-matched color schemes
+    Schemes,
+}
+
+/// Decimal separator used for standalone numbers in structured output
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum DecimalSeparator {
+    /// Decimal point (0.5)
+    #[default]
+    #[value(name = ".")]
+    Dot,
+    /// Decimal comma (0,5)
+    #[value(name = ",")]
+    Comma,
+}
+
+impl DecimalSeparator {
+    /// The separator character
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        match self {
+            Self::Dot => '.',
+            Self::Comma => ',',
+        }
+    }
+}
+
+/// Output style for the describe command
+#[derive(Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq)]
+pub enum DescribeFormat {
+    /// Single natural-language sentence
+    #[default]
+    Phrase,
+    /// JSON object with each descriptor and the sentence
+    Json,
+}
+
+/// Parse an `--auto-stops` specification for CLI arguments
+fn parse_auto_stops(s: &str) -> std::result::Result<AutoStops, String> {
+    AutoStops::parse(s).map_err(|e| e.to_string())
+}
+
+/// Parse a `--tint` specification (target defaults to white)
+fn parse_tint(s: &str) -> std::result::Result<MixToward, String> {
+    MixToward::parse(s, "white").map_err(|e| e.to_string())
+}
+
+/// Parse a `--shade` specification (target defaults to black)
+fn parse_shade(s: &str) -> std::result::Result<MixToward, String> {
+    MixToward::parse(s, "black").map_err(|e| e.to_string())
+}
+
+/// Parse a `--width` value in pixels or percent for CLI arguments
+fn parse_image_width(s: &str) -> std::result::Result<ImageWidth, String> {
+    ImageWidth::parse(s).map_err(|e| e.to_string())
+}
+
+/// Parse an `--easing` keyword or `steps()` function for CLI arguments
+fn parse_easing(s: &str) -> std::result::Result<String, String> {
+    let keywords = crate::gradient_config::EasingConfig::KEYWORDS;
+    if keywords.contains(&s.trim().to_ascii_lowercase().as_str()) {
+        return Ok(s.to_string());
+    }
+    if s.trim_start().starts_with("steps(") {
+        return crate::gradient::EasingFunction::parse_steps(s).map(|_| s.to_string());
+    }
+    Err(format!(
+        "Unknown easing '{s}'. Must be one of: {}, or steps(N[, jump-term])",
+        keywords.join(", ")
+    ))
 }
 
 /// Parse percentage values for CLI arguments
@@ -29,6 +214,13 @@ fn parse_percentage(s: &str) -> std::result::Result<u8, String> {
         .map_err(|_| format!("Invalid percentage value: {s}"))
 }
 
+/// Parse a fractional percentage such as `12.5` or `12.5%` for CLI arguments
+fn parse_percentage_f64(s: &str) -> std::result::Result<f64, String> {
+    s.trim_end_matches('%')
+        .parse::<f64>()
+        .map_err(|_| format!("Invalid percentage value: {s}"))
+}
+
 /// Main CLI structure
 #[derive(Parser)]
 #[command(name = APP_NAME)]
@@ -38,6 +230,30 @@ fn parse_percentage(s: &str) -> std::result::Result<u8, String> {
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Named set of default options from profiles.toml; explicit flags override it
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Validate and compute without writing any files
+    #[arg(
+        long,
+        global = true,
+        help = "Compute everything but only print the files that would be written"
+    )]
+    pub dry_run: bool,
+}
+
+impl Cli {
+    /// The parsed subcommand with the global flags applied to its arguments
+    #[must_use]
+    pub fn into_command(self) -> Commands {
+        let mut command = self.command;
+        if let Some(dry_run) = command.dry_run_mut() {
+            *dry_run = self.dry_run;
+        }
+        command
+    }
 }
 
 /// Available commands
@@ -49,6 +265,59 @@ pub enum Commands {
     Color(ColorArgs),
     /// Analyze hue relationships and color harmony patterns
     Hue(HueArgs),
+    /// Generate a tints-and-shades ramp around a base color
+    Ramp(RampArgs),
+    /// Describe a color in plain English
+    Describe(DescribeArgs),
+    /// Export every entry of a color collection to JSON or YAML
+    ExportCollection(ExportCollectionArgs),
+    /// Report which colors of a palette fall outside a display gamut
+    GamutReport(GamutReportArgs),
+    /// Rewrite colors in one canonical notation, e.g. for linting design tokens
+    Canonicalize(CanonicalizeArgs),
+    /// Check whether two colors are the same within a tolerance (exit code 0 or 1)
+    Equal(EqualArgs),
+    /// List collection colors whose code starts with a prefix, e.g. the RAL 70xx grays
+    Search(SearchArgs),
+    /// Render a palette as a labeled contact-sheet PNG
+    Palette(PaletteArgs),
+    /// Show what color-rs supports, such as the accepted input formats
+    Info(InfoArgs),
+    /// Generate gradients from a base color to each color of a harmony scheme
+    HarmonyGradient(HarmonyGradientArgs),
+    /// Generate a reproducible random palette of distinct colors from a seed
+    Random(RandomArgs),
+    /// Find where a color falls on a named colormap such as viridis
+    ScalePosition(ScalePositionArgs),
+    /// Convert a list of colors to one format as an input-to-output table
+    Convert(ConvertArgs),
+    /// Mix two colors, or find their perceptual midpoint
+    Mix(MixArgs),
+}
+
+impl Commands {
+    /// The `dry_run` flag of commands that write files
+    fn dry_run_mut(&mut self) -> Option<&mut bool> {
+        match self {
+            Self::Gradient(args) => Some(&mut args.dry_run),
+            Self::Color(args) => Some(&mut args.dry_run),
+            Self::Hue(args) => Some(&mut args.dry_run),
+            Self::Ramp(args) => Some(&mut args.dry_run),
+            Self::ExportCollection(args) => Some(&mut args.dry_run),
+            Self::Palette(args) => Some(&mut args.dry_run),
+            Self::HarmonyGradient(args) => Some(&mut args.dry_run),
+            Self::Random(args) => Some(&mut args.dry_run),
+            Self::ScalePosition(args) => Some(&mut args.dry_run),
+            Self::Convert(args) => Some(&mut args.dry_run),
+            Self::Describe(_)
+            | Self::GamutReport(_)
+            | Self::Canonicalize(_)
+            | Self::Equal(_)
+            | Self::Search(_)
+            | Self::Info(_)
+            | Self::Mix(_) => None,
+        }
+    }
 }
 
 /// Arguments for gradient generation
@@ -79,6 +348,88 @@ pub struct GradientArgs {
     #[arg(long, default_value = DEFAULT_EASE_OUT)]
     pub ease_out: f64,
 
+    /// Validate and compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
+
+    /// Decimal separator for standalone numbers in structured output
+    #[arg(
+        long,
+        value_enum,
+        value_name = "SEPARATOR",
+        default_value = ".",
+        help = "Decimal separator for standalone numbers (. or ,); values inside lab()/rgb() strings are unchanged"
+    )]
+    pub decimal_separator: DecimalSeparator,
+
+    /// How RGB values are written in structured output
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        default_value = "u8",
+        help = "RGB notation in structured output: u8 for rgb(255, 0, 0) or float for rgb(1.0, 0.0, 0.0)"
+    )]
+    pub rgb_format: RgbFormat,
+
+    /// Named CSS easing keyword or `steps()` function; overrides --ease-in/--ease-out
+    #[arg(
+        long,
+        value_name = "KEYWORD",
+        value_parser = parse_easing,
+        help = "CSS easing keyword (linear, ease, ease-in, ease-out, ease-in-out) or steps(N[, jump-start|jump-end|jump-none|jump-both]); overrides --ease-in/--ease-out"
+    )]
+    pub easing: Option<String>,
+
+    /// Diverging gradient: start → center → end, with the center at the midpoint
+    ///
+    /// The center needs its own stop, so an even stop count is rounded up to
+    /// the next odd one.
+    #[arg(
+        long,
+        help = "Diverging scale through a neutral center: two mirrored segments meeting exactly halfway (an even --stops count is rounded up to odd, e.g. 4 gives 5 stops)"
+    )]
+    pub diverging: bool,
+
+    /// Center color for --diverging (default: gray at the endpoints' mean LAB lightness)
+    #[arg(
+        long,
+        value_name = "COLOR",
+        requires = "diverging",
+        help = "Center color for --diverging (default: neutral gray at the mean lightness of the endpoints)"
+    )]
+    pub center: Option<String>,
+
+    /// Color space the intelligent stops are interpolated in
+    #[arg(
+        long,
+        value_enum,
+        value_name = "SPACE",
+        default_value = "lab",
+        conflicts_with_all = ["stops_simple", "diverging"],
+        help = "Interpolation space for intelligent stops: lab (default), lch, rgb, hsl, hsv or cam16 (smoothest, slower)"
+    )]
+    pub color_space: crate::color_ops::ColorSpace,
+
+    /// Which way hue travels around the wheel in HSL and HSV gradients
+    #[arg(
+        long,
+        value_enum,
+        value_name = "DIRECTION",
+        default_value = "short",
+        help = "Hue path for --color-space hsl/hsv: short (default), long, cw (increasing hue) or ccw (decreasing hue)"
+    )]
+    pub hue_direction: crate::color_ops::mixing::HueDirection,
+
+    /// Distance method used to name stops after the nearest collection colors
+    #[arg(
+        long,
+        value_name = "METHOD",
+        default_value = "delta-e-2000",
+        help = "Distance method for naming stops and endpoints: delta-e-76, delta-e-2000 (default), euclidean-lab, lch, cmc (2:1), cmc11"
+    )]
+    pub distance_method: String,
+
     /// Generate SVG image of the gradient with specified filename
     #[arg(short = 'S',long, value_name = "FILENAME")]
     pub svg: Option<String>,
@@ -87,6 +438,22 @@ pub struct GradientArgs {
     #[arg(short = 'P', long, value_name = "FILENAME")]
     pub png: Option<String>,
 
+    /// Write the gradient's lightness profile as a grayscale PNG strip
+    #[arg(
+        long,
+        value_name = "FILENAME",
+        help = "Write a grayscale PNG where each column's gray level is the gradient's LAB L at that position (0 = L 0, 255 = L 100)"
+    )]
+    pub lightness_strip: Option<String>,
+
+    /// Write the gradient as a PDF axial shading for print
+    #[arg(
+        long,
+        value_name = "FILENAME",
+        help = "Write a single-page PDF (must end with .pdf) that paints the gradient as an axial shading of its RGB stops"
+    )]
+    pub pdf: Option<String>,
+
     /// Convert text elements to vector paths in SVG output (default: enabled)
     #[arg(
         long,
@@ -100,9 +467,13 @@ pub struct GradientArgs {
     #[arg(long)]
     pub no_legend: bool,
 
-    /// Width of the image in pixels (default: 1000)
-    #[arg(short = 'w', long, default_value = DEFAULT_WIDTH)]
-    pub width: u32,
+    /// Write SVG output without whitespace between elements and with the shortest hex colors
+    #[arg(long)]
+    pub minify: bool,
+
+    /// Width of the image in pixels, or a percentage of the container (e.g. 100%)
+    #[arg(short = 'w', long, default_value = DEFAULT_WIDTH, value_parser = parse_image_width)]
+    pub width: ImageWidth,
 
     /// Output gradient values every X percent
     #[arg(short = 't', long = "step", conflicts_with_all = ["stops"], help = "Output gradient values every X percent")]
@@ -112,6 +483,25 @@ pub struct GradientArgs {
     #[arg(short = 'T', long = "stops", default_value = "5", conflicts_with_all = ["step"], help = "Number of gradient stops using curve derivatives (default: 5)")]
     pub stops: usize,
 
+    /// Grow the stop count while neighboring stops stay at least a minimum ΔE apart
+    #[arg(
+        long = "auto-stops",
+        value_name = "max=N,min-delta-e=D",
+        value_parser = parse_auto_stops,
+        conflicts_with_all = ["step", "stops", "stops_simple"],
+        help = "Use as many equal-ΔE stops as fit with neighbors at least D (ΔE2000) apart, up to N"
+    )]
+    pub auto_stops: Option<AutoStops>,
+
+    /// Add midpoint stops wherever neighboring stops are more than D apart (ΔE2000)
+    #[arg(
+        long,
+        value_name = "D",
+        conflicts_with = "diverging",
+        help = "After placing stops, insert midpoints between neighbors more than D (ΔE2000) apart until all are within D, up to 256 stops"
+    )]
+    pub refine_threshold: Option<f64>,
+
     /// Use equally spaced gradient stops instead of intelligent placement
     #[arg(
         long = "stops-simple",
@@ -120,12 +510,101 @@ pub struct GradientArgs {
     )]
     pub stops_simple: bool,
 
-    /// Output format for file export (toml/t or yaml/y, default: yaml)
+    /// Resolution of the stop search along the curve (default: 10000)
+    #[arg(
+        long,
+        value_name = "COUNT",
+        default_value = DEFAULT_STOP_SAMPLES,
+        help = "Stop placement resolution: each stop is located to within 1/COUNT of the curve (min 100, default: 10000)"
+    )]
+    pub stop_samples: usize,
+
+    /// Print a table of gradient stops instead of structured output
+    #[arg(
+        long,
+        help = "Print a position/hex/RGB/WCAG luminance table to the terminal instead of YAML/TOML"
+    )]
+    pub table: bool,
+
+    /// List stops from the end position to the start position
+    #[arg(
+        long,
+        help = "Emit stops end-to-start (positions count down); colors and curve are unchanged"
+    )]
+    pub descending: bool,
+
+    /// Color property that orders the emitted stops
+    #[arg(
+        long,
+        value_enum,
+        value_name = "PROPERTY",
+        default_value = "position",
+        help = "Order emitted stops by position (default), lightness, hue or chroma; each stop keeps its position"
+    )]
+    pub sort_stops_by: StopSortKey,
+
+    /// Spacing of stop positions between the start and end positions
+    #[arg(
+        long,
+        value_enum,
+        default_value = "linear",
+        help = "Stop position spacing: linear (default) or log (denser near the start; requires --start-position > 0)"
+    )]
+    pub scale: PositionScale,
+
+    /// Extend the color path past both endpoints by this percentage of its length
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        value_parser = parse_percentage_f64,
+        default_value_t = 0.0,
+        help = "Extrapolate the LAB path beyond both endpoints by PERCENT of its length (stops at the sRGB gamut boundary), e.g. 10 or 10%"
+    )]
+    pub overshoot: f64,
+
+    /// Print only the color at this fraction (0.0-1.0) of the gradient
+    #[arg(
+        long,
+        value_name = "T",
+        conflicts_with = "diverging",
+        help = "Print only the color at fraction T (0.0-1.0) of the gradient, with easing applied, e.g. 0.37"
+    )]
+    pub at_single: Option<f64>,
+
+    /// Print only the colors at these fractions (0.0-1.0) of the gradient
+    #[arg(
+        long,
+        value_name = "T,...",
+        value_delimiter = ',',
+        num_args = 1..,
+        conflicts_with_all = ["diverging", "at_single"],
+        help = "Print only the colors at comma-separated fractions (0.0-1.0) of the gradient as YAML or TOML, e.g. 0,0.1,0.25,0.5,1.0; duplicates are dropped"
+    )]
+    pub sample_at: Vec<f64>,
+
+    /// Print the gradient SVG as a percent-encoded data URI instead of the report
+    #[arg(
+        long,
+        conflicts_with_all = ["at_single", "sample_at"],
+        help = "Print only the gradient SVG as a data:image/svg+xml URI for CSS url(), honoring --minify, --no-legend and --width"
+    )]
+    pub data_uri: bool,
+
+    /// Report the lowest WCAG contrast of any stop against a text color
+    #[arg(
+        long,
+        value_name = "COLOR",
+        conflicts_with_all = ["at_single", "sample_at", "data_uri"],
+        help = "Report the worst-case WCAG contrast ratio of the stops against text in COLOR, and at which position it occurs"
+    )]
+    pub text_contrast: Option<String>,
+
+    /// Output format for file export (toml/t, yaml/y, env/e, rust/rs or markdown/md, default: yaml)
     #[arg(
         short = 'o',
         long = "output",
-        value_enum,
-        help = "Output format: toml (t) or yaml (y), default: yaml"
+        value_parser = OutputFormat::parser(Self::OUTPUT_FORMATS),
+        help = "Output format: toml (t), yaml (y), env (e), rust (rs) or markdown (md), default: yaml"
     )]
     pub output_format: Option<OutputFormat>,
 
@@ -147,7 +626,23 @@ pub struct GradientArgs {
     pub func_filter: Option<String>,
 }
 
+/// Every option at its command-line default, with empty start and end colors
+impl Default for GradientArgs {
+    fn default() -> Self {
+        command_defaults("gradient", &["", ""])
+    }
+}
+
 impl GradientArgs {
+    /// Formats `--output` accepts
+    pub const OUTPUT_FORMATS: &'static [OutputFormat] = &[
+        OutputFormat::Toml,
+        OutputFormat::Yaml,
+        OutputFormat::Env,
+        OutputFormat::Rust,
+        OutputFormat::Markdown,
+    ];
+
     /// Validate the gradient arguments
     ///
     /// # Errors
@@ -157,6 +652,13 @@ impl GradientArgs {
     /// - Start position is greater than or equal to end position
     /// - Ease-in or ease-out values are outside 0.0-1.0 range
     /// - Width or steps values are zero or negative
+    /// - Stop sample count is below the supported minimum
+    /// - Logarithmic scale is used with a start position of 0
+    /// - Overshoot is outside 0-100 percent
+    /// - The single sample position is outside 0.0-1.0
+    /// - Stepped easing is combined with a diverging gradient
+    /// - CSS custom-property output is requested
+    /// - The refine threshold is not a positive number
     pub fn validate(&self) -> Result<()> {
         // Validate position bounds
         if self.start_position > MAX_PERCENTAGE || self.end_position > MAX_PERCENTAGE {
@@ -172,6 +674,38 @@ impl GradientArgs {
             ));
         }
 
+        if self.scale == PositionScale::Log && self.start_position == 0 {
+            return Err(ColorError::InvalidArguments(
+                "Logarithmic scale requires a start position greater than 0".to_string(),
+            ));
+        }
+
+        if !(0.0..=100.0).contains(&self.overshoot) {
+            return Err(ColorError::InvalidArguments(
+                "Overshoot must be between 0 and 100 percent".to_string(),
+            ));
+        }
+
+        if let Some(t) = self.at_single
+            && !(0.0..=1.0).contains(&t)
+        {
+            return Err(ColorError::InvalidArguments(
+                "--at-single must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+
+        if let Some(t) = self.sample_at.iter().find(|t| !(0.0..=1.0).contains(*t)) {
+            return Err(ColorError::InvalidArguments(format!(
+                "--sample-at positions must be between 0.0 and 1.0, got {t}"
+            )));
+        }
+
+        if self.diverging && self.stepped_easing().is_some() {
+            return Err(ColorError::InvalidArguments(
+                "steps() easing cannot be used with --diverging".to_string(),
+            ));
+        }
+
         // Validate ease values
         if self.ease_in < BEZIER_MIN || self.ease_in > BEZIER_MAX {
             return Err(ColorError::InvalidArguments(
@@ -193,8 +727,24 @@ impl GradientArgs {
             ));
         }
 
+        if let Some(strip) = &self.lightness_strip
+            && !strip.to_ascii_lowercase().ends_with(".png")
+        {
+            return Err(ColorError::InvalidArguments(format!(
+                "Lightness strip file '{strip}' must end with .png"
+            )));
+        }
+
+        if let Some(pdf) = &self.pdf
+            && !pdf.to_ascii_lowercase().ends_with(".pdf")
+        {
+            return Err(ColorError::InvalidArguments(format!(
+                "PDF file '{pdf}' must end with .pdf"
+            )));
+        }
+
         // Validate width
-        if self.width == 0 {
+        if self.width.pixels() == 0 {
             return Err(ColorError::InvalidArguments(
                 "Width must be greater than 0".to_string(),
             ));
@@ -216,9 +766,36 @@ impl GradientArgs {
             ));
         }
 
+        // Validate stop sample count
+        if self.stop_samples < MIN_STOP_SAMPLE_POINTS {
+            return Err(ColorError::InvalidArguments(format!(
+                "Stop samples must be at least {MIN_STOP_SAMPLE_POINTS}"
+            )));
+        }
+
+        if let Some(format) = &self.output_format {
+            format.check_supported("the gradient command", Self::OUTPUT_FORMATS)?;
+        }
+
+        if let Some(threshold) = self.refine_threshold
+            && !(threshold.is_finite() && threshold > 0.0)
+        {
+            return Err(ColorError::InvalidArguments(format!(
+                "Refine threshold must be a positive ΔE, got {threshold}"
+            )));
+        }
+
         Ok(())
     }
 
+    /// Stepped easing from `--easing steps(...)`, if requested
+    #[must_use]
+    pub fn stepped_easing(&self) -> Option<crate::gradient::EasingFunction> {
+        self.easing
+            .as_deref()
+            .and_then(|easing| crate::gradient::EasingFunction::parse_steps(easing).ok())
+    }
+
     /// Check if SVG generation should be enabled
     #[must_use]
     pub const fn should_generate_svg(&self) -> bool {
@@ -248,6 +825,18 @@ impl GradientArgs {
     }
 }
 
+/// Parse a command's arguments with only its positionals given
+///
+/// Keeps `Default` impls in step with the `#[arg(default_value...)]`
+/// attributes, so a new option needs no change where defaults are built.
+fn command_defaults<T: Args + FromArgMatches>(name: &'static str, positionals: &[&str]) -> T {
+    let matches = T::augment_args(clap::Command::new(name))
+        .no_binary_name(true)
+        .try_get_matches_from(positionals)
+        .expect("command-line defaults always parse");
+    T::from_arg_matches(&matches).expect("command-line defaults always parse")
+}
+
 /// Arguments for color analysis and conversion
 #[derive(Args, Clone)]
 pub struct ColorArgs {
@@ -260,7 +849,7 @@ pub struct ColorArgs {
         long,
         value_name = "METHOD",
         default_value = "lch",
-        help = "Distance calculation method: delta-e-76, delta-e-2000, euclidean-lab, lch"
+        help = "Distance calculation method: delta-e-76, delta-e-2000, euclidean-lab, lch, cmc (2:1), cmc11"
     )]
     pub distance_method: String,
 
@@ -293,51 +882,293 @@ pub struct ColorArgs {
     )]
     pub luminance: Option<f64>,
 
-    /// Output format for file export (toml/t or yaml/y, default: yaml)
+    /// Custom color collection to match against (semicolon CSV with Name;Hex|RGB[;Code])
     #[arg(
-        short = 'o',
-        long = "output",
-        value_enum,
-        help = "Output format: toml (t) or yaml (y), default: yaml"
+        long,
+        value_name = "CSV_FILE",
+        help = "Also match against colors from a CSV file (columns: Name, Hex or RGB, optional Code)"
     )]
-    pub output_format: Option<OutputFormat>,
+    pub collection_file: Option<String>,
 
-    /// Output filename (extension will be added based on format)
+    /// Built-in collections to search and report (default: all)
     #[arg(
-        short = 'f',
-        long = "file",
-        value_name = "FILENAME",
-        help = "Output filename (extension added automatically based on format)"
+        long,
+        value_name = "COLLECTIONS",
+        value_delimiter = ',',
+        help = "Comma-separated collections to search and report: css, ral-classic (ralc), ral-design (rald), all (default)"
     )]
-    pub output_file: Option<String>,
+    pub collections: Vec<crate::color_ops::analysis::hue::ColorCollectionType>,
 
-    /// Filter functionality blocks and fields to display (default: all)
+    /// Report WCAG contrast against another color (e.g. a background)
     #[arg(
-        long = "func",
-        value_name = "FILTERS",
-        help = "Filter blocks/fields: [all], [input], [conversion], [contrast], [grayscale], [color_collections], [color_schemes], [block.field], [!exclude]. Examples: [input,conversion], [contrast.wcag21_relative_luminance], [all,!color_collections.css_colors]"
+        long,
+        value_name = "COLOR",
+        help = "Report WCAG contrast ratio and AA/AAA compliance against the given color"
     )]
-    pub func_filter: Option<String>,
-}
-
-impl ColorArgs {
-    /// Validate the color arguments
-    ///
-    /// # Errors
-    ///
-    /// Returns `ColorError::InvalidArguments` if:
-    /// - Scheme strategy is not 'hsl' or 'lab'
-    /// - Relative luminance is outside 0.0-100.0 range
-    /// - Limit per collection is zero or negative
-    pub fn validate(&self) -> Result<()> {
-        // Validate scheme strategy
-        if !matches!(self.scheme_strategy.as_str(), "hsl" | "lab") {
-            return Err(ColorError::InvalidArguments(
-                "Scheme strategy must be either 'hsl' or 'lab'".to_string(),
-            ));
-        }
+    pub contrast_against: Option<String>,
 
-        // Validate relative luminance range
+    /// Add collection matches that stay legible on this background color
+    #[arg(
+        long,
+        value_name = "COLOR",
+        help = "Also list the closest collection colors that reach --min-ratio WCAG contrast against COLOR"
+    )]
+    pub accessible_against: Option<String>,
+
+    /// Minimum WCAG contrast ratio for --accessible-against matches
+    #[arg(
+        long,
+        value_name = "RATIO",
+        default_value_t = 4.5,
+        requires = "accessible_against",
+        help = "Minimum WCAG contrast ratio (1-21) for --accessible-against matches; 4.5 is AA for normal text"
+    )]
+    pub min_ratio: f64,
+
+    /// Add a complement tuned for maximum contrast against the input color
+    #[arg(
+        long,
+        help = "Add a complementary color whose lightness is chosen for maximum WCAG contrast with the input"
+    )]
+    pub high_contrast_complement: bool,
+
+    /// Nominal hue for schemes of a gray input, whose own hue is undefined
+    #[arg(
+        long,
+        value_name = "DEGREES",
+        allow_negative_numbers = true,
+        help = "Derive schemes of a near-gray input (LCH chroma < 2) from a color of the same lightness at this LCH hue"
+    )]
+    pub gray_hue: Option<f32>,
+
+    /// Analyze every scheme color and report its contrast against the input
+    #[arg(
+        long,
+        help = "Nest a full analysis (luminance, perception, accessibility) and the WCAG contrast against the input under each scheme color"
+    )]
+    pub analyze_schemes: bool,
+
+    /// Report the luminance-matched scheme variants
+    #[arg(
+        long,
+        help = "Add color_schemes.luminance_matched: scheme colors moved to the input's LAB lightness"
+    )]
+    pub luminance_matched: bool,
+
+    /// Write missing luminance-matched scheme variants as explicit nulls
+    #[arg(
+        long,
+        help = "Always include color_schemes.luminance_matched, with null for variants that were not computed (YAML; TOML has no null)"
+    )]
+    pub include_null_schemes: bool,
+
+    /// Report the error from converting the input LAB to sRGB and back
+    #[arg(
+        long,
+        help = "Convert input -> LAB -> sRGB -> LAB and report the ΔE2000 and RGB rounding error, flagging out-of-gamut inputs"
+    )]
+    pub round_trip_check: bool,
+
+    /// Report how clamping the input into sRGB changed it
+    #[arg(
+        long,
+        help = "Show the unclamped sRGB channels (may be <0 or >1), the clamped result and the ΔE2000 clamping introduced"
+    )]
+    pub clamp_report: bool,
+
+    /// Only match collection colors whose LAB lightness falls in this band
+    #[arg(
+        long = "lightness-range",
+        value_name = "[MIN...MAX]",
+        help = "Only match collection colors with LAB lightness in [min...max], e.g., [70...100]"
+    )]
+    pub lightness_range: Option<String>,
+
+    /// Display matched collection names in another language
+    #[arg(
+        long,
+        value_name = "LANG",
+        help = "Show matched color names in LANG (e.g. de) using the bundled color-table/names-LANG.csv; untranslated names stay canonical"
+    )]
+    pub names_lang: Option<String>,
+
+    /// User-supplied translation map for matched color names
+    #[arg(
+        long,
+        value_name = "CSV_FILE",
+        help = "Translate matched color names with a CSV file (columns: Name, Translation); overrides --names-lang"
+    )]
+    pub names_file: Option<String>,
+
+    /// Report every distance metric for each collection match
+    #[arg(
+        long,
+        help = "Add ΔE76, ΔE94, ΔE2000, Euclidean LAB and LCH distances to every collection match"
+    )]
+    pub all_distances: bool,
+
+    /// Drop collection matches whose confidence is below this threshold
+    #[arg(
+        long,
+        value_name = "CONFIDENCE",
+        help = "Hide collection matches with confidence below CONFIDENCE (0.0-1.0), e.g. 0.5 keeps matches within about ΔE 5.8"
+    )]
+    pub min_confidence: Option<f64>,
+
+    /// Mix the input toward a color (white by default) before analysis
+    #[arg(
+        long,
+        value_name = "[COLOR:]AMOUNT",
+        value_parser = parse_tint,
+        conflicts_with = "shade",
+        help = "Tint the input toward COLOR (default white) by AMOUNT (0-1 or 0-100%) before analysis, e.g. 0.2 or white:20%"
+    )]
+    pub tint: Option<MixToward>,
+
+    /// Mix the input toward a color (black by default) before analysis
+    #[arg(
+        long,
+        value_name = "[COLOR:]AMOUNT",
+        value_parser = parse_shade,
+        help = "Shade the input toward COLOR (default black) by AMOUNT (0-1 or 0-100%) before analysis, e.g. 0.3 or navy:30%"
+    )]
+    pub shade: Option<MixToward>,
+
+    /// Text size used to grade contrast against WCAG thresholds
+    #[arg(
+        long,
+        value_enum,
+        value_name = "SIZE",
+        help = "Grade contrast for normal or large text (adds a WCAG compliance level to contrast results)"
+    )]
+    pub text_size: Option<TextSize>,
+
+    /// Validate and compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
+
+    /// Decimal separator for standalone numbers in structured output
+    #[arg(
+        long,
+        value_enum,
+        value_name = "SEPARATOR",
+        default_value = ".",
+        help = "Decimal separator for standalone numbers (. or ,); values inside lab()/rgb() strings are unchanged"
+    )]
+    pub decimal_separator: DecimalSeparator,
+
+    /// How RGB values are written in structured output
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        default_value = "u8",
+        help = "RGB notation in structured output: u8 for rgb(255, 0, 0) or float for rgb(1.0, 0.0, 0.0)"
+    )]
+    pub rgb_format: RgbFormat,
+
+    /// Reference white for LAB input and the reported LAB values
+    #[arg(
+        long,
+        value_enum,
+        value_name = "WHITE",
+        default_value = "d65",
+        help = "Reference white of lab() input and LAB output: d65 (default, sRGB) or d50 (ICC profiles, print)"
+    )]
+    pub lab_variant: LabVariant,
+
+    /// Report blocks to include in the structured output
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        value_name = "SECTIONS",
+        conflicts_with_all = ["to", "compact"],
+        help = "Only include these report sections (comma-separated): input, conversion (color-spaces), contrast (accessibility), grayscale, collections, schemes; default all"
+    )]
+    pub sections: Vec<ReportSection>,
+
+    /// Print only the color converted to this format instead of the full report
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        help = "Convert to a single format and print it: hex, lab, rgb, hsl, hsv, cmyk, xyz, oklch, lch"
+    )]
+    pub to: Option<crate::format_utils::ColorFormat>,
+
+    /// Print a single summary line instead of the full report
+    #[arg(
+        long,
+        conflicts_with_all = ["to", "output_format", "output_file"],
+        help = "Print one line: hex, nearest name with collection and distance, and LCH"
+    )]
+    pub compact: bool,
+
+    /// Output format for file export (toml/t, yaml/y, env/e, markdown/md or css-vars/css, default: yaml)
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_parser = OutputFormat::parser(Self::OUTPUT_FORMATS),
+        help = "Output format: toml (t), yaml (y), env (e), markdown (md) or css-vars (css), default: yaml"
+    )]
+    pub output_format: Option<OutputFormat>,
+
+    /// Output filename (extension will be added based on format)
+    #[arg(
+        short = 'f',
+        long = "file",
+        value_name = "FILENAME",
+        help = "Output filename (extension added automatically based on format)"
+    )]
+    pub output_file: Option<String>,
+
+    /// Filter functionality blocks and fields to display (default: all)
+    #[arg(
+        long = "func",
+        value_name = "FILTERS",
+        help = "Filter blocks/fields: [all], [input], [conversion], [contrast], [grayscale], [color_collections], [color_schemes], [block.field], [!exclude]. Examples: [input,conversion], [contrast.wcag21_relative_luminance], [all,!color_collections.css_colors]"
+    )]
+    pub func_filter: Option<String>,
+}
+
+/// Every option at its command-line default, with an empty color
+impl Default for ColorArgs {
+    fn default() -> Self {
+        command_defaults("color", &[""])
+    }
+}
+
+impl ColorArgs {
+    /// Formats `--output` accepts
+    pub const OUTPUT_FORMATS: &'static [OutputFormat] = &[
+        OutputFormat::Toml,
+        OutputFormat::Yaml,
+        OutputFormat::Env,
+        OutputFormat::Markdown,
+        OutputFormat::CssVars,
+    ];
+
+    /// Validate the color arguments
+    ///
+    /// # Errors
+    ///
+    /// Returns `ColorError::InvalidArguments` if:
+    /// - Scheme strategy is not 'hsl' or 'lab'
+    /// - Relative luminance is outside 0.0-100.0 range
+    /// - Limit per collection is zero or negative
+    /// - Lightness range is not a valid `[min...max]` band within 0-100
+    /// - Minimum confidence is outside 0.0-1.0
+    /// - Minimum contrast ratio is outside 1.0-21.0
+    pub fn validate(&self) -> Result<()> {
+        // Validate scheme strategy
+        if !matches!(self.scheme_strategy.as_str(), "hsl" | "lab") {
+            return Err(ColorError::InvalidArguments(
+                "Scheme strategy must be either 'hsl' or 'lab'".to_string(),
+            ));
+        }
+
+        // Validate relative luminance range
         if let Some(relative_lum) = self.relative_luminance {
             if !(0.0..=1.0).contains(&relative_lum) {
                 return Err(ColorError::InvalidArguments(
@@ -355,6 +1186,20 @@ impl ColorArgs {
             }
         }
 
+        if let Some(confidence) = self.min_confidence
+            && !(0.0..=1.0).contains(&confidence)
+        {
+            return Err(ColorError::InvalidArguments(
+                "Minimum confidence must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+
+        if !(1.0..=21.0).contains(&self.min_ratio) {
+            return Err(ColorError::InvalidArguments(
+                "Minimum contrast ratio must be between 1.0 and 21.0".to_string(),
+            ));
+        }
+
         // Ensure both luminance arguments are not provided simultaneously
         if self.relative_luminance.is_some() && self.luminance.is_some() {
             return Err(ColorError::InvalidArguments(
@@ -363,6 +1208,19 @@ impl ColorArgs {
             ));
         }
 
+        if let Some(format) = &self.output_format {
+            format.check_supported("the color command", Self::OUTPUT_FORMATS)?;
+        }
+
+        if let Some(ref lightness_range) = self.lightness_range {
+            let range = Range::parse(lightness_range)?;
+            if range.min < 0.0 || range.max > 100.0 || range.min > range.max {
+                return Err(ColorError::InvalidArguments(
+                    "Lightness range must be 0-100 with min <= max".to_string(),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -380,7 +1238,7 @@ pub struct HueArgs {
         short = 'H',
         long = "h-range",
         value_name = "[MIN...MAX]",
-        help = "Filter by hue range [min...max] degrees, e.g., [300...360] or [-25...25]"
+        help = "Filter by hue range [min...max] degrees, e.g., [300...360] or [-25...25]; ( or ) makes a bound exclusive, e.g., [0...30)"
     )]
     pub hue_range: Option<String>,
 
@@ -402,6 +1260,14 @@ pub struct HueArgs {
     )]
     pub chroma_range: Option<String>,
 
+    /// Minimum LCH chroma; excludes grays and other near-neutral colors
+    #[arg(
+        long = "min-chroma",
+        value_name = "CHROMA",
+        help = "Exclude colors with LCH chroma below CHROMA (removes grays whose hue is meaningless), e.g., 5"
+    )]
+    pub min_chroma: Option<f64>,
+
     /// Generate horizontal gradient layout
     #[arg(
         short = 'g',
@@ -459,7 +1325,7 @@ pub struct HueArgs {
     #[arg(
         short = 'o',
         long = "output",
-        value_enum,
+        value_parser = OutputFormat::parser(OutputFormat::STRUCTURED),
         help = "Output format: toml (t) or yaml (y), default: yaml"
     )]
     pub output_format: Option<OutputFormat>,
@@ -518,86 +1384,1185 @@ pub struct HueArgs {
         help = "Custom header text for palette layout (replaces default collection title)"
     )]
     pub header_text: Option<String>,
-}
-/// Range specification for filtering
-#[derive(Debug, Clone, PartialEq)]
-pub struct Range {
-    pub min: f64,
-    pub max: f64,
+
+    /// Report differently named colors within this ΔE2000 of each other
+    #[arg(
+        long = "find-duplicates",
+        value_name = "DELTA_E",
+        help = "Report pairs of differently named colors within DELTA_E (ΔE2000) of each other"
+    )]
+    pub find_duplicates: Option<f64>,
+
+    /// Properties that order the listed colors, primary first
+    #[arg(
+        long,
+        value_enum,
+        value_name = "PRIMARY[,SECONDARY]",
+        value_delimiter = ',',
+        default_value = "hue",
+        help = "Order colors by hue (default), vibrance (most vibrant first), neutrality (grayest first) or name; a second key breaks ties, e.g. vibrance,name"
+    )]
+    pub sort: Vec<CollectionSortKey>,
+
+    /// Compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
 }
 
-impl Range {
-    /// Parse range from bracket syntax: [min...max]
-    ///
-    /// # Errors
-    /// Returns error if range format is invalid or values cannot be parsed
-    pub fn parse(input: &str) -> crate::error::Result<Self> {
-        if !input.starts_with('[') || !input.ends_with(']') {
-            return Err(crate::error::ColorError::ParseError(
-                "Range must be in format [min...max]".to_string(),
-            ));
-        }
+/// Arguments for tints-and-shades ramp generation
+#[derive(Debug, Clone, Args)]
+pub struct RampArgs {
+    /// Base color at the center of the ramp (any format supported by the color command)
+    #[arg(value_name = "COLOR")]
+    pub color: String,
 
-        let inner = &input[1..input.len() - 1];
-        let parts: Vec<&str> = inner.split("...").collect();
+    /// Number of tints (base mixed toward white in LAB)
+    #[arg(long, value_name = "COUNT", default_value = DEFAULT_RAMP_TINTS)]
+    pub tints: usize,
 
-        if parts.len() != 2 {
-            return Err(crate::error::ColorError::ParseError(
-                "Range must contain exactly one '...' separator".to_string(),
-            ));
-        }
+    /// Number of shades (base mixed toward black in LAB)
+    #[arg(long, value_name = "COUNT", default_value = DEFAULT_RAMP_SHADES)]
+    pub shades: usize,
 
-        let min = parts[0].parse::<f64>().map_err(|_| {
-            crate::error::ColorError::ParseError(format!("Invalid minimum value: {}", parts[0]))
-        })?;
-        let max = parts[1].parse::<f64>().map_err(|_| {
-            crate::error::ColorError::ParseError(format!("Invalid maximum value: {}", parts[1]))
-        })?;
+    /// Hold LCH hue constant while adjusting lightness instead of mixing in LAB
+    #[arg(long)]
+    pub preserve_hue: bool,
 
-        Ok(Self { min, max })
-    }
+    /// Output format (toml/t, yaml/y or env/e, default: yaml)
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_parser = OutputFormat::parser(Self::OUTPUT_FORMATS),
+        help = "Output format: toml (t), yaml (y) or env (e), default: yaml"
+    )]
+    pub output_format: Option<OutputFormat>,
 
-    /// Check if value is within range, supporting wraparound for hue values
-    #[must_use]
-    pub fn contains_with_wrap(&self, value: f64, _wrap_limit: f64) -> bool {
-        if self.min <= self.max {
-            // Normal range
-            value >= self.min && value <= self.max
-        } else {
-            // Wraparound range (e.g., [350...30] for hue)
-            value >= self.min || value <= self.max
-        }
-    }
+    /// Output filename
+    #[arg(
+        short = 'f',
+        long = "file",
+        value_name = "FILENAME",
+        help = "Save the ramp to FILENAME in the selected output format"
+    )]
+    pub output_file: Option<String>,
 
-    /// Check if value is within range for linear values (lightness, chroma)
-    #[must_use]
-    pub fn contains_linear(&self, value: f64) -> bool {
-        value >= self.min && value <= self.max
-    }
+    /// Compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
 }
 
-impl HueArgs {
-    /// Validate the hue arguments
+impl RampArgs {
+    /// Maximum number of tints or shades on either side of the base
+    pub const MAX_STEPS: usize = 50;
+
+    /// Formats `--output` accepts
+    pub const OUTPUT_FORMATS: &'static [OutputFormat] =
+        &[OutputFormat::Toml, OutputFormat::Yaml, OutputFormat::Env];
+
+    /// Validate ramp arguments
     ///
     /// # Errors
-    /// Returns error if visual output parameters are inconsistent or invalid
+    /// Returns `ColorError::InvalidArguments` if the tint or shade count exceeds [`Self::MAX_STEPS`]
     pub fn validate(&self) -> Result<()> {
-        // Validate collection name
-        match self.collection.as_str() {
-            "css" | "ralc" | "rald" => {}
-            _ => {
-                return Err(ColorError::InvalidArguments(format!(
-                    "Invalid collection '{}'. Must be: css, ralc, or rald",
-                    self.collection
-                )));
-            }
+        if self.tints > Self::MAX_STEPS || self.shades > Self::MAX_STEPS {
+            return Err(ColorError::InvalidArguments(format!(
+                "--tints and --shades must be at most {}",
+                Self::MAX_STEPS
+            )));
+        }
+        if let Some(format) = &self.output_format {
+            format.check_supported("the ramp command", Self::OUTPUT_FORMATS)?;
         }
+        Ok(())
+    }
+}
 
-        // Validate hue range if provided
-        if let Some(ref hue_range) = self.hue_range {
-            let range = Range::parse(hue_range)?;
-            // Hue can be negative for wraparound, but validate reasonable bounds
-            if range.min < -360.0 || range.max > 720.0 {
+/// Arguments for gradients from a base color to its harmony colors
+#[derive(Debug, Clone, Args)]
+pub struct HarmonyGradientArgs {
+    /// Base color every gradient starts from (any format supported by the color command)
+    #[arg(value_name = "COLOR")]
+    pub base: String,
+
+    /// Harmony relationship whose colors end the gradients
+    #[arg(long, value_enum, default_value = "complementary")]
+    pub relationship: HarmonyRelationship,
+
+    /// Color scheme strategy to use
+    #[arg(
+        long = "schemes",
+        value_name = "STRATEGY",
+        default_value = "lab",
+        help = "Color scheme strategy: hsl or lab (default: lab)"
+    )]
+    pub scheme_strategy: String,
+
+    /// Ease-in control point for cubic-bezier (0.0-1.0, default: 0.65)
+    #[arg(long, default_value = DEFAULT_EASE_IN)]
+    pub ease_in: f64,
+
+    /// Ease-out control point for cubic-bezier (0.0-1.0, default: 0.35)
+    #[arg(long, default_value = DEFAULT_EASE_OUT)]
+    pub ease_out: f64,
+
+    /// Number of stops in each gradient (default: 5)
+    #[arg(short = 'T', long, default_value = "5")]
+    pub stops: usize,
+
+    /// Output format (toml/t or yaml/y, default: yaml)
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_parser = OutputFormat::parser(OutputFormat::STRUCTURED),
+        help = "Output format: toml (t) or yaml (y), default: yaml"
+    )]
+    pub output_format: Option<OutputFormat>,
+
+    /// Output filename
+    #[arg(
+        short = 'f',
+        long = "file",
+        value_name = "FILENAME",
+        help = "Save the gradients to FILENAME in the selected output format"
+    )]
+    pub output_file: Option<String>,
+
+    /// Compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
+}
+
+impl HarmonyGradientArgs {
+    /// Validate harmony gradient arguments
+    ///
+    /// # Errors
+    /// Returns `ColorError::InvalidArguments` if the scheme strategy is unknown,
+    /// an ease value is outside 0.0-1.0, fewer than 2 stops are requested, or
+    /// the output format is not YAML or TOML
+    pub fn validate(&self) -> Result<()> {
+        if !matches!(self.scheme_strategy.as_str(), "hsl" | "lab") {
+            return Err(ColorError::InvalidArguments(format!(
+                "Invalid scheme strategy '{}'. Must be: hsl or lab",
+                self.scheme_strategy
+            )));
+        }
+        if !(BEZIER_MIN..=BEZIER_MAX).contains(&self.ease_in)
+            || !(BEZIER_MIN..=BEZIER_MAX).contains(&self.ease_out)
+        {
+            return Err(ColorError::InvalidArguments(
+                "Ease values must be between 0.0 and 1.0".to_string(),
+            ));
+        }
+        if self.stops < 2 {
+            return Err(ColorError::InvalidArguments(
+                "A harmony gradient needs at least 2 stops".to_string(),
+            ));
+        }
+        if let Some(format) = &self.output_format {
+            format.check_supported("the harmony-gradient command", OutputFormat::STRUCTURED)?;
+        }
+        Ok(())
+    }
+}
+
+/// Arguments for seeded random palette generation
+#[derive(Debug, Clone, Args)]
+pub struct RandomArgs {
+    /// Number of colors to generate
+    #[arg(short = 'n', long, value_name = "N", default_value_t = 5)]
+    pub count: usize,
+
+    /// Seed for the generator; the same seed always gives the same palette
+    #[arg(
+        long,
+        value_name = "S",
+        help = "Seed for a reproducible palette; a time-based seed is used and reported when omitted"
+    )]
+    pub seed: Option<u64>,
+
+    /// Smallest LCH chroma of a color
+    #[arg(long, value_name = "CHROMA", default_value_t = 0.0)]
+    pub min_chroma: f64,
+
+    /// Lightness band [min...max] (0-100)
+    #[arg(
+        long = "l-range",
+        value_name = "RANGE",
+        help = "Lightness band [min...max] percent, e.g., [40...80] (default: [0...100])"
+    )]
+    pub lightness_range: Option<String>,
+
+    /// Smallest ΔE2000 between any two colors
+    #[arg(long, value_name = "DISTANCE", default_value_t = 10.0)]
+    pub min_delta_e: f64,
+
+    /// Output format (toml/t or yaml/y, default: yaml)
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_parser = OutputFormat::parser(OutputFormat::STRUCTURED),
+        help = "Output format: toml (t) or yaml (y), default: yaml"
+    )]
+    pub output_format: Option<OutputFormat>,
+
+    /// Output filename
+    #[arg(
+        short = 'f',
+        long = "file",
+        value_name = "FILENAME",
+        help = "Save the palette to FILENAME in the selected output format"
+    )]
+    pub output_file: Option<String>,
+
+    /// Compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
+}
+
+impl RandomArgs {
+    /// Largest palette the random command generates
+    pub const MAX_COUNT: usize = 256;
+
+    /// Lightness band `[min, max]` from `--l-range`, the full 0-100 by default
+    ///
+    /// # Errors
+    /// Returns `ColorError::InvalidArguments` if the range cannot be parsed or
+    /// falls outside 0-100
+    pub fn lightness_band(&self) -> Result<[f64; 2]> {
+        let Some(range) = &self.lightness_range else {
+            return Ok([0.0, 100.0]);
+        };
+        let range = Range::parse(range)?;
+        if range.min < 0.0 || range.max > 100.0 || range.min > range.max {
+            return Err(ColorError::InvalidArguments(format!(
+                "Lightness range must lie within [0...100] with min <= max, got [{}...{}]",
+                range.min, range.max
+            )));
+        }
+        Ok([range.min, range.max])
+    }
+
+    /// Validate random palette arguments
+    ///
+    /// # Errors
+    /// Returns `ColorError::InvalidArguments` if the count is 0 or above
+    /// [`Self::MAX_COUNT`], a minimum is negative, the lightness range is
+    /// invalid, or the output format is not YAML or TOML
+    pub fn validate(&self) -> Result<()> {
+        if self.count == 0 || self.count > Self::MAX_COUNT {
+            return Err(ColorError::InvalidArguments(format!(
+                "Count must be between 1 and {}",
+                Self::MAX_COUNT
+            )));
+        }
+        if [self.min_chroma, self.min_delta_e]
+            .iter()
+            .any(|value| value.is_nan() || *value < 0.0)
+        {
+            return Err(ColorError::InvalidArguments(
+                "--min-chroma and --min-delta-e must be non-negative".to_string(),
+            ));
+        }
+        self.lightness_band()?;
+        if let Some(format) = &self.output_format {
+            format.check_supported("the random command", OutputFormat::STRUCTURED)?;
+        }
+        Ok(())
+    }
+}
+
+/// Arguments for locating a color on a named colormap
+#[derive(Debug, Clone, Args)]
+pub struct ScalePositionArgs {
+    /// Color to locate (any format supported by the color command)
+    #[arg(value_name = "COLOR")]
+    pub color: String,
+
+    /// Colormap to search
+    #[arg(
+        long,
+        value_enum,
+        value_name = "NAME",
+        default_value = "viridis",
+        help = "Colormap to search: viridis, magma, inferno or plasma"
+    )]
+    pub scale: crate::color_ops::colormaps::Colormap,
+
+    /// Output format (toml/t or yaml/y, default: yaml)
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_parser = OutputFormat::parser(OutputFormat::STRUCTURED),
+        help = "Output format: toml (t) or yaml (y), default: yaml"
+    )]
+    pub output_format: Option<OutputFormat>,
+
+    /// Output filename
+    #[arg(
+        short = 'f',
+        long = "file",
+        value_name = "FILENAME",
+        help = "Save the result to FILENAME in the selected output format"
+    )]
+    pub output_file: Option<String>,
+
+    /// Compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
+}
+
+impl ScalePositionArgs {
+    /// Validate scale position arguments
+    ///
+    /// # Errors
+    /// Returns `ColorError::InvalidArguments` if the output format is not YAML or TOML
+    pub fn validate(&self) -> Result<()> {
+        if let Some(format) = &self.output_format {
+            format.check_supported("the scale-position command", OutputFormat::STRUCTURED)?;
+        }
+        Ok(())
+    }
+}
+
+/// Arguments for converting a list of colors to one format
+#[derive(Debug, Clone, Args)]
+pub struct ConvertArgs {
+    /// Colors to convert (any format supported by the color command)
+    #[arg(required_unless_present = "input_file")]
+    pub colors: Vec<String>,
+
+    /// File with one color per line, converted after any colors given as arguments; `-` reads stdin
+    #[arg(long, value_name = "FILENAME")]
+    pub input_file: Option<String>,
+
+    /// How the input is split into colors (lines, csv, whitespace)
+    #[arg(long, value_enum, value_name = "FORMAT", default_value = "lines")]
+    pub stdin_format: StdinFormat,
+
+    /// Format to convert every color to
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        help = "Target format: hex, lab, rgb, hsl, hsv, cmyk, xyz, oklch, lch"
+    )]
+    pub to: crate::format_utils::ColorFormat,
+
+    /// Reference white for `lab()` input and LAB output, as in the color command
+    #[arg(
+        long,
+        value_enum,
+        value_name = "WHITE",
+        default_value = "d65",
+        help = "Reference white of lab() input and LAB output: d65 (default, sRGB) or d50 (ICC profiles, print)"
+    )]
+    pub lab_variant: LabVariant,
+
+    /// Also list the nearest named color of each collection for every input
+    #[arg(long)]
+    pub names: bool,
+
+    /// Match `--names` on one worker thread per core; output order is unchanged
+    #[cfg(feature = "parallel")]
+    #[arg(long, requires = "names")]
+    pub parallel: bool,
+
+    /// Output format (toml/t, yaml/y or markdown/md, default: yaml)
+    #[arg(
+        short = 'o',
+        long = "output",
+        value_parser = OutputFormat::parser(Self::OUTPUT_FORMATS),
+        help = "Output format: toml (t), yaml (y) or markdown (md), default: yaml"
+    )]
+    pub output_format: Option<OutputFormat>,
+
+    /// Output filename
+    #[arg(
+        short = 'f',
+        long = "file",
+        value_name = "FILENAME",
+        help = "Save the table to FILENAME in the selected output format"
+    )]
+    pub output_file: Option<String>,
+
+    /// Compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
+}
+
+impl ConvertArgs {
+    /// Formats `--output` accepts
+    pub const OUTPUT_FORMATS: &'static [OutputFormat] = &[
+        OutputFormat::Toml,
+        OutputFormat::Yaml,
+        OutputFormat::Markdown,
+    ];
+
+    /// Validate convert arguments
+    ///
+    /// # Errors
+    /// Returns `ColorError::InvalidArguments` if the output format is not
+    /// YAML, TOML or Markdown
+    pub fn validate(&self) -> Result<()> {
+        if let Some(format) = &self.output_format {
+            format.check_supported("the convert command", Self::OUTPUT_FORMATS)?;
+        }
+        Ok(())
+    }
+}
+
+/// Harmony relationships a harmony gradient can target
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum HarmonyRelationship {
+    /// The opposite hue
+    #[default]
+    Complementary,
+    /// The two hues either side of the complement
+    SplitComplementary,
+    /// The two hues a third of the circle away
+    Triadic,
+    /// The three other corners of a rectangle on the hue circle
+    Tetradic,
+}
+
+impl HarmonyRelationship {
+    /// Name used for this relationship in color scheme results
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Complementary => "complementary",
+            Self::SplitComplementary => "split_complementary",
+            Self::Triadic => "triadic",
+            Self::Tetradic => "tetradic",
+        }
+    }
+}
+
+/// Arguments for plain-English color description
+#[derive(Debug, Clone, Args)]
+pub struct DescribeArgs {
+    /// Color to describe (any format supported by the color command)
+    #[arg(value_name = "COLOR")]
+    pub color: String,
+
+    /// Output style: a phrase or a JSON object
+    #[arg(long, value_enum, default_value = "phrase")]
+    pub format: DescribeFormat,
+}
+
+/// Arguments for exporting a whole color collection
+#[derive(Debug, Clone, Args)]
+pub struct ExportCollectionArgs {
+    /// Color collection to export (css, ralc, rald)
+    #[arg(long, value_name = "COLLECTION")]
+    pub collection: String,
+
+    /// Output file; the format follows the extension (.json, .yaml or .yml)
+    #[arg(long, value_name = "FILENAME")]
+    pub output: String,
+
+    /// Compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
+}
+
+impl ExportCollectionArgs {
+    /// Validate the export arguments
+    ///
+    /// # Errors
+    /// Returns error if the collection is unknown or the output extension is not
+    /// .json, .yaml or .yml
+    pub fn validate(&self) -> Result<()> {
+        if !matches!(self.collection.as_str(), "css" | "ralc" | "rald") {
+            return Err(ColorError::InvalidArguments(format!(
+                "Invalid collection '{}'. Must be: css, ralc, or rald",
+                self.collection
+            )));
+        }
+        self.export_format()?;
+        Ok(())
+    }
+
+    /// Serialization format implied by the output file extension
+    ///
+    /// # Errors
+    /// Returns error if the extension is not .json, .yaml or .yml
+    pub fn export_format(&self) -> Result<ExportFormat> {
+        let extension = std::path::Path::new(&self.output)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("json") => Ok(ExportFormat::Json),
+            Some("yaml" | "yml") => Ok(ExportFormat::Yaml),
+            _ => Err(ColorError::InvalidArguments(format!(
+                "Output file '{}' must end with .json, .yaml or .yml",
+                self.output
+            ))),
+        }
+    }
+}
+
+/// Arguments for checking a palette against a display gamut
+#[derive(Debug, Clone, Args)]
+pub struct GamutReportArgs {
+    /// Palette file with one color per line (any format supported by the color command); `-` reads stdin
+    #[arg(long, value_name = "FILENAME")]
+    pub input_file: String,
+
+    /// How the input is split into colors (lines, csv, whitespace)
+    #[arg(long, value_enum, value_name = "FORMAT", default_value = "lines")]
+    pub stdin_format: StdinFormat,
+
+    /// Target gamut to check against (srgb, p3)
+    #[arg(long, value_enum, default_value = "srgb")]
+    pub gamut: crate::color_ops::Gamut,
+
+    /// Output format (toml/t or yaml/y, default: yaml)
+    #[arg(short = 'o', long = "output", value_parser = OutputFormat::parser(OutputFormat::STRUCTURED))]
+    pub output_format: Option<OutputFormat>,
+}
+
+/// Arguments for rewriting colors in a canonical notation
+#[derive(Debug, Clone, Args)]
+pub struct CanonicalizeArgs {
+    /// Colors to rewrite (any format supported by the color command)
+    #[arg(required_unless_present = "input_file")]
+    pub colors: Vec<String>,
+
+    /// File with one color per line, rewritten after any colors given as arguments; `-` reads stdin
+    #[arg(long, value_name = "FILENAME")]
+    pub input_file: Option<String>,
+
+    /// How the input is split into colors (lines, csv, whitespace)
+    #[arg(long, value_enum, value_name = "FORMAT", default_value = "lines")]
+    pub stdin_format: StdinFormat,
+
+    /// Notation to emit (hex6, hex-lower, rgb, hsl)
+    #[arg(long, value_enum, default_value = "hex6")]
+    pub notation: Notation,
+}
+
+/// Arguments for comparing two colors within a tolerance
+#[derive(Debug, Clone, Args)]
+pub struct EqualArgs {
+    /// First color (any format supported by the color command)
+    #[arg(value_name = "COLOR")]
+    pub first: String,
+
+    /// Second color
+    #[arg(value_name = "OTHER")]
+    pub second: String,
+
+    /// Largest distance at which the colors still count as equal
+    #[arg(long, value_name = "DISTANCE", default_value_t = 1.0)]
+    pub tolerance: f64,
+
+    /// Distance calculation method: delta-e-76, delta-e-2000, euclidean-lab, lch, cmc, cmc11
+    #[arg(long, value_name = "METHOD", default_value = "delta-e-2000")]
+    pub distance_method: String,
+}
+
+impl EqualArgs {
+    /// Exit code when the colors are within the tolerance
+    pub const EXIT_EQUAL: i32 = 0;
+    /// Exit code when the colors are farther apart than the tolerance
+    pub const EXIT_DIFFERENT: i32 = 1;
+    /// Exit code for invalid colors or options, matching clap's usage errors
+    pub const EXIT_ERROR: i32 = 2;
+
+    /// Exit code for a completed comparison
+    #[must_use]
+    pub const fn exit_code(equal: bool) -> i32 {
+        if equal {
+            Self::EXIT_EQUAL
+        } else {
+            Self::EXIT_DIFFERENT
+        }
+    }
+}
+
+/// Arguments for mixing two colors
+#[derive(Debug, Clone, Args)]
+pub struct MixArgs {
+    /// First color (any format supported by the color command)
+    #[arg(value_name = "COLOR")]
+    pub first: String,
+
+    /// Second color
+    #[arg(value_name = "OTHER")]
+    pub second: String,
+
+    /// How far to move from the first color toward the second (0.0-1.0)
+    #[arg(long, value_name = "AMOUNT", default_value_t = 0.5)]
+    pub amount: f32,
+
+    /// Color space to mix in (lab, lch, rgb, hsl, hsv, cam16)
+    #[arg(long, value_enum, value_name = "SPACE", default_value = "lab")]
+    pub color_space: crate::color_ops::ColorSpace,
+
+    /// Mix at the point with equal ΔE2000 to both colors instead of at --amount
+    #[arg(long, conflicts_with_all = ["amount", "color_space"])]
+    pub perceptual_midpoint: bool,
+}
+
+impl MixArgs {
+    /// Validate mix arguments
+    ///
+    /// # Errors
+    /// Returns `ColorError::InvalidArguments` if the amount is outside 0.0-1.0
+    pub fn validate(&self) -> Result<()> {
+        if !(0.0..=1.0).contains(&self.amount) {
+            return Err(ColorError::InvalidArguments(format!(
+                "Mix amount must be between 0.0 and 1.0, got {}",
+                self.amount
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Arguments for searching a collection by code prefix
+#[derive(Debug, Clone, Args)]
+pub struct SearchArgs {
+    /// Code prefix to match; case and spaces are ignored, so `RAL70` matches `RAL 7000`
+    #[arg(long, value_name = "PREFIX")]
+    pub code: String,
+
+    /// Color collection to search (css, ralc, rald)
+    #[arg(long, value_name = "COLLECTION", default_value = "ralc")]
+    pub collection: String,
+}
+
+/// Arguments for rendering a palette contact sheet
+#[derive(Debug, Clone, Args)]
+pub struct PaletteArgs {
+    /// Palette file with one color per line (any format supported by the color command); `-` reads stdin
+    #[arg(value_name = "FILENAME")]
+    pub input_file: String,
+
+    /// How the input is split into colors (lines, csv, whitespace)
+    #[arg(long, value_enum, value_name = "FORMAT", default_value = "lines")]
+    pub stdin_format: StdinFormat,
+
+    /// PNG file to write the contact sheet to
+    #[arg(long, value_name = "FILENAME")]
+    pub sheet: String,
+
+    /// Number of swatches per row; rows are added as needed
+    #[arg(long, value_name = "COUNT", default_value_t = 8)]
+    pub columns: u32,
+
+    /// Width and height of each swatch in pixels
+    #[arg(long, value_name = "PIXELS", default_value_t = 120)]
+    pub swatch_size: u32,
+
+    /// Font size for swatch labels in points
+    #[arg(short = 's', long, value_name = "SIZE", default_value = DEFAULT_FONT_SIZE)]
+    pub font_size: u32,
+
+    /// Border width around each swatch in pixels
+    #[arg(short = 'b', long, value_name = "PIXELS", default_value = DEFAULT_BORDER_WIDTH)]
+    pub border_width: u32,
+
+    /// Border color around each swatch (color name or hex)
+    #[arg(long, value_name = "COLOR", default_value = DEFAULT_BORDER_COLOR)]
+    pub border_color: String,
+
+    /// Compute without writing any files (set from the global `--dry-run`)
+    #[arg(skip)]
+    pub dry_run: bool,
+}
+
+impl PaletteArgs {
+    /// Validate the contact sheet arguments
+    ///
+    /// # Errors
+    /// Returns error if the column count or swatch size is zero, or the sheet
+    /// file does not end with .png
+    pub fn validate(&self) -> Result<()> {
+        if self.columns == 0 {
+            return Err(ColorError::InvalidArguments(
+                "--columns must be at least 1".to_string(),
+            ));
+        }
+        if self.swatch_size == 0 {
+            return Err(ColorError::InvalidArguments(
+                "--swatch-size must be at least 1".to_string(),
+            ));
+        }
+        if !self.sheet.to_ascii_lowercase().ends_with(".png") {
+            return Err(ColorError::InvalidArguments(format!(
+                "Sheet file '{}' must end with .png",
+                self.sheet
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Canonical color notations for the canonicalize command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Notation {
+    /// Uppercase 6-digit hex, e.g. `#FF0000`
+    Hex6,
+    /// Lowercase 6-digit hex, e.g. `#ff0000`
+    HexLower,
+    /// `rgb(255, 0, 0)`
+    Rgb,
+    /// `hsl(0, 100%, 50%)`
+    Hsl,
+}
+
+/// Property used to order gradient stops in the output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum StopSortKey {
+    /// Gradient position (the calculated order)
+    #[default]
+    Position,
+    /// LAB lightness, darkest first
+    Lightness,
+    /// LCH hue angle, from 0° upward
+    Hue,
+    /// LCH chroma, most neutral first
+    Chroma,
+}
+
+/// Property used to order the colors listed by the hue command
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum CollectionSortKey {
+    /// LCH hue angle
+    #[default]
+    Hue,
+    /// Vibrance (chroma weighted toward mid lightness), most vibrant first
+    Vibrance,
+    /// Neutrality (closeness to gray), most neutral first
+    Neutrality,
+    /// Color name, alphabetically
+    Name,
+}
+
+/// Spacing of gradient stop positions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum PositionScale {
+    /// Evenly spaced positions
+    #[default]
+    Linear,
+    /// Logarithmically spaced positions, e.g. for frequency or decibel axes
+    Log,
+}
+
+/// Arguments for listing supported features
+#[derive(Debug, Clone, Args)]
+pub struct InfoArgs {
+    /// What to list
+    #[arg(long, value_enum, value_name = "TOPIC", default_value = "formats")]
+    pub list: InfoTopic,
+}
+
+/// Topics the info command can list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum InfoTopic {
+    /// Input formats accepted wherever a color is expected
+    #[default]
+    Formats,
+}
+
+/// How a color list read from a file or stdin is split into colors
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum StdinFormat {
+    /// One color per line
+    #[default]
+    Lines,
+    /// Comma-separated colors; double quotes protect commas inside a value
+    Csv,
+    /// Colors separated by spaces, tabs or newlines
+    Whitespace,
+}
+
+impl StdinFormat {
+    /// Split `input` into `(line number, color)` pairs, skipping empty items
+    ///
+    /// Separators inside parentheses never split, so functional notations such
+    /// as `rgb(255, 0, 0)` stay whole in every format.
+    ///
+    /// # Example
+    /// ```rust
+    /// use color_rs::cli::StdinFormat;
+    ///
+    /// let colors = StdinFormat::Csv.tokenize("red, \"rgb(0, 0, 255)\", #00FF00");
+    /// let colors: Vec<&str> = colors.iter().map(|(_, color)| color.as_str()).collect();
+    /// assert_eq!(colors, ["red", "rgb(0, 0, 255)", "#00FF00"]);
+    /// ```
+    #[must_use]
+    pub fn tokenize(self, input: &str) -> Vec<(usize, String)> {
+        input
+            .lines()
+            .enumerate()
+            .flat_map(|(index, line)| {
+                let items = match self {
+                    Self::Lines => vec![line.trim().to_string()],
+                    Self::Csv => split_outside_groups(line, |c| c == ','),
+                    Self::Whitespace => split_outside_groups(line, char::is_whitespace),
+                };
+                items
+                    .into_iter()
+                    .filter(|item| !item.is_empty())
+                    .map(move |item| (index + 1, item))
+            })
+            .collect()
+    }
+}
+
+/// Split `line` at separators that are outside double quotes and parentheses
+///
+/// Quotes are removed from the items and every item is trimmed.
+fn split_outside_groups(line: &str, is_separator: impl Fn(char) -> bool) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut depth = 0usize;
+
+    for c in line.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '(' if !in_quotes => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' if !in_quotes => {
+                depth = depth.saturating_sub(1);
+                current.push(c);
+            }
+            c if !in_quotes && depth == 0 && is_separator(c) => {
+                items.push(current.trim().to_string());
+                current.clear();
+            }
+            c => current.push(c),
+        }
+    }
+    items.push(current.trim().to_string());
+    items
+}
+
+/// Serialization format for collection exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Yaml,
+}
+
+/// Automatic stop count constraints for gradients
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoStops {
+    /// Upper bound on the number of stops
+    pub max: usize,
+    /// Minimum ΔE2000 between neighboring stops
+    pub min_delta_e: f64,
+}
+
+impl AutoStops {
+    /// Parse from `max=N,min-delta-e=D` (keys in any order)
+    ///
+    /// # Errors
+    /// Returns error if a key is missing or unknown, a value cannot be parsed,
+    /// `max` is below 2, or `min-delta-e` is not positive
+    pub fn parse(input: &str) -> crate::error::Result<Self> {
+        let mut max = None;
+        let mut min_delta_e = None;
+
+        for part in input.split(',') {
+            let (key, value) = part.split_once('=').ok_or_else(|| {
+                ColorError::ParseError(format!("Expected key=value in auto-stops, got: {part}"))
+            })?;
+            match key.trim() {
+                "max" => {
+                    max = Some(value.trim().parse::<usize>().map_err(|_| {
+                        ColorError::ParseError(format!("Invalid max value: {value}"))
+                    })?);
+                }
+                "min-delta-e" => {
+                    min_delta_e = Some(value.trim().parse::<f64>().map_err(|_| {
+                        ColorError::ParseError(format!("Invalid min-delta-e value: {value}"))
+                    })?);
+                }
+                other => {
+                    return Err(ColorError::ParseError(format!(
+                        "Unknown auto-stops key: {other} (expected max or min-delta-e)"
+                    )));
+                }
+            }
+        }
+
+        let (Some(max), Some(min_delta_e)) = (max, min_delta_e) else {
+            return Err(ColorError::ParseError(
+                "Auto-stops must be in format max=N,min-delta-e=D".to_string(),
+            ));
+        };
+        if max < 2 {
+            return Err(ColorError::ParseError(
+                "Auto-stops max must be at least 2".to_string(),
+            ));
+        }
+        if min_delta_e <= 0.0 {
+            return Err(ColorError::ParseError(
+                "Auto-stops min-delta-e must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(Self { max, min_delta_e })
+    }
+}
+
+/// Target color and amount for `--tint` / `--shade`
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixToward {
+    /// Color to mix toward (any format supported by the color command)
+    pub target: String,
+    /// Fraction of the way toward the target, 0.0-1.0
+    pub amount: f32,
+}
+
+impl MixToward {
+    /// Parse from `[COLOR:]AMOUNT`, where AMOUNT is `0.2` or `20%`
+    ///
+    /// # Errors
+    /// Returns error if the amount cannot be parsed or is outside 0.0-1.0
+    pub fn parse(input: &str, default_target: &str) -> crate::error::Result<Self> {
+        let (target, amount) = input
+            .rsplit_once(':')
+            .map_or((default_target, input), |(target, amount)| {
+                (target.trim(), amount)
+            });
+        let amount = amount.trim();
+        let value = match amount.strip_suffix('%') {
+            Some(percent) => percent.trim().parse::<f32>().map(|p| p / 100.0),
+            None => amount.parse::<f32>(),
+        }
+        .map_err(|_| ColorError::ParseError(format!("Invalid mix amount: {amount}")))?;
+        if !(0.0..=1.0).contains(&value) {
+            return Err(ColorError::ParseError(format!(
+                "Mix amount must be between 0 and 1 (or 0% and 100%), got {amount}"
+            )));
+        }
+
+        Ok(Self {
+            target: target.to_string(),
+            amount: value,
+        })
+    }
+}
+
+/// Gradient image width: absolute pixels or a share of the containing element
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageWidth {
+    /// Fixed width in pixels
+    Pixels(u32),
+    /// Percentage of the container; the SVG scales through its viewBox
+    Percent(f64),
+}
+
+impl ImageWidth {
+    /// Parse `1500`, `1500px` or `100%`
+    ///
+    /// # Errors
+    /// Returns error if the value cannot be parsed or a percentage is outside (0, 100]
+    pub fn parse(input: &str) -> crate::error::Result<Self> {
+        let input = input.trim();
+        if let Some(percent) = input.strip_suffix('%') {
+            let percent = percent.trim().parse::<f64>().map_err(|_| {
+                ColorError::ParseError(format!("Invalid width percentage: {input}"))
+            })?;
+            if !(percent > 0.0 && percent <= 100.0) {
+                return Err(ColorError::ParseError(format!(
+                    "Width percentage must be in (0, 100], got {input}"
+                )));
+            }
+            return Ok(Self::Percent(percent));
+        }
+
+        input
+            .strip_suffix("px")
+            .unwrap_or(input)
+            .trim()
+            .parse::<u32>()
+            .map(Self::Pixels)
+            .map_err(|_| ColorError::ParseError(format!("Invalid width: {input}")))
+    }
+
+    /// Pixel width of the drawing: the explicit width, or the default canvas
+    /// width that a percentage SVG's viewBox is laid out in
+    #[must_use]
+    pub fn pixels(self) -> u32 {
+        match self {
+            Self::Pixels(pixels) => pixels,
+            Self::Percent(_) => DEFAULT_WIDTH.parse().unwrap_or(1500),
+        }
+    }
+
+    /// Value for the SVG `width` attribute
+    #[must_use]
+    pub fn svg_attribute(self) -> String {
+        match self {
+            Self::Pixels(pixels) => pixels.to_string(),
+            Self::Percent(percent) => format!("{percent}%"),
+        }
+    }
+}
+
+impl From<u32> for ImageWidth {
+    fn from(pixels: u32) -> Self {
+        Self::Pixels(pixels)
+    }
+}
+
+/// Range specification for filtering
+///
+/// Bounds are inclusive by default. Parentheses make a bound exclusive, so
+/// `[0...30)` and `[30...60)` tile the hue circle without sharing 30°.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    pub min: f64,
+    pub max: f64,
+    /// Whether a value equal to `min` is in the range
+    pub min_inclusive: bool,
+    /// Whether a value equal to `max` is in the range
+    pub max_inclusive: bool,
+}
+
+impl Range {
+    /// Parse range from bracket syntax: [min...max]
+    ///
+    /// `(` or `)` in place of a bracket makes that bound exclusive, e.g.
+    /// `[0...30)`.
+    ///
+    /// # Errors
+    /// Returns error if range format is invalid or values cannot be parsed
+    pub fn parse(input: &str) -> crate::error::Result<Self> {
+        let min_inclusive = match input.chars().next() {
+            Some('[') => true,
+            Some('(') => false,
+            _ => return Err(Self::format_error()),
+        };
+        let max_inclusive = match input.chars().last() {
+            Some(']') if input.len() > 1 => true,
+            Some(')') if input.len() > 1 => false,
+            _ => return Err(Self::format_error()),
+        };
+
+        let inner = &input[1..input.len() - 1];
+        let parts: Vec<&str> = inner.split("...").collect();
+
+        if parts.len() != 2 {
+            return Err(crate::error::ColorError::ParseError(
+                "Range must contain exactly one '...' separator".to_string(),
+            ));
+        }
+
+        let min = parts[0].parse::<f64>().map_err(|_| {
+            crate::error::ColorError::ParseError(format!("Invalid minimum value: {}", parts[0]))
+        })?;
+        let max = parts[1].parse::<f64>().map_err(|_| {
+            crate::error::ColorError::ParseError(format!("Invalid maximum value: {}", parts[1]))
+        })?;
+
+        Ok(Self {
+            min,
+            max,
+            min_inclusive,
+            max_inclusive,
+        })
+    }
+
+    fn format_error() -> crate::error::ColorError {
+        crate::error::ColorError::ParseError(
+            "Range must be in format [min...max]; use ( or ) for an exclusive bound".to_string(),
+        )
+    }
+
+    /// Check if value is within range, supporting wraparound for hue values
+    ///
+    /// Values and bounds are compared modulo `wrap_limit`, so `[300...360]`,
+    /// `[-25...25]` and `[350...30]` (min above max wraps through 0) all work
+    /// whatever the sign convention of `value`.
+    #[must_use]
+    pub fn contains_with_wrap(&self, value: f64, wrap_limit: f64) -> bool {
+        if self.max - self.min >= wrap_limit {
+            return true;
+        }
+
+        let value = value.rem_euclid(wrap_limit);
+        let min = self.min.rem_euclid(wrap_limit);
+        let max = self.max.rem_euclid(wrap_limit);
+        if value == min {
+            self.min_inclusive && (min != max || self.max_inclusive)
+        } else if value == max {
+            self.max_inclusive
+        } else if min <= max {
+            value > min && value < max
+        } else {
+            // Wraparound range (e.g., [350...30] for hue)
+            value > min || value < max
+        }
+    }
+
+    /// Check if value is within range for linear values (lightness, chroma)
+    #[must_use]
+    pub fn contains_linear(&self, value: f64) -> bool {
+        let above_min = if self.min_inclusive {
+            value >= self.min
+        } else {
+            value > self.min
+        };
+        let below_max = if self.max_inclusive {
+            value <= self.max
+        } else {
+            value < self.max
+        };
+        above_min && below_max
+    }
+}
+
+impl HueArgs {
+    /// Validate the hue arguments
+    ///
+    /// # Errors
+    /// Returns error if visual output parameters are inconsistent or invalid
+    pub fn validate(&self) -> Result<()> {
+        // Validate collection name
+        match self.collection.as_str() {
+            "css" | "ralc" | "rald" => {}
+            _ => {
+                return Err(ColorError::InvalidArguments(format!(
+                    "Invalid collection '{}'. Must be: css, ralc, or rald",
+                    self.collection
+                )));
+            }
+        }
+
+        if let Some(threshold) = self.find_duplicates
+            && (!threshold.is_finite() || threshold < 0.0)
+        {
+            return Err(ColorError::InvalidArguments(
+                "Duplicate threshold must be a non-negative ΔE value".to_string(),
+            ));
+        }
+
+        if self.sort.is_empty() || self.sort.len() > 2 || self.sort.first() == self.sort.get(1) {
+            return Err(ColorError::InvalidArguments(
+                "--sort takes one key or two different keys, e.g. vibrance,name".to_string(),
+            ));
+        }
+
+        if let Some(format) = &self.output_format {
+            format.check_supported("the hue command", OutputFormat::STRUCTURED)?;
+        }
+
+        // Validate hue range if provided
+        if let Some(ref hue_range) = self.hue_range {
+            let range = Range::parse(hue_range)?;
+            // Hue can be negative for wraparound, but validate reasonable bounds
+            if range.min < -360.0 || range.max > 720.0 {
                 return Err(ColorError::InvalidArguments(
                     "Hue range values should be between -360 and 720 degrees".to_string(),
                 ));
@@ -624,6 +2589,15 @@ impl HueArgs {
             }
         }
 
+        // Validate minimum chroma if provided
+        if let Some(min_chroma) = self.min_chroma
+            && !(0.0..=200.0).contains(&min_chroma)
+        {
+            return Err(ColorError::InvalidArguments(
+                "Minimum chroma must be between 0 and 200".to_string(),
+            ));
+        }
+
         // Validate visual output parameters
         if self.should_generate_visual() {
             // Check that SVG filename is provided
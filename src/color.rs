@@ -131,11 +131,53 @@ fn parse_color_with_parser(color_input: &str) -> Result<(Lab, crate::color_parse
     use crate::color_parser::ColorParser;
 
     let parser = ColorParser::new();
-    parser.parse(color_input).map_err(|e| {
-        ColorError::InvalidColor(format!("Failed to parse color '{color_input}': {e}"))
+    parser.parse(color_input).map_err(|e| match e {
+        // Already names the input and component, and keeps the number error as its source
+        ColorError::InvalidComponent { .. } => e,
+        _ => ColorError::InvalidColor(format!("Failed to parse color '{color_input}': {e}")),
     })
 }
 
+/// Parse color input, reading `lab()` values relative to `lab_variant`
+///
+/// Everything downstream works in D65 LAB, so D50 `lab()` input is adapted.
+///
+/// # Errors
+/// Returns an error if the input is not a valid color.
+pub fn parse_color_as(
+    color_input: &str,
+    lab_variant: crate::cli::LabVariant,
+) -> Result<(Lab, crate::color_parser::ColorFormat)> {
+    let (lab_color, input_format) = parse_color_with_parser(color_input)?;
+    let lab_color = match (input_format, lab_variant) {
+        (crate::color_parser::ColorFormat::Lab, crate::cli::LabVariant::D50) => {
+            crate::color_ops::conversion::lab_d50_to_lab(Lab::new(
+                lab_color.l,
+                lab_color.a,
+                lab_color.b,
+            ))
+        }
+        _ => lab_color,
+    };
+    Ok((lab_color, input_format))
+}
+
+/// Reject a translucent `color_input`, whose alpha `dropped_by` cannot carry
+///
+/// # Errors
+/// Returns an error naming the alpha and `dropped_by` if the input has alpha below 1.
+pub fn reject_alpha(color_input: &str, dropped_by: &str) -> Result<()> {
+    match crate::color_parser::CssColorParser::new().parse(color_input.trim()) {
+        Ok(parsed) if parsed.has_alpha() => Err(ColorError::InvalidArguments(format!(
+            "alpha {} would be dropped by {dropped_by}",
+            crate::precision_utils::PrecisionUtils::format_f64(
+                (parsed.a * 1000.0).round() / 1000.0
+            )
+        ))),
+        _ => Ok(()),
+    }
+}
+
 /// Get color name for a LAB color
 fn get_color_name_for_lab(lab_color: Lab) -> String {
     use crate::color_parser::ColorParser;
@@ -157,6 +199,16 @@ pub fn parse_color_input(input: &str) -> Result<Lab> {
     Ok(lab)
 }
 
+/// Move a LAB color toward a `--tint`/`--shade` target, mixing in LAB space
+fn mix_lab_toward(lab: Lab, mix: &crate::cli::MixToward) -> Result<Lab> {
+    use crate::color_ops::mixing::{ColorSpace, mix_toward};
+    use palette::{IntoColor, Srgb};
+
+    let target: Srgb = parse_color_input(&mix.target)?.into_color();
+    let base: Srgb = lab.into_color();
+    Ok(mix_toward(base, target, mix.amount, ColorSpace::Lab).into_color())
+}
+
 /// Try to parse input as RAL color code or name
 fn try_parse_ral_color(input: &str) -> Option<crate::color_parser::RalMatch> {
     use crate::color_parser::parse_ral_color;
@@ -178,8 +230,28 @@ pub fn color_match_with_schemes(
     args: &crate::cli::ColorArgs,
     algorithm: crate::color_distance_strategies::DistanceAlgorithm,
 ) -> Result<String> {
-    // Parse the input color
-    let (lab_color, _format) = parse_color_with_parser(&args.color)?;
+    // Parse the input color, then apply any tint or shade
+    let (lab_color, input_format) = parse_color_as(&args.color, args.lab_variant)?;
+    reject_alpha(&args.color, "the color command")?;
+    let lab_color = match args.tint.as_ref().or(args.shade.as_ref()) {
+        Some(mix) => mix_lab_toward(lab_color, mix)?,
+        None => lab_color,
+    };
+
+    // Plain conversion requested: skip the full analysis report
+    if let Some(target) = &args.to {
+        return Ok(crate::format_utils::FormatUtils::format_color_as(
+            lab_color,
+            target,
+            args.lab_variant,
+        ));
+    }
+
+    if args.compact {
+        return Ok(crate::color_formatter::ColorFormatter::format_compact(
+            lab_color, algorithm,
+        ));
+    }
 
     // Get color name
     let color_name = get_color_name_for_lab(lab_color);
@@ -187,6 +259,14 @@ pub fn color_match_with_schemes(
     // Build color scheme configuration using modern immutable approach
     let scheme_config = build_scheme_config_from_args(args)?;
 
+    // A gray has no hue to rotate, so its schemes are gray unless given one
+    if args.gray_hue.is_none() && crate::scheme_config::is_near_gray(lab_color) {
+        eprintln!(
+            "Warning: {} has almost no chroma, so its color schemes are shades of gray; use --gray-hue <DEGREES> to derive them from a nominal hue",
+            args.color
+        );
+    }
+
     // Calculate color schemes using modern approach
     let schemes = crate::scheme_config::calculate_color_schemes(scheme_config, lab_color)?;
 
@@ -194,6 +274,7 @@ pub fn color_match_with_schemes(
     format_comprehensive_report_with_structured_output(
         &schemes,
         &args.color,
+        input_format,
         &color_name,
         algorithm,
         args,
@@ -224,6 +305,10 @@ fn build_scheme_config_from_args(
         config = config.preserve_lab_luminance()?;
     }
 
+    if let Some(hue) = args.gray_hue {
+        config = config.set_gray_hue(hue)?;
+    }
+
     Ok(config)
 }
 
@@ -232,17 +317,19 @@ fn build_scheme_config_from_args(
 fn format_comprehensive_report_with_structured_output(
     schemes: &crate::color_schemes::ColorSchemeResult,
     input: &str,
+    input_format: crate::color_parser::ColorFormat,
     color_name: &str,
     algorithm: crate::color_distance_strategies::DistanceAlgorithm,
     args: &crate::cli::ColorArgs,
 ) -> Result<String> {
     use crate::color_report_formatting::{
-        collect_analysis_data, display_terminal_output, generate_formatted_output,
-        write_output_file,
+        collect_analysis_data, describe_output_file, display_terminal_output,
+        generate_formatted_output, write_output_file,
     };
 
     // Collect and structure analysis data
-    let analysis_data = collect_analysis_data(schemes, input, color_name, algorithm, args)?;
+    let analysis_data = collect_analysis_data(schemes, input, color_name, algorithm, args)?
+        .with_input_format(input_format, input);
 
     // Determine output format (default to YAML if not specified)
     let format = args
@@ -251,14 +338,22 @@ fn format_comprehensive_report_with_structured_output(
         .unwrap_or(&crate::cli::OutputFormat::Yaml);
 
     // Generate formatted output
-    let formatted_output = generate_formatted_output(&analysis_data, format)?;
+    let formatted_output = crate::output_formats::localize_decimal_separator(
+        &generate_formatted_output(&analysis_data, format)?,
+        format,
+        args.decimal_separator,
+    );
 
     // Display structured output to terminal with colorization
     display_terminal_output(&formatted_output, format);
 
     // Write to file if requested
     if let Some(filename) = &args.output_file {
-        write_output_file(&analysis_data, filename, format)?;
+        if args.dry_run {
+            describe_output_file(&analysis_data, filename, format, args.decimal_separator)?;
+        } else {
+            write_output_file(&analysis_data, filename, format, args.decimal_separator)?;
+        }
     }
 
     Ok(String::new())
@@ -300,4 +395,82 @@ mod tests {
         assert!((lab_from_hex.a - lab_from_rgb.a).abs() < 0.01);
         assert!((lab_from_hex.b - lab_from_rgb.b).abs() < 0.01);
     }
+
+    #[test]
+    fn test_report_echoes_input_format() {
+        use crate::cli::{Cli, Commands};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("report.yaml");
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "color",
+            "hsl(0, 100%, 50%)",
+            "--file",
+            file.to_str().unwrap(),
+        ])
+        .unwrap();
+        let Commands::Color(args) = cli.command else {
+            panic!("expected color command");
+        };
+
+        color_match_with_schemes(&args, DistanceAlgorithm::DeltaE2000).unwrap();
+        let report: serde_yml::Value =
+            serde_yml::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+        assert_eq!(report["input"]["input_format"], "hsl");
+        assert_eq!(report["input"]["input_value"], "hsl(0, 100%, 50%)");
+    }
+
+    #[test]
+    fn test_compact_output_is_one_summary_line() {
+        use crate::cli::{Cli, Commands};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["color-rs", "color", "#FF6347", "--compact"]).unwrap();
+        let Commands::Color(args) = cli.command else {
+            panic!("expected color command");
+        };
+
+        let line = color_match_with_schemes(&args, DistanceAlgorithm::DeltaE2000).unwrap();
+        assert!(!line.contains('\n'));
+        assert!(
+            line.starts_with("#FF6347 -> Tomato (CSS, ΔE 0.0)"),
+            "{line}"
+        );
+        assert!(line.contains(" L:") && line.contains(" C:") && line.contains(" H:"));
+
+        assert!(
+            Cli::try_parse_from(["color-rs", "color", "red", "--compact", "--to", "hex"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_translucent_input_is_rejected_not_made_opaque() {
+        use crate::cli::{Cli, Commands};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use clap::Parser;
+
+        let run = |argv: &[&str]| {
+            let Commands::Color(args) = Cli::try_parse_from(argv).unwrap().into_command() else {
+                panic!("expected color command");
+            };
+            color_match_with_schemes(&args, DistanceAlgorithm::DeltaE2000)
+        };
+
+        for argv in [
+            &["color-rs", "color", "#FF000080"][..],
+            &["color-rs", "color", "#FF000080", "--compact"],
+            &["color-rs", "color", "#F008", "--to", "hex"],
+        ] {
+            let error = run(argv).unwrap_err().to_string();
+            assert!(
+                error.contains("would be dropped by the color command"),
+                "{error}"
+            );
+        }
+        assert!(run(&["color-rs", "color", "#FF0000FF", "--compact"]).is_ok());
+    }
 }
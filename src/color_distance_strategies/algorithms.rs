@@ -3,8 +3,8 @@
 //! This module contains the actual implementations of various color distance
 //! algorithms, all using pure functional programming patterns.
 
-use super::types::{DistanceAlgorithm, ValidatedLab, ValidationError};
-use crate::config::algorithm_constants;
+use super::types::{CmcWeights, DistanceAlgorithm, ValidatedLab, ValidationError};
+use crate::color_math;
 use std::str::FromStr;
 
 impl DistanceAlgorithm {
@@ -15,6 +15,7 @@ impl DistanceAlgorithm {
     /// - "`delta_e_2000`", "deltae2000", "ciede2000", "de2000" -> `DeltaE2000`  
     /// - "euclidean", "`euclidean_lab`", "lab" -> `EuclideanLab`
     /// - "lch" -> Lch
+    /// - "cmc", "cmc21", "`cmc_2_1`" -> `Cmc` with 2:1 weights; "cmc11", "`cmc_1_1`" -> 1:1
     ///
     /// # Errors
     /// Returns `ValidationError::EmptyAlgorithmName` if input is empty,
@@ -50,6 +51,12 @@ impl DistanceAlgorithm {
             // LCH variants
             "lch" => Ok(Self::Lch),
 
+            // CMC(l:c) variants
+            "cmc" | "cmc21" | "cmc_2_1" | "cmc_2:1" | "delta_e_cmc" => {
+                Ok(Self::Cmc(CmcWeights::Acceptability))
+            }
+            "cmc11" | "cmc_1_1" | "cmc_1:1" => Ok(Self::Cmc(CmcWeights::Perceptibility)),
+
             _ => Err(ValidationError::UnknownAlgorithm(s.to_string())),
         }
     }
@@ -79,6 +86,7 @@ impl DistanceAlgorithm {
             Self::DeltaE2000 => calculate_delta_e_2000(lab1, lab2),
             Self::EuclideanLab => calculate_euclidean_lab(lab1, lab2),
             Self::Lch => calculate_lch_distance(lab1, lab2),
+            Self::Cmc(weights) => calculate_delta_e_cmc(lab1, lab2, weights),
         }
     }
 
@@ -159,11 +167,7 @@ impl std::fmt::Display for DistanceAlgorithm {
 /// Formula: √((ΔL)² + (Δa)² + (Δb)²)
 #[must_use]
 fn calculate_delta_e_76(lab1: ValidatedLab, lab2: ValidatedLab) -> f64 {
-    let dl = f64::from(lab1.l() - lab2.l());
-    let da = f64::from(lab1.a() - lab2.a());
-    let db = f64::from(lab1.b() - lab2.b());
-
-    (dl.mul_add(dl, da.mul_add(da, db * db))).sqrt()
+    color_math::delta_e_76(lab_components(lab1), lab_components(lab2))
 }
 
 /// Delta E 2000 (CIEDE2000) distance calculation
@@ -171,67 +175,29 @@ fn calculate_delta_e_76(lab1: ValidatedLab, lab2: ValidatedLab) -> f64 {
 /// Pure function implementing the CIEDE2000 color difference formula.
 /// Most perceptually accurate but computationally more expensive.
 ///
-/// This is a simplified implementation focusing on correctness.
+/// This is a simplified implementation focusing on correctness; see
+/// [`color_math::delta_e_2000`].
 #[must_use]
 fn calculate_delta_e_2000(lab1: ValidatedLab, lab2: ValidatedLab) -> f64 {
-    let l1 = f64::from(lab1.l());
-    let a1 = f64::from(lab1.a());
-    let b1 = f64::from(lab1.b());
-    let l2 = f64::from(lab2.l());
-    let a2 = f64::from(lab2.a());
-    let b2 = f64::from(lab2.b());
-
-    // Calculate intermediate values
-    let dl = l2 - l1;
-    let da = a2 - a1;
-    let db = b2 - b1;
+    color_math::delta_e_2000(lab_components(lab1), lab_components(lab2))
+}
 
-    // Calculate C (chroma) values
-    let c1 = a1.hypot(b1);
-    let c2 = a2.hypot(b2);
-    let dc = c2 - c1;
+/// Delta E CMC(l:c) distance calculation with `lab1` as the reference
+///
+/// See [`color_math::delta_e_cmc`].
+#[must_use]
+fn calculate_delta_e_cmc(lab1: ValidatedLab, lab2: ValidatedLab, weights: CmcWeights) -> f64 {
+    color_math::delta_e_cmc(
+        lab_components(lab1),
+        lab_components(lab2),
+        weights.lightness(),
+        weights.chroma(),
+    )
+}
 
-    // Calculate H (hue) values
-    // Calculate dh-squared for the color difference formula using optimized mul_add
-    let dh_squared = dc.mul_add(-dc, da.mul_add(da, db * db));
-    let dh = if dh_squared > 0.0 {
-        dh_squared.sqrt()
-    } else {
-        0.0
-    };
-
-    // Weighting functions (simplified - full CIEDE2000 has more complex weighting)
-    let l_avg = f64::midpoint(l1, l2);
-    let c_avg = f64::midpoint(c1, c2);
-
-    // Lightness weighting with optimized mul_add
-    let sl = 1.0
-        + (algorithm_constants::DELTA_E_LIGHTNESS_FACTOR
-            * (l_avg - algorithm_constants::DELTA_E_LIGHTNESS_OFFSET).powi(2))
-            / (l_avg - algorithm_constants::DELTA_E_LIGHTNESS_OFFSET)
-                .mul_add(
-                    l_avg - algorithm_constants::DELTA_E_LIGHTNESS_OFFSET,
-                    algorithm_constants::DELTA_E_LIGHTNESS_DENOMINATOR_OFFSET,
-                )
-                .sqrt();
-
-    // Chroma weighting
-    let sc = algorithm_constants::DELTA_E_CHROMA_FACTOR.mul_add(c_avg, 1.0);
-
-    // Hue weighting
-    let sh = algorithm_constants::DELTA_E_HUE_FACTOR.mul_add(c_avg, 1.0);
-
-    // Parametric factors (standard values)
-    let kl = algorithm_constants::DELTA_E_PARAMETRIC_FACTOR;
-    let kc = algorithm_constants::DELTA_E_PARAMETRIC_FACTOR;
-    let kh = algorithm_constants::DELTA_E_PARAMETRIC_FACTOR;
-
-    // Final Delta E 2000 calculation
-    let delta_l = dl / (kl * sl);
-    let delta_c = dc / (kc * sc);
-    let delta_h = dh / (kh * sh);
-
-    (delta_l.mul_add(delta_l, delta_c.mul_add(delta_c, delta_h * delta_h))).sqrt()
+/// LAB components widened to `f64` for the core color math
+fn lab_components(lab: ValidatedLab) -> [f64; 3] {
+    [f64::from(lab.l()), f64::from(lab.a()), f64::from(lab.b())]
 }
 
 /// Euclidean distance in LAB space
@@ -40,7 +40,8 @@
 
 // Re-export all public types and functions for backward compatibility
 pub use types::{
-    ALens, BLens, DistanceAlgorithm, LabLens, LightnessLens, ValidatedLab, ValidationError,
+    ALens, BLens, CmcWeights, DistanceAlgorithm, LabLens, LightnessLens, ValidatedLab,
+    ValidationError,
 };
 
 pub use algorithms::{
@@ -259,6 +260,30 @@ mod integration_tests {
         assert!(from_hsl.l() >= 0.0 && from_hsl.l() <= 100.0);
     }
 
+    #[test]
+    fn test_fingerprint_quantization() {
+        let base = ValidatedLab::new(50.0, 20.0, -30.0).unwrap();
+        let nearly_same = ValidatedLab::new(50.003, 19.998, -30.004).unwrap();
+        let different = ValidatedLab::new(50.5, 20.0, -30.0).unwrap();
+
+        assert_eq!(base.fingerprint(), nearly_same.fingerprint());
+        assert_ne!(base.fingerprint(), different.fingerprint());
+        assert_eq!(base.fingerprint(), base.fingerprint());
+    }
+
+    #[test]
+    fn test_clamped_constructor() {
+        assert!(ValidatedLab::new(110.0, 0.0, 0.0).is_err());
+
+        let clamped = ValidatedLab::new_clamped(110.0, 0.0, -250.0).unwrap();
+        assert_eq!(clamped.l(), 100.0);
+        assert_eq!(clamped.a(), 0.0);
+        assert_eq!(clamped.b(), -200.0);
+
+        assert!(ValidatedLab::new_clamped(f32::NAN, 0.0, 0.0).is_err());
+        assert!(ValidatedLab::new_clamped(50.0, f32::INFINITY, 0.0).is_err());
+    }
+
     #[test]
     fn test_lens_operations() {
         // Test functional lens patterns
@@ -108,6 +108,28 @@ impl ValidatedLab {
         })
     }
 
+    /// Lenient constructor that clamps out-of-range LAB values instead of rejecting them
+    ///
+    /// Unlike [`Self::new`], which errors on any out-of-range component, this clamps
+    /// lightness to 0-100 and a/b to [-200, 200]. Use it when ingesting noisy data
+    /// where slight overshoot is expected; only non-finite values are rejected.
+    ///
+    /// # Errors
+    /// Returns `ValidationError::LabValuesNotFinite` if any value is NaN or infinite
+    pub fn new_clamped(l: f32, a: f32, b: f32) -> Result<Self, ValidationError> {
+        if !l.is_finite() || !a.is_finite() || !b.is_finite() {
+            return Err(ValidationError::LabValuesNotFinite);
+        }
+
+        Ok(Self {
+            lab: Lab::new(
+                l.clamp(0.0, 100.0),
+                a.clamp(-200.0, 200.0),
+                b.clamp(-200.0, 200.0),
+            ),
+        })
+    }
+
     /// Create a `ValidatedLab` from existing Lab (with validation)
     /// # Errors
     /// Returns `ValidationError` if LAB values are not finite or out of valid range
@@ -192,6 +214,31 @@ impl ValidatedLab {
         Self::new(new_l, new_a, new_b)
     }
 
+    /// Quantization step used by [`ValidatedLab::fingerprint`]
+    pub const FINGERPRINT_QUANTUM: f32 = 0.01;
+
+    /// Stable fingerprint of the quantized LAB value, suitable as a cache key
+    ///
+    /// Each component is rounded to the nearest multiple of
+    /// [`Self::FINGERPRINT_QUANTUM`] (0.01 LAB units, far below a just-noticeable
+    /// difference) and the three integers are hashed with 64-bit FNV-1a. Values
+    /// within the same quantization cell share a fingerprint; values straddling a
+    /// cell boundary may not. The result is independent of platform and Rust version.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // Safe: LAB components are bounded to [-200, 200]
+    pub fn fingerprint(self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        [self.lab.l, self.lab.a, self.lab.b]
+            .iter()
+            .map(|&v| (v / Self::FINGERPRINT_QUANTUM).round() as i32)
+            .flat_map(i32::to_le_bytes)
+            .fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+
     /// Lens for safe field access and updates
     #[must_use]
     pub const fn lens() -> LabLens {
@@ -326,6 +373,37 @@ pub enum DistanceAlgorithm {
     /// LCH Color Space distance calculation
     /// Distance in cylindrical color space, separates lightness from chroma
     Lch,
+
+    /// Delta E CMC(l:c) - Textile industry formula with lightness and chroma weights
+    /// Asymmetric: the first color is the reference
+    Cmc(CmcWeights),
+}
+
+/// Lightness and chroma weights (l:c) of the Delta E CMC formula
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CmcWeights {
+    /// 2:1, for judging whether a difference is acceptable
+    #[default]
+    Acceptability,
+    /// 1:1, for judging whether a difference is perceptible
+    Perceptibility,
+}
+
+impl CmcWeights {
+    /// Lightness weight `l`
+    #[must_use]
+    pub const fn lightness(self) -> f64 {
+        match self {
+            Self::Acceptability => 2.0,
+            Self::Perceptibility => 1.0,
+        }
+    }
+
+    /// Chroma weight `c`
+    #[must_use]
+    pub const fn chroma(self) -> f64 {
+        1.0
+    }
 }
 
 impl DistanceAlgorithm {
@@ -337,6 +415,8 @@ impl DistanceAlgorithm {
             Self::DeltaE2000 => "Delta E 2000",
             Self::EuclideanLab => "Euclidean distance",
             Self::Lch => "LCH Color Space",
+            Self::Cmc(CmcWeights::Acceptability) => "Delta E CMC (2:1)",
+            Self::Cmc(CmcWeights::Perceptibility) => "Delta E CMC (1:1)",
         }
     }
 
@@ -350,17 +430,22 @@ impl DistanceAlgorithm {
             Self::Lch => {
                 "Distance calculation in LCH cylindrical color space - Separates lightness from chroma"
             }
+            Self::Cmc(_) => {
+                "CMC(l:c) formula - Textile industry standard with separate lightness and chroma tolerances"
+            }
         }
     }
 
     /// Get all available algorithms
     #[must_use]
-    pub const fn all() -> [Self; 4] {
+    pub const fn all() -> [Self; 6] {
         [
             Self::DeltaE76,
             Self::DeltaE2000,
             Self::EuclideanLab,
             Self::Lch,
+            Self::Cmc(CmcWeights::Acceptability),
+            Self::Cmc(CmcWeights::Perceptibility),
         ]
     }
 
@@ -373,6 +458,6 @@ impl DistanceAlgorithm {
     /// Check if this algorithm is perceptually accurate
     #[must_use]
     pub const fn is_perceptually_accurate(self) -> bool {
-        matches!(self, Self::DeltaE2000 | Self::Lch)
+        matches!(self, Self::DeltaE2000 | Self::Lch | Self::Cmc(_))
     }
 }
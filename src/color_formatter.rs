@@ -27,10 +27,12 @@ fn f32_to_u8_clamped(value: f32) -> u8 {
     (value * 255.0).round().clamp(0.0, 255.0) as u8
 }
 
+use crate::cli::TextSize;
 use crate::error::Result;
 use crate::output_formats::{
-    BrightnessInfo, ColorAnalysisOutput, ColorCollections, ColorFormats, ColorMatch, ContrastData,
-    ContrastInfo, GrayscaleData,
+    AccessibleMatches, BrightnessInfo, ClampReport, ColorAnalysisOutput, ColorCollections,
+    ColorFormats, ColorMatch, ContrastData, ContrastInfo, GrayscaleData, MatchDistances,
+    RoundTripCheck, TargetContrastInfo,
 };
 use crate::utils::Utils;
 use palette::{Hsl, IntoColor, Lab, Lch, Srgb};
@@ -84,6 +86,9 @@ impl ColorFormatter {
 
     /// Collect color analysis data for file output instead of printing
     ///
+    /// `filter` restricts which collection colors are ranked as matches, and
+    /// `all_distances` adds every distance metric to each match.
+    ///
     /// # Errors
     ///
     /// Returns an error if color conversion or analysis fails
@@ -92,11 +97,19 @@ impl ColorFormatter {
         original_input: &str,
         color_name: &str,
         algorithm: crate::color_distance_strategies::DistanceAlgorithm,
+        filter: Option<&crate::color_parser::SearchFilter>,
+        all_distances: bool,
     ) -> Result<ColorAnalysisOutput> {
         let conversion = Self::collect_format_conversions(lab_color);
         let contrast = Self::collect_contrast_data(lab_color);
         let grayscale = Self::collect_grayscale_data(lab_color);
-        let color_collections = Self::collect_color_collections(lab_color, color_name, algorithm);
+        let color_collections = Self::collect_color_collections(
+            lab_color,
+            color_name,
+            algorithm,
+            filter,
+            all_distances,
+        );
 
         let mut output = ColorAnalysisOutput::new();
         // Update metadata with distance algorithm
@@ -140,14 +153,18 @@ impl ColorFormatter {
 
         ContrastData {
             wcag21_relative_luminance: relative_luminance,
+            luma: crate::color_ops::luminance::luma(srgb),
             contrast_vs_white: ContrastInfo {
                 ratio: white_contrast,
                 assessment: Self::assess_contrast_level(white_contrast),
+                compliance: None,
             },
             contrast_vs_black: ContrastInfo {
                 ratio: black_contrast,
                 assessment: Self::assess_contrast_level(black_contrast),
+                compliance: None,
             },
+            contrast_vs_target: None,
             brightness: BrightnessInfo {
                 lab_assessment: Self::assess_lab_brightness(lab_color.l),
                 wcag_assessment: Self::assess_wcag_brightness(relative_luminance),
@@ -155,6 +172,113 @@ impl ColorFormatter {
         }
     }
 
+    /// Collect WCAG contrast and AA/AAA compliance against a specific color
+    #[must_use]
+    pub fn collect_target_contrast(
+        lab_color: Lab,
+        target_lab: Lab,
+        target_input: &str,
+    ) -> TargetContrastInfo {
+        use crate::color_ops::contrast;
+
+        let target_srgb: Srgb = target_lab.into_color();
+        let ratio = contrast::wcag_ratio(lab_color.into_color(), target_srgb);
+
+        TargetContrastInfo {
+            against: target_input.to_string(),
+            hex: crate::color_ops::conversion::srgb_to_hex(target_srgb),
+            ratio,
+            aa_normal_text: contrast::meets_aa_standard(ratio, false),
+            aa_large_text: contrast::meets_aa_standard(ratio, true),
+            aaa_normal_text: contrast::meets_aaa_standard(ratio, false),
+            aaa_large_text: contrast::meets_aaa_standard(ratio, true),
+            compliance: None,
+        }
+    }
+
+    /// Convert `lab_color` to 8-bit sRGB and back, reporting the error introduced
+    ///
+    /// Out-of-gamut inputs are clamped on the way to sRGB, so their round-trip
+    /// error includes the gamut mapping and `in_gamut` is false.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Safe: values clamped to [0.0, 255.0] range
+    pub fn round_trip_check(lab_color: Lab) -> RoundTripCheck {
+        use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+        use palette::convert::IntoColorUnclamped;
+
+        let exact: Srgb = lab_color.into_color_unclamped();
+        let channels = [exact.red, exact.green, exact.blue];
+        let in_gamut = crate::color_ops::Gamut::Srgb.contains(lab_color);
+
+        let quantized = channels.map(|c| (c * 255.0).round().clamp(0.0, 255.0) as u8);
+        let rgb_delta = channels
+            .iter()
+            .zip(quantized)
+            .map(|(exact, rounded)| (f64::from(*exact) * 255.0 - f64::from(rounded)).abs())
+            .fold(0.0, f64::max);
+
+        let [r, g, b] = quantized;
+        let round_tripped: Lab = Srgb::new(r, g, b).into_format::<f32>().into_color();
+
+        RoundTripCheck {
+            lab_in: crate::format_utils::FormatUtils::lab_to_lab(lab_color),
+            rgb: format!("rgb({r}, {g}, {b})"),
+            lab_out: crate::format_utils::FormatUtils::lab_to_lab(round_tripped),
+            delta_e_2000: calculate_distance(
+                DistanceAlgorithm::DeltaE2000,
+                lab_color,
+                round_tripped,
+            ),
+            rgb_delta,
+            in_gamut,
+        }
+    }
+
+    /// Compare `lab_color` as unclamped sRGB with its clamped sRGB color
+    ///
+    /// In-gamut inputs report no clamped channels and a ΔE of zero.
+    #[must_use]
+    pub fn clamp_report(lab_color: Lab) -> ClampReport {
+        use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+        use palette::convert::IntoColorUnclamped;
+
+        let exact: Srgb = lab_color.into_color_unclamped();
+        let unclamped = [exact.red, exact.green, exact.blue].map(f64::from);
+        let clamped = unclamped.map(|c| c.clamp(0.0, 1.0));
+        let srgb = |[r, g, b]: [f64; 3]| format!("srgb({r:.4}, {g:.4}, {b:.4})");
+
+        let clamped_channels = ["red", "green", "blue"]
+            .iter()
+            .zip(unclamped)
+            .filter(|(_, value)| !(0.0..=1.0).contains(value))
+            .map(|(channel, value)| format!("{channel}: {value:.4}"))
+            .collect();
+
+        let [r, g, b] = clamped;
+        let clamped_lab: Lab = Srgb::new(r as f32, g as f32, b as f32).into_color();
+
+        ClampReport {
+            unclamped: srgb(unclamped),
+            clamped: srgb(clamped),
+            clamped_channels,
+            delta_e_2000: calculate_distance(DistanceAlgorithm::DeltaE2000, lab_color, clamped_lab),
+        }
+    }
+
+    /// Grade every reported contrast ratio against the thresholds for `text_size`
+    pub fn apply_text_size_compliance(contrast: &mut ContrastData, text_size: TextSize) {
+        use crate::color_ops::contrast;
+
+        let level =
+            |ratio: f64| Some(contrast::compliance_level(ratio, text_size.is_large()).to_string());
+
+        contrast.contrast_vs_white.compliance = level(contrast.contrast_vs_white.ratio);
+        contrast.contrast_vs_black.compliance = level(contrast.contrast_vs_black.ratio);
+        if let Some(target) = contrast.contrast_vs_target.as_mut() {
+            target.compliance = level(target.ratio);
+        }
+    }
+
     /// Assess contrast level
     fn assess_contrast_level(ratio: f64) -> String {
         if ratio >= 7.0 {
@@ -204,15 +328,16 @@ impl ColorFormatter {
         let lch4_hex = crate::color_ops::conversion::srgb_to_hex(lch4_lab.into_color());
         let lch6_hex = crate::color_ops::conversion::srgb_to_hex(lch6_lab.into_color());
 
+        let hue = crate::color_ops::analysis::hue::normalize_hue(f64::from(lch.hue.into_inner()));
         GrayscaleData {
             lch0_hex,
-            lch0: format!("lch({:.2}, 0.000, {:.1})", lch.l, lch.hue.into_degrees()),
+            lch0: format!("lch({:.2}, 0.000, {hue:.1})", lch.l),
             lch2_hex,
-            lch2: format!("lch({:.2}, 2.000, {:.1})", lch.l, lch.hue.into_degrees()),
+            lch2: format!("lch({:.2}, 2.000, {hue:.1})", lch.l),
             lch4_hex,
-            lch4: format!("lch({:.2}, 4.000, {:.1})", lch.l, lch.hue.into_degrees()),
+            lch4: format!("lch({:.2}, 4.000, {hue:.1})", lch.l),
             lch6_hex,
-            lch6: format!("lch({:.2}, 6.000, {:.1})", lch.l, lch.hue.into_degrees()),
+            lch6: format!("lch({:.2}, 6.000, {hue:.1})", lch.l),
         }
     }
 
@@ -221,82 +346,192 @@ impl ColorFormatter {
         lab_color: Lab,
         _color_name: &str,
         algorithm: crate::color_distance_strategies::DistanceAlgorithm,
+        filter: Option<&crate::color_parser::SearchFilter>,
+        all_distances: bool,
     ) -> ColorCollections {
+        use crate::color_parser::UniversalColor;
         use crate::color_parser::unified_manager::UnifiedColorManager;
 
-        let manager = UnifiedColorManager::new().unwrap_or_default();
+        let manager = UnifiedColorManager::new();
         let srgb: Srgb = lab_color.into_color();
         let rgb = [
             f32_to_u8_clamped(srgb.red),
             f32_to_u8_clamped(srgb.green),
             f32_to_u8_clamped(srgb.blue),
         ];
+        let target = UniversalColor::from_rgb(rgb);
+        let to_output = |matches: Vec<crate::color_parser::ColorMatch>| -> Vec<ColorMatch> {
+            matches
+                .iter()
+                .map(|m| Self::collection_match_output(m, &target, all_distances))
+                .collect()
+        };
 
-        // Get CSS colors
-        let css_matches = manager.find_closest_css_colors_with_algorithm(rgb, 4, algorithm);
-        let css_colors = css_matches
-            .into_iter()
-            .map(|m| {
-                let match_lab = Lab::from(m.entry.color.lab);
-                let match_srgb: Srgb = match_lab.into_color();
-                ColorMatch {
-                    name: m.entry.metadata.name.clone(),
-                    hex: crate::color_ops::conversion::srgb_to_hex(match_srgb),
-                    lch: crate::format_utils::FormatUtils::lab_to_lch(match_lab),
-                    code: m.entry.metadata.code.clone(),
-                    distance: m.distance,
-                    wcag21_relative_luminance: crate::color_ops::luminance::wcag_relative(
-                        match_srgb,
-                    ),
-                }
-            })
-            .collect();
+        ColorCollections {
+            css_colors: to_output(
+                manager.find_closest_css_colors_with_algorithm(rgb, 4, filter, algorithm),
+            ),
+            ral_classic: to_output(
+                manager.find_closest_ral_classic_with_algorithm(rgb, 4, filter, algorithm),
+            ),
+            ral_design: to_output(
+                manager.find_closest_ral_design_with_algorithm(rgb, 4, filter, algorithm),
+            ),
+            custom: Vec::new(),
+            accessible: None,
+        }
+    }
+
+    /// Collect up to 4 closest matches from a user-supplied collection
+    #[must_use]
+    pub fn collect_custom_collection_matches(
+        lab_color: Lab,
+        collection: &dyn crate::color_parser::ColorCollection,
+        algorithm: crate::color_distance_strategies::DistanceAlgorithm,
+        filter: Option<&crate::color_parser::SearchFilter>,
+        all_distances: bool,
+    ) -> Vec<ColorMatch> {
+        use crate::color_parser::UniversalColor;
+
+        let srgb: Srgb = lab_color.into_color();
+        let target = UniversalColor::from_rgb([
+            f32_to_u8_clamped(srgb.red),
+            f32_to_u8_clamped(srgb.green),
+            f32_to_u8_clamped(srgb.blue),
+        ]);
+
+        collection
+            .find_closest_with_algorithm(&target, 4, filter, algorithm)
+            .iter()
+            .map(|m| Self::collection_match_output(m, &target, all_distances))
+            .collect()
+    }
+
+    /// Collect up to 4 closest matches per collection that reach `min_ratio`
+    /// WCAG contrast against `background`
+    #[must_use]
+    pub fn collect_accessible_matches(
+        lab_color: Lab,
+        background: Lab,
+        min_ratio: f64,
+        algorithm: crate::color_distance_strategies::DistanceAlgorithm,
+        all_distances: bool,
+    ) -> AccessibleMatches {
+        use crate::color_parser::UniversalColor;
+        use crate::color_parser::unified_manager::UnifiedColorManager;
 
-        // Get RAL Classic colors
-        let ral_classic_matches =
-            manager.find_closest_ral_classic_with_algorithm(rgb, 4, algorithm);
-        let ral_classic = ral_classic_matches
+        let to_rgb = |lab: Lab| {
+            let srgb: Srgb = lab.into_color();
+            [
+                f32_to_u8_clamped(srgb.red),
+                f32_to_u8_clamped(srgb.green),
+                f32_to_u8_clamped(srgb.blue),
+            ]
+        };
+        let rgb = to_rgb(lab_color);
+        let target = UniversalColor::from_rgb(rgb);
+
+        let manager = UnifiedColorManager::new();
+        let mut collections = manager
+            .find_closest_accessible(rgb, to_rgb(background), min_ratio, algorithm, 4)
             .into_iter()
-            .map(|m| {
-                let match_lab = Lab::from(m.entry.color.lab);
-                let match_srgb: Srgb = match_lab.into_color();
-                ColorMatch {
-                    name: m.entry.metadata.name.clone(),
-                    hex: crate::color_ops::conversion::srgb_to_hex(match_srgb),
-                    lch: crate::format_utils::FormatUtils::lab_to_lch(match_lab),
-                    code: m.entry.metadata.code.clone(),
-                    distance: m.distance,
-                    wcag21_relative_luminance: crate::color_ops::luminance::wcag_relative(
-                        match_srgb,
-                    ),
-                }
-            })
-            .collect();
+            .map(|(_, matches)| {
+                matches
+                    .iter()
+                    .map(|m| Self::collection_match_output(m, &target, all_distances))
+                    .collect::<Vec<_>>()
+            });
+
+        AccessibleMatches {
+            background: crate::color_ops::conversion::srgb_to_hex(background.into_color()),
+            min_ratio,
+            css_colors: collections.next().unwrap_or_default(),
+            ral_classic: collections.next().unwrap_or_default(),
+            ral_design: collections.next().unwrap_or_default(),
+        }
+    }
+
+    /// One-line summary of a color for logs, e.g.
+    /// `#FF6B35 -> Gerbera red (RAL Design System+, ΔE 2.5) L:63 C:78 H:46`
+    ///
+    /// The name is the nearest entry across the CSS, RAL Classic and RAL Design
+    /// collections under `algorithm`. Plain text, never styled.
+    #[must_use]
+    pub fn format_compact(
+        lab_color: Lab,
+        algorithm: crate::color_distance_strategies::DistanceAlgorithm,
+    ) -> String {
+        use crate::color_parser::unified_manager::UnifiedColorManager;
+
+        let srgb: Srgb = lab_color.into_color();
+        let hex = crate::color_ops::conversion::srgb_to_hex(srgb);
+        let rgb = [
+            f32_to_u8_clamped(srgb.red),
+            f32_to_u8_clamped(srgb.green),
+            f32_to_u8_clamped(srgb.blue),
+        ];
 
-        // Get RAL Design colors
-        let ral_design_matches = manager.find_closest_ral_design_with_algorithm(rgb, 4, algorithm);
-        let ral_design = ral_design_matches
+        let manager = UnifiedColorManager::new();
+        let nearest = manager
+            .find_closest_across_all_with_algorithm(rgb, 1, algorithm)
             .into_iter()
-            .map(|m| {
-                let match_lab = Lab::from(m.entry.color.lab);
-                let match_srgb: Srgb = match_lab.into_color();
-                ColorMatch {
-                    name: m.entry.metadata.name.clone(),
-                    hex: crate::color_ops::conversion::srgb_to_hex(match_srgb),
-                    lch: crate::format_utils::FormatUtils::lab_to_lch(match_lab),
-                    code: m.entry.metadata.code.clone(),
-                    distance: m.distance,
-                    wcag21_relative_luminance: crate::color_ops::luminance::wcag_relative(
-                        match_srgb,
-                    ),
-                }
-            })
-            .collect();
+            .filter_map(|(collection, matches)| matches.into_iter().next().map(|m| (collection, m)))
+            .min_by(|(_, a), (_, b)| a.distance.total_cmp(&b.distance));
+        let name = nearest.map_or_else(
+            || "no match".to_string(),
+            |(collection, m)| {
+                let collection = if collection == "CSS Named Colors" {
+                    "CSS"
+                } else {
+                    collection.as_str()
+                };
+                format!(
+                    "{} ({collection}, ΔE {:.1})",
+                    m.entry.metadata.name, m.distance
+                )
+            },
+        );
 
-        ColorCollections {
-            css_colors,
-            ral_classic,
-            ral_design,
+        let lch: Lch = lab_color.into_color();
+        let hue = crate::color_ops::analysis::hue::normalize_hue(f64::from(lch.hue.into_inner()));
+        format!(
+            "{hex} -> {name} L:{:.0} C:{:.0} H:{hue:.0}",
+            lch.l, lch.chroma
+        )
+    }
+
+    /// Convert a collection search result into its structured output form
+    fn collection_match_output(
+        m: &crate::color_parser::ColorMatch,
+        target: &crate::color_parser::UniversalColor,
+        all_distances: bool,
+    ) -> ColorMatch {
+        let match_lab = Lab::from(m.entry.color.lab);
+        let match_srgb: Srgb = match_lab.into_color();
+        ColorMatch {
+            name: m.entry.metadata.name.clone(),
+            hex: crate::color_ops::conversion::srgb_to_hex(match_srgb),
+            lch: crate::format_utils::FormatUtils::lab_to_lch(match_lab),
+            code: m.entry.metadata.code.clone(),
+            distance: m.distance,
+            confidence: crate::color_matching::match_confidence(m.distance),
+            wcag21_relative_luminance: crate::color_ops::luminance::wcag_relative(match_srgb),
+            distances: all_distances
+                .then(|| Self::match_distances(Lab::from(target.lab), match_lab)),
+        }
+    }
+
+    /// Distance between two colors under every supported metric
+    #[must_use]
+    pub fn match_distances(target: Lab, candidate: Lab) -> MatchDistances {
+        use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+
+        MatchDistances {
+            delta_e_76: calculate_distance(DistanceAlgorithm::DeltaE76, target, candidate),
+            delta_e_94: crate::color_ops::distance::delta_e_cie94_lab(target, candidate),
+            delta_e_2000: calculate_distance(DistanceAlgorithm::DeltaE2000, target, candidate),
+            euclidean_lab: calculate_distance(DistanceAlgorithm::EuclideanLab, target, candidate),
+            lch: calculate_distance(DistanceAlgorithm::Lch, target, candidate),
         }
     }
 
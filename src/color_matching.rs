@@ -346,6 +346,29 @@ pub fn match_color_by_type(
     )
 }
 
+/// ΔE at which match confidence falls to 1/e (about 0.37)
+pub const MATCH_CONFIDENCE_SCALE: f64 = 7.0;
+
+/// Confidence (0-1) that a named match describes the target color
+///
+/// Decays as a Gaussian of the match distance: `exp(-(ΔE / 7)²)`. An exact
+/// match scores 1.0, a just-noticeable difference (ΔE ≈ 2.3) about 0.9,
+/// ΔE 5 about 0.6, ΔE 10 about 0.13 and ΔE 15 about 0.01, so far-off names
+/// are flagged rather than presented as authoritative.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_matching::match_confidence;
+///
+/// assert_eq!(match_confidence(0.0), 1.0);
+/// assert!(match_confidence(15.0) < 0.05);
+/// ```
+#[must_use]
+pub fn match_confidence(distance: f64) -> f64 {
+    let scaled = distance.max(0.0) / MATCH_CONFIDENCE_SCALE;
+    (-scaled * scaled).exp()
+}
+
 /// Unified color matcher using functional composition
 pub fn match_across_all_collections(
     target: &UniversalColor,
@@ -481,4 +504,13 @@ mod tests {
         assert!(config.enable_preprocessing);
         assert!(config.enable_post_processing);
     }
+
+    #[test]
+    fn test_match_confidence_decays_with_distance() {
+        assert!((match_confidence(0.0) - 1.0).abs() < 1e-12);
+        assert!(match_confidence(1.0) > 0.95);
+        assert!(match_confidence(5.0) > match_confidence(10.0));
+        assert!(match_confidence(15.0) < 0.05);
+        assert!(match_confidence(40.0) < 1e-6);
+    }
 }
@@ -0,0 +1,326 @@
+//! Core color math without `std`
+//!
+//! Pure numeric functions for sRGB ↔ CIELAB (D65) conversion and Delta E
+//! distances, written against `core` only so they can be used on embedded
+//! targets. Colors are plain `[f64; 3]` arrays: sRGB components in 0.0-1.0 and
+//! LAB as `[L, a, b]`.
+//!
+//! With the `libm` feature, floating-point functions come from `libm`
+//! instead of `std`. The distance strategies in
+//! [`color_distance_strategies`](crate::color_distance_strategies) delegate to
+//! this module, so both builds compute identical Delta E values.
+//!
+//! The file has no dependencies on the rest of the crate; `tests/no_std_math`
+//! compiles it inside a `#![no_std]` crate.
+
+/// Delta E 2000 lightness weighting factor
+pub const DELTA_E_LIGHTNESS_FACTOR: f64 = 0.015;
+
+/// Delta E 2000 lightness weighting base offset
+pub const DELTA_E_LIGHTNESS_OFFSET: f64 = 50.0;
+
+/// Delta E 2000 lightness weighting denominator offset
+pub const DELTA_E_LIGHTNESS_DENOMINATOR_OFFSET: f64 = 20.0;
+
+/// Delta E 2000 chroma weighting factor
+pub const DELTA_E_CHROMA_FACTOR: f64 = 0.045;
+
+/// Delta E 2000 hue weighting factor
+pub const DELTA_E_HUE_FACTOR: f64 = 0.015;
+
+/// Standard parametric factor (kL, kC, kH default values)
+pub const DELTA_E_PARAMETRIC_FACTOR: f64 = 1.0;
+
+/// Delta E CMC lightness weight below the L* = 16 cutoff
+pub const CMC_DARK_LIGHTNESS_WEIGHT: f64 = 0.511;
+
+/// Lightness below which Delta E CMC uses a constant lightness weight
+pub const CMC_DARK_LIGHTNESS_CUTOFF: f64 = 16.0;
+
+/// D65 reference white (X, Y, Z)
+const D65_WHITE: [f64; 3] = [0.950_47, 1.0, 1.088_83];
+
+/// CIE epsilon (216/24389)
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+
+/// CIE kappa (24389/27)
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+
+#[cfg(not(feature = "libm"))]
+mod float {
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    pub fn powf(x: f64, y: f64) -> f64 {
+        x.powf(y)
+    }
+
+    pub fn cbrt(x: f64) -> f64 {
+        x.cbrt()
+    }
+
+    pub fn hypot(x: f64, y: f64) -> f64 {
+        x.hypot(y)
+    }
+
+    pub fn mul_add(x: f64, y: f64, z: f64) -> f64 {
+        x.mul_add(y, z)
+    }
+
+    pub fn cos(x: f64) -> f64 {
+        x.cos()
+    }
+
+    pub fn atan2(y: f64, x: f64) -> f64 {
+        y.atan2(x)
+    }
+}
+
+#[cfg(feature = "libm")]
+mod float {
+    pub use libm::{atan2, cbrt, cos, fma as mul_add, hypot, pow as powf, sqrt};
+}
+
+/// Convert a gamma-encoded sRGB component to linear light
+#[must_use]
+pub fn srgb_to_linear(component: f64) -> f64 {
+    if component <= 0.040_45 {
+        component / 12.92
+    } else {
+        float::powf((component + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Convert a linear-light component to gamma-encoded sRGB
+#[must_use]
+pub fn linear_to_srgb(component: f64) -> f64 {
+    if component <= 0.003_130_8 {
+        component * 12.92
+    } else {
+        1.055 * float::powf(component, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// Convert sRGB (0.0-1.0 per component) to CIELAB with a D65 white point
+#[must_use]
+pub fn srgb_to_lab(rgb: [f64; 3]) -> [f64; 3] {
+    let [r, g, b] = rgb.map(srgb_to_linear);
+
+    let x = 0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b;
+    let y = 0.212_672_9 * r + 0.715_152_2 * g + 0.072_175_0 * b;
+    let z = 0.019_333_9 * r + 0.119_192_0 * g + 0.950_304_1 * b;
+
+    let f = |t: f64| {
+        if t > LAB_EPSILON {
+            float::cbrt(t)
+        } else {
+            (LAB_KAPPA * t + 16.0) / 116.0
+        }
+    };
+    let fx = f(x / D65_WHITE[0]);
+    let fy = f(y / D65_WHITE[1]);
+    let fz = f(z / D65_WHITE[2]);
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Convert CIELAB (D65) to sRGB
+///
+/// Components are not clamped, so out-of-gamut colors fall outside 0.0-1.0.
+#[must_use]
+pub fn lab_to_srgb(lab: [f64; 3]) -> [f64; 3] {
+    let [l, a, b] = lab;
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+
+    let f_inv = |t: f64| {
+        let cubed = t * t * t;
+        if cubed > LAB_EPSILON {
+            cubed
+        } else {
+            (116.0 * t - 16.0) / LAB_KAPPA
+        }
+    };
+    let x = f_inv(fx) * D65_WHITE[0];
+    let y = if l > LAB_KAPPA * LAB_EPSILON {
+        fy * fy * fy
+    } else {
+        l / LAB_KAPPA
+    } * D65_WHITE[1];
+    let z = f_inv(fz) * D65_WHITE[2];
+
+    let r = 3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z;
+    let g = -0.969_266_0 * x + 1.876_010_8 * y + 0.041_556_0 * z;
+    let b = 0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z;
+
+    [r, g, b].map(linear_to_srgb)
+}
+
+/// Delta E 76 (CIE76): Euclidean distance between two LAB colors
+#[must_use]
+pub fn delta_e_76(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
+    let dl = lab1[0] - lab2[0];
+    let da = lab1[1] - lab2[1];
+    let db = lab1[2] - lab2[2];
+
+    float::sqrt(float::mul_add(dl, dl, float::mul_add(da, da, db * db)))
+}
+
+/// Delta E 2000 distance between two LAB colors
+///
+/// Uses the crate's simplified CIEDE2000 weighting (lightness, chroma and hue
+/// terms without the rotation term).
+#[must_use]
+pub fn delta_e_2000(lab1: [f64; 3], lab2: [f64; 3]) -> f64 {
+    let [l1, a1, b1] = lab1;
+    let [l2, a2, b2] = lab2;
+
+    let dl = l2 - l1;
+    let da = a2 - a1;
+    let db = b2 - b1;
+
+    let c1 = float::hypot(a1, b1);
+    let c2 = float::hypot(a2, b2);
+    let dc = c2 - c1;
+
+    let dh_squared = float::mul_add(dc, -dc, float::mul_add(da, da, db * db));
+    let dh = if dh_squared > 0.0 {
+        float::sqrt(dh_squared)
+    } else {
+        0.0
+    };
+
+    let l_avg = f64::midpoint(l1, l2);
+    let c_avg = f64::midpoint(c1, c2);
+
+    let l_offset = l_avg - DELTA_E_LIGHTNESS_OFFSET;
+    let sl = 1.0
+        + (DELTA_E_LIGHTNESS_FACTOR * (l_offset * l_offset))
+            / float::sqrt(float::mul_add(
+                l_offset,
+                l_offset,
+                DELTA_E_LIGHTNESS_DENOMINATOR_OFFSET,
+            ));
+    let sc = float::mul_add(DELTA_E_CHROMA_FACTOR, c_avg, 1.0);
+    let sh = float::mul_add(DELTA_E_HUE_FACTOR, c_avg, 1.0);
+
+    let delta_l = dl / (DELTA_E_PARAMETRIC_FACTOR * sl);
+    let delta_c = dc / (DELTA_E_PARAMETRIC_FACTOR * sc);
+    let delta_h = dh / (DELTA_E_PARAMETRIC_FACTOR * sh);
+
+    float::sqrt(float::mul_add(
+        delta_l,
+        delta_l,
+        float::mul_add(delta_c, delta_c, delta_h * delta_h),
+    ))
+}
+
+/// Delta E CMC(l:c) weights `[l·SL, c·SC, SH]` for a reference LAB color
+///
+/// The weights depend on the reference color only, which makes the formula
+/// asymmetric: the distance from `a` to `b` differs from `b` to `a`.
+#[must_use]
+pub fn delta_e_cmc_weights(reference: [f64; 3], lightness: f64, chroma: f64) -> [f64; 3] {
+    let [l, a, b] = reference;
+    let c = float::hypot(a, b);
+    let hue = float::atan2(b, a).to_degrees();
+    let hue = if hue < 0.0 { hue + 360.0 } else { hue };
+
+    let sl = if l < CMC_DARK_LIGHTNESS_CUTOFF {
+        CMC_DARK_LIGHTNESS_WEIGHT
+    } else {
+        0.040_975 * l / float::mul_add(0.017_65, l, 1.0)
+    };
+    let sc = 0.0638 * c / float::mul_add(0.0131, c, 1.0) + 0.638;
+
+    let c4 = c * c * c * c;
+    let f = float::sqrt(c4 / (c4 + 1900.0));
+    let t = if (164.0..=345.0).contains(&hue) {
+        0.56 + abs(0.2 * float::cos((hue + 168.0).to_radians()))
+    } else {
+        0.36 + abs(0.4 * float::cos((hue + 35.0).to_radians()))
+    };
+    let sh = sc * float::mul_add(f, t, 1.0 - f);
+
+    [lightness * sl, chroma * sc, sh]
+}
+
+/// Delta E CMC(l:c) distance from the reference `lab1` to the sample `lab2`
+///
+/// `lightness` and `chroma` are the `l` and `c` weights: 2:1 is the usual
+/// acceptability setting, 1:1 the perceptibility setting.
+#[must_use]
+pub fn delta_e_cmc(lab1: [f64; 3], lab2: [f64; 3], lightness: f64, chroma: f64) -> f64 {
+    let [l1, a1, b1] = lab1;
+    let [l2, a2, b2] = lab2;
+    let [wl, wc, wh] = delta_e_cmc_weights(lab1, lightness, chroma);
+
+    let dl = l1 - l2;
+    let dc = float::hypot(a1, b1) - float::hypot(a2, b2);
+    let da = a1 - a2;
+    let db = b1 - b2;
+    let dh_squared = float::mul_add(dc, -dc, float::mul_add(da, da, db * db));
+    let dh_squared = if dh_squared > 0.0 { dh_squared } else { 0.0 };
+
+    let delta_l = dl / wl;
+    let delta_c = dc / wc;
+    float::sqrt(float::mul_add(
+        delta_l,
+        delta_l,
+        float::mul_add(delta_c, delta_c, dh_squared / (wh * wh)),
+    ))
+}
+
+/// Absolute value without relying on `std`
+fn abs(x: f64) -> f64 {
+    if x < 0.0 { -x } else { x }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srgb_lab_roundtrip() {
+        let red = srgb_to_lab([1.0, 0.0, 0.0]);
+        assert!((red[0] - 53.24).abs() < 0.05);
+        assert!((red[1] - 80.09).abs() < 0.1);
+        assert!((red[2] - 67.20).abs() < 0.1);
+
+        let rgb = [0.2, 0.55, 0.8];
+        let back = lab_to_srgb(srgb_to_lab(rgb));
+        for (original, converted) in rgb.iter().zip(back) {
+            assert!((original - converted).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_delta_e_formulas() {
+        let gray = [50.0, 0.0, 0.0];
+        let lighter = [60.0, 0.0, 0.0];
+
+        assert!((delta_e_76(gray, lighter) - 10.0).abs() < 1e-12);
+        assert!(delta_e_2000(gray, gray).abs() < 1e-12);
+        assert!(delta_e_2000(gray, lighter) > 0.0);
+        assert!(delta_e_2000(gray, lighter) <= delta_e_76(gray, lighter));
+    }
+
+    #[test]
+    fn test_delta_e_cmc_reference_values() {
+        // Reference pair from the colour-science documentation of delta_E_CMC
+        let lab1 = [100.0, 21.572_103_57, 272.228_193_5];
+        let lab2 = [100.0, 426.679_453_53, 72.395_908_35];
+        assert!((delta_e_cmc(lab1, lab2, 2.0, 1.0) - 172.704_771_2).abs() < 1e-6);
+        assert!((delta_e_cmc(lab1, lab2, 1.0, 1.0) - 172.704_771_2).abs() < 1e-6);
+
+        // With a lightness difference the l weight matters, and the formula is asymmetric
+        let gray = [50.0, 2.5, 0.0];
+        let violet = [73.0, 25.0, -18.0];
+        assert!((delta_e_cmc(gray, violet, 2.0, 1.0) - 37.923_276).abs() < 1e-5);
+        assert!((delta_e_cmc(gray, violet, 1.0, 1.0) - 42.108_755).abs() < 1e-5);
+        assert!((delta_e_cmc(violet, gray, 2.0, 1.0) - 16.873_959).abs() < 1e-5);
+        assert!(delta_e_cmc(gray, gray, 2.0, 1.0).abs() < 1e-12);
+    }
+}
@@ -3,7 +3,8 @@
 //! Provides serializable color types for JSON/YAML output and conversion
 //! utilities between color spaces for analysis purposes.
 
-use palette::{Hsl, Hsv, Lab, Lch, Srgb};
+use super::hue::normalize_hue;
+use palette::{Hsl, Hsv, Lab, Lch, Oklch, Srgb};
 use serde::{Deserialize, Serialize};
 
 /// Serializable RGB color representation
@@ -41,7 +42,7 @@ pub struct SerializableHsl {
 impl From<Hsl> for SerializableHsl {
     fn from(hsl: Hsl) -> Self {
         Self {
-            hue: hsl.hue.into_inner(),
+            hue: normalize_hue(f64::from(hsl.hue.into_inner())) as f32,
             saturation: hsl.saturation,
             lightness: hsl.lightness,
         }
@@ -59,7 +60,7 @@ pub struct SerializableHsv {
 impl From<Hsv> for SerializableHsv {
     fn from(hsv: Hsv) -> Self {
         Self {
-            hue: hsv.hue.into_inner(),
+            hue: normalize_hue(f64::from(hsv.hue.into_inner())) as f32,
             saturation: hsv.saturation,
             value: hsv.value,
         }
@@ -97,7 +98,45 @@ impl From<Lch> for SerializableLch {
         Self {
             l: lch.l,
             chroma: lch.chroma,
-            hue: lch.hue.into_inner(),
+            hue: normalize_hue(f64::from(lch.hue.into_inner())) as f32,
+        }
+    }
+}
+
+/// Serializable OKLCH color representation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SerializableOklch {
+    pub l: f32,
+    pub chroma: f32,
+    pub hue: f32,
+}
+
+impl From<Oklch> for SerializableOklch {
+    fn from(oklch: Oklch) -> Self {
+        Self {
+            l: oklch.l,
+            chroma: oklch.chroma,
+            hue: normalize_hue(f64::from(oklch.hue.into_inner())) as f32,
+        }
+    }
+}
+
+/// Serializable CMYK color representation (each component 0-1)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SerializableCmyk {
+    pub cyan: f32,
+    pub magenta: f32,
+    pub yellow: f32,
+    pub key: f32,
+}
+
+impl From<(f32, f32, f32, f32)> for SerializableCmyk {
+    fn from((cyan, magenta, yellow, key): (f32, f32, f32, f32)) -> Self {
+        Self {
+            cyan,
+            magenta,
+            yellow,
+            key,
         }
     }
 }
@@ -109,6 +148,8 @@ pub struct ColorSpaces {
     pub hsv: SerializableHsv,
     pub lab: SerializableLab,
     pub lch: SerializableLch,
+    pub oklch: SerializableOklch,
+    pub cmyk: SerializableCmyk,
 }
 
 /// Get color space representations
@@ -121,5 +162,7 @@ pub fn get_color_spaces(color: Srgb) -> ColorSpaces {
         hsv: conversion::srgb_to_hsv(color).into(),
         lab: conversion::srgb_to_lab(color).into(),
         lch: conversion::srgb_to_lch(color).into(),
+        oklch: conversion::srgb_to_oklch(color).into(),
+        cmyk: conversion::srgb_to_cmyk(color).into(),
     }
 }
@@ -4,7 +4,7 @@
 //! accessibility characteristics, and classification functions.
 
 use crate::color_ops::{contrast, luminance};
-use palette::Srgb;
+use palette::{Lab, Srgb};
 use serde::{Deserialize, Serialize};
 
 use super::conversions::{ColorSpaces, SerializableRgb, get_color_spaces};
@@ -31,9 +31,16 @@ pub struct ColorAnalysis {
 /// Basic color properties
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ColorProperties {
-    /// WCAG relative luminance (0.0 to 1.0)
+    /// WCAG relative luminance (0.0 to 1.0); same value as `relative_luminance`,
+    /// kept for compatibility
     pub luminance: f64,
 
+    /// WCAG relative luminance of the gamma-decoded sRGB (0.0 to 1.0), used for contrast
+    pub relative_luminance: f64,
+
+    /// Rec. 709 luma of the gamma-encoded sRGB values (not a luminance)
+    pub luma: f64,
+
     /// Perceived brightness (0.0 to 100.0)
     pub brightness: f64,
 
@@ -59,6 +66,15 @@ pub struct PerceptualData {
     /// Color temperature ("Warm", "Cool", "Neutral")
     pub temperature: String,
 
+    /// Numeric warmth from -1.0 (coolest) to 1.0 (warmest), see [`temperature_score`]
+    pub temperature_score: f64,
+
+    /// Chroma weighted toward mid lightness, see [`vibrance`]
+    pub vibrance: f64,
+
+    /// Grayness from 1.0 (no chroma) toward 0.0, see [`neutrality`]
+    pub neutrality: f64,
+
     /// Saturation level description
     pub saturation_level: String,
 
@@ -155,6 +171,8 @@ fn analyze_properties(color: Srgb) -> ColorProperties {
 
     ColorProperties {
         luminance: luminance_val,
+        relative_luminance: luminance_val,
+        luma: luminance::luma(color),
         brightness,
         is_light,
         is_dark,
@@ -163,16 +181,37 @@ fn analyze_properties(color: Srgb) -> ColorProperties {
     }
 }
 
+impl PerceptualData {
+    /// Whether the color leans warm (positive temperature score)
+    #[must_use]
+    pub fn is_warm(&self) -> bool {
+        self.temperature_score > 0.0
+    }
+
+    /// Whether the color leans cool (negative temperature score)
+    #[must_use]
+    pub fn is_cool(&self) -> bool {
+        self.temperature_score < 0.0
+    }
+}
+
 /// Analyze perceptual characteristics
 fn analyze_perception(_color: Srgb, color_spaces: &ColorSpaces) -> PerceptualData {
     let hue_category = classify_hue(color_spaces.hsv.hue);
     let temperature = classify_temperature(color_spaces.hsv.hue);
+    let temperature_score = temperature_score(f64::from(color_spaces.hsv.hue));
+    let lab = Lab::new(color_spaces.lab.l, color_spaces.lab.a, color_spaces.lab.b);
+    let vibrance = vibrance(lab);
+    let neutrality = neutrality(lab);
     let saturation_level = classify_saturation(color_spaces.hsv.saturation);
     let mood = classify_mood(&hue_category, &temperature, color_spaces.hsv.value);
 
     PerceptualData {
         hue_category,
         temperature,
+        temperature_score,
+        vibrance,
+        neutrality,
         saturation_level,
         mood,
     }
@@ -274,6 +313,68 @@ pub fn classify_temperature(hue_degrees: f32) -> String {
     }
 }
 
+/// Angle (hue, in degrees) at which [`temperature_score`] peaks at +1.0
+pub const WARMEST_HUE: f64 = 30.0;
+
+/// Numeric warmth score for sorting colors by temperature
+///
+/// Maps hue on a cosine curve to -1.0 (coolest) ..= 1.0 (warmest): the score
+/// peaks at +1.0 at 30° (red-orange), falls to 0.0 at 120° and 300° and
+/// bottoms out at -1.0 at 210° (cyan-blue). The sign agrees with
+/// [`classify_temperature`] except near the 135° and 315° boundaries.
+#[must_use]
+pub fn temperature_score(hue: f64) -> f64 {
+    (hue - WARMEST_HUE).to_radians().cos()
+}
+
+/// Lightness at which [`vibrance`] gives chroma its full weight
+pub const VIBRANCE_PEAK_LIGHTNESS: f64 = 50.0;
+
+/// How punchy a color looks: LAB chroma weighted by closeness to mid lightness
+///
+/// The weight is `1 - ((L - 50) / 50)²`, so chroma counts fully at L = 50 and
+/// not at all at black or white. Saturated mid-tones score high; pastels and
+/// dark shades of the same hue score low.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::analysis::vibrance;
+/// use palette::Lab;
+///
+/// let vivid = vibrance(Lab::new(50.0, 60.0, 40.0));
+/// let pastel = vibrance(Lab::new(90.0, 12.0, 8.0));
+/// assert!(vivid > pastel);
+/// ```
+#[must_use]
+pub fn vibrance(lab: Lab) -> f64 {
+    let chroma = f64::from(lab.a).hypot(f64::from(lab.b));
+    let offset = (f64::from(lab.l) - VIBRANCE_PEAK_LIGHTNESS) / VIBRANCE_PEAK_LIGHTNESS;
+    chroma * offset.mul_add(-offset, 1.0).max(0.0)
+}
+
+/// LAB chroma at which [`neutrality`] has fallen to 1/e
+pub const NEUTRALITY_CHROMA_SCALE: f64 = 10.0;
+
+/// How gray a color is: 1.0 at zero chroma, decaying exponentially with chroma
+///
+/// Lightness is ignored, so black, white and every gray score 1.0. The score
+/// is about 0.6 at chroma 5 (a tinted UI neutral) and effectively 0.0 for
+/// saturated colors.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::analysis::neutrality;
+/// use palette::Lab;
+///
+/// assert_eq!(neutrality(Lab::new(50.0, 0.0, 0.0)), 1.0);
+/// assert!(neutrality(Lab::new(50.0, 3.0, -4.0)) > neutrality(Lab::new(50.0, 30.0, -40.0)));
+/// ```
+#[must_use]
+pub fn neutrality(lab: Lab) -> f64 {
+    let chroma = f64::from(lab.a).hypot(f64::from(lab.b));
+    (-chroma / NEUTRALITY_CHROMA_SCALE).exp()
+}
+
 /// Classify saturation level
 #[must_use]
 pub fn classify_saturation(saturation: f32) -> String {
@@ -309,3 +410,88 @@ pub fn classify_mood(hue_category: &str, _temperature: &str, value: f32) -> Stri
 
     format!("{intensity} {base_mood}")
 }
+
+/// Classify perceived lightness from CIELAB L* (0-100)
+#[must_use]
+pub fn classify_lightness(lightness: f32) -> String {
+    match lightness {
+        l if l < 20.0 => "Very Dark".to_string(),
+        l if l < 40.0 => "Dark".to_string(),
+        l if l < 60.0 => "Medium".to_string(),
+        l if l < 80.0 => "Light".to_string(),
+        _ => "Very Light".to_string(),
+    }
+}
+
+/// Plain-English description of a color
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColorDescription {
+    /// Hex representation of the described color
+    pub hex: String,
+
+    /// Lightness descriptor (from LAB L*)
+    pub lightness: String,
+
+    /// Saturation level description
+    pub saturation: String,
+
+    /// Hue category, or "Gray" for achromatic colors
+    pub hue: String,
+
+    /// Color temperature ("Warm", "Cool", "Neutral")
+    pub temperature: String,
+
+    /// Natural-language sentence, e.g. "a light, moderately saturated, warm red"
+    pub description: String,
+}
+
+/// LCH chroma below which a color is described as gray
+const ACHROMATIC_CHROMA: f32 = 5.0;
+
+/// Describe a color in plain English
+///
+/// Combines [`classify_lightness`], [`classify_saturation`], [`classify_hue`] and
+/// [`classify_temperature`] into a phrase such as "a medium, saturated, cool blue".
+/// Near-neutral colors are described by lightness alone ("a dark gray").
+#[must_use]
+pub fn describe_color(color: Srgb) -> ColorDescription {
+    let color_spaces = get_color_spaces(color);
+    let lightness = classify_lightness(color_spaces.lab.l);
+    let saturation = classify_saturation(color_spaces.hsv.saturation);
+
+    let (hue, temperature) = if color_spaces.lch.chroma < ACHROMATIC_CHROMA {
+        ("Gray".to_string(), "Neutral".to_string())
+    } else {
+        (
+            classify_hue(color_spaces.hsv.hue),
+            classify_temperature(color_spaces.hsv.hue),
+        )
+    };
+
+    let description = if hue == "Gray" {
+        format!("a {} gray", lightness.to_lowercase())
+    } else {
+        let saturation_phrase = match saturation.as_str() {
+            "Very Low" => "grayish",
+            "Low" => "muted",
+            "Medium" => "moderately saturated",
+            "High" => "saturated",
+            _ => "vivid",
+        };
+        format!(
+            "a {}, {saturation_phrase}, {} {}",
+            lightness.to_lowercase(),
+            temperature.to_lowercase(),
+            hue.to_lowercase()
+        )
+    };
+
+    ColorDescription {
+        hex: crate::color_ops::conversion::srgb_to_hex(color),
+        lightness,
+        saturation,
+        hue,
+        temperature,
+        description,
+    }
+}
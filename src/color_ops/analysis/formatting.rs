@@ -17,6 +17,7 @@ pub struct ColorComparison {
     pub distance_metrics: DistanceMetrics,
     pub contrast_ratio: f64,
     pub perceptual_similarity: String,
+    pub acceptability: String,
 }
 
 /// Distance metrics between colors
@@ -44,6 +45,7 @@ pub fn compare_colors(color1: Srgb, color2: Srgb) -> ColorComparison {
     let distance_metrics = calculate_distance_metrics(color1, color2);
     let contrast_ratio = contrast::wcag_ratio(color1, color2);
     let perceptual_similarity = classify_similarity(color1, color2);
+    let acceptability = delta_e_acceptability(distance_metrics.delta_e_2000).to_string();
 
     ColorComparison {
         color1: analysis1,
@@ -51,6 +53,7 @@ pub fn compare_colors(color1: Srgb, color2: Srgb) -> ColorComparison {
         distance_metrics,
         contrast_ratio,
         perceptual_similarity,
+        acceptability,
     }
 }
 
@@ -79,3 +82,27 @@ fn classify_similarity(color1: Srgb, color2: Srgb) -> String {
         _ => "Extremely Different".to_string(),
     }
 }
+
+/// Classify a ΔE value into the standard industry acceptability bands
+///
+/// - `< 1`: not perceptible by human eyes
+/// - `1-2`: perceptible through close observation
+/// - `2-10`: perceptible at a glance
+/// - `>= 10`: clearly different colors
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::analysis::delta_e_acceptability;
+///
+/// assert_eq!(delta_e_acceptability(0.5), "Not Perceptible");
+/// assert_eq!(delta_e_acceptability(50.0), "Different");
+/// ```
+#[must_use]
+pub fn delta_e_acceptability(delta_e: f64) -> &'static str {
+    match delta_e {
+        d if d < 1.0 => "Not Perceptible",
+        d if d < 2.0 => "Perceptible on Close Look",
+        d if d < 10.0 => "Perceptible at a Glance",
+        _ => "Different",
+    }
+}
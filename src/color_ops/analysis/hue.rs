@@ -7,6 +7,7 @@
 //! - `analyze_hue_relationships()` - Analyze hue relationships between colors
 //! - `filter_by_hue_criteria()` - Filter colors based on hue, saturation, and lightness criteria
 //! - `sort_by_criteria()` - Sort colors by various criteria (hue distance, saturation, etc.)
+//! - `sort_by_criteria_then()` - Sort by a primary criterion with a secondary one for ties
 //! - `calculate_hue_distance()` - Calculate perceptual hue distance between colors
 //!
 //! ## Design Principles
@@ -25,6 +26,7 @@ use crate::color_parser::{CssColorCollection, RalClassicCollection, RalDesignCol
 use crate::error::{ColorError, Result};
 use palette::{IntoColor, Lch, Srgb};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 /// Color collection selection for hue analysis
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -61,6 +63,12 @@ impl ColorCollectionType {
     pub fn all_collections() -> Vec<Self> {
         vec![Self::Css, Self::RalClassic, Self::RalDesign]
     }
+
+    /// Whether `selection` includes this collection; an empty selection includes all
+    #[must_use]
+    pub fn is_selected(&self, selection: &[Self]) -> bool {
+        selection.is_empty() || selection.iter().any(|c| c == self || *c == Self::All)
+    }
 }
 
 /// Options for hue analysis operations
@@ -87,6 +95,10 @@ pub enum SortCriteria {
     Lightness,
     /// Sort alphabetically by color name
     Name,
+    /// Sort by vibrance, most vibrant first
+    Vibrance,
+    /// Sort by neutrality, grayest first
+    Neutrality,
 }
 
 impl std::str::FromStr for SortCriteria {
@@ -98,8 +110,10 @@ impl std::str::FromStr for SortCriteria {
             "saturation" => Ok(Self::Saturation),
             "lightness" => Ok(Self::Lightness),
             "name" => Ok(Self::Name),
+            "vibrance" => Ok(Self::Vibrance),
+            "neutrality" => Ok(Self::Neutrality),
             _ => Err(ColorError::InvalidArguments(format!(
-                "Invalid sort criteria: {s}. Valid options: hue-distance, saturation, lightness, name"
+                "Invalid sort criteria: {s}. Valid options: hue-distance, saturation, lightness, name, vibrance, neutrality"
             ))),
         }
     }
@@ -238,7 +252,9 @@ pub fn filter_by_hue_criteria(
 
 /// Sort colors by the specified criteria
 ///
-/// Implements various sorting methods for hue analysis results.
+/// Implements various sorting methods for hue analysis results. Ties are
+/// broken by name, then code, so the order is the same on every run; use
+/// [`sort_by_criteria_then`] to choose the tie-breaking criterion.
 ///
 /// # Arguments
 /// * `colors` - Mutable reference to vector of hue analysis results
@@ -249,52 +265,88 @@ pub fn sort_by_criteria(
     criteria: SortCriteria,
     reference_hue: Option<f64>,
 ) {
+    sort_by_criteria_then(colors, criteria, None, reference_hue);
+}
+
+/// Sort colors by a primary criterion, then a secondary one for ties
+///
+/// Colors still tied after both criteria are ordered by name, then code, so
+/// the order is deterministic.
+///
+/// # Arguments
+/// * `colors` - Mutable reference to vector of hue analysis results
+/// * `primary` - Sort criteria applied first
+/// * `secondary` - Sort criteria for colors equal under `primary`
+/// * `reference_hue` - Reference hue for hue distance sorting (if applicable)
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::analysis::hue::{SortCriteria, sort_by_criteria_then};
+///
+/// let mut colors = Vec::new();
+/// sort_by_criteria_then(&mut colors, SortCriteria::HueDistance, Some(SortCriteria::Lightness), Some(0.0));
+/// ```
+pub fn sort_by_criteria_then(
+    colors: &mut [HueAnalysisResult],
+    primary: SortCriteria,
+    secondary: Option<SortCriteria>,
+    reference_hue: Option<f64>,
+) {
+    colors.sort_by(|a, b| {
+        compare_by_criteria(&primary, a, b, reference_hue)
+            .then_with(|| {
+                secondary.as_ref().map_or(Ordering::Equal, |secondary| {
+                    compare_by_criteria(secondary, a, b, reference_hue)
+                })
+            })
+            .then_with(|| compare_by_criteria(&SortCriteria::Name, a, b, reference_hue))
+            .then_with(|| a.code.cmp(&b.code))
+    });
+}
+
+/// Order two colors by a single criterion
+fn compare_by_criteria(
+    criteria: &SortCriteria,
+    a: &HueAnalysisResult,
+    b: &HueAnalysisResult,
+    reference_hue: Option<f64>,
+) -> Ordering {
     match criteria {
         SortCriteria::HueDistance => {
             if let Some(ref_hue) = reference_hue {
-                colors.sort_by(|a, b| {
-                    let dist_a =
-                        calculate_hue_distance(f64::from(a.color.hue.into_degrees()), ref_hue);
-                    let dist_b =
-                        calculate_hue_distance(f64::from(b.color.hue.into_degrees()), ref_hue);
-                    dist_a
-                        .partial_cmp(&dist_b)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
+                let dist_a = calculate_hue_distance(f64::from(a.color.hue.into_degrees()), ref_hue);
+                let dist_b = calculate_hue_distance(f64::from(b.color.hue.into_degrees()), ref_hue);
+                dist_a.partial_cmp(&dist_b).unwrap_or(Ordering::Equal)
             } else {
                 // Sort by hue value if no reference
-                colors.sort_by(|a, b| {
-                    a.color
-                        .hue
-                        .into_degrees()
-                        .partial_cmp(&b.color.hue.into_degrees())
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
+                a.color
+                    .hue
+                    .into_degrees()
+                    .partial_cmp(&b.color.hue.into_degrees())
+                    .unwrap_or(Ordering::Equal)
             }
         }
-        SortCriteria::Saturation => {
-            colors.sort_by(|a, b| {
-                b.color
-                    .chroma
-                    .partial_cmp(&a.color.chroma)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
-        }
-        SortCriteria::Lightness => {
-            colors.sort_by(|a, b| {
-                b.color
-                    .l
-                    .partial_cmp(&a.color.l)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+        SortCriteria::Saturation => b
+            .color
+            .chroma
+            .partial_cmp(&a.color.chroma)
+            .unwrap_or(Ordering::Equal),
+        SortCriteria::Lightness => b.color.l.partial_cmp(&a.color.l).unwrap_or(Ordering::Equal),
+        SortCriteria::Name => match (&a.name, &b.name) {
+            (Some(name_a), Some(name_b)) => name_a.cmp(name_b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+        SortCriteria::Vibrance => {
+            let vibrance_a = super::vibrance(a.color.into_color());
+            let vibrance_b = super::vibrance(b.color.into_color());
+            vibrance_b.total_cmp(&vibrance_a)
         }
-        SortCriteria::Name => {
-            colors.sort_by(|a, b| match (&a.name, &b.name) {
-                (Some(name_a), Some(name_b)) => name_a.cmp(name_b),
-                (Some(_), None) => std::cmp::Ordering::Less,
-                (None, Some(_)) => std::cmp::Ordering::Greater,
-                (None, None) => std::cmp::Ordering::Equal,
-            });
+        SortCriteria::Neutrality => {
+            let neutrality_a = super::neutrality(a.color.into_color());
+            let neutrality_b = super::neutrality(b.color.into_color());
+            neutrality_b.total_cmp(&neutrality_a)
         }
     }
 }
@@ -574,7 +626,7 @@ impl HueDisplayItem {
     /// # Returns
     /// New `HueDisplayItem` for display
     pub fn from_analysis_result(result: &HueAnalysisResult, previous_hue: Option<f64>) -> Self {
-        let hue = f64::from(result.color.hue.into_degrees());
+        let hue = normalize_hue(f64::from(result.color.hue.into_degrees()));
         let hue_shift = previous_hue.map(|prev| {
             // Calculate the shortest hue shift
             let diff = hue - prev;
@@ -749,7 +801,7 @@ impl HueDisplayItemSerialized {
             lch: LchValues {
                 l: f64::from(lch.l),
                 c: f64::from(lch.chroma),
-                h: f64::from(lch.hue.into_degrees()),
+                h: normalize_hue(f64::from(lch.hue.into_degrees())),
             },
             name: item.name.clone(),
             hue_shift: item.hue_shift,
@@ -812,7 +864,7 @@ pub fn export_hue_analysis(
                 "L:{:.1} C:{:.1} H:{:.1}",
                 input_color.l,
                 input_color.chroma,
-                f64::from(input_color.hue.into_degrees())
+                normalize_hue(f64::from(input_color.hue.into_degrees()))
             ),
             target_hue: options.target_hue,
             tolerance: options.tolerance,
@@ -850,6 +902,31 @@ pub fn export_hue_analysis(
                 ColorError::InvalidArguments(format!("Failed to write file {full_filename}: {e}"))
             })?;
         }
+        crate::cli::OutputFormat::Env => {
+            let content = crate::output_formats::to_env_string(&output).map_err(|e| {
+                ColorError::InvalidArguments(format!("env serialization failed: {e}"))
+            })?;
+            let full_filename = if filename.ends_with(".env") {
+                filename.to_string()
+            } else {
+                format!("{filename}.env")
+            };
+            std::fs::write(&full_filename, content).map_err(|e| {
+                ColorError::InvalidArguments(format!("Failed to write file {full_filename}: {e}"))
+            })?;
+        }
+        crate::cli::OutputFormat::Rust
+        | crate::cli::OutputFormat::Markdown
+        | crate::cli::OutputFormat::CssVars => {
+            return Err(format.unsupported_by(
+                "hue analysis export",
+                &[
+                    crate::cli::OutputFormat::Toml,
+                    crate::cli::OutputFormat::Yaml,
+                    crate::cli::OutputFormat::Env,
+                ],
+            ));
+        }
     }
 
     Ok(())
@@ -900,6 +977,56 @@ mod tests {
         assert!(!meets_criteria(&color, Some(120.0), &options_strict));
     }
 
+    #[test]
+    fn test_sort_ties_are_ordered_by_name() {
+        let result = |name: &str, hue: f32, lightness: f32| HueAnalysisResult {
+            color: Lch::new(lightness, 40.0, hue),
+            name: Some(name.to_string()),
+            code: None,
+            hue_distance: 0.0,
+            saturation: 40.0,
+            lightness: f64::from(lightness),
+            collection: "test".to_string(),
+        };
+        let names = |colors: &[HueAnalysisResult]| {
+            colors
+                .iter()
+                .map(|color| color.name.clone().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        // 10° and 350° are both 10° from red, in either input order
+        let forward = vec![
+            result("zinnia", 10.0, 50.0),
+            result("amaranth", 350.0, 60.0),
+            result("coral", 30.0, 70.0),
+        ];
+        let mut reversed = forward.clone();
+        reversed.reverse();
+        for mut colors in [forward.clone(), reversed] {
+            sort_by_criteria(&mut colors, SortCriteria::HueDistance, Some(0.0));
+            assert_eq!(names(&colors), ["amaranth", "zinnia", "coral"]);
+        }
+
+        // A secondary criterion takes precedence over the name
+        let mut colors = forward;
+        sort_by_criteria_then(
+            &mut colors,
+            SortCriteria::HueDistance,
+            Some(SortCriteria::Lightness),
+            Some(0.0),
+        );
+        assert_eq!(names(&colors), ["amaranth", "zinnia", "coral"]);
+        colors[0].color.l = 40.0;
+        sort_by_criteria_then(
+            &mut colors,
+            SortCriteria::HueDistance,
+            Some(SortCriteria::Lightness),
+            Some(0.0),
+        );
+        assert_eq!(names(&colors), ["zinnia", "amaranth", "coral"]);
+    }
+
     #[test]
     fn test_sort_criteria_from_str() {
         assert_eq!(
@@ -915,6 +1042,14 @@ mod tests {
             SortCriteria::Lightness
         );
         assert_eq!(SortCriteria::from_str("name").unwrap(), SortCriteria::Name);
+        assert_eq!(
+            SortCriteria::from_str("vibrance").unwrap(),
+            SortCriteria::Vibrance
+        );
+        assert_eq!(
+            SortCriteria::from_str("neutrality").unwrap(),
+            SortCriteria::Neutrality
+        );
 
         assert!(SortCriteria::from_str("invalid").is_err());
     }
@@ -31,20 +31,21 @@ pub mod hue;
 
 // Re-export main functions for backward compatibility
 pub use core::{
-    analyze_color, classify_hue, classify_mood, classify_saturation, classify_temperature,
+    analyze_color, classify_hue, classify_lightness, classify_mood, classify_saturation,
+    classify_temperature, describe_color, neutrality, temperature_score, vibrance,
 };
 
-pub use formatting::compare_colors;
+pub use formatting::{compare_colors, delta_e_acceptability};
 
 // Re-export all types for public API
 pub use conversions::{
-    ColorSpaces, SerializableHsl, SerializableHsv, SerializableLab, SerializableLch,
-    SerializableRgb, get_color_spaces,
+    ColorSpaces, SerializableCmyk, SerializableHsl, SerializableHsv, SerializableLab,
+    SerializableLch, SerializableOklch, SerializableRgb, get_color_spaces,
 };
 
 pub use core::{
-    AccessibilityData, ColorAnalysis, ColorProperties, PerceptualData, TextRecommendations,
-    WcagInfo,
+    AccessibilityData, ColorAnalysis, ColorDescription, ColorProperties, PerceptualData,
+    TextRecommendations, WcagInfo,
 };
 
 pub use formatting::{ColorComparison, DistanceMetrics};
@@ -66,6 +67,20 @@ mod tests {
         assert_eq!(analysis.properties.hex, "#FF0000");
     }
 
+    #[test]
+    fn test_color_spaces_include_cmyk_and_oklch() {
+        let spaces = analyze_color(Srgb::new(1.0, 0.0, 0.0)).color_spaces;
+
+        assert!(spaces.cmyk.cyan.abs() < 1e-6);
+        assert!(spaces.cmyk.magenta > 0.99);
+        assert!(spaces.cmyk.yellow > 0.99);
+        assert!(spaces.cmyk.key.abs() < 1e-6);
+
+        assert!((spaces.oklch.l - 0.628).abs() < 0.01);
+        assert!((20.0..40.0).contains(&spaces.oklch.hue));
+        assert!(spaces.oklch.chroma > 0.2);
+    }
+
     #[test]
     fn test_analyze_color_white() {
         let white = Srgb::new(1.0, 1.0, 1.0);
@@ -88,6 +103,51 @@ mod tests {
         assert_eq!(classify_hue(300.0), "Violet");
     }
 
+    #[test]
+    fn test_temperature_score() {
+        let orange = analyze_color(Srgb::new(1.0, 0.55, 0.0));
+        assert!(orange.perception.temperature_score > 0.0);
+        assert!(orange.perception.is_warm());
+
+        let cyan = analyze_color(Srgb::new(0.0, 0.8, 1.0));
+        assert!(cyan.perception.temperature_score < 0.0);
+        assert!(cyan.perception.is_cool());
+
+        assert!((temperature_score(30.0) - 1.0).abs() < 1e-9);
+        assert!((temperature_score(210.0) + 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vibrance_prefers_saturated_mid_tones() {
+        use palette::{IntoColor, Lab, Lch};
+
+        let at = |l: f32, chroma: f32| vibrance(Lch::new(l, chroma, 40.0).into_color());
+        let mid_tone = at(50.0, 60.0);
+        assert!(mid_tone > at(88.0, 15.0), "pastel");
+        assert!(mid_tone > at(12.0, 25.0), "dark");
+        assert!(mid_tone > at(50.0, 10.0), "muted");
+
+        assert!(vibrance(Lab::new(0.0, 0.0, 0.0)).abs() < 1e-9);
+        let red = analyze_color(Srgb::new(1.0, 0.0, 0.0));
+        assert!(red.perception.vibrance > 50.0);
+    }
+
+    #[test]
+    fn test_neutrality_scores_grays_highest() {
+        use palette::{IntoColor, Lab};
+
+        let gray = analyze_color(Srgb::new(0.5, 0.5, 0.5));
+        assert!((gray.perception.neutrality - 1.0).abs() < 1e-3);
+        assert_eq!(neutrality(Lab::new(50.0, 0.0, 0.0)), 1.0);
+
+        let red = analyze_color(Srgb::new(1.0, 0.0, 0.0));
+        assert!(red.perception.neutrality < 1e-3);
+
+        let warm_gray: Lab = Srgb::new(0.52, 0.5, 0.48).into_color();
+        assert!(neutrality(warm_gray) < 1.0);
+        assert!(neutrality(warm_gray) > 0.5);
+    }
+
     #[test]
     fn test_temperature_classification() {
         assert_eq!(classify_temperature(0.0), "Warm"); // Red
@@ -116,6 +176,29 @@ mod tests {
         assert!(comparison.distance_metrics.delta_e_2000 > 20.0);
         assert_eq!(comparison.perceptual_similarity, "Extremely Different"); // Updated to match improved algorithm
         assert!(comparison.contrast_ratio > 1.0);
+        assert_eq!(comparison.acceptability, "Different");
+    }
+
+    #[test]
+    fn test_relative_luminance_vs_luma() {
+        let gray = Srgb::new(0.5, 0.5, 0.5);
+        let analysis = analyze_color(gray);
+
+        // Gamma decoding pulls mid-gray's relative luminance down to ~0.214
+        assert!((analysis.properties.relative_luminance - 0.214).abs() < 0.001);
+        assert!((analysis.properties.luma - 0.5).abs() < 1e-6);
+        assert_eq!(
+            analysis.properties.luminance,
+            analysis.properties.relative_luminance
+        );
+    }
+
+    #[test]
+    fn test_delta_e_acceptability() {
+        assert_eq!(delta_e_acceptability(0.5), "Not Perceptible");
+        assert_eq!(delta_e_acceptability(1.5), "Perceptible on Close Look");
+        assert_eq!(delta_e_acceptability(5.0), "Perceptible at a Glance");
+        assert_eq!(delta_e_acceptability(50.0), "Different");
     }
 
     #[test]
@@ -168,4 +251,16 @@ mod tests {
         let _temp = classify_temperature(180.0);
         let _sat = classify_saturation(0.5);
     }
+
+    #[test]
+    fn test_describe_light_red() {
+        let light_red = Srgb::new(1.0, 0.6, 0.6);
+        let description = describe_color(light_red);
+        assert!(description.description.contains("light"));
+        assert!(description.description.contains("red"));
+        assert_eq!(description.hue, "Red");
+
+        let gray = describe_color(Srgb::new(0.5, 0.5, 0.5));
+        assert_eq!(gray.description, "a medium gray");
+    }
 }
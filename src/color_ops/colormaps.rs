@@ -0,0 +1,200 @@
+//! Named colormaps
+//!
+//! Built-in scientific colormaps as evenly spaced sRGB key colors, with
+//! sampling and a reverse lookup that finds where a color falls on a scale.
+//! Keys are the matplotlib colormaps at every tenth of the scale; colors in
+//! between are linear sRGB interpolations, which closely follow the original
+//! 256-entry tables.
+
+use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+use palette::{IntoColor, Lab, Srgb};
+
+/// Samples taken along a scale when looking up a color's position
+pub const POSITION_SAMPLES: usize = 1000;
+
+/// Built-in colormap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Colormap {
+    /// Dark purple through blue and green to yellow
+    Viridis,
+    /// Black through purple and coral to pale yellow
+    Magma,
+    /// Black through purple, red and orange to pale yellow
+    Inferno,
+    /// Deep blue through magenta and orange to yellow
+    Plasma,
+}
+
+impl Colormap {
+    /// Lowercase name of the colormap
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Viridis => "viridis",
+            Self::Magma => "magma",
+            Self::Inferno => "inferno",
+            Self::Plasma => "plasma",
+        }
+    }
+
+    /// Key colors at positions 0.0, 0.1, ..., 1.0
+    #[must_use]
+    pub const fn keys(self) -> &'static [[u8; 3]] {
+        match self {
+            Self::Viridis => &[
+                [0x44, 0x01, 0x54],
+                [0x48, 0x25, 0x76],
+                [0x41, 0x44, 0x87],
+                [0x35, 0x60, 0x8D],
+                [0x2A, 0x78, 0x8E],
+                [0x21, 0x90, 0x8C],
+                [0x22, 0xA8, 0x84],
+                [0x43, 0xBF, 0x71],
+                [0x7A, 0xD1, 0x51],
+                [0xBB, 0xDF, 0x27],
+                [0xFD, 0xE7, 0x25],
+            ],
+            Self::Magma => &[
+                [0x00, 0x00, 0x04],
+                [0x14, 0x0E, 0x36],
+                [0x3B, 0x0F, 0x70],
+                [0x64, 0x1A, 0x80],
+                [0x8C, 0x29, 0x81],
+                [0xB7, 0x37, 0x79],
+                [0xDE, 0x49, 0x68],
+                [0xF7, 0x70, 0x5C],
+                [0xFE, 0x9F, 0x6D],
+                [0xFE, 0xCF, 0x92],
+                [0xFC, 0xFD, 0xBF],
+            ],
+            Self::Inferno => &[
+                [0x00, 0x00, 0x04],
+                [0x16, 0x0B, 0x39],
+                [0x42, 0x0A, 0x68],
+                [0x6A, 0x17, 0x6E],
+                [0x93, 0x26, 0x67],
+                [0xBC, 0x37, 0x54],
+                [0xDD, 0x51, 0x3A],
+                [0xF3, 0x78, 0x19],
+                [0xFC, 0xA5, 0x0A],
+                [0xF6, 0xD7, 0x46],
+                [0xFC, 0xFF, 0xA4],
+            ],
+            Self::Plasma => &[
+                [0x0D, 0x08, 0x87],
+                [0x41, 0x04, 0x9D],
+                [0x6A, 0x00, 0xA8],
+                [0x8F, 0x0D, 0xA4],
+                [0xB1, 0x2A, 0x90],
+                [0xCC, 0x46, 0x78],
+                [0xE1, 0x64, 0x62],
+                [0xF1, 0x84, 0x4B],
+                [0xFC, 0xA6, 0x36],
+                [0xFC, 0xCE, 0x25],
+                [0xF0, 0xF9, 0x21],
+            ],
+        }
+    }
+
+    /// Color at `position` (clamped to 0.0-1.0) on the scale
+    ///
+    /// # Example
+    /// ```rust
+    /// use color_rs::color_ops::colormaps::Colormap;
+    /// use color_rs::color_ops::srgb_to_hex;
+    ///
+    /// assert_eq!(srgb_to_hex(Colormap::Viridis.sample(0.0)), "#440154");
+    /// assert_eq!(srgb_to_hex(Colormap::Viridis.sample(1.0)), "#FDE725");
+    /// ```
+    #[must_use]
+    pub fn sample(self, position: f64) -> Srgb {
+        let keys = self.keys();
+        let scaled = position.clamp(0.0, 1.0) * (keys.len() - 1) as f64;
+        let index = (scaled.floor() as usize).min(keys.len() - 2);
+        let factor = (scaled - index as f64) as f32;
+
+        let channel = |c: usize| {
+            let (from, to) = (f32::from(keys[index][c]), f32::from(keys[index + 1][c]));
+            (to - from).mul_add(factor, from) / 255.0
+        };
+        Srgb::new(channel(0), channel(1), channel(2))
+    }
+}
+
+/// Where a color falls on a colormap
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScalePosition {
+    /// Position on the scale (0.0-1.0)
+    pub position: f64,
+    /// Scale color at that position
+    pub color: Srgb,
+    /// ΔE2000 between the color and the scale color
+    pub delta_e: f64,
+}
+
+/// Find the position on `colormap` whose color is nearest to `color`
+///
+/// Samples the scale at [`POSITION_SAMPLES`] even steps and returns the one
+/// with the smallest ΔE2000; ties go to the lower position.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::colormaps::{Colormap, scale_position};
+/// use palette::{IntoColor, Lab, Srgb};
+///
+/// let yellow: Lab = Srgb::new(0.99, 0.9, 0.15).into_color();
+/// assert!(scale_position(Colormap::Viridis, yellow).position > 0.95);
+/// ```
+#[must_use]
+pub fn scale_position(colormap: Colormap, color: Lab) -> ScalePosition {
+    let at_step = |step: usize| {
+        let position = step as f64 / POSITION_SAMPLES as f64;
+        let sample = colormap.sample(position);
+        let sample_lab: Lab = sample.into_color();
+        ScalePosition {
+            position,
+            color: sample,
+            delta_e: calculate_distance(DistanceAlgorithm::DeltaE2000, color, sample_lab),
+        }
+    };
+
+    (1..=POSITION_SAMPLES)
+        .map(at_step)
+        .fold(at_step(0), |nearest, candidate| {
+            if candidate.delta_e < nearest.delta_e {
+                candidate
+            } else {
+                nearest
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deep_blue_maps_near_start_of_viridis() {
+        // Navy is nearest the dark blue-violet start of viridis
+        let navy: Lab = Srgb::new(0.0, 0.0, 128.0 / 255.0).into_color();
+        let nearest = scale_position(Colormap::Viridis, navy);
+        assert!(nearest.position < 0.1, "{nearest:?}");
+
+        // A key color is found exactly
+        let teal: Lab = Srgb::new(0x21_u8, 0x90, 0x8C)
+            .into_format::<f32>()
+            .into_color();
+        let nearest = scale_position(Colormap::Viridis, teal);
+        assert!((nearest.position - 0.5).abs() < 1e-9, "{nearest:?}");
+        assert!(nearest.delta_e < 0.1);
+
+        for colormap in [
+            Colormap::Viridis,
+            Colormap::Magma,
+            Colormap::Inferno,
+            Colormap::Plasma,
+        ] {
+            assert_eq!(colormap.keys().len(), 11);
+        }
+    }
+}
@@ -153,6 +153,34 @@ pub fn meets_aaa_standard(ratio: f64, large_text: bool) -> bool {
     }
 }
 
+/// Check if contrast ratio meets WCAG AA for large text (3:1)
+///
+/// Large text is 18pt+ regular or 14pt+ bold.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::contrast;
+///
+/// assert!(contrast::meets_aa_large(3.5));
+/// assert!(!contrast::meets_aa_large(2.9));
+/// ```
+pub fn meets_aa_large(ratio: f64) -> bool {
+    meets_aa_standard(ratio, true)
+}
+
+/// Check if contrast ratio meets WCAG AAA for large text (4.5:1)
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::contrast;
+///
+/// assert!(contrast::meets_aaa_large(4.5));
+/// assert!(!contrast::meets_aaa_large(3.5));
+/// ```
+pub fn meets_aaa_large(ratio: f64) -> bool {
+    meets_aaa_standard(ratio, true)
+}
+
 /// Get accessibility compliance level for a contrast ratio
 ///
 /// Returns the highest WCAG compliance level achieved.
@@ -270,6 +298,17 @@ mod tests {
         assert!(!meets_aaa_standard(4.4, true)); // Fails large
     }
 
+    #[test]
+    fn test_large_text_thresholds() {
+        // 3.5:1 is enough for large text but not for normal text
+        assert!(meets_aa_large(3.5));
+        assert!(!meets_aa_standard(3.5, false));
+        assert!(!meets_aaa_large(3.5));
+
+        assert!(meets_aaa_large(4.5));
+        assert!(!meets_aaa_standard(4.5, false));
+    }
+
     #[test]
     fn test_compliance_level() {
         assert_eq!(compliance_level(8.0, false), "AAA");
@@ -3,7 +3,9 @@
 //! Pure functions for converting between different color spaces.
 //! All conversions use the palette crate's color space implementations.
 
-use palette::{Hsl, Hsv, IntoColor, Lab, Lch, Srgb, Xyz};
+use palette::chromatic_adaptation::AdaptFromUnclamped;
+use palette::white_point::D50;
+use palette::{Hsl, Hsv, IntoColor, Lab, Lch, Oklch, Srgb, Xyz};
 
 // ============================================================================
 // RGB Conversions
@@ -138,6 +140,53 @@ pub fn lab_to_srgb(lab: Lab) -> Srgb {
     lab.into_color()
 }
 
+/// Convert D65 CIELAB to D50 CIELAB, the LAB of ICC profiles
+///
+/// Adapts the white point with the Bradford transform, so neutrals stay
+/// neutral and colors keep their appearance under the D50 reference white.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::conversion;
+/// use palette::Lab;
+///
+/// let gray = conversion::lab_to_lab_d50(Lab::new(50.0, 0.0, 0.0));
+/// assert!((gray.l - 50.0).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn lab_to_lab_d50(lab: Lab) -> Lab<D50> {
+    let xyz: Xyz = lab.into_color();
+    Xyz::<D50>::adapt_from_unclamped(xyz).into_color()
+}
+
+/// Convert D50 CIELAB back to D65 CIELAB with the Bradford transform
+#[must_use]
+pub fn lab_d50_to_lab(lab: Lab<D50>) -> Lab {
+    let xyz: Xyz<D50> = lab.into_color();
+    Xyz::adapt_from_unclamped(xyz).into_color()
+}
+
+/// Convert sRGB to D50 CIELAB, as used by ICC profiles and print workflows
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::conversion;
+/// use palette::Srgb;
+///
+/// let white = conversion::srgb_to_lab_d50(Srgb::new(1.0, 1.0, 1.0));
+/// assert!((white.l - 100.0).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn srgb_to_lab_d50(srgb: Srgb) -> Lab<D50> {
+    lab_to_lab_d50(srgb_to_lab(srgb))
+}
+
+/// Convert D50 CIELAB to sRGB color space
+#[must_use]
+pub fn lab_d50_to_srgb(lab: Lab<D50>) -> Srgb {
+    lab_to_srgb(lab_d50_to_lab(lab))
+}
+
 // ============================================================================
 // LCH Conversions
 // ============================================================================
@@ -185,6 +234,56 @@ pub fn lch_to_srgb(lch: Lch) -> Srgb {
     lch.into_color()
 }
 
+/// Convert sRGB to OKLCH color space
+///
+/// # Arguments
+/// * `srgb` - Source color in sRGB space
+///
+/// # Returns
+/// * Color in OKLCH space (L: 0-1, C: 0+, H: 0-360°)
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::conversion;
+/// use palette::Srgb;
+///
+/// let red = Srgb::new(1.0, 0.0, 0.0);
+/// let oklch = conversion::srgb_to_oklch(red);
+/// assert!((oklch.hue.into_positive_degrees() - 29.2).abs() < 0.5);
+/// ```
+pub fn srgb_to_oklch(srgb: Srgb) -> Oklch {
+    srgb.into_color()
+}
+
+// ============================================================================
+// CMYK Conversions
+// ============================================================================
+
+/// Convert sRGB to naive CMYK (no ICC profile)
+///
+/// # Arguments
+/// * `srgb` - Source color in sRGB space
+///
+/// # Returns
+/// * `(cyan, magenta, yellow, key)`, each 0-1; pure black is `(0, 0, 0, 1)`
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::conversion;
+/// use palette::Srgb;
+///
+/// let red = Srgb::new(1.0, 0.0, 0.0);
+/// assert_eq!(conversion::srgb_to_cmyk(red), (0.0, 1.0, 1.0, 0.0));
+/// ```
+pub fn srgb_to_cmyk(srgb: Srgb) -> (f32, f32, f32, f32) {
+    let key = 1.0 - srgb.red.max(srgb.green).max(srgb.blue);
+    if key >= 1.0 {
+        return (0.0, 0.0, 0.0, 1.0);
+    }
+    let ink = |channel: f32| (1.0 - channel - key) / (1.0 - key);
+    (ink(srgb.red), ink(srgb.green), ink(srgb.blue), key)
+}
+
 // ============================================================================
 // XYZ Conversions
 // ============================================================================
@@ -401,6 +500,29 @@ mod tests {
         assert!((original.blue - converted.blue).abs() < 1e-3);
     }
 
+    #[test]
+    fn test_lab_d50_variant() {
+        let gray = Srgb::new(0.5, 0.5, 0.5);
+        let d65 = srgb_to_lab(gray);
+        let d50 = srgb_to_lab_d50(gray);
+
+        // Bradford adaptation keeps a neutral neutral under both reference whites
+        assert!((d50.l - d65.l).abs() < 0.01);
+        for (a, b) in [(d65.a, d65.b), (d50.a, d50.b)] {
+            assert!(a.abs() < 0.05 && b.abs() < 0.05, "a* {a}, b* {b}");
+        }
+
+        // Chromatic colors shift noticeably between the reference whites
+        let orange = Srgb::new(0.9, 0.5, 0.1);
+        let (d65, d50) = (srgb_to_lab(orange), srgb_to_lab_d50(orange));
+        assert!((d50.a - d65.a).abs() > 1.0);
+
+        let back = lab_d50_to_srgb(d50);
+        assert!((back.red - orange.red).abs() < 1e-4);
+        assert!((back.green - orange.green).abs() < 1e-4);
+        assert!((back.blue - orange.blue).abs() < 1e-4);
+    }
+
     #[test]
     fn test_rgb_tuple_conversions() {
         let rgb = (255, 128, 64);
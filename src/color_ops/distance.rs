@@ -57,10 +57,54 @@ pub fn delta_e_cie76(color1: Srgb, color2: Srgb) -> f64 {
 /// let distance = distance::delta_e_cie94(color1, color2);
 /// ```
 pub fn delta_e_cie94(color1: Srgb, color2: Srgb) -> f64 {
-    // Note: Using DeltaE76 as approximation since palette doesn't have CIE94
-    let lab1: Lab = color1.into_color();
-    let lab2: Lab = color2.into_color();
-    calculate_distance(DistanceAlgorithm::DeltaE76, lab1, lab2)
+    delta_e_cie94_lab(color1.into_color(), color2.into_color())
+}
+
+/// Calculate Delta E CIE94 distance between two LAB colors
+///
+/// Uses the graphic-arts weights (kL = kC = kH = 1, K1 = 0.045, K2 = 0.015),
+/// with the first color as the reference for the chroma and hue weighting.
+#[must_use]
+pub fn delta_e_cie94_lab(lab1: Lab, lab2: Lab) -> f64 {
+    const K1: f64 = 0.045;
+    const K2: f64 = 0.015;
+
+    let (l1, a1, b1) = (f64::from(lab1.l), f64::from(lab1.a), f64::from(lab1.b));
+    let (l2, a2, b2) = (f64::from(lab2.l), f64::from(lab2.a), f64::from(lab2.b));
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let delta_l = l1 - l2;
+    let delta_c = c1 - c2;
+    let delta_h_sq = ((a1 - a2).powi(2) + (b1 - b2).powi(2) - delta_c.powi(2)).max(0.0);
+
+    let s_c = K1.mul_add(c1, 1.0);
+    let s_h = K2.mul_add(c1, 1.0);
+
+    (delta_l.powi(2) + (delta_c / s_c).powi(2) + delta_h_sq / s_h.powi(2)).sqrt()
+}
+
+/// Calculate Delta E CMC(l:c) distance between two LAB colors
+///
+/// `lab1` is the reference (standard) and `lab2` the sample; the formula is
+/// asymmetric. Use `l = 2.0, c = 1.0` for acceptability and `l = 1.0, c = 1.0`
+/// for perceptibility.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::distance;
+/// use palette::Lab;
+///
+/// let standard = Lab::new(50.0, 2.5, 0.0);
+/// let sample = Lab::new(73.0, 25.0, -18.0);
+/// let acceptability = distance::delta_e_cmc(standard, sample, 2.0, 1.0);
+/// let perceptibility = distance::delta_e_cmc(standard, sample, 1.0, 1.0);
+/// assert!(acceptability < perceptibility);
+/// ```
+#[must_use]
+pub fn delta_e_cmc(lab1: Lab, lab2: Lab, l: f64, c: f64) -> f64 {
+    let components = |lab: Lab| [lab.l, lab.a, lab.b].map(f64::from);
+    crate::color_math::delta_e_cmc(components(lab1), components(lab2), l, c)
 }
 
 /// Calculate Delta E 2000 distance between two colors
@@ -167,6 +211,26 @@ pub fn lab_direct(lab1: Lab, lab2: Lab) -> f64 {
     (dl * dl + da * da + db * db).sqrt()
 }
 
+/// Whether two colors are the same within `tolerance` under `algorithm`
+///
+/// Colors exactly `tolerance` apart count as equal.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_distance_strategies::DistanceAlgorithm;
+/// use color_rs::color_ops::distance::colors_equal;
+/// use palette::Lab;
+///
+/// let a = Lab::new(50.0, 0.0, 0.0);
+/// let b = Lab::new(51.0, 0.0, 0.0);
+/// assert!(colors_equal(a, b, 1.0, DistanceAlgorithm::DeltaE76));
+/// assert!(!colors_equal(a, b, 0.5, DistanceAlgorithm::DeltaE76));
+/// ```
+#[must_use]
+pub fn colors_equal(a: Lab, b: Lab, tolerance: f64, algorithm: DistanceAlgorithm) -> bool {
+    calculate_distance(algorithm, a, b) <= tolerance
+}
+
 /// Find the closest color from a collection using Delta E 2000
 ///
 /// Utility function to find the perceptually closest color from a collection.
@@ -219,6 +283,36 @@ mod tests {
         assert!(delta_e_2000(color, color) < 1e-6);
     }
 
+    #[test]
+    fn test_delta_e_cie94_weights_chroma() {
+        // Pure chroma difference is discounted relative to CIE76
+        let lab1 = Lab::new(50.0, 60.0, 0.0);
+        let lab2 = Lab::new(50.0, 50.0, 0.0);
+        let expected = 10.0 / 0.045f64.mul_add(60.0, 1.0);
+        assert!((delta_e_cie94_lab(lab1, lab2) - expected).abs() < 1e-4);
+        assert!((delta_e_cie94_lab(lab1, Lab::new(40.0, 60.0, 0.0)) - 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_delta_e_cmc_algorithm() {
+        use crate::color_distance_strategies::CmcWeights;
+
+        let standard = Lab::new(50.0, 2.5, 0.0);
+        let sample = Lab::new(73.0, 25.0, -18.0);
+        assert!((delta_e_cmc(standard, sample, 2.0, 1.0) - 37.923).abs() < 1e-3);
+        assert!((delta_e_cmc(standard, sample, 1.0, 1.0) - 42.109).abs() < 1e-3);
+
+        for (name, weights, expected) in [
+            ("cmc", CmcWeights::Acceptability, 37.923),
+            ("cmc21", CmcWeights::Acceptability, 37.923),
+            ("cmc11", CmcWeights::Perceptibility, 42.109),
+        ] {
+            let algorithm: DistanceAlgorithm = name.parse().unwrap();
+            assert_eq!(algorithm, DistanceAlgorithm::Cmc(weights));
+            assert!((calculate_distance(algorithm, standard, sample) - expected).abs() < 1e-3);
+        }
+    }
+
     #[test]
     fn test_rgb_euclidean_distance() {
         let red = Srgb::new(1.0, 0.0, 0.0);
@@ -271,4 +365,23 @@ mod tests {
         assert!((delta_e_2000(color1, color2) - delta_e_2000(color2, color1)).abs() < 1e-10);
         assert!((rgb_euclidean(color1, color2) - rgb_euclidean(color2, color1)).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_colors_equal_at_tolerance_boundary() {
+        let a = Lab::new(50.0, 10.0, 0.0);
+        let b = Lab::new(50.0, 13.0, 4.0); // exactly 5.0 apart in ΔE76
+
+        assert!(colors_equal(a, b, 5.0, DistanceAlgorithm::DeltaE76));
+        assert!(!colors_equal(a, b, 4.999, DistanceAlgorithm::DeltaE76));
+        assert!(colors_equal(a, a, 0.0, DistanceAlgorithm::DeltaE2000));
+
+        let de2000 = calculate_distance(DistanceAlgorithm::DeltaE2000, a, b);
+        assert!(colors_equal(a, b, de2000, DistanceAlgorithm::DeltaE2000));
+        assert!(!colors_equal(
+            a,
+            b,
+            de2000 - 1e-6,
+            DistanceAlgorithm::DeltaE2000
+        ));
+    }
 }
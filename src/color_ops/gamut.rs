@@ -0,0 +1,79 @@
+//! Gamut membership checks
+//!
+//! Pure functions for deciding whether a LAB color can be shown on an RGB
+//! display without clipping. Conversions are unclamped so out-of-gamut colors
+//! keep their negative or >1.0 channel values.
+
+use palette::convert::IntoColorUnclamped;
+use palette::rgb::DisplayP3;
+use palette::{Lab, Srgb};
+
+/// Channel tolerance for colors that sit on the gamut boundary after rounding
+pub const GAMUT_TOLERANCE: f32 = 1e-4;
+
+/// RGB gamut to check colors against
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Gamut {
+    /// sRGB, the standard web and desktop gamut
+    #[default]
+    Srgb,
+    /// Display P3, the wide gamut of recent phones and monitors
+    #[value(name = "p3", alias = "display-p3")]
+    DisplayP3,
+}
+
+impl Gamut {
+    /// Display name of the gamut
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Srgb => "sRGB",
+            Self::DisplayP3 => "Display P3",
+        }
+    }
+
+    /// Whether `lab` maps into this gamut without clipping
+    ///
+    /// # Example
+    /// ```rust
+    /// use color_rs::color_ops::Gamut;
+    /// use palette::Lab;
+    ///
+    /// // A vivid green that P3 displays can show but sRGB cannot
+    /// let green = Lab::new(85.0, -95.0, 80.0);
+    /// assert!(!Gamut::Srgb.contains(green));
+    /// assert!(Gamut::DisplayP3.contains(green));
+    /// ```
+    #[must_use]
+    pub fn contains(self, lab: Lab) -> bool {
+        let channels = match self {
+            Self::Srgb => {
+                let rgb: Srgb = lab.into_color_unclamped();
+                [rgb.red, rgb.green, rgb.blue]
+            }
+            Self::DisplayP3 => {
+                let rgb: DisplayP3 = lab.into_color_unclamped();
+                [rgb.red, rgb.green, rgb.blue]
+            }
+        };
+        channels
+            .iter()
+            .all(|c| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p3_contains_srgb() {
+        let red: Lab = palette::IntoColor::into_color(Srgb::new(1.0_f32, 0.0, 0.0));
+        assert!(Gamut::Srgb.contains(red));
+        assert!(Gamut::DisplayP3.contains(red));
+
+        let beyond_p3 = Lab::new(60.0, -140.0, 120.0);
+        assert!(!Gamut::Srgb.contains(beyond_p3));
+        assert!(!Gamut::DisplayP3.contains(beyond_p3));
+    }
+}
@@ -75,6 +75,27 @@ pub fn relative_luminance(srgb: Srgb) -> f64 {
     wcag_relative(srgb)
 }
 
+/// Calculate Rec. 709 luma (Y') from gamma-encoded sRGB values
+///
+/// Applies the same weights as [`wcag_relative`] but skips the sRGB gamma
+/// decoding, so the result is a weighted sum of the *encoded* channels. It is
+/// not a luminance and not suitable for WCAG contrast; it is reported so the
+/// difference from the gamma-aware relative luminance is explicit.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::luminance;
+/// use palette::Srgb;
+///
+/// let gray = Srgb::new(0.5, 0.5, 0.5);
+/// assert!((luminance::luma(gray) - 0.5).abs() < 1e-6);
+/// assert!(luminance::wcag_relative(gray) < 0.25);
+/// ```
+#[must_use]
+pub fn luma(srgb: Srgb) -> f64 {
+    0.2126 * f64::from(srgb.red) + 0.7152 * f64::from(srgb.green) + 0.0722 * f64::from(srgb.blue)
+}
+
 /// Calculate perceived brightness using LAB L* component
 ///
 /// Uses the LAB color space L* component which better represents
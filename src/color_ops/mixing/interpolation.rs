@@ -3,7 +3,59 @@
 //! Provides various color space interpolation algorithms for smooth color transitions.
 //! Each method operates in a different color space for different visual characteristics.
 
-use palette::{IntoColor, Lab, Lch, Mix, Srgb};
+use palette::cam16::{Cam16Jmh, Cam16UcsJab, Parameters};
+use palette::{FromColor, Hsl, Hsv, IntoColor, Lab, Lch, Mix, Srgb};
+
+/// Adapting luminance (cd/m²) of the CAM16 viewing conditions: a typical
+/// office display
+const CAM16_ADAPTING_LUMINANCE: f32 = 40.0;
+
+/// Which way hue travels around the color wheel when interpolating
+///
+/// `Cw` moves toward increasing hue angles and `Ccw` toward decreasing ones,
+/// so red (0°) to blue (240°) goes through magenta (300°) with `Ccw` and
+/// through green (120°) with `Cw`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum HueDirection {
+    /// Take the shorter arc (at most 180°)
+    #[default]
+    Short,
+    /// Take the longer arc (at least 180°)
+    Long,
+    /// Always move toward increasing hue angles
+    Cw,
+    /// Always move toward decreasing hue angles
+    Ccw,
+}
+
+/// Interpolate between two hue angles in degrees
+///
+/// Handles the 0°/360° seam: inputs may be any angle and the result is
+/// normalized to `[0, 360)`.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::interpolation::{HueDirection, interpolate_hue};
+///
+/// assert_eq!(interpolate_hue(350.0, 10.0, 0.5, HueDirection::Short), 0.0);
+/// assert_eq!(interpolate_hue(350.0, 10.0, 0.5, HueDirection::Long), 180.0);
+/// ```
+#[must_use]
+pub fn interpolate_hue(from: f32, to: f32, factor: f32, direction: HueDirection) -> f32 {
+    // Increasing-angle distance from `from` to `to`, in [0, 360)
+    let cw = (to - from).rem_euclid(360.0);
+    let ccw = cw - 360.0;
+    let delta = match direction {
+        HueDirection::Short if cw <= 180.0 => cw,
+        HueDirection::Short => ccw,
+        HueDirection::Long if cw > 180.0 || cw == 0.0 => cw,
+        HueDirection::Long => ccw,
+        HueDirection::Cw => cw,
+        HueDirection::Ccw if cw == 0.0 => 0.0,
+        HueDirection::Ccw => ccw,
+    };
+    delta.mul_add(factor, from).rem_euclid(360.0)
+}
 
 /// Mix two colors using linear RGB interpolation
 ///
@@ -92,6 +144,41 @@ pub fn lch_interpolation(color1: Srgb, color2: Srgb, factor: f32) -> Srgb {
     mixed_lch.into_color()
 }
 
+/// Mix two colors using CAM16-UCS interpolation
+///
+/// Interpolates in the uniform color space of the CIE CAM16 appearance model
+/// (default D65 viewing conditions, 40 cd/m²), which is perceptually smoother
+/// than LAB, most visibly across hues such as red to green. Each call runs the
+/// full appearance model in both directions, so it costs roughly 10-20x as
+/// much as `lab_interpolation`.
+///
+/// # Arguments
+/// * `color1` - First color
+/// * `color2` - Second color
+/// * `factor` - Mixing factor (0.0 = color1, 1.0 = color2)
+///
+/// # Returns
+/// * Mixed color in sRGB space
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::interpolation;
+/// use palette::Srgb;
+///
+/// let red = Srgb::new(1.0, 0.0, 0.0);
+/// let green = Srgb::new(0.0, 1.0, 0.0);
+/// let mixed = interpolation::cam16_ucs_interpolation(red, green, 0.5);
+/// ```
+#[must_use]
+pub fn cam16_ucs_interpolation(color1: Srgb, color2: Srgb, factor: f32) -> Srgb {
+    let parameters = Parameters::default_static_wp(CAM16_ADAPTING_LUMINANCE).bake();
+    let to_ucs =
+        |color: Srgb| Cam16UcsJab::from_color(Cam16Jmh::from_xyz(color.into_color(), parameters));
+
+    let mixed = to_ucs(color1).mix(to_ucs(color2), factor);
+    Srgb::from_color(Cam16Jmh::from_color(mixed).into_xyz(parameters))
+}
+
 /// Mix two colors using HSL color space interpolation
 ///
 /// Interpolates in HSL space, useful for maintaining saturation and
@@ -115,11 +202,47 @@ pub fn lch_interpolation(color1: Srgb, color2: Srgb, factor: f32) -> Srgb {
 /// let mixed = interpolation::hsl_interpolation(orange, purple, 0.4);
 /// ```
 pub fn hsl_interpolation(color1: Srgb, color2: Srgb, factor: f32) -> Srgb {
+    hsl_interpolation_directed(color1, color2, factor, HueDirection::Short)
+}
+
+/// Mix two colors in HSL space with an explicit hue direction
+///
+/// Saturation and lightness are interpolated linearly; hue follows
+/// `direction` around the wheel (see [`interpolate_hue`]).
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::interpolation::{self, HueDirection};
+/// use palette::Srgb;
+///
+/// let red = Srgb::new(1.0, 0.0, 0.0);
+/// let blue = Srgb::new(0.0, 0.0, 1.0);
+/// // The long way round passes through green instead of magenta
+/// let via_green = interpolation::hsl_interpolation_directed(red, blue, 0.5, HueDirection::Long);
+/// assert!(via_green.green > via_green.blue);
+/// ```
+#[must_use]
+pub fn hsl_interpolation_directed(
+    color1: Srgb,
+    color2: Srgb,
+    factor: f32,
+    direction: HueDirection,
+) -> Srgb {
     use crate::color_ops::conversion;
 
     let hsl1 = conversion::srgb_to_hsl(color1);
     let hsl2 = conversion::srgb_to_hsl(color2);
-    let mixed_hsl = hsl1.mix(hsl2, factor);
+    let hue = interpolate_hue(
+        hsl1.hue.into_positive_degrees(),
+        hsl2.hue.into_positive_degrees(),
+        factor,
+        direction,
+    );
+    let mixed_hsl = Hsl::new(
+        hue,
+        (hsl2.saturation - hsl1.saturation).mul_add(factor, hsl1.saturation),
+        (hsl2.lightness - hsl1.lightness).mul_add(factor, hsl1.lightness),
+    );
     conversion::hsl_to_srgb(mixed_hsl)
 }
 
@@ -146,10 +269,34 @@ pub fn hsl_interpolation(color1: Srgb, color2: Srgb, factor: f32) -> Srgb {
 /// let mixed = interpolation::hsv_interpolation(bright_red, dark_red, 0.6);
 /// ```
 pub fn hsv_interpolation(color1: Srgb, color2: Srgb, factor: f32) -> Srgb {
+    hsv_interpolation_directed(color1, color2, factor, HueDirection::Short)
+}
+
+/// Mix two colors in HSV space with an explicit hue direction
+///
+/// Saturation and value are interpolated linearly; hue follows `direction`
+/// around the wheel (see [`interpolate_hue`]).
+#[must_use]
+pub fn hsv_interpolation_directed(
+    color1: Srgb,
+    color2: Srgb,
+    factor: f32,
+    direction: HueDirection,
+) -> Srgb {
     use crate::color_ops::conversion;
 
     let hsv1 = conversion::srgb_to_hsv(color1);
     let hsv2 = conversion::srgb_to_hsv(color2);
-    let mixed_hsv = hsv1.mix(hsv2, factor);
+    let hue = interpolate_hue(
+        hsv1.hue.into_positive_degrees(),
+        hsv2.hue.into_positive_degrees(),
+        factor,
+        direction,
+    );
+    let mixed_hsv = Hsv::new(
+        hue,
+        (hsv2.saturation - hsv1.saturation).mul_add(factor, hsv1.saturation),
+        (hsv2.value - hsv1.value).mul_add(factor, hsv1.value),
+    );
     conversion::hsv_to_srgb(mixed_hsv)
 }
@@ -4,7 +4,7 @@
 //! programming principles and single responsibility design.
 //!
 //! ## Submodule Organization
-//! - `interpolation` - Color space interpolation methods (RGB, LAB, LCH, HSL, HSV)
+//! - `interpolation` - Color space interpolation methods (RGB, LAB, LCH, HSL, HSV, CAM16-UCS)
 //! - `blending` - Color blending algorithms (multiply, screen, overlay)
 //! - `utilities` - High-level mixing utilities (palette creation, weighted mixing)
 //!
@@ -13,6 +13,12 @@
 //! - `linear_rgb()` - Fast RGB interpolation
 //! - `lab_interpolation()` - Perceptually uniform mixing
 //! - `create_palette()` - Generate color palettes
+//! - `resample_palette()` - Resample a palette to a new size with even perceptual spacing
+//! - `tints_and_shades()` - Ramp of tints and shades around a base color
+//! - `scale_lightness_preserve_hue()` - Change lightness without hue drift
+//! - `perceptual_midpoint()` - Point equidistant in ΔE2000 from two colors
+//! - `mix_toward()` - Move a color a fraction of the way toward another
+//! - `flatten_over_checker()` - Composite a translucent color over a transparency checkerboard
 //! - `weighted_mix()` - Mix multiple colors with weights
 //!
 //! ## Example Usage
@@ -40,18 +46,47 @@ pub mod utilities;
 
 // Re-export main functions for backward compatibility
 pub use interpolation::{
-    hsl_interpolation, hsv_interpolation, lab_interpolation, lch_interpolation, linear_rgb,
+    HueDirection, cam16_ucs_interpolation, hsl_interpolation, hsl_interpolation_directed,
+    hsv_interpolation, hsv_interpolation_directed, interpolate_hue, lab_interpolation,
+    lch_interpolation, linear_rgb,
 };
 
 pub use blending::{multiply_blend, overlay_blend, screen_blend};
 
-pub use utilities::{ColorSpace, create_palette, mix, weighted_mix};
+pub use utilities::{
+    ColorSpace, create_palette, flatten_over_checker, mix, mix_toward, mix_toward_directed,
+    perceptual_midpoint, resample_palette, scale_lightness_preserve_hue, tints_and_shades,
+    tints_and_shades_preserve_hue, weighted_mix,
+};
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use palette::Srgb;
 
+    #[test]
+    fn test_hsl_hue_direction_across_seam() {
+        let red = Srgb::new(1.0, 0.0, 0.0);
+        let blue = Srgb::new(0.0, 0.0, 1.0);
+
+        // Default (short) and ccw: 0° -> 240° passes magenta at 300°
+        for mid in [
+            hsl_interpolation(red, blue, 0.5),
+            hsl_interpolation_directed(red, blue, 0.5, HueDirection::Ccw),
+        ] {
+            assert!(mid.red > 0.9 && mid.blue > 0.9 && mid.green < 0.1);
+        }
+
+        // Long and cw go the other way, through green at 120°
+        for direction in [HueDirection::Long, HueDirection::Cw] {
+            let mid = hsl_interpolation_directed(red, blue, 0.5, direction);
+            assert!(mid.green > 0.9 && mid.red < 0.1 && mid.blue < 0.1);
+        }
+
+        assert!((interpolate_hue(350.0, 10.0, 0.25, HueDirection::Short) - 355.0).abs() < 1e-4);
+        assert!((interpolate_hue(350.0, 10.0, 0.75, HueDirection::Short) - 5.0).abs() < 1e-4);
+    }
+
     #[test]
     fn test_basic_mixing() {
         let red = Srgb::new(1.0, 0.0, 0.0);
@@ -74,6 +109,55 @@ mod tests {
         assert!((blended.blue - 0.5).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_mix_toward_endpoints() {
+        let base = Srgb::new(0.2, 0.4, 0.8);
+        let white = Srgb::new(1.0, 1.0, 1.0);
+        let close = |a: Srgb, b: Srgb| {
+            (a.red - b.red).abs() < 1e-3
+                && (a.green - b.green).abs() < 1e-3
+                && (a.blue - b.blue).abs() < 1e-3
+        };
+
+        for space in [ColorSpace::Rgb, ColorSpace::Lab, ColorSpace::Lch] {
+            assert!(close(mix_toward(base, white, 0.0, space), base));
+            assert!(close(mix_toward(base, white, 1.0, space), white));
+        }
+        let tint = mix_toward(base, white, 0.2, ColorSpace::Lab);
+        assert!(tint.red > base.red && tint.red < white.red);
+    }
+
+    #[test]
+    fn test_cam16_spacing_is_more_even_than_lab() {
+        use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+        use palette::{IntoColor, Lab};
+
+        // Coefficient of variation of ΔE2000 between neighboring samples
+        let unevenness = |space: ColorSpace| {
+            let palette = create_palette(
+                &[Srgb::new(1.0, 0.0, 0.0), Srgb::new(0.0, 1.0, 0.0)],
+                17,
+                space,
+            );
+            let steps: Vec<f64> = palette
+                .windows(2)
+                .map(|pair| {
+                    let a: Lab = pair[0].into_color();
+                    let b: Lab = pair[1].into_color();
+                    calculate_distance(DistanceAlgorithm::DeltaE2000, a, b)
+                })
+                .collect();
+            let mean = steps.iter().sum::<f64>() / steps.len() as f64;
+            let variance =
+                steps.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / steps.len() as f64;
+            variance.sqrt() / mean
+        };
+
+        let cam16 = unevenness(ColorSpace::Cam16Ucs);
+        let lab = unevenness(ColorSpace::Lab);
+        assert!(cam16 < lab, "CAM16 {cam16} vs LAB {lab}");
+    }
+
     #[test]
     fn test_palette_creation() {
         let keys = vec![
@@ -93,6 +177,86 @@ mod tests {
         assert!((palette[2].green - 1.0).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_palette_resampling() {
+        let keys = [
+            Srgb::new(1.0, 0.0, 0.0), // Red
+            Srgb::new(1.0, 1.0, 0.0), // Yellow
+            Srgb::new(0.0, 0.0, 1.0), // Blue
+        ];
+
+        let resampled = resample_palette(&keys, 5, ColorSpace::Lab);
+        assert_eq!(resampled.len(), 5);
+
+        // Endpoints are preserved
+        let first = resampled[0];
+        let last = resampled[4];
+        assert!((first.red - 1.0).abs() < 1e-3 && first.green < 1e-3 && first.blue < 1e-3);
+        assert!(last.red < 1e-3 && last.green < 1e-3 && (last.blue - 1.0).abs() < 1e-3);
+
+        // Degenerate inputs
+        assert_eq!(resample_palette(&keys[..1], 5, ColorSpace::Lab).len(), 1);
+        assert!(resample_palette(&keys, 0, ColorSpace::Lab).is_empty());
+    }
+
+    #[test]
+    fn test_tints_and_shades_monotonic() {
+        use palette::{IntoColor, Lab};
+
+        let base = Srgb::new(0.2, 0.5, 0.7);
+        let ramp = tints_and_shades(base, 5, 5);
+        assert_eq!(ramp.len(), 11);
+        assert_eq!(ramp[5], base);
+
+        let lightness: Vec<f32> = ramp
+            .iter()
+            .map(|c| {
+                let lab: Lab = (*c).into_color();
+                lab.l
+            })
+            .collect();
+        assert!(lightness.windows(2).all(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    fn test_preserve_hue_reduces_drift() {
+        use palette::{IntoColor, Lab, Lch};
+
+        let blue: Lab = Srgb::new(0.0, 0.0, 1.0).into_color();
+        let hue_of = |srgb: Srgb| {
+            let lch: Lch = srgb.into_color();
+            lch.hue.into_positive_degrees()
+        };
+        let base_hue = hue_of(Srgb::new(0.0, 0.0, 1.0));
+
+        // Naive: scale L only and let the conversion clip to the sRGB gamut
+        let naive: Srgb = Lab::new(blue.l * 1.8, blue.a, blue.b).into_color();
+        let naive_drift = (hue_of(naive) - base_hue).abs();
+
+        let preserved: Srgb = scale_lightness_preserve_hue(blue, 1.8).into_color();
+        let preserved_drift = (hue_of(preserved) - base_hue).abs();
+
+        assert!(preserved_drift < 0.1, "preserved drift {preserved_drift}");
+        assert!(naive_drift > 1.0, "naive drift {naive_drift}");
+
+        let ramp = tints_and_shades_preserve_hue(Srgb::new(0.0, 0.0, 1.0), 3, 3);
+        assert_eq!(ramp.len(), 7);
+    }
+
+    #[test]
+    fn test_perceptual_midpoint_equidistant() {
+        use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+        use palette::Lab;
+
+        let dark_blue = Lab::new(20.0, 10.0, -50.0);
+        let yellow = Lab::new(90.0, -5.0, 80.0);
+        let mid = perceptual_midpoint(dark_blue, yellow);
+
+        let to_a = calculate_distance(DistanceAlgorithm::DeltaE2000, dark_blue, mid);
+        let to_b = calculate_distance(DistanceAlgorithm::DeltaE2000, yellow, mid);
+        assert!((to_a - to_b).abs() < 0.01, "{to_a} vs {to_b}");
+    }
+
     #[test]
     fn test_weighted_mixing() {
         let colors = &[
@@ -106,6 +270,25 @@ mod tests {
         assert!(mixed.blue < 1e-6);
     }
 
+    #[test]
+    fn test_flatten_over_checker() {
+        let translucent_red = palette::Srgba::new(1.0, 0.0, 0.0, 0.5);
+        let (over_white, over_gray) = flatten_over_checker(
+            translucent_red,
+            Srgb::new(1.0, 1.0, 1.0),
+            Srgb::new(0.5, 0.5, 0.5),
+        );
+
+        for (actual, expected) in [
+            (over_white, Srgb::new(1.0, 0.5, 0.5)),
+            (over_gray, Srgb::new(0.75, 0.25, 0.25)),
+        ] {
+            assert!((actual.red - expected.red).abs() < 1e-6);
+            assert!((actual.green - expected.green).abs() < 1e-6);
+            assert!((actual.blue - expected.blue).abs() < 1e-6);
+        }
+    }
+
     #[test]
     fn test_module_re_exports() {
         // Verify all expected functions are accessible
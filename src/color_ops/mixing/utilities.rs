@@ -4,12 +4,15 @@
 //! and other complex color manipulation operations.
 
 use super::interpolation::{
-    hsl_interpolation, hsv_interpolation, lab_interpolation, lch_interpolation, linear_rgb,
+    HueDirection, cam16_ucs_interpolation, hsl_interpolation, hsl_interpolation_directed,
+    hsv_interpolation, hsv_interpolation_directed, lab_interpolation, lch_interpolation,
+    linear_rgb,
 };
-use palette::Srgb;
+use palette::convert::IntoColorUnclamped;
+use palette::{IntoColor, Lab, Lch, Srgb, Srgba};
 
 /// Color space options for interpolation
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum ColorSpace {
     /// Linear RGB interpolation
     Rgb,
@@ -21,6 +24,9 @@ pub enum ColorSpace {
     Hsl,
     /// HSV color space (Hue, Saturation, Value)
     Hsv,
+    /// CAM16-UCS (smoothest perceptual transitions, slowest to compute)
+    #[value(name = "cam16", alias = "cam16-ucs")]
+    Cam16Ucs,
 }
 
 /// Create a color palette by mixing between multiple colors
@@ -70,20 +76,99 @@ pub fn create_palette(key_colors: &[Srgb], steps: usize, color_space: ColorSpace
         let color1 = key_colors[segment_index];
         let color2 = key_colors[segment_index + 1];
 
-        let mixed = match color_space {
-            ColorSpace::Rgb => linear_rgb(color1, color2, local_position),
-            ColorSpace::Lab => lab_interpolation(color1, color2, local_position),
-            ColorSpace::Lch => lch_interpolation(color1, color2, local_position),
-            ColorSpace::Hsl => hsl_interpolation(color1, color2, local_position),
-            ColorSpace::Hsv => hsv_interpolation(color1, color2, local_position),
-        };
-
-        palette.push(mixed);
+        palette.push(interpolate_in_space(
+            color1,
+            color2,
+            local_position,
+            color_space,
+        ));
     }
 
     palette
 }
 
+/// Resample an ordered palette to a target number of colors
+///
+/// Treats the palette as gradient keys and places `target` samples at equal
+/// perceptual (Delta E 2000) arc length along the path through the keys, so
+/// the result is evenly spaced even when the source keys are not. Endpoints are
+/// preserved. Unlike `create_palette`, key positions are weighted by their
+/// perceptual distance rather than their index.
+///
+/// # Arguments
+/// * `palette` - Ordered source colors (any length)
+/// * `target` - Number of colors to produce
+/// * `color_space` - Color space used to interpolate within each segment
+///
+/// # Returns
+/// * Vector of `target` colors (or the source unchanged if it has fewer than 2 colors)
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::utilities;
+/// use palette::Srgb;
+///
+/// let palette = [
+///     Srgb::new(1.0, 0.0, 0.0),
+///     Srgb::new(1.0, 1.0, 0.0),
+///     Srgb::new(0.0, 0.0, 1.0),
+/// ];
+/// let resampled = utilities::resample_palette(&palette, 7, utilities::ColorSpace::Lch);
+/// assert_eq!(resampled.len(), 7);
+/// ```
+#[must_use]
+pub fn resample_palette(palette: &[Srgb], target: usize, color_space: ColorSpace) -> Vec<Srgb> {
+    if palette.len() < 2 {
+        return palette.to_vec();
+    }
+    if target < 2 {
+        return palette.iter().take(target).copied().collect();
+    }
+
+    let segment_lengths: Vec<f64> = palette
+        .windows(2)
+        .map(|pair| crate::color_ops::distance::delta_e_2000(pair[0], pair[1]))
+        .collect();
+    let total_length: f64 = segment_lengths.iter().sum();
+
+    if total_length <= f64::EPSILON {
+        return create_palette(palette, target, color_space);
+    }
+
+    (0..target)
+        .map(|i| {
+            let mut remaining = total_length * i as f64 / (target - 1) as f64;
+            let mut segment = 0;
+            while segment < segment_lengths.len() - 1 && remaining > segment_lengths[segment] {
+                remaining -= segment_lengths[segment];
+                segment += 1;
+            }
+
+            let length = segment_lengths[segment];
+            #[allow(clippy::cast_possible_truncation)] // Safe: factor is within [0, 1]
+            let factor = if length > f64::EPSILON {
+                (remaining / length).clamp(0.0, 1.0) as f32
+            } else {
+                0.0
+            };
+
+            interpolate_in_space(palette[segment], palette[segment + 1], factor, color_space)
+        })
+        .collect()
+}
+
+/// Interpolate two colors in the requested color space
+fn interpolate_in_space(color1: Srgb, color2: Srgb, factor: f32, color_space: ColorSpace) -> Srgb {
+    match color_space {
+        ColorSpace::Rgb => linear_rgb(color1, color2, factor),
+        ColorSpace::Lab => lab_interpolation(color1, color2, factor),
+        ColorSpace::Lch => lch_interpolation(color1, color2, factor),
+        ColorSpace::Hsl => hsl_interpolation(color1, color2, factor),
+        ColorSpace::Hsv => hsv_interpolation(color1, color2, factor),
+        ColorSpace::Cam16Ucs => cam16_ucs_interpolation(color1, color2, factor),
+    }
+}
+
 /// Mix multiple colors with specified weights
 ///
 /// Weighted average of multiple colors. Weights don't need to sum to 1.0.
@@ -119,6 +204,247 @@ pub fn weighted_mix(colors_and_weights: &[(Srgb, f32)], color_space: ColorSpace)
     }
 }
 
+/// Create a tints-and-shades ramp around a base color
+///
+/// Tints mix the base toward white and shades toward black in LAB space, at
+/// evenly spaced fractions that exclude pure white and black. The ramp is
+/// ordered from the lightest tint, through the base, to the darkest shade.
+///
+/// # Arguments
+/// * `base` - Color at the center of the ramp
+/// * `tints` - Number of colors lighter than the base
+/// * `shades` - Number of colors darker than the base
+///
+/// # Returns
+/// * Vector of `tints + 1 + shades` colors
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::utilities;
+/// use palette::Srgb;
+///
+/// let ramp = utilities::tints_and_shades(Srgb::new(0.2, 0.4, 0.8), 3, 2);
+/// assert_eq!(ramp.len(), 6);
+/// ```
+#[must_use]
+pub fn tints_and_shades(base: Srgb, tints: usize, shades: usize) -> Vec<Srgb> {
+    let white = Srgb::new(1.0, 1.0, 1.0);
+    let black = Srgb::new(0.0, 0.0, 0.0);
+
+    let tint_colors = (1..=tints)
+        .rev()
+        .map(|i| lab_interpolation(base, white, i as f32 / (tints + 1) as f32));
+    let shade_colors =
+        (1..=shades).map(|i| lab_interpolation(base, black, i as f32 / (shades + 1) as f32));
+
+    tint_colors
+        .chain(std::iter::once(base))
+        .chain(shade_colors)
+        .collect()
+}
+
+/// Create a tints-and-shades ramp that holds the base hue constant
+///
+/// Same layout as [`tints_and_shades`], but each step sets LCH lightness
+/// directly via [`scale_lightness_preserve_hue`] instead of mixing toward
+/// white or black, so tints keep as much chroma as the sRGB gamut allows.
+#[must_use]
+pub fn tints_and_shades_preserve_hue(base: Srgb, tints: usize, shades: usize) -> Vec<Srgb> {
+    let base_lab: Lab = base.into_color();
+    let to_srgb =
+        |lightness: f32| -> Srgb { with_lightness_preserve_hue(base_lab, lightness).into_color() };
+
+    let tint_colors = (1..=tints).rev().map(|i| {
+        let t = i as f32 / (tints + 1) as f32;
+        to_srgb(base_lab.l + (100.0 - base_lab.l) * t)
+    });
+    let shade_colors = (1..=shades).map(|i| {
+        let t = i as f32 / (shades + 1) as f32;
+        to_srgb(base_lab.l * (1.0 - t))
+    });
+
+    tint_colors
+        .chain(std::iter::once(base))
+        .chain(shade_colors)
+        .collect()
+}
+
+/// Scale LAB lightness while holding the LCH hue constant
+///
+/// Scaling L alone usually pushes saturated colors out of the sRGB gamut, and
+/// per-channel clamping then shifts the hue (blues drift toward purple). This
+/// instead reduces LCH chroma just enough to stay in gamut, leaving hue intact.
+///
+/// # Arguments
+/// * `lab` - Color to adjust
+/// * `factor` - Multiplier for L (result is clamped to 0-100)
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::utilities;
+/// use palette::Lab;
+///
+/// let lighter = utilities::scale_lightness_preserve_hue(Lab::new(32.3, 79.2, -107.9), 1.5);
+/// assert!((lighter.l - 48.45).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn scale_lightness_preserve_hue(lab: Lab, factor: f32) -> Lab {
+    with_lightness_preserve_hue(lab, lab.l * factor)
+}
+
+/// Set LCH lightness, reducing chroma until the color fits in sRGB
+fn with_lightness_preserve_hue(lab: Lab, lightness: f32) -> Lab {
+    const GAMUT_EPSILON: f32 = 1e-4;
+    const SEARCH_ITERATIONS: usize = 24;
+
+    let lch: Lch = lab.into_color();
+    let lightness = lightness.clamp(0.0, 100.0);
+    let candidate = |chroma: f32| -> Lab { Lch::new(lightness, chroma, lch.hue).into_color() };
+    let in_gamut = |chroma: f32| {
+        // Unclamped: `into_color` would silently clip to the gamut
+        let srgb: Srgb = candidate(chroma).into_color_unclamped();
+        [srgb.red, srgb.green, srgb.blue]
+            .iter()
+            .all(|c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(c))
+    };
+
+    if in_gamut(lch.chroma) {
+        return candidate(lch.chroma);
+    }
+
+    let (mut low, mut high) = (0.0, lch.chroma);
+    for _ in 0..SEARCH_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        if in_gamut(mid) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    candidate(low)
+}
+
+/// Find the perceptual midpoint of two colors
+///
+/// The arithmetic LAB midpoint is not always perceived as halfway, because
+/// ΔE2000 is not a Euclidean metric. This binary-searches the straight LAB path
+/// for the point whose ΔE2000 to both endpoints is equal.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::utilities;
+/// use palette::Lab;
+///
+/// let mid = utilities::perceptual_midpoint(Lab::new(20.0, 40.0, 10.0), Lab::new(80.0, -20.0, 30.0));
+/// assert!(mid.l > 20.0 && mid.l < 80.0);
+/// ```
+#[must_use]
+pub fn perceptual_midpoint(a: Lab, b: Lab) -> Lab {
+    use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+    use palette::Mix;
+
+    const SEARCH_ITERATIONS: usize = 40;
+
+    // ΔE(a, c) - ΔE(b, c) rises monotonically from negative to positive along the path
+    let imbalance = |t: f32| {
+        let c = a.mix(b, t);
+        calculate_distance(DistanceAlgorithm::DeltaE2000, a, c)
+            - calculate_distance(DistanceAlgorithm::DeltaE2000, b, c)
+    };
+
+    let (mut low, mut high) = (0.0_f32, 1.0_f32);
+    for _ in 0..SEARCH_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        if imbalance(mid) < 0.0 {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    a.mix(b, (low + high) / 2.0)
+}
+
+/// Move `base` a fraction of the way toward `target`
+///
+/// Designer-friendly form of [`mix`] with an explicit color space: "tint 20%
+/// toward white" is `mix_toward(base, white, 0.2, ColorSpace::Lab)`. `amount`
+/// is clamped to 0.0-1.0, so 0.0 keeps the base and 1.0 yields the target.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::{ColorSpace, mix_toward};
+/// use palette::Srgb;
+///
+/// let tint = mix_toward(Srgb::new(0.2, 0.4, 0.8), Srgb::new(1.0, 1.0, 1.0), 0.2, ColorSpace::Lab);
+/// assert!(tint.red > 0.2);
+/// ```
+#[must_use]
+pub fn mix_toward(base: Srgb, target: Srgb, amount: f32, space: ColorSpace) -> Srgb {
+    let amount = amount.clamp(0.0, 1.0);
+    match space {
+        ColorSpace::Lab => mix(base, target, amount),
+        _ => interpolate_in_space(base, target, amount, space),
+    }
+}
+
+/// [`mix_toward`] with hue traveling around the wheel in `direction`
+///
+/// Only the hue-based spaces (`Hsl`, `Hsv`) honor `direction`; every other
+/// space mixes exactly as [`mix_toward`] does.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::{ColorSpace, HueDirection, mix_toward_directed};
+/// use palette::Srgb;
+///
+/// let red = Srgb::new(1.0, 0.0, 0.0);
+/// let blue = Srgb::new(0.0, 0.0, 1.0);
+/// let via_green = mix_toward_directed(red, blue, 0.5, ColorSpace::Hsl, HueDirection::Long);
+/// assert!(via_green.green > 0.9);
+/// ```
+#[must_use]
+pub fn mix_toward_directed(
+    base: Srgb,
+    target: Srgb,
+    amount: f32,
+    space: ColorSpace,
+    direction: HueDirection,
+) -> Srgb {
+    let amount = amount.clamp(0.0, 1.0);
+    match space {
+        ColorSpace::Hsl => hsl_interpolation_directed(base, target, amount, direction),
+        ColorSpace::Hsv => hsv_interpolation_directed(base, target, amount, direction),
+        _ => mix_toward(base, target, amount, space),
+    }
+}
+
+/// Composite a translucent color over both cells of a transparency checkerboard
+///
+/// Uses the source-over operator in gamma-encoded sRGB, as browsers do, and
+/// returns the visible colors over the `light` and `dark` cells in that order.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::mixing::flatten_over_checker;
+/// use palette::{Srgb, Srgba};
+///
+/// let (over_light, over_dark) =
+///     flatten_over_checker(Srgba::new(0.0, 0.0, 1.0, 1.0), Srgb::new(1.0, 1.0, 1.0), Srgb::new(0.8, 0.8, 0.8));
+/// assert_eq!(over_light, over_dark);
+/// ```
+#[must_use]
+pub fn flatten_over_checker(color: Srgba, light: Srgb, dark: Srgb) -> (Srgb, Srgb) {
+    let alpha = color.alpha.clamp(0.0, 1.0);
+    let over = |background: Srgb| {
+        Srgb::new(
+            (color.red - background.red).mul_add(alpha, background.red),
+            (color.green - background.green).mul_add(alpha, background.green),
+            (color.blue - background.blue).mul_add(alpha, background.blue),
+        )
+    };
+    (over(light), over(dark))
+}
+
 /// Alias for `lab_interpolation` - recommended mixing method
 #[must_use]
 pub fn mix(color1: Srgb, color2: Srgb, factor: f32) -> Srgb {
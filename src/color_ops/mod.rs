@@ -16,8 +16,11 @@
 //! - **distance**: Perceptual and mathematical color distance metrics
 //! - **contrast**: WCAG contrast ratios and accessibility compliance
 //! - **conversion**: Color space transformations and format conversions
+//! - **gamut**: sRGB and Display P3 gamut membership
 //! - **analysis**: Comprehensive color analysis and comparison
 //! - **mixing**: Color blending, interpolation, and palette generation
+//! - **random**: Seeded random palettes under lightness, chroma and ΔE constraints
+//! - **colormaps**: Built-in named colormaps (viridis, magma, ...) and position lookup
 //!
 //! # Usage Examples
 //!
@@ -50,25 +53,37 @@
 
 // Core operation modules
 pub mod analysis;
+pub mod colormaps;
 pub mod contrast;
 pub mod conversion;
 pub mod distance;
+pub mod gamut;
 pub mod luminance;
 pub mod mixing;
+pub mod random;
 
 // Re-export commonly used functions for convenience
 pub use analysis::{
     AccessibilityData, ColorAnalysis, ColorComparison, ColorProperties, ColorSpaces,
     PerceptualData, analyze_color, compare_colors,
 };
-pub use contrast::{compliance_level, meets_aa_standard, meets_aaa_standard, ratio, wcag_ratio};
+pub use contrast::{
+    compliance_level, meets_aa_large, meets_aa_standard, meets_aaa_large, meets_aaa_standard,
+    ratio, wcag_ratio,
+};
 pub use conversion::{
     hex_to_srgb, rgb_tuple_to_srgb, srgb_to_hex, srgb_to_hsl, srgb_to_hsv, srgb_to_lab,
     srgb_to_lch, srgb_to_rgb_tuple,
 };
-pub use distance::{delta_e_2000, delta_e_cie76, delta_e_cie94, find_closest, perceptual_distance};
+pub use distance::{
+    delta_e_2000, delta_e_cie76, delta_e_cie94, delta_e_cie94_lab, delta_e_cmc, find_closest,
+    perceptual_distance,
+};
+pub use gamut::Gamut;
 pub use luminance::{perceived_brightness, relative_luminance, wcag_relative};
 pub use mixing::{
-    ColorSpace, create_palette, lab_interpolation, lch_interpolation, linear_rgb, mix,
-    multiply_blend, overlay_blend, screen_blend, weighted_mix,
+    ColorSpace, create_palette, lab_interpolation, lch_interpolation, linear_rgb, mix, mix_toward,
+    multiply_blend, overlay_blend, perceptual_midpoint, resample_palette,
+    scale_lightness_preserve_hue, screen_blend, tints_and_shades, tints_and_shades_preserve_hue,
+    weighted_mix,
 };
@@ -0,0 +1,182 @@
+//! Seeded random palettes
+//!
+//! Samples LCH colors inside lightness and chroma constraints and keeps only
+//! in-gamut sRGB colors at least a minimum ΔE2000 from every color already
+//! chosen. A SplitMix64 generator keeps a palette identical for a given seed on
+//! every platform and release.
+
+use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+use crate::color_ops::Gamut;
+use crate::error::{ColorError, Result};
+use palette::{FromColor, Lab, Lch, Srgb};
+
+/// Candidates drawn per requested color before the constraints are rejected
+pub const ATTEMPTS_PER_COLOR: usize = 10_000;
+
+/// Largest chroma sampled; no sRGB color goes beyond it
+const MAX_SAMPLED_CHROMA: f64 = 134.0;
+
+/// Constraints for [`random_palette`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RandomPaletteConstraints {
+    /// Number of colors to generate
+    pub count: usize,
+    /// Smallest LCH chroma of a color
+    pub min_chroma: f64,
+    /// Inclusive LAB lightness band, `[min, max]` within 0-100
+    pub lightness: [f64; 2],
+    /// Smallest ΔE2000 between any two colors
+    pub min_delta_e: f64,
+}
+
+impl Default for RandomPaletteConstraints {
+    fn default() -> Self {
+        Self {
+            count: 5,
+            min_chroma: 0.0,
+            lightness: [0.0, 100.0],
+            min_delta_e: 10.0,
+        }
+    }
+}
+
+/// Deterministic SplitMix64 pseudo-random generator
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    /// Create a generator whose sequence is fixed by `seed`
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Next 64 random bits
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `[min, max)`
+    pub fn next_in(&mut self, min: f64, max: f64) -> f64 {
+        // The top 53 bits fill an f64 mantissa exactly
+        let unit = (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64;
+        (max - min).mul_add(unit, min)
+    }
+}
+
+/// Generate a palette of distinct sRGB colors from `seed`
+///
+/// Colors are snapped to 8-bit sRGB before the distance check, so the
+/// returned colors are exactly the ones their hex codes describe. The same
+/// seed and constraints always give the same palette.
+///
+/// # Errors
+/// Returns `ColorError::InvalidArguments` if the constraints leave too little
+/// room: no palette was found within [`ATTEMPTS_PER_COLOR`] draws per color.
+///
+/// # Example
+/// ```rust
+/// use color_rs::color_ops::random::{RandomPaletteConstraints, random_palette};
+///
+/// let constraints = RandomPaletteConstraints::default();
+/// let palette = random_palette(42, constraints)?;
+/// assert_eq!(palette.len(), 5);
+/// assert_eq!(palette, random_palette(42, constraints)?);
+/// # Ok::<(), color_rs::error::ColorError>(())
+/// ```
+pub fn random_palette(seed: u64, constraints: RandomPaletteConstraints) -> Result<Vec<Lab>> {
+    let RandomPaletteConstraints {
+        count,
+        min_chroma,
+        lightness: [min_lightness, max_lightness],
+        min_delta_e,
+    } = constraints;
+
+    let mut rng = SeededRng::new(seed);
+    let mut colors: Vec<Lab> = Vec::with_capacity(count);
+    for _ in 0..ATTEMPTS_PER_COLOR.saturating_mul(count) {
+        if colors.len() == count {
+            break;
+        }
+
+        let l = rng.next_in(min_lightness, max_lightness);
+        let chroma = rng.next_in(min_chroma, MAX_SAMPLED_CHROMA.max(min_chroma));
+        let hue = rng.next_in(0.0, 360.0);
+        let candidate = Lab::from_color(Lch::new(l as f32, chroma as f32, hue as f32));
+        if !Gamut::Srgb.contains(candidate) {
+            continue;
+        }
+
+        let snapped = Lab::from_color(
+            Srgb::from_color(candidate)
+                .into_format::<u8>()
+                .into_format::<f32>(),
+        );
+        let snapped_lch = Lch::from_color(snapped);
+        if f64::from(snapped_lch.chroma) < min_chroma
+            || !(min_lightness..=max_lightness).contains(&f64::from(snapped.l))
+        {
+            continue;
+        }
+
+        let distinct = colors.iter().all(|&chosen| {
+            calculate_distance(DistanceAlgorithm::DeltaE2000, chosen, snapped) >= min_delta_e
+        });
+        if distinct {
+            colors.push(snapped);
+        }
+    }
+
+    if colors.len() < count {
+        return Err(ColorError::InvalidArguments(format!(
+            "Could only place {} of {count} colors at least ΔE {min_delta_e} apart with chroma >= {min_chroma} and lightness {min_lightness}-{max_lightness}; loosen the constraints",
+            colors.len()
+        )));
+    }
+    Ok(colors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_palette_is_deterministic_and_distinct() {
+        let constraints = RandomPaletteConstraints {
+            count: 8,
+            min_chroma: 30.0,
+            lightness: [40.0, 80.0],
+            min_delta_e: 15.0,
+        };
+
+        let palette = random_palette(7, constraints).unwrap();
+        assert_eq!(palette, random_palette(7, constraints).unwrap());
+        assert_ne!(palette, random_palette(8, constraints).unwrap());
+
+        assert_eq!(palette.len(), 8);
+        for (i, first) in palette.iter().enumerate() {
+            assert!((40.0..=80.0).contains(&first.l));
+            assert!(Lch::from_color(*first).chroma >= 30.0);
+            for second in &palette[i + 1..] {
+                let distance = calculate_distance(DistanceAlgorithm::DeltaE2000, *first, *second);
+                assert!(
+                    distance >= 15.0,
+                    "{first:?} and {second:?} are {distance} apart"
+                );
+            }
+        }
+
+        let impossible = RandomPaletteConstraints {
+            count: 50,
+            min_delta_e: 60.0,
+            ..constraints
+        };
+        assert!(random_palette(7, impossible).is_err());
+    }
+}
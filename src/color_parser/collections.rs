@@ -3,6 +3,7 @@
 //! A trait-based system for managing different color collections with unified search capabilities.
 //! Supports different native color spaces while using LAB for perceptually accurate comparisons.
 
+use super::kd_tree::LabKdTree;
 use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
 use palette::{IntoColor, Lab, Srgb};
 use std::collections::HashMap;
@@ -188,6 +189,8 @@ pub struct SearchFilter {
     pub groups: Option<Vec<String>>,
     /// Luminance range filter [min, max]
     pub luminance_range: Option<[f64; 2]>,
+    /// LAB lightness (L) range filter [min, max]
+    pub lightness_range: Option<[f64; 2]>,
     /// Maximum color distance for "close enough" matches
     pub max_distance: Option<f64>,
     /// Name pattern matching
@@ -248,6 +251,14 @@ pub trait ColorCollection: Send + Sync {
         self.find_closest_with_algorithm(target, max_results, filter, DistanceAlgorithm::DeltaE2000)
     }
 
+    /// Spatial index over the LAB coordinates of [`colors`](Self::colors)
+    ///
+    /// Collections that build one at load time get tree-based searches;
+    /// otherwise searches fall back to a linear scan.
+    fn lab_index(&self) -> Option<&LabKdTree> {
+        None
+    }
+
     /// Find the closest color matches to a target color using a specific algorithm
     fn find_closest_with_algorithm(
         &self,
@@ -255,6 +266,30 @@ pub trait ColorCollection: Send + Sync {
         max_results: usize,
         filter: Option<&SearchFilter>,
         algorithm: DistanceAlgorithm,
+    ) -> Vec<ColorMatch> {
+        let Some(index) = self.lab_index() else {
+            return self.find_closest_linear(target, max_results, filter, algorithm);
+        };
+
+        let colors = self.colors();
+        index
+            .nearest(colors, target, max_results, algorithm, |entry| {
+                self.matches_filter(entry, filter)
+            })
+            .into_iter()
+            .map(|(i, distance)| ColorMatch::new(colors[i].clone(), distance))
+            .collect()
+    }
+
+    /// Find the closest color matches by comparing against every entry
+    ///
+    /// Reference implementation for [`find_closest_with_algorithm`](Self::find_closest_with_algorithm).
+    fn find_closest_linear(
+        &self,
+        target: &UniversalColor,
+        max_results: usize,
+        filter: Option<&SearchFilter>,
+        algorithm: DistanceAlgorithm,
     ) -> Vec<ColorMatch> {
         let mut matches: Vec<ColorMatch> = self
             .colors()
@@ -343,6 +378,14 @@ pub trait ColorCollection: Send + Sync {
             }
         }
 
+        // Check LAB lightness range
+        if let Some(range) = filter.lightness_range {
+            let lightness = f64::from(entry.color.lab[0]);
+            if lightness < range[0] || lightness > range[1] {
+                return false;
+            }
+        }
+
         // Check name pattern
         if let Some(ref pattern) = filter.name_pattern {
             if !entry
@@ -489,4 +532,21 @@ mod tests {
         assert_eq!(entry.metadata.code, Some("R001".to_string()));
         assert_eq!(entry.metadata.group, Some("Primary".to_string()));
     }
+
+    #[test]
+    fn test_lightness_range_filter() {
+        let collection = crate::color_parser::CssColorCollection::new().unwrap();
+        let target = UniversalColor::from_rgb([0, 0, 0]);
+        let filter = SearchFilter {
+            lightness_range: Some([70.0, 100.0]),
+            ..Default::default()
+        };
+
+        let unfiltered = collection.find_closest(&target, 4, None);
+        assert_eq!(unfiltered[0].entry.metadata.name.to_lowercase(), "black");
+
+        let matches = collection.find_closest(&target, 4, Some(&filter));
+        assert_eq!(matches.len(), 4);
+        assert!(matches.iter().all(|m| m.entry.color.lab[0] >= 70.0));
+    }
 }
@@ -8,9 +8,8 @@ use super::ral_matcher::{RalClassification, RalMatch};
 use super::unified_manager::UnifiedColorManager;
 
 /// Lazy static unified manager for backward compatibility
-static UNIFIED_MANAGER: std::sync::LazyLock<UnifiedColorManager> = std::sync::LazyLock::new(|| {
-    UnifiedColorManager::new().expect("Failed to create UnifiedColorManager")
-});
+static UNIFIED_MANAGER: std::sync::LazyLock<UnifiedColorManager> =
+    std::sync::LazyLock::new(UnifiedColorManager::new);
 
 /// Convert new `ColorMatch` to old `RalMatch` for backward compatibility
 fn color_match_to_ral_match(
@@ -3,20 +3,62 @@
 //! Implementation of the unified color collection system for CSS named colors.
 
 use super::collections::{ColorCollection, ColorEntry, UniversalColor};
-use super::csv_loader::CsvLoader;
+use super::csv_loader::{CsvColorEntry, CsvLoader};
+use super::kd_tree::LabKdTree;
 use anyhow::Result;
+use std::path::Path;
 
 /// CSS Named Colors Collection
 pub struct CssColorCollection {
     colors: Vec<ColorEntry>,
+    index: LabKdTree,
 }
 
+/// Path of the CSS named colors table, relative to the working directory
+pub const CSS_COLORS_CSV: &str = "color-table/css-colors.csv";
+
+/// CSS named colors table compiled into the binary
+const BUNDLED_CSS_COLORS: &str = include_str!("../../color-table/css-colors.csv");
+
 impl CssColorCollection {
     /// Create a new CSS color collection
     pub fn new() -> Result<Self> {
-        let csv_colors = CsvLoader::load_colors_from_csv("color-table/css-colors.csv")?;
+        Self::from_csv(CSS_COLORS_CSV)
+    }
+
+    /// Load a CSS color collection from a `Code;Name;Hex` CSV file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is malformed.
+    pub fn from_csv<P: AsRef<Path>>(file_path: P) -> Result<Self> {
+        Ok(Self::from_csv_entries(&CsvLoader::load_colors_from_csv(
+            file_path,
+        )?))
+    }
+
+    /// The CSS named colors compiled into the binary
+    #[must_use]
+    pub fn bundled() -> Self {
+        let entries =
+            CsvLoader::load_colors_from_reader(BUNDLED_CSS_COLORS.as_bytes(), "bundled CSS colors")
+                .expect("bundled CSS color table is valid");
+        Self::from_csv_entries(&entries)
+    }
+
+    /// Load from `file_path`, falling back to the bundled colors with a warning
+    ///
+    /// See [`CsvLoader::load_colors_or_bundled`].
+    #[must_use]
+    pub fn from_csv_or_bundled(file_path: &str) -> Self {
+        Self::from_csv_entries(&CsvLoader::load_colors_or_bundled(
+            file_path,
+            BUNDLED_CSS_COLORS,
+        ))
+    }
 
-        let colors = csv_colors
+    fn from_csv_entries(csv_colors: &[CsvColorEntry]) -> Self {
+        let colors: Vec<ColorEntry> = csv_colors
             .iter()
             .map(|entry| {
                 let rgb = CsvLoader::hex_to_rgb(&entry.hex).unwrap_or([0, 0, 0]); // Fallback to black on error
@@ -29,7 +71,8 @@ impl CssColorCollection {
             })
             .collect();
 
-        Ok(Self { colors })
+        let index = LabKdTree::build(&colors);
+        Self { colors, index }
     }
 }
 
@@ -41,6 +84,10 @@ impl ColorCollection for CssColorCollection {
     fn colors(&self) -> &[ColorEntry] {
         &self.colors
     }
+
+    fn lab_index(&self) -> Option<&LabKdTree> {
+        Some(&self.index)
+    }
 }
 
 #[cfg(test)]
@@ -74,4 +121,22 @@ mod tests {
         assert!(!matches.is_empty());
         assert_eq!(matches[0].entry.metadata.name, "Red"); // Name is capitalized in CSV
     }
+
+    #[test]
+    fn test_missing_csv_falls_back_to_bundled_colors() {
+        assert!(CssColorCollection::from_csv("missing/css-colors.csv").is_err());
+
+        let collection = CssColorCollection::from_csv_or_bundled("missing/css-colors.csv");
+        assert_eq!(
+            collection.colors().len(),
+            CssColorCollection::new().unwrap().colors().len()
+        );
+
+        let red = collection.find_by_name("red").expect("red is bundled");
+        assert_eq!(red.color.rgb, [255, 0, 0]);
+
+        let target = UniversalColor::from_rgb([254, 1, 1]);
+        let matches = collection.find_closest(&target, 1, None);
+        assert_eq!(matches[0].entry.metadata.name, "Red");
+    }
 }
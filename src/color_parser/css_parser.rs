@@ -65,7 +65,7 @@ impl CssColorParser {
         )))
     }
 
-    /// Parse hex color (#rgb or #rrggbb)
+    /// Parse hex color (#rgb, #rgba, #rrggbb or #rrggbbaa)
     fn parse_hex(input: &str) -> Result<ParsedColor> {
         let hex_part = &input[1..]; // Remove #
 
@@ -90,6 +90,19 @@ impl CssColorParser {
 
                 Ok(ParsedColor::from_rgb(r, g, b, ColorFormat::Hex))
             }
+            4 | 8 => {
+                // #rgba / #rrggbbaa: the last digit(s) are the alpha channel
+                let (color, alpha) = hex_part.split_at(hex_part.len() * 3 / 4);
+                let rgb = Self::parse_hex(&format!("#{color}"))?;
+                let alpha = if alpha.len() == 1 {
+                    format!("{alpha}{alpha}")
+                } else {
+                    alpha.to_string()
+                };
+                let a = f64::from(ParseUtils::parse_hex_component(&alpha)?) / 255.0;
+
+                Ok(ParsedColor::new(rgb.r, rgb.g, rgb.b, a, ColorFormat::Hex))
+            }
             _ => Err(ColorError::InvalidColor(
                 "Invalid hex color length".to_string(),
             )),
@@ -123,7 +136,7 @@ impl CssColorParser {
                         "RGBA requires 4 parameters".to_string(),
                     ));
                 }
-                let (r, g, b) = Self::parse_rgb_params(&params)?;
+                let (r, g, b) = Self::parse_rgb_params(&params[..3])?;
                 let a = ParseUtils::parse_alpha(params[3])?;
                 Ok(ParsedColor::new(r, g, b, a, ColorFormat::Rgba))
             }
@@ -214,20 +227,11 @@ impl CssColorParser {
             ));
         }
 
-        let lightness: f32 = params[0]
-            .trim()
-            .parse()
-            .map_err(|_| ColorError::InvalidColor("Invalid LCH L value".to_string()))?;
+        let lightness: f32 = params[0].trim().parse()?;
 
-        let chroma: f32 = params[1]
-            .trim()
-            .parse()
-            .map_err(|_| ColorError::InvalidColor("Invalid LCH C value".to_string()))?;
+        let chroma: f32 = params[1].trim().parse()?;
 
-        let hue: f32 = params[2]
-            .trim()
-            .parse()
-            .map_err(|_| ColorError::InvalidColor("Invalid LCH H value".to_string()))?;
+        let hue: f32 = params[2].trim().parse()?;
 
         // Convert LCH to LAB using palette's functional approach
         let lch = Lch::new(lightness, chroma, hue);
@@ -4,8 +4,14 @@
 
 use anyhow::{Context, Result};
 use csv::ReaderBuilder;
+use std::collections::BTreeSet;
 use std::fs::File;
+use std::io::Read;
 use std::path::Path;
+use std::sync::Mutex;
+
+/// Tables already reported as replaced by their bundled copy
+static BUNDLED_FALLBACKS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
 
 /// Color data entry from CSV
 #[derive(Debug, Clone)]
@@ -30,52 +36,209 @@ impl CsvLoader {
             format!("Failed to open CSV file: {}", file_path.as_ref().display())
         })?;
 
+        Self::load_colors_from_reader(file, &file_path.as_ref().display().to_string())
+    }
+
+    /// Columns every bundled collection CSV must provide
+    pub const REQUIRED_COLUMNS: [&'static str; 3] = ["Code", "Name", "Hex"];
+
+    /// Load `file_path`, falling back to `bundled`, a copy compiled into the binary
+    ///
+    /// Keeps the built-in collections working when the table is missing, e.g.
+    /// when the binary runs outside the repository. The first fallback for
+    /// each path prints a warning; later ones are silent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bundled` is not a valid color table.
+    #[must_use]
+    pub fn load_colors_or_bundled(file_path: &str, bundled: &str) -> Vec<CsvColorEntry> {
+        Self::load_colors_from_csv(file_path).unwrap_or_else(|e| {
+            let first = BUNDLED_FALLBACKS
+                .lock()
+                .map_or(true, |mut warned| warned.insert(file_path.to_string()));
+            if first {
+                eprintln!("Warning: {e:#}; using the bundled copy");
+            }
+            Self::load_colors_from_reader(bundled.as_bytes(), &format!("bundled {file_path}"))
+                .expect("bundled color table is valid")
+        })
+    }
+
+    /// Load color data in the `Code;Name;Hex` format from any reader
+    ///
+    /// `source` names the data in error messages. The header row is checked
+    /// against [`Self::REQUIRED_COLUMNS`] (case-insensitive, any order, extra
+    /// columns ignored) before any record is read, so a file whose schema has
+    /// changed fails as a whole instead of loading partially.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data cannot be read, a required column is
+    /// missing (naming the file and column), or a record is malformed (naming
+    /// the file and line).
+    pub fn load_colors_from_reader<R: Read>(data: R, source: &str) -> Result<Vec<CsvColorEntry>> {
         let mut reader = ReaderBuilder::new()
             .delimiter(b';')
             .has_headers(true)
-            .from_reader(file);
+            .from_reader(data);
+
+        let headers = reader
+            .headers()
+            .with_context(|| format!("Failed to read CSV header from {source}"))?
+            .clone();
+        let [code_col, name_col, hex_col] = Self::REQUIRED_COLUMNS.map(|name| {
+            headers
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(name))
+                .with_context(|| {
+                    format!(
+                        "{source}: line 1: missing required column '{name}' (expected {})",
+                        Self::REQUIRED_COLUMNS.join(";")
+                    )
+                })
+        });
+        let (code_col, name_col, hex_col) = (code_col?, name_col?, hex_col?);
 
         let mut colors = Vec::new();
 
         for result in reader.records() {
-            let record = result.with_context(|| {
-                format!(
-                    "Failed to read CSV record from {}",
-                    file_path.as_ref().display()
-                )
-            })?;
-
-            if record.len() != 3 {
-                anyhow::bail!(
-                    "Invalid CSV format: expected 3 columns (Code;Name;Hex), got {}",
-                    record.len()
-                );
-            }
+            let record =
+                result.with_context(|| format!("Failed to read CSV record from {source}"))?;
+            let line = record.position().map_or(0, csv::Position::line);
+            let field = |col: usize| record.get(col).map_or("", str::trim).to_string();
 
             let entry = CsvColorEntry {
-                code: record[0].trim().to_string(),
-                name: record[1].trim().to_string(),
-                hex: record[2].trim().to_string(),
+                code: field(code_col),
+                name: field(name_col),
+                hex: field(hex_col),
             };
 
             // Validate hex format
             if !entry.hex.starts_with('#') || entry.hex.len() != 7 {
-                anyhow::bail!("Invalid hex color format: {} (expected #RRGGBB)", entry.hex);
+                anyhow::bail!(
+                    "{source}: line {line}: invalid hex color format: {} (expected #RRGGBB)",
+                    entry.hex
+                );
             }
 
             colors.push(entry);
         }
 
         if colors.is_empty() {
+            anyhow::bail!("No color data found in CSV file: {source}");
+        }
+
+        Ok(colors)
+    }
+
+    /// Load a user-supplied color set from CSV
+    ///
+    /// The file is semicolon-separated with a header row. Columns are located by
+    /// header name (case-insensitive), so their order does not matter:
+    /// - `Name` (required)
+    /// - `Hex` (`#RRGGBB`) or `RGB` (`r,g,b` or `rgb(r, g, b)`), at least one required
+    /// - `Code` (optional, defaults to the name)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, the header lacks the required
+    /// columns, or a record is malformed. Record errors include the line number.
+    pub fn load_custom_colors_from_csv<P: AsRef<Path>>(file_path: P) -> Result<Vec<CsvColorEntry>> {
+        let path = file_path.as_ref();
+        let file = File::open(path)
+            .with_context(|| format!("Failed to open CSV file: {}", path.display()))?;
+
+        let mut reader = ReaderBuilder::new()
+            .delimiter(b';')
+            .has_headers(true)
+            .flexible(true)
+            .from_reader(file);
+
+        let headers = reader
+            .headers()
+            .with_context(|| format!("Failed to read CSV header from {}", path.display()))?
+            .clone();
+        let column = |name: &str| {
+            headers
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(name))
+        };
+
+        let name_col = column("name").with_context(|| {
+            format!("{}: line 1: missing required column 'Name'", path.display())
+        })?;
+        let hex_col = column("hex");
+        let rgb_col = column("rgb");
+        let code_col = column("code");
+        if hex_col.is_none() && rgb_col.is_none() {
             anyhow::bail!(
-                "No color data found in CSV file: {}",
-                file_path.as_ref().display()
+                "{}: line 1: expected a 'Hex' or 'RGB' column",
+                path.display()
             );
         }
 
+        let mut colors = Vec::new();
+
+        for result in reader.records() {
+            let record = result
+                .with_context(|| format!("Failed to read CSV record from {}", path.display()))?;
+            let line = record.position().map_or(0, csv::Position::line);
+            let field = |col: Option<usize>| {
+                col.and_then(|c| record.get(c))
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+            };
+
+            let name = field(Some(name_col))
+                .with_context(|| format!("{}: line {line}: missing name", path.display()))?;
+
+            let hex = if let Some(hex) = field(hex_col) {
+                Self::hex_to_rgb(hex)
+                    .with_context(|| format!("{}: line {line}", path.display()))?;
+                hex.to_string()
+            } else if let Some(rgb) = field(rgb_col) {
+                let [r, g, b] = Self::parse_rgb_triplet(rgb)
+                    .with_context(|| format!("{}: line {line}", path.display()))?;
+                format!("#{r:02X}{g:02X}{b:02X}")
+            } else {
+                anyhow::bail!("{}: line {line}: missing hex or rgb value", path.display());
+            };
+
+            colors.push(CsvColorEntry {
+                code: field(code_col).unwrap_or(name).to_string(),
+                name: name.to_string(),
+                hex,
+            });
+        }
+
+        if colors.is_empty() {
+            anyhow::bail!("No color data found in CSV file: {}", path.display());
+        }
+
         Ok(colors)
     }
 
+    /// Parse an RGB triplet written as `r,g,b` or `rgb(r, g, b)`
+    fn parse_rgb_triplet(value: &str) -> Result<[u8; 3]> {
+        let inner = value
+            .trim()
+            .strip_prefix("rgb(")
+            .and_then(|v| v.strip_suffix(')'))
+            .unwrap_or(value);
+
+        let components = inner
+            .split(',')
+            .map(|c| c.trim().parse::<u8>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .with_context(|| format!("Invalid RGB value: {value} (expected r,g,b)"))?;
+
+        match components.as_slice() {
+            [r, g, b] => Ok([*r, *g, *b]),
+            _ => anyhow::bail!("Invalid RGB value: {value} (expected r,g,b)"),
+        }
+    }
+
     /// Convert hex string to RGB array
     pub fn hex_to_rgb(hex: &str) -> Result<[u8; 3]> {
         let hex = hex.trim_start_matches('#');
@@ -127,4 +290,24 @@ mod tests {
         let result = CsvLoader::load_colors_from_csv(temp_file.path());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_missing_required_column() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "Code;Name;Color").unwrap();
+        writeln!(temp_file, "black;Black;#000000").unwrap();
+
+        let error = CsvLoader::load_colors_from_csv(temp_file.path())
+            .err()
+            .unwrap();
+        let message = format!("{error:#}");
+        assert!(message.contains(&temp_file.path().display().to_string()));
+        assert!(message.contains("missing required column 'Hex'"));
+
+        // Column order and extra columns do not matter
+        let data = "Hex;Name;Notes;Code\n#FFFFFF;White;;white\n";
+        let colors = CsvLoader::load_colors_from_reader(data.as_bytes(), "inline").unwrap();
+        assert_eq!(colors[0].code, "white");
+        assert_eq!(colors[0].hex, "#FFFFFF");
+    }
 }
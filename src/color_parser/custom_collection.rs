@@ -0,0 +1,128 @@
+//! Custom Color Collection Implementation
+//!
+//! Ad-hoc collection built at runtime from a user-supplied CSV file
+//! (see [`CsvLoader::load_custom_colors_from_csv`] for the expected columns),
+//! or merged from other collections.
+
+use super::collections::{ColorCollection, ColorEntry, UniversalColor};
+use super::csv_loader::CsvLoader;
+use super::kd_tree::LabKdTree;
+use anyhow::Result;
+use std::path::Path;
+
+/// User-supplied color collection loaded from CSV
+pub struct CustomColorCollection {
+    colors: Vec<ColorEntry>,
+    index: LabKdTree,
+}
+
+impl CustomColorCollection {
+    /// Load a custom color collection from a CSV file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or contains malformed records.
+    pub fn from_csv<P: AsRef<Path>>(file_path: P) -> Result<Self> {
+        let csv_colors = CsvLoader::load_custom_colors_from_csv(file_path)?;
+
+        let colors = csv_colors
+            .into_iter()
+            .map(|entry| {
+                // Hex values are validated by the loader
+                let rgb = CsvLoader::hex_to_rgb(&entry.hex).unwrap_or([0, 0, 0]);
+
+                ColorEntry::new(UniversalColor::from_rgb(rgb), entry.name)
+                    .with_code(entry.code)
+                    .with_group("Custom".to_string())
+                    .with_original_format(entry.hex)
+            })
+            .collect();
+
+        Ok(Self::from_entries(colors))
+    }
+
+    /// Build a collection from existing entries
+    #[must_use]
+    pub fn from_entries(colors: Vec<ColorEntry>) -> Self {
+        let index = LabKdTree::build(&colors);
+        Self { colors, index }
+    }
+
+    /// Merge several collections into one searchable set
+    ///
+    /// Entries keep their source order. With a `dedup_threshold`, an entry within
+    /// that ΔE2000 of an already kept entry is dropped, so the earliest source's
+    /// name wins.
+    #[must_use]
+    pub fn merge(sources: &[&dyn ColorCollection], dedup_threshold: Option<f64>) -> Self {
+        let mut colors: Vec<ColorEntry> = Vec::new();
+
+        for entry in sources.iter().flat_map(|source| source.colors()) {
+            let is_duplicate = dedup_threshold.is_some_and(|threshold| {
+                colors
+                    .iter()
+                    .any(|kept| kept.color.distance_to(&entry.color) <= threshold)
+            });
+            if !is_duplicate {
+                colors.push(entry.clone());
+            }
+        }
+
+        Self::from_entries(colors)
+    }
+}
+
+impl ColorCollection for CustomColorCollection {
+    fn name(&self) -> &'static str {
+        "Custom Collection"
+    }
+
+    fn colors(&self) -> &[ColorEntry] {
+        &self.colors
+    }
+
+    fn lab_index(&self) -> Option<&LabKdTree> {
+        Some(&self.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_custom_collection_matching() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "Name;Hex;RGB;Code").unwrap();
+        writeln!(temp_file, "Brand Red;#D0021B;;BR-1").unwrap();
+        writeln!(temp_file, "Brand Blue;;31,78,173;").unwrap();
+
+        let collection = CustomColorCollection::from_csv(temp_file.path()).unwrap();
+        assert_eq!(collection.colors().len(), 2);
+        assert_eq!(collection.colors()[1].color.rgb, [31, 78, 173]);
+        assert_eq!(
+            collection.colors()[1].metadata.code.as_deref(),
+            Some("Brand Blue")
+        );
+
+        let target = UniversalColor::from_rgb([200, 10, 30]);
+        let matches = collection.find_closest(&target, 1, None);
+        assert_eq!(matches[0].entry.metadata.name, "Brand Red");
+        assert_eq!(matches[0].entry.metadata.code.as_deref(), Some("BR-1"));
+    }
+
+    #[test]
+    fn test_custom_collection_reports_line_numbers() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "Name;Hex").unwrap();
+        writeln!(temp_file, "Good;#112233").unwrap();
+        writeln!(temp_file, "Bad;#GG0000").unwrap();
+
+        let error = CustomColorCollection::from_csv(temp_file.path())
+            .err()
+            .unwrap();
+        assert!(format!("{error:#}").contains("line 3"));
+    }
+}
@@ -0,0 +1,300 @@
+//! LAB k-d Tree
+//!
+//! A 3D k-d tree over a collection's LAB coordinates, built once when the
+//! collection is loaded. Nearest-neighbour queries walk the tree and skip any
+//! subtree whose splitting plane is provably farther than the current k-th
+//! best match.
+//!
+//! Euclidean metrics (Delta E 76, Euclidean LAB, LCH) prune on the plane
+//! distance directly. Delta E 2000 is not Euclidean, so the plane distance is
+//! converted into a lower bound on Delta E 2000 first and every surviving
+//! candidate is scored with the true metric. Delta E CMC is bounded the same
+//! way using the weights of the query color, which is its reference. Results
+//! are identical to a linear scan, including the order of ties.
+
+use super::collections::{ColorEntry, UniversalColor};
+use crate::color_distance_strategies::DistanceAlgorithm;
+use crate::color_math::{
+    DELTA_E_CHROMA_FACTOR, DELTA_E_LIGHTNESS_DENOMINATOR_OFFSET, DELTA_E_LIGHTNESS_FACTOR,
+    DELTA_E_LIGHTNESS_OFFSET, DELTA_E_PARAMETRIC_FACTOR, delta_e_cmc_weights,
+};
+
+/// Slack added to the pruning threshold to absorb `f32` → `f64` rounding
+const PRUNE_EPSILON: f64 = 1e-9;
+
+/// A tree node referencing one collection entry
+#[derive(Debug, Clone)]
+struct Node {
+    /// Index of the entry in the collection's color slice
+    index: usize,
+    /// LAB coordinates of the entry
+    point: [f64; 3],
+    /// Splitting axis (0 = L, 1 = a, 2 = b)
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+/// Static k-d tree over LAB coordinates of a color collection
+#[derive(Debug, Clone, Default)]
+pub struct LabKdTree {
+    nodes: Vec<Node>,
+    root: Option<usize>,
+}
+
+impl LabKdTree {
+    /// Build a tree over the LAB coordinates of `entries`
+    ///
+    /// Node indices refer back into `entries`, so the tree must be queried
+    /// with the same slice it was built from.
+    #[must_use]
+    pub fn build(entries: &[ColorEntry]) -> Self {
+        let mut points: Vec<(usize, [f64; 3])> = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (index, entry.color.lab.map(f64::from)))
+            .collect();
+
+        let mut tree = Self {
+            nodes: Vec::with_capacity(points.len()),
+            root: None,
+        };
+        tree.root = tree.build_subtree(&mut points, 0);
+        tree
+    }
+
+    fn build_subtree(&mut self, points: &mut [(usize, [f64; 3])], depth: usize) -> Option<usize> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let axis = depth % 3;
+        let median = points.len() / 2;
+        points.select_nth_unstable_by(median, |a, b| a.1[axis].total_cmp(&b.1[axis]));
+
+        let (index, point) = points[median];
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            index,
+            point,
+            axis,
+            left: None,
+            right: None,
+        });
+
+        let (below, rest) = points.split_at_mut(median);
+        self.nodes[node].left = self.build_subtree(below, depth + 1);
+        self.nodes[node].right = self.build_subtree(&mut rest[1..], depth + 1);
+        Some(node)
+    }
+
+    /// Number of indexed entries
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the tree indexes no entries
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Find the `k` entries closest to `target` under `algorithm`
+    ///
+    /// Only entries accepted by `accept` are considered. Returns
+    /// `(entry index, distance)` pairs sorted by distance, with ties in
+    /// collection order.
+    pub fn nearest(
+        &self,
+        entries: &[ColorEntry],
+        target: &UniversalColor,
+        k: usize,
+        algorithm: DistanceAlgorithm,
+        accept: impl Fn(&ColorEntry) -> bool,
+    ) -> Vec<(usize, f64)> {
+        let mut search = Search {
+            entries,
+            target,
+            algorithm,
+            accept,
+            target_point: target.lab.map(f64::from),
+            k,
+            best: Vec::with_capacity(k + 1),
+        };
+        if k > 0 {
+            self.visit(self.root, &mut search);
+        }
+        search.best
+    }
+
+    fn visit<F: Fn(&ColorEntry) -> bool>(&self, node: Option<usize>, search: &mut Search<'_, F>) {
+        let Some(node) = node.map(|n| &self.nodes[n]) else {
+            return;
+        };
+
+        search.consider(node.index);
+
+        let offset = search.target_point[node.axis] - node.point[node.axis];
+        let (near, far) = if offset < 0.0 {
+            (node.left, node.right)
+        } else {
+            (node.right, node.left)
+        };
+
+        self.visit(near, search);
+        if !search.can_prune(offset.abs()) {
+            self.visit(far, search);
+        }
+    }
+}
+
+/// State of a single nearest-neighbour query
+struct Search<'a, F> {
+    entries: &'a [ColorEntry],
+    target: &'a UniversalColor,
+    algorithm: DistanceAlgorithm,
+    accept: F,
+    target_point: [f64; 3],
+    k: usize,
+    /// Best matches so far as `(index, distance)`, sorted by distance then index
+    best: Vec<(usize, f64)>,
+}
+
+impl<F: Fn(&ColorEntry) -> bool> Search<'_, F> {
+    fn consider(&mut self, index: usize) {
+        let entry = &self.entries[index];
+        if !(self.accept)(entry) {
+            return;
+        }
+
+        let distance = self
+            .target
+            .distance_to_with_algorithm(&entry.color, self.algorithm);
+        let position = self
+            .best
+            .partition_point(|&(i, d)| d < distance || (d == distance && i < index));
+        if position < self.k {
+            self.best.insert(position, (index, distance));
+            self.best.truncate(self.k);
+        }
+    }
+
+    /// Whether every entry at least `plane_distance` away in LAB can be skipped
+    fn can_prune(&self, plane_distance: f64) -> bool {
+        self.best.len() == self.k
+            && self.lower_bound(plane_distance) > self.best[self.k - 1].1 + PRUNE_EPSILON
+    }
+
+    /// Smallest possible metric distance for an entry at Euclidean LAB distance `d`
+    fn lower_bound(&self, d: f64) -> f64 {
+        match self.algorithm {
+            DistanceAlgorithm::DeltaE76
+            | DistanceAlgorithm::EuclideanLab
+            | DistanceAlgorithm::Lch => d,
+            DistanceAlgorithm::DeltaE2000 => {
+                // Delta E 2000 divides the lightness, chroma and hue terms (whose
+                // squares sum to d²) by weights of at least 1, so it is at least
+                // d over the largest weight. Candidate chroma is at most the
+                // target chroma plus d, which bounds the chroma weight.
+                let [l, a, b] = self.target_point;
+                let l_offset = (l - DELTA_E_LIGHTNESS_OFFSET)
+                    .abs()
+                    .max(DELTA_E_LIGHTNESS_OFFSET);
+                let sl_max = 1.0
+                    + DELTA_E_LIGHTNESS_FACTOR * l_offset * l_offset
+                        / l_offset
+                            .mul_add(l_offset, DELTA_E_LIGHTNESS_DENOMINATOR_OFFSET)
+                            .sqrt();
+                let sc_max = DELTA_E_CHROMA_FACTOR.mul_add(a.hypot(b) + d / 2.0, 1.0);
+                d / (DELTA_E_PARAMETRIC_FACTOR * sl_max.max(sc_max))
+            }
+            DistanceAlgorithm::Cmc(weights) => {
+                // CMC weights depend on the reference (the target) only, and the
+                // lightness, chroma and hue terms' squares sum to d²
+                let [wl, wc, wh] =
+                    delta_e_cmc_weights(self.target_point, weights.lightness(), weights.chroma());
+                d / wl.max(wc).max(wh)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color_parser::{ColorCollection, RalDesignCollection};
+
+    #[test]
+    fn test_tree_matches_linear_scan() {
+        let collection = RalDesignCollection::new().expect("Failed to load RAL Design");
+        let tree = LabKdTree::build(collection.colors());
+        assert_eq!(tree.len(), collection.colors().len());
+
+        let queries = [
+            [0, 0, 0],
+            [255, 255, 255],
+            [255, 0, 0],
+            [18, 180, 90],
+            [40, 60, 200],
+            [128, 128, 128],
+            [250, 220, 30],
+            [90, 20, 70],
+        ];
+        let algorithms = [
+            DistanceAlgorithm::DeltaE76,
+            DistanceAlgorithm::DeltaE2000,
+            DistanceAlgorithm::EuclideanLab,
+            DistanceAlgorithm::Lch,
+            DistanceAlgorithm::Cmc(crate::color_distance_strategies::CmcWeights::Acceptability),
+        ];
+
+        for rgb in queries {
+            let target = UniversalColor::from_rgb(rgb);
+            for algorithm in algorithms {
+                let linear = collection.find_closest_linear(&target, 5, None, algorithm);
+                let indexed = collection.find_closest_with_algorithm(&target, 5, None, algorithm);
+
+                let names = |matches: &[crate::color_parser::ColorMatch]| {
+                    matches
+                        .iter()
+                        .map(|m| (m.entry.metadata.name.clone(), m.distance))
+                        .collect::<Vec<_>>()
+                };
+                assert_eq!(
+                    names(&indexed),
+                    names(&linear),
+                    "{rgb:?} with {algorithm:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tree_respects_filter() {
+        let collection = RalDesignCollection::new().expect("Failed to load RAL Design");
+        let filter = crate::color_parser::SearchFilter {
+            lightness_range: Some([70.0, 100.0]),
+            ..Default::default()
+        };
+        let target = UniversalColor::from_rgb([10, 10, 10]);
+
+        let linear = collection.find_closest_linear(
+            &target,
+            3,
+            Some(&filter),
+            DistanceAlgorithm::DeltaE2000,
+        );
+        let indexed = collection.find_closest_with_algorithm(
+            &target,
+            3,
+            Some(&filter),
+            DistanceAlgorithm::DeltaE2000,
+        );
+        assert_eq!(indexed.len(), 3);
+        for (a, b) in indexed.iter().zip(&linear) {
+            assert_eq!(a.entry.metadata.name, b.entry.metadata.name);
+            assert!(a.entry.color.lab[0] >= 70.0);
+        }
+    }
+}
@@ -9,6 +9,7 @@
 
 pub mod css_parser;
 pub mod csv_loader;
+pub mod name_translations;
 pub mod parse_utils;
 pub mod ral_matcher;
 pub mod types;
@@ -17,6 +18,8 @@ pub mod types;
 pub mod collections;
 pub mod compat;
 pub mod css_collection;
+pub mod custom_collection;
+pub mod kd_tree;
 pub mod ral_classic_collection;
 pub mod ral_design_collection;
 pub mod unified_manager;
@@ -28,12 +31,15 @@ pub use types::{ColorFormat, ColorParseResult, ParsedColor};
 // New unified collection system exports
 pub use collections::*;
 pub use css_collection::CssColorCollection;
+pub use custom_collection::CustomColorCollection;
+pub use kd_tree::LabKdTree;
+pub use name_translations::NameTranslations;
 pub use ral_classic_collection::RalClassicCollection;
 pub use ral_design_collection::RalDesignCollection;
 pub use unified_manager::UnifiedColorManager;
 
 use crate::error::{ColorError, Result};
-use palette::{IntoColor, Lab, Lch, Srgb};
+use palette::{IntoColor, Lab, Lch, Srgb, Xyz};
 
 /// Helper function to convert RGB tuple to LAB using functional palette approach
 fn rgb_to_lab(rgb: (u8, u8, u8)) -> Lab {
@@ -55,6 +61,25 @@ fn lab_to_rgb(lab: Lab) -> (u8, u8, u8) {
     )
 }
 
+/// Parse one numeric component of a functional color such as `lab(...)`
+///
+/// A malformed number keeps its `ParseFloatError` as the error source.
+fn parse_component(input: &str, value: &str, component: &'static str) -> Result<f32> {
+    value
+        .trim()
+        .parse()
+        .map_err(|source| ColorError::InvalidComponent {
+            input: input.trim().to_string(),
+            component,
+            source,
+        })
+}
+
+/// Input formats accepted by [`ColorParser::parse`], in the order they are tried
+const SUPPORTED_FORMATS: &[&str] = &[
+    "lch", "hex", "rgb", "rgba", "hsl", "hsla", "named", "ral", "lab", "xyz",
+];
+
 /// Unified color parser that can handle various input formats
 pub struct ColorParser {
     css_parser: CssColorParser,
@@ -66,28 +91,37 @@ impl ColorParser {
     /// Create a new color parser
     #[must_use]
     pub fn new() -> Self {
-        let unified_manager = UnifiedColorManager::new().unwrap_or_else(|_| {
-            // Fallback to default if creation fails
-            UnifiedColorManager::default()
-        });
-
         Self {
             css_parser: CssColorParser::new(),
-            css_collection: CssColorCollection::new().unwrap_or_else(|_| {
-                // Fallback: create empty collection if CSV loading fails
-                CssColorCollection::new().unwrap()
-            }),
-            unified_manager,
+            css_collection: CssColorCollection::from_csv_or_bundled(css_collection::CSS_COLORS_CSV),
+            unified_manager: UnifiedColorManager::new(),
         }
     }
 
+    /// Every input format [`Self::parse`] accepts
+    ///
+    /// Hex input may omit the `#`; `ral` covers RAL codes and RAL color names.
+    ///
+    /// # Example
+    /// ```rust
+    /// use color_rs::color_parser::ColorParser;
+    ///
+    /// assert!(ColorParser::supported_formats().contains(&"lch"));
+    /// ```
+    #[must_use]
+    pub const fn supported_formats() -> &'static [&'static str] {
+        SUPPORTED_FORMATS
+    }
+
     /// Parse any color input and return LAB color with format information
     pub fn parse(&self, input: &str) -> Result<(Lab, ColorFormat)> {
         let input = input.trim();
 
         // Try LCH parsing first for direct LAB conversion (avoids RGB roundtrip)
-        if let Ok(lab) = Self::parse_lch_color(input) {
-            return Ok((lab, ColorFormat::Lch));
+        match Self::parse_lch_color(input) {
+            Ok(lab) => return Ok((lab, ColorFormat::Lch)),
+            Err(err @ ColorError::InvalidComponent { .. }) => return Err(err),
+            Err(_) => {}
         }
 
         // Try CSS parsing (handles hex, rgb, rgba, hsl, hsla, named colors)
@@ -124,9 +158,18 @@ impl ColorParser {
             }
         }
 
-        // Try LAB color parsing (lab(L, a, b))
-        if let Ok(lab) = Self::parse_lab_color(input) {
-            return Ok((lab, ColorFormat::Lab));
+        // Try LAB color parsing (lab(L, a, b)); a malformed component is reported as such
+        match Self::parse_lab_color(input) {
+            Ok(lab) => return Ok((lab, ColorFormat::Lab)),
+            Err(err @ ColorError::InvalidComponent { .. }) => return Err(err),
+            Err(_) => {}
+        }
+
+        // Try XYZ color parsing (xyz(X, Y, Z))
+        match Self::parse_xyz_color(input) {
+            Ok(lab) => return Ok((lab, ColorFormat::Xyz)),
+            Err(err @ ColorError::InvalidComponent { .. }) => return Err(err),
+            Err(_) => {}
         }
 
         // If all parsing methods failed, return error
@@ -136,31 +179,25 @@ impl ColorParser {
     }
 
     /// Check if input looks like a hex color without # symbol
+    ///
+    /// Accepts the same 3, 4, 6 and 8 digit forms as `#`-prefixed hex, in
+    /// either case; expansion is left to the hex parser.
     fn is_hex_without_hash(&self, input: &str) -> bool {
-        input.len() == 6 && input.chars().all(|c| c.is_ascii_hexdigit())
+        matches!(input.len(), 3 | 4 | 6 | 8) && input.chars().all(|c| c.is_ascii_hexdigit())
     }
 
     /// Parse LAB color in the format lab(L, a, b)
     fn parse_lab_color(input: &str) -> Result<Lab> {
-        let input = input.trim().to_lowercase();
+        let lower = input.trim().to_lowercase();
 
-        if input.starts_with("lab(") && input.ends_with(')') {
-            let content = &input[4..input.len() - 1]; // Remove "lab(" and ")"
+        if lower.starts_with("lab(") && lower.ends_with(')') {
+            let content = &lower[4..lower.len() - 1]; // Remove "lab(" and ")"
             let parts: Vec<&str> = content.split(',').collect();
 
             if parts.len() == 3 {
-                let l: f32 = parts[0]
-                    .trim()
-                    .parse()
-                    .map_err(|_| ColorError::InvalidColor("Invalid LAB L value".to_string()))?;
-                let a: f32 = parts[1]
-                    .trim()
-                    .parse()
-                    .map_err(|_| ColorError::InvalidColor("Invalid LAB a value".to_string()))?;
-                let b: f32 = parts[2]
-                    .trim()
-                    .parse()
-                    .map_err(|_| ColorError::InvalidColor("Invalid LAB b value".to_string()))?;
+                let l = parse_component(input, parts[0], "LAB L")?;
+                let a = parse_component(input, parts[1], "LAB a")?;
+                let b = parse_component(input, parts[2], "LAB b")?;
 
                 return Ok(Lab::new(l, a, b));
             }
@@ -171,27 +208,45 @@ impl ColorParser {
         ))
     }
 
+    /// Parse CIE XYZ color in the format xyz(X, Y, Z) - direct to LAB conversion
+    ///
+    /// Values use the D65 white point on the same scale as the `conversion.xyz`
+    /// output, where reference white is approximately xyz(0.950, 1.000, 1.089).
+    fn parse_xyz_color(input: &str) -> Result<Lab> {
+        let lower = input.trim().to_lowercase();
+
+        if lower.starts_with("xyz(") && lower.ends_with(')') {
+            let content = &lower[4..lower.len() - 1]; // Remove "xyz(" and ")"
+            let parts: Vec<&str> = content.split(',').collect();
+
+            if parts.len() == 3 {
+                let x = parse_component(input, parts[0], "XYZ X")?;
+                let y = parse_component(input, parts[1], "XYZ Y")?;
+                let z = parse_component(input, parts[2], "XYZ Z")?;
+
+                // Convert XYZ (D65) directly to LAB (no RGB roundtrip)
+                let lab: Lab = Xyz::new(x, y, z).into_color();
+                return Ok(lab);
+            }
+        }
+
+        Err(ColorError::InvalidColor(
+            "Invalid XYZ color format".to_string(),
+        ))
+    }
+
     /// Parse LCH color in the format lch(L, C, H) - direct to LAB conversion
     fn parse_lch_color(input: &str) -> Result<Lab> {
-        let input = input.trim().to_lowercase();
+        let lower = input.trim().to_lowercase();
 
-        if input.starts_with("lch(") && input.ends_with(')') {
-            let content = &input[4..input.len() - 1]; // Remove "lch(" and ")"
+        if lower.starts_with("lch(") && lower.ends_with(')') {
+            let content = &lower[4..lower.len() - 1]; // Remove "lch(" and ")"
             let parts: Vec<&str> = content.split(',').collect();
 
             if parts.len() == 3 {
-                let l: f32 = parts[0]
-                    .trim()
-                    .parse()
-                    .map_err(|_| ColorError::InvalidColor("Invalid LCH L value".to_string()))?;
-                let c: f32 = parts[1]
-                    .trim()
-                    .parse()
-                    .map_err(|_| ColorError::InvalidColor("Invalid LCH C value".to_string()))?;
-                let h: f32 = parts[2]
-                    .trim()
-                    .parse()
-                    .map_err(|_| ColorError::InvalidColor("Invalid LCH H value".to_string()))?;
+                let l = parse_component(input, parts[0], "LCH L")?;
+                let c = parse_component(input, parts[1], "LCH C")?;
+                let h = parse_component(input, parts[2], "LCH H")?;
 
                 // Convert LCH directly to LAB (no RGB roundtrip)
                 let lch = Lch::new(l, c, h);
@@ -275,3 +330,79 @@ pub fn parse_color_comprehensive(input: &str) -> Result<ColorParseResult> {
         Err(e) => Err(e),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format_utils::FormatUtils;
+
+    #[test]
+    fn test_supported_formats() {
+        let formats = ColorParser::supported_formats();
+        assert!(formats.contains(&"lch"));
+        assert!(formats.contains(&"lab"));
+
+        // Keep the list in sync with the parsers: every entry needs an example here
+        let parser = ColorParser::new();
+        for format in formats {
+            let example = match *format {
+                "lch" => "lch(50, 30, 120)",
+                "hex" => "#336699",
+                "rgb" => "rgb(51, 102, 153)",
+                "rgba" => "rgba(51, 102, 153, 0.5)",
+                "hsl" => "hsl(210, 50%, 40%)",
+                "hsla" => "hsla(210, 50%, 40%, 0.5)",
+                "named" => "steelblue",
+                "ral" => "RAL 5005",
+                "lab" => "lab(50, 10, -20)",
+                "xyz" => "xyz(0.2, 0.2, 0.3)",
+                other => panic!("no example for supported format '{other}'"),
+            };
+            assert!(parser.parse(example).is_ok(), "{format}: {example}");
+        }
+    }
+
+    #[test]
+    fn test_parse_hex_without_hash() {
+        let parser = ColorParser::new();
+
+        for input in [
+            "f00", "F00", "FF0000", "ff0000", " FF0000 ", "f00f", "FF0000FF",
+        ] {
+            let (lab, format) = parser.parse(input).unwrap();
+            assert_eq!(format, ColorFormat::Hex, "{input}");
+            assert_eq!(lab_to_rgb(lab), (255, 0, 0), "{input}");
+        }
+
+        // Names still take the named-color path, even when they are hex-length
+        let (_, format) = parser.parse("bisque").unwrap();
+        assert_eq!(format, ColorFormat::Named);
+        assert!(parser.parse("ff00").is_ok());
+        assert!(parser.parse("ff000").is_err());
+    }
+
+    #[test]
+    fn test_parse_xyz_color() {
+        let parser = ColorParser::new();
+        let (lab, format) = parser.parse("xyz(0.4124, 0.2126, 0.0193)").unwrap();
+
+        assert_eq!(format, ColorFormat::Xyz);
+        assert_eq!(lab_to_rgb(lab), (255, 0, 0));
+    }
+
+    #[test]
+    fn test_xyz_round_trip() {
+        let parser = ColorParser::new();
+        let (original, _) = parser.parse("#3A7BD5").unwrap();
+
+        let xyz = FormatUtils::lab_to_xyz(original);
+        let (round_trip, format) = parser.parse(&xyz).unwrap();
+
+        // XYZ output is rounded to 3 decimals, so allow one step per channel
+        assert_eq!(format, ColorFormat::Xyz);
+        let (r1, g1, b1) = lab_to_rgb(original);
+        let (r2, g2, b2) = lab_to_rgb(round_trip);
+        assert!(r1.abs_diff(r2) <= 1 && g1.abs_diff(g2) <= 1 && b1.abs_diff(b2) <= 1);
+        assert!((round_trip.l - original.l).abs() < 0.5);
+    }
+}
@@ -0,0 +1,99 @@
+//! Localized Color Names
+//!
+//! Translation maps from canonical collection names to localized display names.
+//! Maps are semicolon-separated CSV files with `Name;Translation` columns; bundled
+//! maps live in `color-table/names-<lang>.csv`. Translations only affect how
+//! matches are displayed, never how they are ranked.
+
+use anyhow::{Context, Result};
+use csv::ReaderBuilder;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Lookup from canonical color names to localized names
+#[derive(Debug, Clone, Default)]
+pub struct NameTranslations {
+    names: HashMap<String, String>,
+}
+
+impl NameTranslations {
+    /// Load the bundled translation map for a language code (e.g. `de`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the language code is malformed or no bundled map exists for it.
+    pub fn bundled(lang: &str) -> Result<Self> {
+        if lang.is_empty() || !lang.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            anyhow::bail!("Invalid language code: {lang}");
+        }
+        Self::from_csv(format!(
+            "color-table/names-{}.csv",
+            lang.to_ascii_lowercase()
+        ))
+        .with_context(|| format!("No bundled color names for language '{lang}'"))
+    }
+
+    /// Load a translation map from a CSV file with `Name` and `Translation` columns
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or lacks the required columns.
+    pub fn from_csv<P: AsRef<Path>>(file_path: P) -> Result<Self> {
+        let path = file_path.as_ref();
+        let mut reader = ReaderBuilder::new()
+            .delimiter(b';')
+            .has_headers(true)
+            .flexible(true)
+            .from_path(path)
+            .with_context(|| format!("Failed to open CSV file: {}", path.display()))?;
+
+        let headers = reader
+            .headers()
+            .with_context(|| format!("Failed to read CSV header from {}", path.display()))?
+            .clone();
+        let column = |name: &str| {
+            headers
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(name))
+                .with_context(|| {
+                    format!(
+                        "{}: line 1: missing required column '{name}'",
+                        path.display()
+                    )
+                })
+        };
+        let name_col = column("name")?;
+        let translation_col = column("translation")?;
+
+        let mut names = HashMap::new();
+        for result in reader.records() {
+            let record = result
+                .with_context(|| format!("Failed to read CSV record from {}", path.display()))?;
+            let field = |col: usize| record.get(col).map(str::trim).filter(|v| !v.is_empty());
+            if let (Some(name), Some(translation)) = (field(name_col), field(translation_col)) {
+                names.insert(name.to_lowercase(), translation.to_string());
+            }
+        }
+
+        Ok(Self { names })
+    }
+
+    /// Build a translation map from `(canonical, localized)` pairs
+    #[must_use]
+    pub fn from_pairs<'a>(pairs: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        Self {
+            names: pairs
+                .into_iter()
+                .map(|(name, translation)| (name.to_lowercase(), translation.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Localized name, or the canonical name when no translation exists
+    #[must_use]
+    pub fn translate<'a>(&'a self, name: &'a str) -> &'a str {
+        self.names
+            .get(&name.to_lowercase())
+            .map_or(name, String::as_str)
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! Common utilities for parsing operations to reduce code duplication
 
-use crate::error::{ColorError, Result};
+use crate::error::Result;
 use std::str::FromStr;
 
 /// Parsing utilities for common operations
@@ -11,8 +11,7 @@ pub struct ParseUtils;
 impl ParseUtils {
     /// Parse a hex string with consistent error handling
     pub fn parse_hex_component(hex_str: &str) -> Result<u8> {
-        u8::from_str_radix(hex_str, 16)
-            .map_err(|_| ColorError::InvalidColor("Invalid hex color component".to_string()))
+        Ok(u8::from_str_radix(hex_str, 16)?)
     }
 
     /// Parse a color component (0-255) with consistent error handling
@@ -20,15 +19,12 @@ impl ParseUtils {
         let value = value.trim();
 
         if let Some(percentage_str) = value.strip_suffix('%') {
-            let percentage = f64::from_str(percentage_str)
-                .map_err(|_| ColorError::InvalidColor("Invalid percentage value".to_string()))?;
+            let percentage = f64::from_str(percentage_str)?;
             #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
             // Safe: values clamped to [0.0, 255.0] range
             Ok(((percentage / 100.0 * 255.0).round().clamp(0.0, 255.0)) as u8)
         } else {
-            let int_val = u32::from_str(value).map_err(|_| {
-                ColorError::InvalidColor("Invalid color component value".to_string())
-            })?;
+            let int_val = u32::from_str(value)?;
             #[allow(clippy::cast_possible_truncation)] // Safe: clamped to u8 range [0, 255]
             Ok((int_val.clamp(0, 255)) as u8)
         }
@@ -39,13 +35,11 @@ impl ParseUtils {
         let value = value.trim();
 
         if let Some(percentage_str) = value.strip_suffix('%') {
-            let percentage = f64::from_str(percentage_str)
-                .map_err(|_| ColorError::InvalidColor("Invalid percentage value".to_string()))?;
+            let percentage = f64::from_str(percentage_str)?;
             Ok((percentage / 100.0).clamp(0.0, 1.0))
         } else {
             // Allow float values without % for convenience
-            let float_val = f64::from_str(value)
-                .map_err(|_| ColorError::InvalidColor("Invalid percentage value".to_string()))?;
+            let float_val = f64::from_str(value)?;
             Ok(float_val.clamp(0.0, 1.0))
         }
     }
@@ -53,22 +47,18 @@ impl ParseUtils {
     /// Parse an alpha value (0.0-1.0) with consistent error handling
     pub fn parse_alpha(value: &str) -> Result<f64> {
         let value = value.trim();
-        let alpha = f64::from_str(value)
-            .map_err(|_| ColorError::InvalidColor("Invalid alpha value".to_string()))?;
+        let alpha = f64::from_str(value)?;
         Ok(alpha.clamp(0.0, 1.0))
     }
 
     /// Parse a hue value with consistent error handling
     pub fn parse_hue(value: &str) -> Result<f64> {
-        f64::from_str(value.trim())
-            .map_err(|_| ColorError::InvalidColor("Invalid hue value".to_string()))
+        Ok(f64::from_str(value.trim())?)
     }
 
     /// Parse RGB component with consistent error handling (for existing color.rs code)
     pub fn parse_rgb_component(value: &str) -> Result<u8> {
-        u32::from_str(value.trim())
-            .map_err(|_| ColorError::InvalidColor("Invalid RGB value".to_string()))
-            .map(|v| (v.clamp(0, 255)) as u8)
+        Ok(u32::from_str(value.trim())?.clamp(0, 255) as u8)
     }
 }
 
@@ -106,4 +96,33 @@ mod tests {
         assert_eq!(ParseUtils::parse_alpha("0.5").unwrap(), 0.5);
         assert!(ParseUtils::parse_alpha("invalid").is_err());
     }
+
+    #[test]
+    fn test_bad_numbers_are_typed_errors() {
+        use crate::error::ColorError;
+
+        assert!(matches!(
+            ParseUtils::parse_hue("abc"),
+            Err(ColorError::ParseFloatError(_))
+        ));
+        assert!(matches!(
+            ParseUtils::parse_rgb_component("-1"),
+            Err(ColorError::ParseIntError(_))
+        ));
+
+        let lab_error = crate::color::parse_color_input("lab(50, abc, 10)").unwrap_err();
+        assert!(matches!(
+            lab_error,
+            ColorError::InvalidComponent {
+                component: "LAB a",
+                ..
+            }
+        ));
+        assert_eq!(
+            lab_error.to_string(),
+            "Failed to parse color 'lab(50, abc, 10)': invalid LAB a value: invalid float literal"
+        );
+        let source = std::error::Error::source(&lab_error).unwrap();
+        assert!(source.is::<std::num::ParseFloatError>());
+    }
 }
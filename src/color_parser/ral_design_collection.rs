@@ -3,20 +3,44 @@
 //! Implementation of the unified color collection system for RAL Design System+ colors.
 
 use super::collections::{ColorCollection, ColorEntry, ColorMatch, SearchFilter, UniversalColor};
-use super::csv_loader::CsvLoader;
+use super::csv_loader::{CsvColorEntry, CsvLoader};
+use super::kd_tree::LabKdTree;
+use crate::color_distance_strategies::DistanceAlgorithm;
 use anyhow::Result;
 
 /// RAL Design System+ Colors Collection
 pub struct RalDesignCollection {
     colors: Vec<ColorEntry>,
+    index: LabKdTree,
 }
 
+/// Path of the RAL Design System+ table, relative to the working directory
+pub const RAL_DESIGN_CSV: &str = "color-table/ral-design.csv";
+
+/// RAL Design System+ table compiled into the binary
+const BUNDLED_RAL_DESIGN_COLORS: &str = include_str!("../../color-table/ral-design.csv");
+
 impl RalDesignCollection {
     /// Create a new RAL Design System+ color collection
     pub fn new() -> Result<Self> {
-        let csv_colors = CsvLoader::load_colors_from_csv("color-table/ral-design.csv")?;
+        Ok(Self::from_csv_entries(&CsvLoader::load_colors_from_csv(
+            RAL_DESIGN_CSV,
+        )?))
+    }
 
-        let colors = csv_colors
+    /// Load from `file_path`, falling back to the bundled colors with a warning
+    ///
+    /// See [`CsvLoader::load_colors_or_bundled`].
+    #[must_use]
+    pub fn from_csv_or_bundled(file_path: &str) -> Self {
+        Self::from_csv_entries(&CsvLoader::load_colors_or_bundled(
+            file_path,
+            BUNDLED_RAL_DESIGN_COLORS,
+        ))
+    }
+
+    fn from_csv_entries(csv_colors: &[CsvColorEntry]) -> Self {
+        let colors: Vec<ColorEntry> = csv_colors
             .iter()
             .map(|entry| {
                 let rgb = CsvLoader::hex_to_rgb(&entry.hex).unwrap_or([0, 0, 0]); // Fallback to black on error
@@ -34,7 +58,8 @@ impl RalDesignCollection {
             })
             .collect();
 
-        Ok(Self { colors })
+        let index = LabKdTree::build(&colors);
+        Self { colors, index }
     }
 
     /// Extract design group from code (e.g., "RAL 000 15 00" -> "RAL 000")
@@ -78,6 +103,10 @@ impl ColorCollection for RalDesignCollection {
         &self.colors
     }
 
+    fn lab_index(&self) -> Option<&LabKdTree> {
+        Some(&self.index)
+    }
+
     fn find_by_code(&self, code: &str) -> Option<ColorEntry> {
         self.colors
             .iter()
@@ -91,36 +120,26 @@ impl ColorCollection for RalDesignCollection {
         limit: usize,
         filter: Option<&SearchFilter>,
     ) -> Vec<ColorMatch> {
-        let mut distances: Vec<_> = self
-            .colors
-            .iter()
-            .filter(|entry| {
-                if let Some(filter) = filter {
-                    if let Some(groups_filter) = &filter.groups {
-                        if let Some(entry_group) = &entry.metadata.group {
-                            if !groups_filter.contains(entry_group) {
-                                return false;
-                            }
-                        } else {
-                            return false;
-                        }
-                    }
-                }
-                true
-            })
-            .map(|entry| {
-                let distance = target.distance_to(&entry.color);
-                ColorMatch::new(entry.clone(), distance)
-            })
-            .collect();
-
-        distances.sort_by(|a, b| {
-            a.distance
-                .partial_cmp(&b.distance)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        distances.truncate(limit);
-        distances
+        let groups_filter = filter.and_then(|filter| filter.groups.as_ref());
+        self.index
+            .nearest(
+                &self.colors,
+                target,
+                limit,
+                DistanceAlgorithm::DeltaE2000,
+                |entry| {
+                    groups_filter.is_none_or(|groups| {
+                        entry
+                            .metadata
+                            .group
+                            .as_ref()
+                            .is_some_and(|group| groups.contains(group))
+                    })
+                },
+            )
+            .into_iter()
+            .map(|(i, distance)| ColorMatch::new(self.colors[i].clone(), distance))
+            .collect()
     }
 }
 
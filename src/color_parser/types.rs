@@ -63,6 +63,26 @@ pub enum ColorFormat {
     Lab,
     /// LCH format (lch(L,C,H))
     Lch,
+    /// CIE XYZ format (xyz(X,Y,Z), D65 white point, Y = 1.0 for reference white)
+    Xyz,
+}
+
+impl ColorFormat {
+    /// Lowercase notation name (e.g. `hsl`, `named`)
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Hex => "hex",
+            Self::Rgb => "rgb",
+            Self::Rgba => "rgba",
+            Self::Hsl => "hsl",
+            Self::Hsla => "hsla",
+            Self::Named => "named",
+            Self::Lab => "lab",
+            Self::Lch => "lch",
+            Self::Xyz => "xyz",
+        }
+    }
 }
 
 impl ParsedColor {
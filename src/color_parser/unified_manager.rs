@@ -4,13 +4,13 @@
 //! Provides backward compatibility while enabling the new unified architecture.
 
 use super::collections::{
-    ColorCollection, ColorCollectionManager, ColorMatch, SearchFilter, UniversalColor,
+    ColorCollection, ColorCollectionManager, ColorEntry, ColorMatch, SearchFilter, UniversalColor,
 };
-use super::css_collection::CssColorCollection;
-use super::ral_classic_collection::RalClassicCollection;
-use super::ral_design_collection::RalDesignCollection;
+use super::css_collection::{CSS_COLORS_CSV, CssColorCollection};
+use super::custom_collection::CustomColorCollection;
+use super::ral_classic_collection::{RAL_CLASSIC_CSV, RalClassicCollection};
+use super::ral_design_collection::{RAL_DESIGN_CSV, RalDesignCollection};
 use crate::color_distance_strategies::DistanceAlgorithm;
-use anyhow::Result;
 
 /// Unified manager for all color collections with backward compatibility
 pub struct UnifiedColorManager {
@@ -22,24 +22,34 @@ pub struct UnifiedColorManager {
 
 impl UnifiedColorManager {
     /// Create a new unified color manager with all built-in collections
-    pub fn new() -> Result<Self> {
+    ///
+    /// Tables missing from `color-table/` are replaced by the copies compiled
+    /// into the binary, with a warning (see [`CsvLoader::load_colors_or_bundled`](super::csv_loader::CsvLoader::load_colors_or_bundled)).
+    #[must_use]
+    pub fn new() -> Self {
         let mut manager = ColorCollectionManager::new();
 
-        let css_collection = CssColorCollection::new()?;
-        let ral_classic_collection = RalClassicCollection::new()?;
-        let ral_design_collection = RalDesignCollection::new()?;
+        let css_collection = CssColorCollection::from_csv_or_bundled(CSS_COLORS_CSV);
+        let ral_classic_collection = RalClassicCollection::from_csv_or_bundled(RAL_CLASSIC_CSV);
+        let ral_design_collection = RalDesignCollection::from_csv_or_bundled(RAL_DESIGN_CSV);
 
         // Add collections to manager
-        manager.add_collection(Box::new(CssColorCollection::new()?));
-        manager.add_collection(Box::new(RalClassicCollection::new()?));
-        manager.add_collection(Box::new(RalDesignCollection::new()?));
+        manager.add_collection(Box::new(CssColorCollection::from_csv_or_bundled(
+            CSS_COLORS_CSV,
+        )));
+        manager.add_collection(Box::new(RalClassicCollection::from_csv_or_bundled(
+            RAL_CLASSIC_CSV,
+        )));
+        manager.add_collection(Box::new(RalDesignCollection::from_csv_or_bundled(
+            RAL_DESIGN_CSV,
+        )));
 
-        Ok(Self {
+        Self {
             manager,
             css_collection,
             ral_classic_collection,
             ral_design_collection,
-        })
+        }
     }
 
     /// Find closest colors across all collections (new unified API)
@@ -95,6 +105,44 @@ impl UnifiedColorManager {
         None
     }
 
+    /// All entries of `collection` (`css`, `ralc` or `rald`) whose code starts
+    /// with `prefix`, in collection order
+    ///
+    /// Case and whitespace are ignored on both sides, so `RAL70` and `ral 70`
+    /// both list the RAL 7000 grays. Matches carry a distance of 0. An unknown
+    /// collection or an empty prefix yields no matches.
+    #[must_use]
+    pub fn find_by_code_prefix(&self, collection: &str, prefix: &str) -> Vec<ColorMatch> {
+        let normalize = |code: &str| {
+            code.chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .to_ascii_uppercase()
+        };
+        let prefix = normalize(prefix);
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+
+        let entries: &[ColorEntry] = match collection {
+            "css" => self.css_collection.colors(),
+            "ralc" => self.ral_classic_collection.colors(),
+            "rald" => self.ral_design_collection.colors(),
+            _ => return Vec::new(),
+        };
+        entries
+            .iter()
+            .filter(|entry| {
+                entry
+                    .metadata
+                    .code
+                    .as_deref()
+                    .is_some_and(|code| normalize(code).starts_with(&prefix))
+            })
+            .map(|entry| ColorMatch::new(entry.clone(), 0.0))
+            .collect()
+    }
+
     /// Search with advanced filtering
     #[must_use]
     pub fn search_with_filter(
@@ -131,11 +179,12 @@ impl UnifiedColorManager {
         &self,
         rgb: [u8; 3],
         max_results: usize,
+        filter: Option<&SearchFilter>,
         algorithm: DistanceAlgorithm,
     ) -> Vec<ColorMatch> {
         let target = UniversalColor::from_rgb(rgb);
         self.css_collection
-            .find_closest_with_algorithm(&target, max_results, None, algorithm)
+            .find_closest_with_algorithm(&target, max_results, filter, algorithm)
     }
 
     /// Find closest RAL Classic colors with custom distance algorithm
@@ -143,13 +192,14 @@ impl UnifiedColorManager {
         &self,
         rgb: [u8; 3],
         max_results: usize,
+        filter: Option<&SearchFilter>,
         algorithm: DistanceAlgorithm,
     ) -> Vec<ColorMatch> {
         let target = UniversalColor::from_rgb(rgb);
         self.ral_classic_collection.find_closest_with_algorithm(
             &target,
             max_results,
-            None,
+            filter,
             algorithm,
         )
     }
@@ -159,21 +209,191 @@ impl UnifiedColorManager {
         &self,
         rgb: [u8; 3],
         max_results: usize,
+        filter: Option<&SearchFilter>,
         algorithm: DistanceAlgorithm,
     ) -> Vec<ColorMatch> {
         let target = UniversalColor::from_rgb(rgb);
         self.ral_design_collection.find_closest_with_algorithm(
             &target,
             max_results,
-            None,
+            filter,
             algorithm,
         )
     }
+
+    /// Find the closest colors in each collection that stay legible on `background`
+    ///
+    /// Entries whose WCAG contrast ratio against `background` is below
+    /// `min_ratio` are skipped; the rest are ranked by distance to `target` as
+    /// usual, so fewer than `max_results` matches may come back per collection.
+    #[must_use]
+    pub fn find_closest_accessible(
+        &self,
+        target: [u8; 3],
+        background: [u8; 3],
+        min_ratio: f64,
+        algorithm: DistanceAlgorithm,
+        max_results: usize,
+    ) -> Vec<(String, Vec<ColorMatch>)> {
+        let target = UniversalColor::from_rgb(target);
+        let background = (background[0], background[1], background[2]);
+        let meets_ratio = |entry: &ColorEntry| {
+            let [r, g, b] = entry.color.rgb;
+            crate::color_ops::contrast::wcag_ratio_rgb((r, g, b), background) >= min_ratio
+        };
+
+        let collections: [&dyn ColorCollection; 3] = [
+            &self.css_collection,
+            &self.ral_classic_collection,
+            &self.ral_design_collection,
+        ];
+        collections
+            .into_iter()
+            .map(|collection| {
+                let colors = collection.colors();
+                let matches = match collection.lab_index() {
+                    Some(index) => index
+                        .nearest(colors, &target, max_results, algorithm, meets_ratio)
+                        .into_iter()
+                        .map(|(i, distance)| ColorMatch::new(colors[i].clone(), distance))
+                        .collect(),
+                    None => {
+                        let mut matches: Vec<ColorMatch> = colors
+                            .iter()
+                            .filter(|entry| meets_ratio(entry))
+                            .map(|entry| {
+                                let distance =
+                                    target.distance_to_with_algorithm(&entry.color, algorithm);
+                                ColorMatch::new(entry.clone(), distance)
+                            })
+                            .collect();
+                        matches.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+                        matches.truncate(max_results);
+                        matches
+                    }
+                };
+                (collection.name().to_string(), matches)
+            })
+            .collect()
+    }
+
+    /// Find closest colors across all collections for each color of a batch
+    ///
+    /// Results are returned in input order, one entry per input color.
+    #[must_use]
+    pub fn find_closest_batch(
+        &self,
+        colors: &[[u8; 3]],
+        max_results_per_collection: usize,
+    ) -> Vec<Vec<(String, Vec<ColorMatch>)>> {
+        colors
+            .iter()
+            .map(|&rgb| self.find_closest_across_all(rgb, max_results_per_collection))
+            .collect()
+    }
+
+    /// Parallel variant of [`Self::find_closest_batch`]
+    ///
+    /// The batch is split into contiguous chunks matched on scoped worker threads
+    /// (one per available core). Chunk results are joined by position, so the
+    /// output order is identical to the serial version.
+    #[cfg(feature = "parallel")]
+    #[must_use]
+    pub fn find_closest_batch_parallel(
+        &self,
+        colors: &[[u8; 3]],
+        max_results_per_collection: usize,
+    ) -> Vec<Vec<(String, Vec<ColorMatch>)>> {
+        let workers = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+        let chunk_size = colors.len().div_ceil(workers).max(1);
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = colors
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || self.find_closest_batch(chunk, max_results_per_collection))
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("batch matching worker panicked"))
+                .collect()
+        })
+    }
+}
+
+impl UnifiedColorManager {
+    /// Merge built-in collections into one searchable collection
+    ///
+    /// Collections are merged in the order given. See
+    /// [`CustomColorCollection::merge`] for the deduplication rule; use it
+    /// directly to include custom CSV collections.
+    #[must_use]
+    pub fn merged_collection(
+        &self,
+        types: &[crate::color_matching::CollectionType],
+        dedup_threshold: Option<f64>,
+    ) -> CustomColorCollection {
+        use crate::color_matching::CollectionType;
+
+        let sources: Vec<&dyn ColorCollection> = types
+            .iter()
+            .map(|collection_type| -> &dyn ColorCollection {
+                match collection_type {
+                    CollectionType::Css => &self.css_collection,
+                    CollectionType::RalClassic => &self.ral_classic_collection,
+                    CollectionType::RalDesign => &self.ral_design_collection,
+                }
+            })
+            .collect();
+
+        CustomColorCollection::merge(&sources, dedup_threshold)
+    }
+
+    /// Find pairs of differently named entries whose colors are within `threshold` ΔE2000
+    ///
+    /// Useful for collection hygiene: identical or near-identical colors published
+    /// under different names. Pairs are returned as `(first, second, delta_e)` in
+    /// ascending distance order.
+    #[must_use]
+    pub fn find_duplicates(
+        collection: &dyn ColorCollection,
+        threshold: f64,
+    ) -> Vec<(String, String, f64)> {
+        let colors = collection.colors();
+        let mut duplicates: Vec<(String, String, f64)> = colors
+            .iter()
+            .enumerate()
+            .flat_map(|(i, first)| {
+                colors[i + 1..].iter().filter_map(move |second| {
+                    if first
+                        .metadata
+                        .name
+                        .eq_ignore_ascii_case(&second.metadata.name)
+                    {
+                        return None;
+                    }
+                    let distance = first.color.distance_to(&second.color);
+                    (distance <= threshold).then(|| {
+                        (
+                            first.metadata.name.clone(),
+                            second.metadata.name.clone(),
+                            distance,
+                        )
+                    })
+                })
+            })
+            .collect();
+
+        duplicates.sort_by(|a, b| a.2.total_cmp(&b.2));
+        duplicates
+    }
 }
 
 impl Default for UnifiedColorManager {
     fn default() -> Self {
-        Self::new().expect("Failed to create UnifiedColorManager")
+        Self::new()
     }
 }
 
@@ -183,7 +403,7 @@ mod tests {
 
     #[test]
     fn test_unified_manager_creation() {
-        let manager = UnifiedColorManager::new().expect("Failed to create UnifiedColorManager");
+        let manager = UnifiedColorManager::new();
         let collections = manager.manager.collection_names();
         assert_eq!(collections.len(), 3);
         assert!(collections.contains(&"CSS Named Colors"));
@@ -193,7 +413,7 @@ mod tests {
 
     #[test]
     fn test_find_closest_across_all() {
-        let manager = UnifiedColorManager::new().expect("Failed to create UnifiedColorManager");
+        let manager = UnifiedColorManager::new();
         let results = manager.find_closest_across_all([255, 0, 0], 2);
 
         assert_eq!(results.len(), 3); // CSS, RAL Classic, RAL Design
@@ -207,9 +427,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_find_closest_accessible_meets_ratio() {
+        use crate::color_ops::contrast::wcag_ratio_rgb;
+
+        let manager = UnifiedColorManager::new();
+        let white = [255, 255, 255];
+        let results = manager.find_closest_accessible(
+            [255, 200, 0],
+            white,
+            4.5,
+            DistanceAlgorithm::DeltaE2000,
+            3,
+        );
+
+        assert_eq!(results.len(), 3);
+        for (collection_name, matches) in results {
+            assert!(!matches.is_empty(), "{collection_name} returned no matches");
+            assert!(matches.windows(2).all(|w| w[0].distance <= w[1].distance));
+            for m in matches {
+                let [r, g, b] = m.entry.color.rgb;
+                let ratio = wcag_ratio_rgb((r, g, b), (255, 255, 255));
+                assert!(ratio >= 4.5, "{} has ratio {ratio}", m.entry.metadata.name);
+            }
+        }
+    }
+
     #[test]
     fn test_find_by_code() {
-        let manager = UnifiedColorManager::new().expect("Failed to create UnifiedColorManager");
+        let manager = UnifiedColorManager::new();
 
         // Test RAL Classic code - use a code that should exist
         if let Some(first_ral) = manager.ral_classic_collection.colors().first() {
@@ -232,9 +478,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_find_by_code_prefix() {
+        let manager = UnifiedColorManager::new();
+
+        let grays = manager.find_by_code_prefix("ralc", "RAL 70");
+        assert!(grays.len() > 10);
+        for m in &grays {
+            let code = m.entry.metadata.code.as_deref().unwrap();
+            assert!(code.starts_with("RAL 70"), "{code}");
+            assert!(m.entry.metadata.name.to_lowercase().contains("grey"));
+        }
+
+        let compact = manager.find_by_code_prefix("ralc", "ral70");
+        assert_eq!(compact.len(), grays.len());
+
+        assert!(manager.find_by_code_prefix("ralc", "RAL 99X").is_empty());
+        assert!(manager.find_by_code_prefix("ralc", "").is_empty());
+        assert!(manager.find_by_code_prefix("pantone", "RAL 70").is_empty());
+    }
+
     #[test]
     fn test_group_filtering() {
-        let manager = UnifiedColorManager::new().expect("Failed to create UnifiedColorManager");
+        let manager = UnifiedColorManager::new();
 
         // Test RAL Classic groups
         let groups = manager.ral_classic_collection.groups();
@@ -244,4 +510,91 @@ mod tests {
         let design_groups = manager.ral_design_collection.groups();
         assert!(!design_groups.is_empty());
     }
+
+    #[test]
+    fn test_find_duplicates() {
+        use crate::color_parser::CustomColorCollection;
+        use std::io::Write;
+
+        let mut temp_file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(temp_file, "Name;Hex").unwrap();
+        writeln!(temp_file, "Signal Red;#D0021B").unwrap();
+        writeln!(temp_file, "Alarm Red;#D0031C").unwrap();
+        writeln!(temp_file, "Ocean;#1F4EAD").unwrap();
+
+        let collection = CustomColorCollection::from_csv(temp_file.path()).unwrap();
+        let duplicates = UnifiedColorManager::find_duplicates(&collection, 1.0);
+
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].0, "Signal Red");
+        assert_eq!(duplicates[0].1, "Alarm Red");
+        assert!(duplicates[0].2 < 1.0);
+    }
+
+    #[test]
+    fn test_merged_collection() {
+        use crate::color_matching::CollectionType;
+        use crate::color_parser::collections::ColorEntry;
+
+        let manager = UnifiedColorManager::new();
+        let css = manager.merged_collection(&[CollectionType::Css], None);
+        let css_count = css.colors().len();
+
+        let custom = CustomColorCollection::from_entries(vec![
+            ColorEntry::new(
+                UniversalColor::from_rgb([255, 0, 1]),
+                "Brand Red".to_string(),
+            ),
+            ColorEntry::new(
+                UniversalColor::from_rgb([31, 78, 173]),
+                "Brand Blue".to_string(),
+            ),
+        ]);
+
+        let merged = CustomColorCollection::merge(&[&css, &custom], None);
+        assert_eq!(merged.colors().len(), css_count + 2);
+
+        // Brand Red duplicates CSS red, so the CSS name is kept; CSS aliases
+        // such as aqua/cyan collapse too
+        let deduped = CustomColorCollection::merge(&[&css, &custom], Some(1.0));
+        assert!(deduped.colors().len() < css_count + 1);
+        assert!(deduped.find_by_name("Brand Red").is_none());
+        assert!(deduped.find_by_name("Brand Blue").is_some());
+
+        let target = UniversalColor::from_rgb([255, 0, 1]);
+        let best = &deduped.find_closest(&target, 1, None)[0];
+        assert!(best.entry.metadata.name.eq_ignore_ascii_case("red"));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_batch_matches_serial() {
+        let manager = UnifiedColorManager::new();
+        let colors: Vec<[u8; 3]> = (0..64u8)
+            .map(|i| [i.wrapping_mul(37), i.wrapping_mul(91), 255 - i * 3])
+            .collect();
+
+        let summarize = |results: Vec<Vec<(String, Vec<ColorMatch>)>>| {
+            results
+                .into_iter()
+                .map(|per_color| {
+                    per_color
+                        .into_iter()
+                        .map(|(collection, matches)| {
+                            let names: Vec<_> = matches
+                                .iter()
+                                .map(|m| (m.entry.metadata.name.clone(), m.distance))
+                                .collect();
+                            (collection, names)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let serial = summarize(manager.find_closest_batch(&colors, 2));
+        let parallel = summarize(manager.find_closest_batch_parallel(&colors, 2));
+        assert_eq!(serial.len(), colors.len());
+        assert_eq!(serial, parallel);
+    }
 }
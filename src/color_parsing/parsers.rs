@@ -42,14 +42,12 @@ pub fn get_css_color_name(rgb: [u8; 3]) -> String {
 /// Get color name using full comprehensive color matching
 pub fn get_full_color_name(rgb: [u8; 3], _tolerance: f64) -> String {
     // Use unified manager for comprehensive color matching
-    if let Ok(unified_manager) = UnifiedColorManager::new() {
-        let matches = unified_manager.find_closest_across_all(rgb, 1);
+    let matches = UnifiedColorManager::new().find_closest_across_all(rgb, 1);
 
-        // Find the best match across all collections
-        for (_, collection_matches) in &matches {
-            if let Some(color_match) = collection_matches.first() {
-                return color_match.entry.metadata.name.clone();
-            }
+    // Find the best match across all collections
+    for (_, collection_matches) in &matches {
+        if let Some(color_match) = collection_matches.first() {
+            return color_match.entry.metadata.name.clone();
         }
     }
 
@@ -3,12 +3,14 @@
 //! Contains the main data collection and formatting functions for color analysis output.
 //! Handles structured data preparation and format conversion.
 
-use crate::cli::{ColorArgs, OutputFormat};
+use crate::cli::{ColorArgs, OutputFormat, Range};
 use crate::color_distance_strategies::DistanceAlgorithm;
 use crate::color_formatter::ColorFormatter;
+use crate::color_ops::analysis::hue::ColorCollectionType;
+use crate::color_parser::{CustomColorCollection, NameTranslations, SearchFilter};
 use crate::color_schemes::ColorSchemeResult;
 use crate::error::{ColorError, Result};
-use crate::output_formats::ColorAnalysisOutput;
+use crate::output_formats::{ColorAnalysisOutput, ColorSchemes, EnhancedColorSchemeItem};
 use palette::{Hsl, IntoColor, Lab, Srgb};
 
 /// Convert LAB to hex color string
@@ -76,23 +78,270 @@ pub fn collect_analysis_data(
     algorithm: DistanceAlgorithm,
     args: &ColorArgs,
 ) -> Result<ColorAnalysisOutput> {
+    // Restrict collection matches to a LAB lightness band before ranking
+    let filter = args
+        .lightness_range
+        .as_deref()
+        .map(Range::parse)
+        .transpose()?
+        .map(|range| SearchFilter {
+            lightness_range: Some([range.min, range.max]),
+            ..Default::default()
+        });
+
     // Collect structured data for both terminal and file output
     let mut analysis_data = ColorFormatter::collect_color_analysis_data(
         schemes.base_color,
         input,
         color_name,
         algorithm,
+        filter.as_ref(),
+        args.all_distances,
     )?;
 
     // Add color schemes data with selected strategy
-    let color_schemes = super::utilities::collect_enhanced_color_schemes_data(
+    let mut color_schemes = super::utilities::collect_enhanced_color_schemes_data(
         schemes,
         &args.scheme_strategy,
         algorithm,
     );
+    if args.high_contrast_complement {
+        color_schemes.high_contrast_complement = Some(
+            super::utilities::collect_high_contrast_complement(schemes.base_color, algorithm),
+        );
+    }
+    if args.analyze_schemes {
+        super::utilities::analyze_scheme_colors(&mut color_schemes, schemes.base_color);
+    }
+    // Schemes are always computed with LAB luminance preserved; the matched
+    // variants are only reported when asked for
+    if !args.luminance_matched {
+        color_schemes.luminance_matched = crate::output_formats::LuminanceMatchedSchemes::default();
+    }
+    if args.include_null_schemes {
+        color_schemes.luminance_matched = color_schemes.luminance_matched.with_nulls();
+    }
     analysis_data = analysis_data.with_color_schemes(color_schemes);
 
-    Ok(analysis_data)
+    // Report contrast against a user-specified color
+    if let Some(against) = &args.contrast_against {
+        let target_lab = crate::color::parse_color_input(against)?;
+        analysis_data.contrast.contrast_vs_target = Some(ColorFormatter::collect_target_contrast(
+            schemes.base_color,
+            target_lab,
+            against,
+        ));
+    }
+
+    // Diagnose precision loss through the sRGB round-trip
+    if args.round_trip_check {
+        analysis_data.round_trip_check = Some(ColorFormatter::round_trip_check(schemes.base_color));
+    }
+
+    // Show what clamping into sRGB did to the input
+    if args.clamp_report {
+        analysis_data.clamp_report = Some(ColorFormatter::clamp_report(schemes.base_color));
+    }
+
+    // Normalized float channels for shader and graphics code
+    if args.rgb_format == crate::cli::RgbFormat::Float {
+        analysis_data.conversion.rgb =
+            crate::format_utils::FormatUtils::lab_to_rgb_float(schemes.base_color);
+    }
+
+    // ICC-style LAB for print workflows
+    if args.lab_variant == crate::cli::LabVariant::D50 {
+        analysis_data.conversion.lab =
+            crate::format_utils::FormatUtils::lab_to_lab_as(schemes.base_color, args.lab_variant);
+    }
+
+    // Grade contrast results for the requested text size
+    if let Some(text_size) = args.text_size {
+        ColorFormatter::apply_text_size_compliance(&mut analysis_data.contrast, text_size);
+    }
+
+    // Include matches from a user-supplied collection file
+    if let Some(path) = &args.collection_file {
+        let collection = CustomColorCollection::from_csv(path).map_err(|e| {
+            ColorError::InvalidArguments(format!("Failed to load collection file: {e:#}"))
+        })?;
+        analysis_data.color_collections.custom = ColorFormatter::collect_custom_collection_matches(
+            schemes.base_color,
+            &collection,
+            algorithm,
+            filter.as_ref(),
+            args.all_distances,
+        );
+    }
+
+    // Include the closest matches that stay legible on a background
+    if let Some(background) = &args.accessible_against {
+        let background_lab = crate::color::parse_color_input(background)?;
+        analysis_data.color_collections.accessible =
+            Some(ColorFormatter::collect_accessible_matches(
+                schemes.base_color,
+                background_lab,
+                args.min_ratio,
+                algorithm,
+                args.all_distances,
+            ));
+    }
+
+    // Keep only the built-in collections the user selected
+    retain_collections(&mut analysis_data, &args.collections);
+
+    // Hide matches too far away for their names to be meaningful
+    if let Some(min_confidence) = args.min_confidence {
+        let collections = &mut analysis_data.color_collections;
+        for matches in [
+            &mut collections.css_colors,
+            &mut collections.ral_classic,
+            &mut collections.ral_design,
+            &mut collections.custom,
+        ] {
+            matches.retain(|color_match| color_match.confidence >= min_confidence);
+        }
+        if let Some(accessible) = &mut collections.accessible {
+            for matches in [
+                &mut accessible.css_colors,
+                &mut accessible.ral_classic,
+                &mut accessible.ral_design,
+            ] {
+                matches.retain(|color_match| color_match.confidence >= min_confidence);
+            }
+        }
+    }
+
+    // Localize matched names for display; matches were ranked on canonical names
+    let translations = if let Some(path) = &args.names_file {
+        Some(NameTranslations::from_csv(path))
+    } else {
+        args.names_lang.as_deref().map(NameTranslations::bundled)
+    };
+    if let Some(translations) = translations {
+        let translations = translations.map_err(|e| {
+            ColorError::InvalidArguments(format!("Failed to load color name translations: {e:#}"))
+        })?;
+        translate_match_names(&mut analysis_data, &translations);
+    }
+
+    // Base and selected-strategy scheme colors as CSS custom properties
+    let strategy_prefix = format!("{}_", args.scheme_strategy);
+    let css_properties = std::iter::once(("base", schemes.base_color))
+        .chain(
+            schemes
+                .iter_relationships()
+                .filter_map(|(name, color)| Some((name.strip_prefix(&strategy_prefix)?, color))),
+        )
+        .map(|(name, color)| {
+            (
+                format!("--color-{}", name.replace('_', "-")),
+                lab_to_hex(color),
+            )
+        })
+        .collect();
+    analysis_data = analysis_data.with_css_properties(css_properties);
+
+    // Leave out the report sections the user did not ask for
+    Ok(analysis_data.with_sections(&args.sections))
+}
+
+/// Replace collection match names with their translations, keeping canonical
+/// names where no translation exists
+pub fn translate_match_names(
+    analysis_data: &mut ColorAnalysisOutput,
+    translations: &NameTranslations,
+) {
+    let collections = &mut analysis_data.color_collections;
+    for color_match in collections
+        .css_colors
+        .iter_mut()
+        .chain(collections.ral_classic.iter_mut())
+        .chain(collections.ral_design.iter_mut())
+        .chain(collections.custom.iter_mut())
+        .chain(collections.accessible.iter_mut().flat_map(|accessible| {
+            accessible
+                .css_colors
+                .iter_mut()
+                .chain(accessible.ral_classic.iter_mut())
+                .chain(accessible.ral_design.iter_mut())
+        }))
+    {
+        color_match.name = translations.translate(&color_match.name).to_string();
+    }
+
+    for item in scheme_items_mut(&mut analysis_data.color_schemes) {
+        for scheme_match in [&mut item.css, &mut item.ral_classic, &mut item.ral_design]
+            .into_iter()
+            .flatten()
+        {
+            scheme_match.name = translations.translate(&scheme_match.name).to_string();
+        }
+    }
+}
+
+/// Every scheme color in the report, including the optional variants
+fn scheme_items_mut(
+    schemes: &mut ColorSchemes,
+) -> impl Iterator<Item = &mut EnhancedColorSchemeItem> {
+    let matched = &mut schemes.luminance_matched;
+    std::iter::once(&mut schemes.complementary)
+        .chain(schemes.split_complementary.iter_mut())
+        .chain(schemes.triadic.iter_mut())
+        .chain(schemes.tetradic.iter_mut())
+        .chain(schemes.high_contrast_complement.iter_mut())
+        .chain(matched.complementary.value.iter_mut())
+        .chain(matched.split_complementary.value.iter_mut().flatten())
+        .chain(matched.triadic.value.iter_mut().flatten())
+        .chain(matched.tetradic.value.iter_mut().flatten())
+}
+
+/// Drop matches from built-in collections outside `selection`
+///
+/// An empty selection keeps every collection. Dropped collections disappear
+/// from the match lists, the accessible matches and each scheme color.
+pub fn retain_collections(
+    analysis_data: &mut ColorAnalysisOutput,
+    selection: &[ColorCollectionType],
+) {
+    let keep = |kind: ColorCollectionType| kind.is_selected(selection);
+    let (css, ral_classic, ral_design) = (
+        keep(ColorCollectionType::Css),
+        keep(ColorCollectionType::RalClassic),
+        keep(ColorCollectionType::RalDesign),
+    );
+
+    let collections = &mut analysis_data.color_collections;
+    let accessible = collections.accessible.iter_mut();
+    for (matches, kept) in [
+        (&mut collections.css_colors, css),
+        (&mut collections.ral_classic, ral_classic),
+        (&mut collections.ral_design, ral_design),
+    ]
+    .into_iter()
+    .chain(accessible.flat_map(|a| {
+        [
+            (&mut a.css_colors, css),
+            (&mut a.ral_classic, ral_classic),
+            (&mut a.ral_design, ral_design),
+        ]
+    })) {
+        if !kept {
+            matches.clear();
+        }
+    }
+
+    for item in scheme_items_mut(&mut analysis_data.color_schemes) {
+        for (scheme_match, kept) in [
+            (&mut item.css, css),
+            (&mut item.ral_classic, ral_classic),
+            (&mut item.ral_design, ral_design),
+        ] {
+            if !kept {
+                *scheme_match = None;
+            }
+        }
+    }
 }
 
 /// Generate formatted output based on the selected format
@@ -110,5 +359,13 @@ pub fn generate_formatted_output(
         OutputFormat::Yaml => analysis_data
             .to_yaml()
             .map_err(|e| ColorError::InvalidArguments(format!("Failed to serialize to YAML: {e}"))),
+        OutputFormat::Env => analysis_data
+            .to_env()
+            .map_err(|e| ColorError::InvalidArguments(format!("Failed to serialize to env: {e}"))),
+        OutputFormat::Rust => {
+            Err(format.unsupported_by("the color command", crate::cli::ColorArgs::OUTPUT_FORMATS))
+        }
+        OutputFormat::Markdown => Ok(analysis_data.to_markdown()),
+        OutputFormat::CssVars => Ok(analysis_data.to_css_vars()),
     }
 }
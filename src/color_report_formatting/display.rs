@@ -22,6 +22,8 @@ pub fn colorize_structured_line(line: &str, format: &OutputFormat) -> String {
     match format {
         OutputFormat::Toml => colorize_toml_line(indent, trimmed),
         OutputFormat::Yaml => colorize_yaml_line(indent, trimmed),
+        OutputFormat::Env => colorize_env_line(trimmed),
+        OutputFormat::Rust | OutputFormat::Markdown | OutputFormat::CssVars => line.to_string(),
     }
 }
 
@@ -60,3 +62,11 @@ fn colorize_yaml_line(indent: &str, trimmed: &str) -> String {
         format!("{indent}{trimmed}")
     }
 }
+
+/// Colorize env format lines (`KEY=#RRGGBB`)
+fn colorize_env_line(trimmed: &str) -> String {
+    trimmed.split_once('=').map_or_else(
+        || trimmed.to_string(),
+        |(key, value)| format!("{}={}", key.green(), value),
+    )
+}
@@ -37,14 +37,16 @@ pub mod utilities;
 // Re-export main functions for backward compatibility
 pub use core::{
     collect_analysis_data, generate_formatted_output, lab_to_hex, lab_to_hsl_tuple, lab_to_rgb,
-    rgb_to_lab, rgb_to_srgb,
+    rgb_to_lab, rgb_to_srgb, translate_match_names,
 };
 
-pub use output::write_output_file;
+pub use output::{describe_output_file, write_output_file};
 
 pub use display::{colorize_structured_line, display_terminal_output};
 
-pub use utilities::collect_enhanced_color_schemes_data;
+pub use utilities::{
+    analyze_scheme_colors, collect_enhanced_color_schemes_data, collect_high_contrast_complement,
+};
 
 #[cfg(test)]
 mod tests {
@@ -84,6 +86,402 @@ mod tests {
         assert!(hsl.1 < 0.1); // Low saturation
     }
 
+    #[test]
+    fn test_contrast_against_black_on_white() {
+        use crate::cli::ColorArgs;
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use crate::scheme_config::{ColorSchemeConfig, calculate_color_schemes};
+
+        let args = ColorArgs {
+            color: "black".to_string(),
+            contrast_against: Some("white".to_string()),
+            ..Default::default()
+        };
+        let schemes =
+            calculate_color_schemes(ColorSchemeConfig::default(), Lab::new(0.0, 0.0, 0.0)).unwrap();
+        let data = collect_analysis_data(&schemes, "black", "black", DistanceAlgorithm::Lch, &args)
+            .unwrap();
+
+        let target = data.contrast.contrast_vs_target.unwrap();
+        assert!((target.ratio - 21.0).abs() < 0.01);
+        assert!(target.aaa_normal_text);
+        assert!(target.aa_large_text);
+    }
+
+    #[test]
+    fn test_luminance_matched_omitted_without_preservation() {
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use crate::scheme_config::{ColorSchemeConfig, calculate_color_schemes};
+
+        let base = rgb_to_lab((51, 102, 204));
+        let plain = calculate_color_schemes(ColorSchemeConfig::default(), base).unwrap();
+        let color_schemes =
+            collect_enhanced_color_schemes_data(&plain, "lab", DistanceAlgorithm::Lch);
+        let yaml = serde_yml::to_string(&color_schemes).unwrap();
+        let json = serde_json::to_string(&color_schemes).unwrap();
+        let toml = toml::to_string(&color_schemes).unwrap();
+        assert!(!yaml.contains("luminance_matched"));
+        assert!(!json.contains("luminance_matched"));
+        assert!(!toml.contains("luminance_matched"));
+
+        let nulls = crate::output_formats::ColorSchemes {
+            luminance_matched: color_schemes.luminance_matched.clone().with_nulls(),
+            ..color_schemes
+        };
+        let json: serde_json::Value = serde_json::to_value(&nulls).unwrap();
+        assert!(json["luminance_matched"]["triadic"].is_null());
+        assert!(json["luminance_matched"].get("triadic").is_some());
+
+        let preserved = calculate_color_schemes(
+            ColorSchemeConfig::default()
+                .preserve_lab_luminance()
+                .unwrap(),
+            base,
+        )
+        .unwrap();
+        let color_schemes =
+            collect_enhanced_color_schemes_data(&preserved, "lab", DistanceAlgorithm::Lch);
+        let json: serde_json::Value = serde_json::to_value(&color_schemes).unwrap();
+        assert_eq!(
+            json["luminance_matched"]["triadic"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_default_color_report_omits_luminance_matched() {
+        use crate::cli::ColorArgs;
+        use crate::color_distance_strategies::DistanceAlgorithm;
+
+        let dir = tempfile::tempdir().unwrap();
+        let report = |luminance_matched: bool| {
+            let file = dir.path().join("report.yaml");
+            let args = ColorArgs {
+                color: "red".to_string(),
+                output_file: Some(file.to_string_lossy().into_owned()),
+                luminance_matched,
+                ..Default::default()
+            };
+            crate::color::color_match_with_schemes(&args, DistanceAlgorithm::Lch).unwrap();
+            std::fs::read_to_string(file).unwrap()
+        };
+
+        assert!(!report(false).contains("luminance_matched"));
+        assert!(report(true).contains("luminance_matched"));
+    }
+
+    #[test]
+    fn test_analyze_schemes_reports_triadic_contrast() {
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use crate::scheme_config::{ColorSchemeConfig, calculate_color_schemes};
+
+        let base = rgb_to_lab((51, 102, 204));
+        let schemes = calculate_color_schemes(ColorSchemeConfig::default(), base).unwrap();
+        let mut color_schemes =
+            collect_enhanced_color_schemes_data(&schemes, "lab", DistanceAlgorithm::Lch);
+        assert!(
+            color_schemes
+                .triadic
+                .iter()
+                .all(|item| item.analysis.is_none())
+        );
+
+        analyze_scheme_colors(&mut color_schemes, schemes.base_color);
+        assert_eq!(color_schemes.triadic.len(), 2);
+        for item in &color_schemes.triadic {
+            let analysis = item.analysis.as_ref().expect("triadic analysis");
+            assert!((1.0..=21.0).contains(&analysis.contrast_vs_base));
+            assert_eq!(analysis.details.properties.hex, item.hex);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_check_in_gamut_is_near_zero() {
+        use crate::color_formatter::ColorFormatter;
+
+        let check = ColorFormatter::round_trip_check(rgb_to_lab((51, 102, 204)));
+        assert!(check.in_gamut);
+        assert_eq!(check.rgb, "rgb(51, 102, 204)");
+        assert!(check.delta_e_2000 < 0.01);
+        assert!(check.rgb_delta < 0.01);
+
+        let vivid_green = crate::color::parse_color_input("lch(60, 130, 140)").unwrap();
+        let check = ColorFormatter::round_trip_check(vivid_green);
+        assert!(!check.in_gamut);
+        assert!(check.delta_e_2000 > 1.0);
+    }
+
+    #[test]
+    fn test_clamp_report_out_of_gamut_lch() {
+        use crate::color_formatter::ColorFormatter;
+
+        let vivid_green = crate::color::parse_color_input("lch(60, 130, 140)").unwrap();
+        let report = ColorFormatter::clamp_report(vivid_green);
+        assert!(report.delta_e_2000 > 1.0);
+        assert!(report.clamped_channels.iter().any(|c| c.contains(": -")));
+        assert!(report.unclamped.contains("(-"));
+        assert!(!report.clamped.contains('-'));
+
+        let report = ColorFormatter::clamp_report(rgb_to_lab((51, 102, 204)));
+        assert!(report.clamped_channels.is_empty());
+        assert!(report.delta_e_2000 < 0.01);
+    }
+
+    #[test]
+    fn test_collections_limit_reported_groups() {
+        use crate::cli::{Cli, Commands, OutputFormat};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use crate::scheme_config::{ColorSchemeConfig, calculate_color_schemes};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["color-rs", "color", "red", "--collections", "ral-classic"])
+            .unwrap();
+        let Commands::Color(args) = cli.command else {
+            panic!("expected color command");
+        };
+        let red = rgb_to_lab((255, 0, 0));
+        let schemes = calculate_color_schemes(ColorSchemeConfig::default(), red).unwrap();
+        let data =
+            collect_analysis_data(&schemes, "red", "red", DistanceAlgorithm::Lch, &args).unwrap();
+        let output = generate_formatted_output(&data, &OutputFormat::Yaml).unwrap();
+
+        assert!(!data.color_collections.ral_classic.is_empty());
+        assert!(!output.contains("css"));
+        assert!(!output.contains("ral_design"));
+        assert!(data.color_schemes.complementary.ral_classic.is_some());
+    }
+
+    #[test]
+    fn test_sections_filter_report_blocks() {
+        use crate::cli::{Cli, Commands, OutputFormat};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use crate::scheme_config::{ColorSchemeConfig, calculate_color_schemes};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["color-rs", "color", "red", "--sections", "accessibility"])
+            .unwrap();
+        let Commands::Color(args) = cli.command else {
+            panic!("expected color command");
+        };
+        let red = rgb_to_lab((255, 0, 0));
+        let schemes = calculate_color_schemes(ColorSchemeConfig::default(), red).unwrap();
+        let data =
+            collect_analysis_data(&schemes, "red", "red", DistanceAlgorithm::Lch, &args).unwrap();
+
+        for format in [OutputFormat::Yaml, OutputFormat::Toml] {
+            let output = generate_formatted_output(&data, &format).unwrap();
+            assert!(output.contains("metadata"));
+            assert!(output.contains("contrast"));
+            assert!(output.contains("wcag21_relative_luminance"));
+            for omitted in ["oklch", "lch0_hex", "ral_classic", "complementary"] {
+                assert!(!output.contains(omitted), "{omitted} in {output}");
+            }
+        }
+
+        let all = collect_analysis_data(
+            &schemes,
+            "red",
+            "red",
+            DistanceAlgorithm::Lch,
+            &crate::cli::ColorArgs {
+                sections: Vec::new(),
+                ..args
+            },
+        )
+        .unwrap();
+        let output = generate_formatted_output(&all, &OutputFormat::Yaml).unwrap();
+        assert!(output.contains("oklch") && output.contains("complementary"));
+    }
+
+    #[test]
+    fn test_css_vars_output_is_a_root_block() {
+        use crate::cli::{Cli, Commands, OutputFormat};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use crate::scheme_config::{ColorSchemeConfig, calculate_color_schemes};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["color-rs", "color", "red", "-o", "css-vars"]).unwrap();
+        let Commands::Color(args) = cli.command else {
+            panic!("expected color command");
+        };
+        let red = rgb_to_lab((255, 0, 0));
+        let schemes = calculate_color_schemes(ColorSchemeConfig::default(), red).unwrap();
+        let data =
+            collect_analysis_data(&schemes, "red", "red", DistanceAlgorithm::Lch, &args).unwrap();
+        let output = generate_formatted_output(&data, &OutputFormat::CssVars).unwrap();
+
+        assert!(output.starts_with(":root {\n"), "{output}");
+        assert!(output.ends_with("}\n"), "{output}");
+        assert!(output.contains("  --color-base: #FF0000;\n"), "{output}");
+        let complementary = output
+            .lines()
+            .find(|line| line.starts_with("  --color-complementary: #"))
+            .unwrap_or_else(|| panic!("no complementary declaration in {output}"));
+        assert_eq!(
+            complementary.len(),
+            "  --color-complementary: #RRGGBB;".len()
+        );
+        assert!(complementary.ends_with(';'));
+        // Every line between the braces is a custom-property declaration
+        let body: Vec<&str> = output
+            .lines()
+            .skip(1)
+            .take_while(|line| *line != "}")
+            .collect();
+        assert_eq!(body.len(), 9);
+        assert!(
+            body.iter()
+                .all(|line| line.starts_with("  --color-") && line.ends_with(';'))
+        );
+    }
+
+    #[test]
+    fn test_env_output_format() {
+        use crate::cli::{ColorArgs, OutputFormat};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use crate::scheme_config::{ColorSchemeConfig, calculate_color_schemes};
+
+        let args = ColorArgs {
+            color: "red".to_string(),
+            output_format: Some(OutputFormat::Env),
+            ..Default::default()
+        };
+        let red = rgb_to_lab((255, 0, 0));
+        let schemes = calculate_color_schemes(ColorSchemeConfig::default(), red).unwrap();
+        let data =
+            collect_analysis_data(&schemes, "red", "red", DistanceAlgorithm::Lch, &args).unwrap();
+        let output = generate_formatted_output(&data, &OutputFormat::Env).unwrap();
+
+        let complementary = output
+            .lines()
+            .find(|line| line.contains("COMPLEMENTARY=#"))
+            .expect("complementary color line");
+        let hex = complementary.split_once('=').unwrap().1;
+        assert!(hex.len() == 7 && hex[1..].chars().all(|c| c.is_ascii_hexdigit()));
+
+        for line in output.lines() {
+            let (key, value) = line.split_once('=').unwrap();
+            assert!(
+                key.chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+            );
+            assert!(value.starts_with('#'));
+            assert!(!line.contains(['{', '}', '[', ']', ':', '"']));
+        }
+    }
+
+    #[test]
+    fn test_all_distances_augments_matches() {
+        use crate::cli::{ColorArgs, OutputFormat};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use crate::scheme_config::{ColorSchemeConfig, calculate_color_schemes};
+
+        let args = ColorArgs {
+            color: "#3A7BD5".to_string(),
+            distance_method: "delta-e-2000".to_string(),
+            all_distances: true,
+            ..Default::default()
+        };
+        let lab = rgb_to_lab((0x3A, 0x7B, 0xD5));
+        let schemes = calculate_color_schemes(ColorSchemeConfig::default(), lab).unwrap();
+        let data = collect_analysis_data(
+            &schemes,
+            "#3A7BD5",
+            "",
+            DistanceAlgorithm::DeltaE2000,
+            &args,
+        )
+        .unwrap();
+
+        let collections = &data.color_collections;
+        for color_match in collections
+            .css_colors
+            .iter()
+            .chain(&collections.ral_classic)
+            .chain(&collections.ral_design)
+        {
+            let distances = color_match.distances.as_ref().expect("all distances");
+            assert!((color_match.distance - distances.delta_e_2000).abs() < 1e-9);
+            assert!(distances.delta_e_76 >= distances.delta_e_94);
+        }
+
+        let yaml = generate_formatted_output(&data, &OutputFormat::Yaml).unwrap();
+        for key in [
+            "delta_e_76:",
+            "delta_e_94:",
+            "delta_e_2000:",
+            "euclidean_lab:",
+            "lch:",
+        ] {
+            assert!(yaml.contains(key), "missing {key}");
+        }
+    }
+
+    #[test]
+    fn test_decimal_separator_comma() {
+        use crate::cli::{DecimalSeparator, OutputFormat};
+        use crate::output_formats::localize_decimal_separator;
+
+        let yaml = "contrast:\n  wcag21_relative_luminance: 0.5\n  lab: lab(53.24, 80.09, 67.2)\n  ratio: 21\n- 0.25\n";
+        let localized =
+            localize_decimal_separator(yaml, &OutputFormat::Yaml, DecimalSeparator::Comma);
+        assert!(localized.contains("wcag21_relative_luminance: 0,5\n"));
+        assert!(localized.contains("lab: lab(53.24, 80.09, 67.2)"));
+        assert!(localized.contains("ratio: 21\n"));
+        assert!(localized.contains("- 0,25\n"));
+
+        let toml = "luminance = 0.5\nlab = \"lab(53.24, 80.09, 67.2)\"\n";
+        let localized =
+            localize_decimal_separator(toml, &OutputFormat::Toml, DecimalSeparator::Comma);
+        assert_eq!(
+            localized,
+            "luminance = \"0,5\"\nlab = \"lab(53.24, 80.09, 67.2)\"\n"
+        );
+        assert!(toml::from_str::<toml::Value>(&localized).is_ok());
+
+        assert_eq!(
+            localize_decimal_separator(yaml, &OutputFormat::Yaml, DecimalSeparator::Dot),
+            yaml
+        );
+    }
+
+    #[test]
+    fn test_names_file_translates_matches() {
+        use crate::cli::ColorArgs;
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use crate::scheme_config::{ColorSchemeConfig, calculate_color_schemes};
+        use std::io::Write;
+
+        let mut names = tempfile::NamedTempFile::new().unwrap();
+        writeln!(names, "Name;Translation").unwrap();
+        writeln!(names, "Red;Rot").unwrap();
+
+        let mut args = ColorArgs {
+            color: "red".to_string(),
+            ..Default::default()
+        };
+        let red = Lab::new(53.24, 80.09, 67.20);
+        let schemes = calculate_color_schemes(ColorSchemeConfig::default(), red).unwrap();
+        let canonical =
+            collect_analysis_data(&schemes, "red", "red", DistanceAlgorithm::Lch, &args).unwrap();
+
+        args.names_file = Some(names.path().to_string_lossy().into_owned());
+        let translated =
+            collect_analysis_data(&schemes, "red", "red", DistanceAlgorithm::Lch, &args).unwrap();
+
+        let canonical_css = &canonical.color_collections.css_colors;
+        let translated_css = &translated.color_collections.css_colors;
+        assert_eq!(canonical_css[0].name, "Red");
+        assert_eq!(translated_css[0].name, "Rot");
+        for (before, after) in canonical_css.iter().zip(translated_css).skip(1) {
+            assert_eq!(before.name, after.name);
+            assert_eq!(before.hex, after.hex);
+        }
+    }
+
     #[test]
     fn test_module_re_exports() {
         // Verify all expected functions are accessible
@@ -2,9 +2,10 @@
 //!
 //! Handles file writing, format-specific output generation, and file management utilities.
 
-use crate::cli::OutputFormat;
+use super::core::generate_formatted_output;
+use crate::cli::{DecimalSeparator, OutputFormat};
 use crate::error::{ColorError, Result};
-use crate::output_formats::ColorAnalysisOutput;
+use crate::output_formats::{ColorAnalysisOutput, localize_decimal_separator};
 use colored::Colorize;
 use std::fs::File;
 use std::io::Write;
@@ -17,43 +18,77 @@ pub fn write_output_file(
     analysis_data: &ColorAnalysisOutput,
     filename: &str,
     format: &OutputFormat,
+    separator: DecimalSeparator,
 ) -> Result<()> {
-    match format {
-        OutputFormat::Toml => write_toml_file(analysis_data, filename),
-        OutputFormat::Yaml => write_yaml_file(analysis_data, filename),
-    }
-}
+    let (target, content) = prepare_output_file(analysis_data, filename, format, separator)?;
 
-/// Write analysis data to TOML file
-fn write_toml_file(analysis_data: &ColorAnalysisOutput, filename: &str) -> Result<()> {
-    let toml_filename = ensure_file_extension(filename, "toml");
-    let toml_content = analysis_data
-        .to_toml()
-        .map_err(|e| ColorError::InvalidArguments(format!("Failed to serialize to TOML: {e}")))?;
-
-    write_file_content(&toml_filename, &toml_content)?;
+    write_file_content(&target, &content)?;
     println!(
-        "Color analysis saved to TOML file: {}",
-        toml_filename.green()
+        "Color analysis saved to {} file: {}",
+        format_label(format),
+        target.green()
     );
     Ok(())
 }
 
-/// Write analysis data to YAML file
-fn write_yaml_file(analysis_data: &ColorAnalysisOutput, filename: &str) -> Result<()> {
-    let yaml_filename = ensure_yaml_extension(filename);
-    let yaml_content = analysis_data
-        .to_yaml()
-        .map_err(|e| ColorError::InvalidArguments(format!("Failed to serialize to YAML: {e}")))?;
+/// Report where analysis data would be written without creating the file
+///
+/// Serializes the data exactly as [`write_output_file`] would, so serialization
+/// errors still surface, then prints the target path and size.
+///
+/// # Errors
+/// Returns an error if serialization fails
+pub fn describe_output_file(
+    analysis_data: &ColorAnalysisOutput,
+    filename: &str,
+    format: &OutputFormat,
+    separator: DecimalSeparator,
+) -> Result<()> {
+    let (target, content) = prepare_output_file(analysis_data, filename, format, separator)?;
 
-    write_file_content(&yaml_filename, &yaml_content)?;
     println!(
-        "Color analysis saved to YAML file: {}",
-        yaml_filename.green()
+        "Dry run: would save color analysis ({} bytes) to: {}",
+        content.len(),
+        target.green()
     );
     Ok(())
 }
 
+/// Serialize analysis data and resolve the target filename for a format
+fn prepare_output_file(
+    analysis_data: &ColorAnalysisOutput,
+    filename: &str,
+    format: &OutputFormat,
+    separator: DecimalSeparator,
+) -> Result<(String, String)> {
+    let content = generate_formatted_output(analysis_data, format)?;
+    let target = match format {
+        OutputFormat::Toml => ensure_file_extension(filename, "toml"),
+        OutputFormat::Yaml => ensure_yaml_extension(filename),
+        OutputFormat::Env => ensure_file_extension(filename, "env"),
+        OutputFormat::Rust => ensure_file_extension(filename, "rs"),
+        OutputFormat::Markdown => ensure_file_extension(filename, "md"),
+        OutputFormat::CssVars => ensure_file_extension(filename, "css"),
+    };
+
+    Ok((
+        target,
+        localize_decimal_separator(&content, format, separator),
+    ))
+}
+
+/// Human-readable name of an output format for status messages
+const fn format_label(format: &OutputFormat) -> &'static str {
+    match format {
+        OutputFormat::Toml => "TOML",
+        OutputFormat::Yaml => "YAML",
+        OutputFormat::Env => "env",
+        OutputFormat::Rust => "Rust",
+        OutputFormat::Markdown => "Markdown",
+        OutputFormat::CssVars => "CSS",
+    }
+}
+
 /// Ensure filename has the correct extension
 fn ensure_file_extension(filename: &str, extension: &str) -> String {
     if std::path::Path::new(filename)
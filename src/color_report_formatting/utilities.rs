@@ -5,8 +5,11 @@
 
 use crate::color_distance_strategies::DistanceAlgorithm;
 use crate::color_schemes::ColorSchemeResult;
-use crate::output_formats::{CollectionMatch, ColorSchemes, EnhancedColorSchemeItem};
-use palette::Lab;
+use crate::output_formats::{
+    CollectionMatch, ColorSchemes, EnhancedColorSchemeItem, LuminanceMatchedSchemes,
+    SchemeColorAnalysis,
+};
+use palette::{IntoColor, Lab, Srgb};
 
 use super::core::{lab_to_hex, lab_to_hsl_tuple, lab_to_rgb, rgb_to_lab, rgb_to_srgb};
 
@@ -20,7 +23,7 @@ pub fn collect_enhanced_color_schemes_data(
     use crate::color_parser::unified_manager::UnifiedColorManager;
 
     // Create manager for color matching with strategy support
-    let manager = UnifiedColorManager::new().unwrap_or_default();
+    let manager = UnifiedColorManager::new();
 
     // Select the appropriate strategy schemes
     let selected_schemes = match strategy {
@@ -38,6 +41,32 @@ pub fn collect_enhanced_color_schemes_data(
         ),
     };
 
+    // Luminance-matched variants are `None` unless preservation was enabled
+    let matched = match strategy {
+        "hsl" => (
+            schemes.luminance_matched_hsl_complementary,
+            schemes.luminance_matched_hsl_split_complementary,
+            schemes.luminance_matched_hsl_triadic,
+            schemes.luminance_matched_hsl_tetradic,
+        ),
+        _ => (
+            schemes.luminance_matched_lab_complementary,
+            schemes.luminance_matched_lab_split_complementary,
+            schemes.luminance_matched_lab_triadic,
+            schemes.luminance_matched_lab_tetradic,
+        ),
+    };
+    let item = |lab: Lab| lab_to_enhanced_item(lab, &manager, distance_algorithm);
+    let luminance_matched = LuminanceMatchedSchemes {
+        complementary: matched.0.map(item).into(),
+        split_complementary: matched.1.map(|(a, b)| vec![item(a), item(b)]).into(),
+        triadic: matched.2.map(|(a, b)| vec![item(a), item(b)]).into(),
+        tetradic: matched
+            .3
+            .map(|(a, b, c)| vec![item(a), item(b), item(c)])
+            .into(),
+    };
+
     ColorSchemes {
         complementary: lab_to_enhanced_item(selected_schemes.0, &manager, distance_algorithm),
         split_complementary: vec![
@@ -53,6 +82,45 @@ pub fn collect_enhanced_color_schemes_data(
             lab_to_enhanced_item(selected_schemes.3.1, &manager, distance_algorithm),
             lab_to_enhanced_item(selected_schemes.3.2, &manager, distance_algorithm),
         ],
+        high_contrast_complement: None,
+        luminance_matched,
+    }
+}
+
+/// Build the `--high-contrast-complement` scheme item for a base color
+#[must_use]
+pub fn collect_high_contrast_complement(
+    base: Lab,
+    distance_algorithm: DistanceAlgorithm,
+) -> EnhancedColorSchemeItem {
+    use crate::color_parser::unified_manager::UnifiedColorManager;
+
+    let manager = UnifiedColorManager::new();
+    lab_to_enhanced_item(
+        crate::color_schemes::high_contrast_complement(base),
+        &manager,
+        distance_algorithm,
+    )
+}
+
+/// Attach a full color analysis and WCAG contrast against `base` to every scheme color
+pub fn analyze_scheme_colors(schemes: &mut ColorSchemes, base: Lab) {
+    let base_srgb: Srgb = base.into_color();
+
+    for item in std::iter::once(&mut schemes.complementary)
+        .chain(schemes.split_complementary.iter_mut())
+        .chain(schemes.triadic.iter_mut())
+        .chain(schemes.tetradic.iter_mut())
+        .chain(schemes.high_contrast_complement.iter_mut())
+    {
+        let Ok(rgb) = item.hex.parse::<Srgb<u8>>() else {
+            continue;
+        };
+        let srgb: Srgb = rgb.into_format();
+        item.analysis = Some(SchemeColorAnalysis {
+            contrast_vs_base: crate::color_ops::contrast::wcag_ratio(base_srgb, srgb),
+            details: crate::color_ops::analysis::analyze_color(srgb),
+        });
     }
 }
 
@@ -90,6 +158,7 @@ fn lab_to_enhanced_item(
         css: css_match,
         ral_classic: ral_classic_match,
         ral_design: ral_design_match,
+        analysis: None,
     }
 }
 
@@ -100,7 +169,7 @@ fn get_closest_css_match(
     distance_algorithm: DistanceAlgorithm,
 ) -> Option<CollectionMatch> {
     let rgb = [target.rgb[0], target.rgb[1], target.rgb[2]];
-    let matches = manager.find_closest_css_colors_with_algorithm(rgb, 1, distance_algorithm);
+    let matches = manager.find_closest_css_colors_with_algorithm(rgb, 1, None, distance_algorithm);
 
     matches.first().map(|closest| {
         let target_lab = rgb_to_lab((target.rgb[0], target.rgb[1], target.rgb[2]));
@@ -140,7 +209,7 @@ fn get_closest_ral_classic_match(
     distance_algorithm: DistanceAlgorithm,
 ) -> Option<CollectionMatch> {
     let rgb = [target.rgb[0], target.rgb[1], target.rgb[2]];
-    let matches = manager.find_closest_ral_classic_with_algorithm(rgb, 1, distance_algorithm);
+    let matches = manager.find_closest_ral_classic_with_algorithm(rgb, 1, None, distance_algorithm);
 
     matches.first().map(|closest| {
         let target_lab = rgb_to_lab((target.rgb[0], target.rgb[1], target.rgb[2]));
@@ -180,7 +249,7 @@ fn get_closest_ral_design_match(
     distance_algorithm: DistanceAlgorithm,
 ) -> Option<CollectionMatch> {
     let rgb = [target.rgb[0], target.rgb[1], target.rgb[2]];
-    let matches = manager.find_closest_ral_design_with_algorithm(rgb, 1, distance_algorithm);
+    let matches = manager.find_closest_ral_design_with_algorithm(rgb, 1, None, distance_algorithm);
 
     matches.first().map(|closest| {
         let target_lab = rgb_to_lab((target.rgb[0], target.rgb[1], target.rgb[2]));
@@ -92,6 +92,42 @@ pub fn complementary_lab(color: Lab) -> Lab {
     Lab::new(color.l, -color.a, -color.b)
 }
 
+/// Calculate a complementary color with maximum WCAG contrast against the base
+///
+/// Keeps the hue of [`complementary_lab`] and searches Lab lightness for the
+/// in-gamut value with the highest contrast ratio. When no lightness can hold
+/// the complement's chroma in sRGB, chroma is reduced until one can.
+#[must_use]
+pub fn high_contrast_complement(base: Lab) -> Lab {
+    let complement = complementary_lab(base);
+    let base_srgb: Srgb = base.into_color();
+
+    let mut chroma_scale = 1.0_f32;
+    loop {
+        let a = complement.a * chroma_scale;
+        let b = complement.b * chroma_scale;
+        let best = (0..=200)
+            .map(|step| Lab::new(step as f32 * 0.5, a, b))
+            .filter(|candidate| is_in_srgb_gamut(*candidate))
+            .map(|candidate| {
+                let contrast =
+                    crate::color_ops::contrast::wcag_ratio(base_srgb, candidate.into_color());
+                (candidate, contrast)
+            })
+            .max_by(|x, y| x.1.total_cmp(&y.1));
+
+        if let Some((candidate, _)) = best {
+            return candidate;
+        }
+        chroma_scale *= 0.9;
+    }
+}
+
+/// Whether a Lab color maps into sRGB without clamping
+fn is_in_srgb_gamut(color: Lab) -> bool {
+    crate::color_ops::Gamut::Srgb.contains(color)
+}
+
 /// Calculate split-complementary colors in Lab space
 pub fn split_complementary_lab(color: Lab) -> (Lab, Lab) {
     // Split-complementary approximated in Lab space by rotating a/b vector
@@ -239,6 +275,21 @@ mod tests {
         assert!((comp.b + red_lab.b).abs() < 0.01);
     }
 
+    #[test]
+    fn test_high_contrast_complement() {
+        let base: Lab = Srgb::new(0.2, 0.4, 0.8).into_color();
+        let base_srgb: Srgb = base.into_color();
+        let plain = complementary_lab(base);
+        let high = high_contrast_complement(base);
+
+        let plain_contrast = crate::color_ops::contrast::wcag_ratio(base_srgb, plain.into_color());
+        let high_contrast = crate::color_ops::contrast::wcag_ratio(base_srgb, high.into_color());
+        assert!(high_contrast > plain_contrast);
+
+        // Hue stays on the complementary side of the base
+        assert!(high.a * plain.a >= 0.0 && high.b * plain.b >= 0.0);
+    }
+
     #[test]
     fn test_triadic_hsl() {
         let red_lab: Lab = Srgb::new(1.0, 0.0, 0.0).into_color();
@@ -286,6 +286,108 @@ pub struct ColorSchemeResult {
     pub luminance_matched_lab_tetradic: Option<(Lab, Lab, Lab)>,
 }
 
+impl ColorSchemeResult {
+    /// Every base relationship as `(name, color)`, HSL strategy first
+    ///
+    /// Names follow the result fields; multi-color schemes number their
+    /// members, e.g. `lab_triadic_1` and `lab_triadic_2`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use color_rs::ColorSchemeCalculator;
+    /// use palette::Lab;
+    ///
+    /// let result = ColorSchemeCalculator::default()
+    ///     .calculate(Lab::new(53.24, 80.09, 67.2))
+    ///     .unwrap();
+    /// let names: Vec<&str> = result.iter_relationships().map(|(name, _)| name).collect();
+    /// assert_eq!(names.len(), 16);
+    /// assert_eq!(names[0], "hsl_complementary");
+    /// ```
+    pub fn iter_relationships(&self) -> impl Iterator<Item = (&'static str, Lab)> {
+        relationships(
+            Some(self.hsl_complementary),
+            Some(self.hsl_split_complementary),
+            Some(self.hsl_triadic),
+            Some(self.hsl_tetradic),
+            Some(self.lab_complementary),
+            Some(self.lab_split_complementary),
+            Some(self.lab_triadic),
+            Some(self.lab_tetradic),
+        )
+    }
+
+    /// Luminance-matched relationships as `(name, color)`, named like
+    /// [`iter_relationships`](Self::iter_relationships)
+    ///
+    /// Only variants that were calculated are yielded, so this is empty unless
+    /// luminance matching was requested.
+    pub fn iter_luminance_matched_relationships(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, Lab)> {
+        relationships(
+            self.luminance_matched_hsl_complementary,
+            self.luminance_matched_hsl_split_complementary,
+            self.luminance_matched_hsl_triadic,
+            self.luminance_matched_hsl_tetradic,
+            self.luminance_matched_lab_complementary,
+            self.luminance_matched_lab_split_complementary,
+            self.luminance_matched_lab_triadic,
+            self.luminance_matched_lab_tetradic,
+        )
+    }
+}
+
+/// Flatten one strategy pair of scheme results into named relationships
+#[allow(clippy::too_many_arguments)]
+fn relationships(
+    hsl_complementary: Option<Lab>,
+    hsl_split_complementary: Option<(Lab, Lab)>,
+    hsl_triadic: Option<(Lab, Lab)>,
+    hsl_tetradic: Option<(Lab, Lab, Lab)>,
+    lab_complementary: Option<Lab>,
+    lab_split_complementary: Option<(Lab, Lab)>,
+    lab_triadic: Option<(Lab, Lab)>,
+    lab_tetradic: Option<(Lab, Lab, Lab)>,
+) -> impl Iterator<Item = (&'static str, Lab)> {
+    let single = |name, color: Option<Lab>| color.map(|c| vec![(name, c)]).unwrap_or_default();
+    let pair = |names: [&'static str; 2], colors: Option<(Lab, Lab)>| {
+        colors
+            .map(|(a, b)| vec![(names[0], a), (names[1], b)])
+            .unwrap_or_default()
+    };
+    let triple = |names: [&'static str; 3], colors: Option<(Lab, Lab, Lab)>| {
+        colors
+            .map(|(a, b, c)| vec![(names[0], a), (names[1], b), (names[2], c)])
+            .unwrap_or_default()
+    };
+
+    [
+        single("hsl_complementary", hsl_complementary),
+        pair(
+            ["hsl_split_complementary_1", "hsl_split_complementary_2"],
+            hsl_split_complementary,
+        ),
+        pair(["hsl_triadic_1", "hsl_triadic_2"], hsl_triadic),
+        triple(
+            ["hsl_tetradic_1", "hsl_tetradic_2", "hsl_tetradic_3"],
+            hsl_tetradic,
+        ),
+        single("lab_complementary", lab_complementary),
+        pair(
+            ["lab_split_complementary_1", "lab_split_complementary_2"],
+            lab_split_complementary,
+        ),
+        pair(["lab_triadic_1", "lab_triadic_2"], lab_triadic),
+        triple(
+            ["lab_tetradic_1", "lab_tetradic_2", "lab_tetradic_3"],
+            lab_tetradic,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+}
+
 /// Helper struct for basic scheme calculations
 struct BasicSchemes {
     hsl_complementary: Lab,
@@ -382,6 +484,48 @@ mod tests {
         assert!(result.lab_triadic.0.l >= 0.0); // Basic validity check
     }
 
+    #[test]
+    fn test_iter_relationships_names() {
+        let red_lab: Lab = Srgb::new(1.0, 0.0, 0.0).into_color();
+        let result = ColorSchemeCalculator::default().calculate(red_lab).unwrap();
+
+        let names: Vec<&str> = result.iter_relationships().map(|(name, _)| name).collect();
+        assert_eq!(
+            names,
+            [
+                "hsl_complementary",
+                "hsl_split_complementary_1",
+                "hsl_split_complementary_2",
+                "hsl_triadic_1",
+                "hsl_triadic_2",
+                "hsl_tetradic_1",
+                "hsl_tetradic_2",
+                "hsl_tetradic_3",
+                "lab_complementary",
+                "lab_split_complementary_1",
+                "lab_split_complementary_2",
+                "lab_triadic_1",
+                "lab_triadic_2",
+                "lab_tetradic_1",
+                "lab_tetradic_2",
+                "lab_tetradic_3",
+            ]
+        );
+        let (_, triadic) = result
+            .iter_relationships()
+            .find(|(name, _)| *name == "lab_triadic_2")
+            .unwrap();
+        assert_eq!(triadic, result.lab_triadic.1);
+        assert_eq!(result.iter_luminance_matched_relationships().count(), 0);
+
+        let matched = ColorSchemeBuilder::new()
+            .preserve_lab_luminance()
+            .build()
+            .calculate(red_lab)
+            .unwrap();
+        assert_eq!(matched.iter_luminance_matched_relationships().count(), 16);
+    }
+
     #[test]
     fn test_calculator_default() {
         let calculator = ColorSchemeCalculator::default();
@@ -15,9 +15,9 @@ pub mod strategies;
 // Re-export main functionality for clean API
 pub use algorithms::{
     adjust_color_lab_luminance, adjust_color_relative_luminance, complementary_hsl,
-    complementary_lab, preserve_lab_luminance, preserve_wcag_relative_luminance,
-    split_complementary_hsl, split_complementary_lab, tetradic_hsl, tetradic_lab, triadic_hsl,
-    triadic_lab,
+    complementary_lab, high_contrast_complement, preserve_lab_luminance,
+    preserve_wcag_relative_luminance, split_complementary_hsl, split_complementary_lab,
+    tetradic_hsl, tetradic_lab, triadic_hsl, triadic_lab,
 };
 
 pub use strategies::{ColorSchemeStrategy, HslColorSchemeStrategy, LabColorSchemeStrategy};
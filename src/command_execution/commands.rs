@@ -170,25 +170,13 @@ fn create_gradient_metadata(args: &GradientArgs) -> HashMap<String, String> {
     metadata
 }
 
-/// Execute hue analysis command
-/// # Errors
-/// Execute hue collection display with range filtering and sorting
-/// Returns error if collection loading, range parsing, or formatting fails
-pub fn execute_hue_analysis(
-    args: &crate::cli::HueArgs,
-    _output_path: Option<&str>,
-) -> Result<ExecutionResult> {
-    use crate::cli::OutputFormat;
-    use crate::cli::Range;
-    use crate::color_parser::collections::ColorCollection;
+/// Load a built-in collection by its short name (css, ralc, rald)
+fn load_collection(
+    name: &str,
+) -> Result<Box<dyn crate::color_parser::collections::ColorCollection>> {
     use crate::color_parser::{CssColorCollection, RalClassicCollection, RalDesignCollection};
-    use crate::color_report_formatting::display;
-    use crate::output_formats::{HueCollectionConfiguration, HueCollectionOutput, HueColorEntry};
-    use palette::Lch;
-    use std::collections::HashMap;
 
-    // Load the specified collection
-    let collection: Box<dyn ColorCollection> = match args.collection.as_str() {
+    Ok(match name {
         "css" => Box::new(CssColorCollection::new().map_err(|e| {
             crate::error::ColorError::ParseError(format!("Failed to load CSS collection: {e}"))
         })?),
@@ -204,11 +192,30 @@ pub fn execute_hue_analysis(
         })?),
         _ => {
             return Err(crate::error::ColorError::ParseError(format!(
-                "Unknown collection: {}",
-                args.collection
+                "Unknown collection: {name}"
             )));
         }
+    })
+}
+
+/// Execute hue analysis command
+/// # Errors
+/// Execute hue collection display with range filtering and sorting
+/// Returns error if collection loading, range parsing, or formatting fails
+pub fn execute_hue_analysis(
+    args: &crate::cli::HueArgs,
+    _output_path: Option<&str>,
+) -> Result<ExecutionResult> {
+    use crate::cli::OutputFormat;
+    use crate::cli::Range;
+    use crate::color_report_formatting::display;
+    use crate::output_formats::{
+        HueCollectionConfiguration, HueCollectionOutput, HueColorEntry, HueDuplicateEntry,
     };
+    use std::collections::HashMap;
+
+    // Load the specified collection
+    let collection = load_collection(&args.collection)?;
 
     // Parse range filters if provided
     let hue_range = if let Some(ref range_str) = args.hue_range {
@@ -230,57 +237,44 @@ pub fn execute_hue_analysis(
     };
 
     // Filter and sort collection by hue
-    let mut filtered_colors: Vec<_> = collection
-        .colors()
-        .iter()
-        .filter_map(|color_entry| {
-            // Convert to LCH for filtering
-            let srgb = palette::Srgb::new(
-                color_entry.color.rgb[0] as f32 / 255.0,
-                color_entry.color.rgb[1] as f32 / 255.0,
-                color_entry.color.rgb[2] as f32 / 255.0,
-            );
-            let lch: Lch = palette::FromColor::from_color(srgb);
-
-            // Apply range filters
-            if let Some(ref hr) = hue_range {
-                if !hr.contains_with_wrap(lch.hue.into_degrees() as f64, 360.0) {
-                    return None;
-                }
-            }
-
-            if let Some(ref lr) = lightness_range {
-                if !lr.contains_linear(lch.l as f64) {
-                    return None;
-                }
-            }
+    let mut filtered_colors = filter_collection_colors(
+        collection.colors(),
+        hue_range.as_ref(),
+        lightness_range.as_ref(),
+        chroma_range.as_ref(),
+        args.min_chroma,
+    );
 
-            if let Some(ref cr) = chroma_range {
-                if !cr.contains_linear(lch.chroma as f64) {
-                    return None;
+    // Sort by the requested keys; remaining ties go to hue, code and name so
+    // the order is the same on every run
+    filtered_colors.sort_by(|a, b| {
+        use crate::cli::CollectionSortKey;
+        use std::cmp::Ordering;
+
+        let compare = |key: &CollectionSortKey| -> Ordering {
+            let score = |score: fn(palette::Lab) -> f64| {
+                let score = |lch: &palette::Lch| score(palette::IntoColor::into_color(*lch));
+                score(&b.1).total_cmp(&score(&a.1))
+            };
+            match key {
+                CollectionSortKey::Hue => {
+                    a.1.hue
+                        .into_positive_degrees()
+                        .total_cmp(&b.1.hue.into_positive_degrees())
                 }
+                CollectionSortKey::Vibrance => score(crate::color_ops::analysis::vibrance),
+                CollectionSortKey::Neutrality => score(crate::color_ops::analysis::neutrality),
+                CollectionSortKey::Name => a.0.metadata.name.cmp(&b.0.metadata.name),
             }
-
-            Some((color_entry, lch))
-        })
-        .collect();
-
-    // Sort by hue (primary), then by code (secondary)
-    filtered_colors.sort_by(|a, b| {
-        let hue_cmp =
-            a.1.hue
-                .into_degrees()
-                .partial_cmp(&b.1.hue.into_degrees())
-                .unwrap_or(std::cmp::Ordering::Equal);
-        if hue_cmp != std::cmp::Ordering::Equal {
-            hue_cmp
-        } else {
-            a.0.metadata
-                .code
-                .as_ref()
-                .unwrap_or(&String::new())
-                .cmp(b.0.metadata.code.as_ref().unwrap_or(&String::new()))
-        }
+        };
+        args.sort
+            .iter()
+            .chain([&CollectionSortKey::Hue])
+            .fold(Ordering::Equal, |ordering, key| {
+                ordering.then_with(|| compare(key))
+            })
+            .then_with(|| a.0.metadata.code.cmp(&b.0.metadata.code))
+            .then_with(|| a.0.metadata.name.cmp(&b.0.metadata.name))
     });
 
     // Create structured output
@@ -290,6 +284,7 @@ pub fn execute_hue_analysis(
         hue_range: args.hue_range.clone(),
         lightness_range: args.lightness_range.clone(),
         chroma_range: args.chroma_range.clone(),
+        min_chroma: args.min_chroma,
     };
 
     let hue_colors: Vec<HueColorEntry> = {
@@ -301,7 +296,8 @@ pub fn execute_hue_analysis(
                 let name = color_entry.metadata.name.clone();
 
                 // Calculate hue and hue shift
-                let hue = lch.hue.into_degrees() as f64;
+                let hue =
+                    crate::color_ops::analysis::hue::normalize_hue(lch.hue.into_degrees() as f64);
                 let hue_shift = previous_hue.map(|prev| {
                     let diff = hue - prev;
                     if diff > 180.0 {
@@ -347,9 +343,28 @@ pub fn execute_hue_analysis(
             .collect()
     };
 
+    // Collection hygiene: near-identical colors published under different names
+    let duplicates: Vec<HueDuplicateEntry> = args
+        .find_duplicates
+        .map(|threshold| {
+            crate::color_parser::UnifiedColorManager::find_duplicates(
+                collection.as_ref(),
+                threshold,
+            )
+            .into_iter()
+            .map(|(first, second, delta_e)| HueDuplicateEntry {
+                first,
+                second,
+                delta_e,
+            })
+            .collect()
+        })
+        .unwrap_or_default();
+
     let hue_output = HueCollectionOutput::new()
         .with_configuration(configuration)
-        .with_colors(hue_colors);
+        .with_colors(hue_colors)
+        .with_duplicates(duplicates);
 
     // Generate YAML output for colored terminal display
     let yaml_output = hue_output.to_yaml().map_err(|e| {
@@ -362,7 +377,7 @@ pub fn execute_hue_analysis(
     // Handle file export if requested
     if let Some(file_path) = &args.output_file {
         let output_format = args.output_format.clone().unwrap_or_default();
-        export_hue_collection_display(&hue_output, output_format, file_path)?;
+        export_hue_collection_display(&hue_output, output_format, file_path, args.dry_run)?;
     }
 
     // Handle visual output if requested
@@ -387,19 +402,26 @@ pub fn execute_hue_analysis(
         // Generate visual output
         let image_generator = crate::image::ImageGenerator::new();
 
-        if args.should_generate_gradient() {
-            println!("Generating horizontal gradient: {}", args.svg_name());
-            image_generator.generate_hue_gradient(args, &analysis_results)?;
+        if args.dry_run {
+            println!("Dry run: would save hue visual to: {}", args.svg_name());
             if args.should_generate_png() {
-                println!("Generated PNG: {}", args.png_name());
+                println!("Dry run: would save PNG to: {}", args.png_name());
+            }
+        } else {
+            if args.should_generate_gradient() {
+                println!("Generating horizontal gradient: {}", args.svg_name());
+                image_generator.generate_hue_gradient(args, &analysis_results)?;
+                if args.should_generate_png() {
+                    println!("Generated PNG: {}", args.png_name());
+                }
             }
-        }
 
-        if args.should_generate_palette() {
-            println!("Generating vertical palette: {}", args.svg_name());
-            image_generator.generate_hue_palette(args, &analysis_results)?;
-            if args.should_generate_png() {
-                println!("Generated PNG: {}", args.png_name());
+            if args.should_generate_palette() {
+                println!("Generating vertical palette: {}", args.svg_name());
+                image_generator.generate_hue_palette(args, &analysis_results)?;
+                if args.should_generate_png() {
+                    println!("Generated PNG: {}", args.png_name());
+                }
             }
         }
     }
@@ -420,6 +442,9 @@ pub fn execute_hue_analysis(
     if let Some(ref cr) = args.chroma_range {
         metadata.insert("chroma_range".to_string(), cr.clone());
     }
+    if let Some(min_chroma) = args.min_chroma {
+        metadata.insert("min_chroma".to_string(), min_chroma.to_string());
+    }
 
     // Return success result without terminal output since we already displayed it
     Ok(ExecutionResult::success_with_metadata(
@@ -428,14 +453,962 @@ pub fn execute_hue_analysis(
     ))
 }
 
+/// Keep collection colors that pass the hue command's range and chroma filters
+///
+/// Returns each kept entry with its LCH value. `min_chroma` drops near-neutral
+/// colors (grays, near-whites, near-blacks) whose hue angle is just noise.
+pub(crate) fn filter_collection_colors<'a>(
+    colors: &'a [crate::color_parser::ColorEntry],
+    hue_range: Option<&crate::cli::Range>,
+    lightness_range: Option<&crate::cli::Range>,
+    chroma_range: Option<&crate::cli::Range>,
+    min_chroma: Option<f64>,
+) -> Vec<(&'a crate::color_parser::ColorEntry, palette::Lch)> {
+    use palette::Lch;
+
+    colors
+        .iter()
+        .filter_map(|color_entry| {
+            // Convert to LCH for filtering
+            let srgb = palette::Srgb::new(
+                color_entry.color.rgb[0] as f32 / 255.0,
+                color_entry.color.rgb[1] as f32 / 255.0,
+                color_entry.color.rgb[2] as f32 / 255.0,
+            );
+            let lch: Lch = palette::FromColor::from_color(srgb);
+
+            // Exclude achromatic colors before any hue-based filtering
+            if let Some(min) = min_chroma
+                && f64::from(lch.chroma) < min
+            {
+                return None;
+            }
+
+            // Apply range filters
+            if let Some(hr) = hue_range {
+                if !hr.contains_with_wrap(lch.hue.into_degrees() as f64, 360.0) {
+                    return None;
+                }
+            }
+
+            if let Some(lr) = lightness_range {
+                if !lr.contains_linear(lch.l as f64) {
+                    return None;
+                }
+            }
+
+            if let Some(cr) = chroma_range {
+                if !cr.contains_linear(lch.chroma as f64) {
+                    return None;
+                }
+            }
+
+            Some((color_entry, lch))
+        })
+        .collect()
+}
+
+/// Execute tints-and-shades ramp generation
+///
+/// # Errors
+/// Returns an error if the base color cannot be parsed, serialization fails,
+/// or the output file cannot be written.
+pub fn execute_ramp(args: &crate::cli::RampArgs) -> Result<ExecutionResult> {
+    use crate::cli::OutputFormat;
+    use crate::color_report_formatting::display;
+    use crate::output_formats::{ProgramMetadata, RampConfiguration, RampEntry, RampOutput};
+    use palette::{IntoColor, Lch, Srgb};
+
+    let base_lab = crate::color::parse_color_input(&args.color)?;
+    let base: Srgb = base_lab.into_color();
+    let colors = if args.preserve_hue {
+        crate::color_ops::mixing::tints_and_shades_preserve_hue(base, args.tints, args.shades)
+    } else {
+        crate::color_ops::mixing::tints_and_shades(base, args.tints, args.shades)
+    };
+
+    let ramp = colors
+        .iter()
+        .enumerate()
+        .map(|(index, color)| {
+            let name = match index.cmp(&args.tints) {
+                std::cmp::Ordering::Less => format!("tint-{}", args.tints - index),
+                std::cmp::Ordering::Equal => "base".to_string(),
+                std::cmp::Ordering::Greater => format!("shade-{}", index - args.tints),
+            };
+            let lch: Lch = (*color).into_color();
+            RampEntry {
+                name,
+                hex: crate::color_ops::conversion::srgb_to_hex(*color),
+                lch: format!(
+                    "lch({:.1}, {:.1}, {:.1})",
+                    lch.l,
+                    lch.chroma,
+                    lch.hue.into_positive_degrees()
+                ),
+            }
+        })
+        .collect();
+
+    let ramp_output = RampOutput {
+        metadata: ProgramMetadata::without_distance(),
+        configuration: RampConfiguration {
+            base_color: args.color.clone(),
+            tints: args.tints,
+            shades: args.shades,
+            preserve_hue: args.preserve_hue,
+        },
+        ramp,
+    };
+
+    let format = args.output_format.clone().unwrap_or_default();
+    let content = match format {
+        OutputFormat::Yaml => ramp_output.to_yaml().map_err(|e| {
+            crate::error::ColorError::ParseError(format!("YAML serialization failed: {e}"))
+        })?,
+        OutputFormat::Toml => ramp_output.to_toml().map_err(|e| {
+            crate::error::ColorError::ParseError(format!("TOML serialization failed: {e}"))
+        })?,
+        OutputFormat::Env => ramp_output.to_env().map_err(|e| {
+            crate::error::ColorError::ParseError(format!("env serialization failed: {e}"))
+        })?,
+        OutputFormat::Rust | OutputFormat::Markdown | OutputFormat::CssVars => {
+            return Err(
+                format.unsupported_by("the ramp command", crate::cli::RampArgs::OUTPUT_FORMATS)
+            );
+        }
+    };
+
+    display::display_terminal_output(&content, &format);
+
+    if let Some(file_path) = &args.output_file {
+        write_output_file(file_path, &content, args.dry_run)?;
+    }
+
+    Ok(ExecutionResult::success(String::new()))
+}
+
+/// Compute gradients from a base color to each color of a harmony scheme
+///
+/// The scheme colors come from the color scheme calculator with the requested
+/// strategy; each gradient uses the standard LAB gradient calculation.
+///
+/// # Errors
+/// Returns an error if the base color cannot be parsed or the scheme
+/// calculation fails
+pub fn harmony_gradients(
+    args: &crate::cli::HarmonyGradientArgs,
+) -> Result<crate::output_formats::HarmonyGradientOutput> {
+    use crate::color_schemes::ColorSchemeCalculator;
+    use crate::format_utils::FormatUtils;
+    use crate::gradient::GradientCalculator;
+    use crate::output_formats::{
+        HarmonyGradient, HarmonyGradientConfiguration, HarmonyGradientOutput, HarmonyGradientStop,
+        ProgramMetadata,
+    };
+
+    let base = crate::color::parse_color_input(&args.base)?;
+    let schemes = ColorSchemeCalculator::default().calculate(base)?;
+    let prefix = format!("{}_{}", args.scheme_strategy, args.relationship.name());
+
+    let gradients = schemes
+        .iter_relationships()
+        .filter(|(name, _)| {
+            name.strip_prefix(prefix.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('_'))
+        })
+        .map(|(name, target)| HarmonyGradient {
+            target: name[args.scheme_strategy.len() + 1..].to_string(),
+            start: FormatUtils::lab_to_hex(base),
+            end: FormatUtils::lab_to_hex(target),
+            stops: GradientCalculator::calculate_unified_gradient(
+                base,
+                target,
+                0,
+                100,
+                args.ease_in,
+                args.ease_out,
+                args.stops,
+                false,
+            )
+            .into_iter()
+            .map(|stop| HarmonyGradientStop {
+                position: stop.position,
+                hex: FormatUtils::lab_to_hex(stop.lab_color),
+            })
+            .collect(),
+        })
+        .collect();
+
+    Ok(HarmonyGradientOutput {
+        metadata: ProgramMetadata::new(Some(
+            crate::color_distance_strategies::DistanceAlgorithm::DeltaE2000.name(),
+        )),
+        configuration: HarmonyGradientConfiguration {
+            base_color: args.base.clone(),
+            relationship: args.relationship.name().to_string(),
+            scheme_strategy: args.scheme_strategy.clone(),
+            ease_in: args.ease_in,
+            ease_out: args.ease_out,
+            stops: args.stops,
+        },
+        gradients,
+    })
+}
+
+/// Execute harmony gradient generation: display and optionally save the gradients
+///
+/// # Errors
+/// Returns an error if the gradients cannot be computed, the output format is
+/// not YAML or TOML, serialization fails, or the file cannot be written
+pub fn execute_harmony_gradient(args: &crate::cli::HarmonyGradientArgs) -> Result<ExecutionResult> {
+    use crate::cli::OutputFormat;
+    use crate::color_report_formatting::display;
+
+    let output = harmony_gradients(args)?;
+    let format = args.output_format.clone().unwrap_or_default();
+    let content = match format {
+        OutputFormat::Yaml => output
+            .to_yaml()
+            .map_err(|e| ColorError::ParseError(format!("YAML serialization failed: {e}")))?,
+        OutputFormat::Toml => output
+            .to_toml()
+            .map_err(|e| ColorError::ParseError(format!("TOML serialization failed: {e}")))?,
+        format @ (OutputFormat::Env
+        | OutputFormat::Rust
+        | OutputFormat::Markdown
+        | OutputFormat::CssVars) => {
+            return Err(
+                format.unsupported_by("the harmony-gradient command", OutputFormat::STRUCTURED)
+            );
+        }
+    };
+
+    display::display_terminal_output(&content, &format);
+
+    if let Some(file_path) = &args.output_file {
+        write_output_file(file_path, &content, args.dry_run)?;
+    }
+
+    Ok(ExecutionResult::success(String::new()))
+}
+
+/// Generate the seeded random palette `args` describes
+///
+/// Without `--seed` a seed is taken from the clock; the output reports the
+/// seed used either way, so any palette can be reproduced.
+///
+/// # Errors
+/// Returns an error if the lightness range is invalid or no palette fits the
+/// constraints.
+pub fn random_palette(
+    args: &crate::cli::RandomArgs,
+) -> Result<crate::output_formats::RandomPaletteOutput> {
+    use crate::color_ops::random::RandomPaletteConstraints;
+    use crate::output_formats::{
+        ProgramMetadata, RandomPaletteColor, RandomPaletteConfiguration, RandomPaletteOutput,
+    };
+    use palette::{Lch, Srgb};
+
+    let seed = args.seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+    });
+    let lightness = args.lightness_band()?;
+    let colors = crate::color_ops::random::random_palette(
+        seed,
+        RandomPaletteConstraints {
+            count: args.count,
+            min_chroma: args.min_chroma,
+            lightness,
+            min_delta_e: args.min_delta_e,
+        },
+    )?;
+
+    let parser = crate::color_parser::ColorParser::new();
+    let colors = colors
+        .into_iter()
+        .map(|lab| {
+            let srgb: Srgb = lab.into_color();
+            let lch: Lch = lab.into_color();
+            RandomPaletteColor {
+                name: parser.get_color_name(crate::color_ops::conversion::srgb_to_rgb_tuple(srgb)),
+                hex: crate::color_ops::conversion::srgb_to_hex(srgb),
+                lch: format!(
+                    "lch({:.1}, {:.1}, {:.1})",
+                    lch.l,
+                    lch.chroma,
+                    lch.hue.into_positive_degrees()
+                ),
+            }
+        })
+        .collect();
+
+    Ok(RandomPaletteOutput {
+        metadata: ProgramMetadata::new(Some(
+            crate::color_distance_strategies::DistanceAlgorithm::DeltaE2000.name(),
+        )),
+        configuration: RandomPaletteConfiguration {
+            count: args.count,
+            seed,
+            min_chroma: args.min_chroma,
+            lightness_range: format!("[{}...{}]", lightness[0], lightness[1]),
+            min_delta_e: args.min_delta_e,
+        },
+        colors,
+    })
+}
+
+/// Locate the color `args` describes on its named colormap
+///
+/// # Errors
+/// Returns an error if the color cannot be parsed.
+pub fn scale_position(
+    args: &crate::cli::ScalePositionArgs,
+) -> Result<crate::output_formats::ScalePositionOutput> {
+    use crate::color_ops::conversion::srgb_to_hex;
+    use crate::output_formats::{ProgramMetadata, ScalePositionOutput};
+    use palette::Srgb;
+
+    let lab = crate::color::parse_color_input(&args.color)?;
+    let nearest = crate::color_ops::colormaps::scale_position(args.scale, lab);
+    let srgb: Srgb = lab.into_color();
+
+    Ok(ScalePositionOutput {
+        metadata: ProgramMetadata::new(Some(
+            crate::color_distance_strategies::DistanceAlgorithm::DeltaE2000.name(),
+        )),
+        input: args.color.clone(),
+        hex: srgb_to_hex(srgb),
+        scale: args.scale.name().to_string(),
+        position: nearest.position,
+        scale_color: srgb_to_hex(nearest.color),
+        delta_e: nearest.delta_e,
+    })
+}
+
+/// Execute a colormap position lookup
+///
+/// # Errors
+/// Returns an error if the color cannot be parsed, serialization fails, or the
+/// output file cannot be written.
+pub fn execute_scale_position(args: &crate::cli::ScalePositionArgs) -> Result<ExecutionResult> {
+    use crate::cli::OutputFormat;
+    use crate::color_report_formatting::display;
+
+    let output = scale_position(args)?;
+    let format = args.output_format.clone().unwrap_or_default();
+    let content = match format {
+        OutputFormat::Yaml => output
+            .to_yaml()
+            .map_err(|e| ColorError::ParseError(format!("YAML serialization failed: {e}")))?,
+        OutputFormat::Toml => output
+            .to_toml()
+            .map_err(|e| ColorError::ParseError(format!("TOML serialization failed: {e}")))?,
+        format @ (OutputFormat::Env
+        | OutputFormat::Rust
+        | OutputFormat::Markdown
+        | OutputFormat::CssVars) => {
+            return Err(
+                format.unsupported_by("the scale-position command", OutputFormat::STRUCTURED)
+            );
+        }
+    };
+
+    display::display_terminal_output(&content, &format);
+
+    if let Some(file_path) = &args.output_file {
+        write_output_file(file_path, &content, args.dry_run)?;
+    }
+
+    Ok(ExecutionResult::success(String::new()))
+}
+
+/// Convert every color `args` lists to the target format, in input order
+///
+/// Argument colors come first, then the colors of `--input-file` split
+/// according to `--stdin-format`; each is parsed and formatted exactly as
+/// `color --to` does.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or any input is not a valid
+/// color.
+pub fn convert_colors(
+    args: &crate::cli::ConvertArgs,
+) -> Result<crate::output_formats::ConversionTableOutput> {
+    use crate::format_utils::FormatUtils;
+    use crate::output_formats::{
+        ConversionRow, ConversionTableOutput, NearestName, ProgramMetadata,
+    };
+
+    let mut inputs: Vec<(String, String)> = args
+        .colors
+        .iter()
+        .map(|color| (color.clone(), color.clone()))
+        .collect();
+    if let Some(path) = &args.input_file {
+        let content = read_color_list(path)?;
+        inputs.extend(
+            args.stdin_format
+                .tokenize(&content)
+                .into_iter()
+                .map(|(line_number, color)| (format!("{path}: line {line_number}"), color)),
+        );
+    }
+
+    let parsed = inputs
+        .into_iter()
+        .map(|(source, color)| {
+            crate::color::parse_color_as(color.trim(), args.lab_variant)
+                .map(|(lab, _)| (color.trim().to_string(), lab))
+                .map_err(|e| ColorError::ParseError(format!("{source}: {e}")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut nearest = if args.names {
+        let rgbs: Vec<[u8; 3]> = parsed
+            .iter()
+            .map(|(_, lab)| {
+                let (r, g, b) = crate::color_report_formatting::lab_to_rgb(*lab);
+                [r, g, b]
+            })
+            .collect();
+        let manager = crate::color_parser::unified_manager::UnifiedColorManager::new();
+        #[cfg(feature = "parallel")]
+        let matches = if args.parallel {
+            manager.find_closest_batch_parallel(&rgbs, 1)
+        } else {
+            manager.find_closest_batch(&rgbs, 1)
+        };
+        #[cfg(not(feature = "parallel"))]
+        let matches = manager.find_closest_batch(&rgbs, 1);
+        matches
+            .into_iter()
+            .map(|collections| {
+                collections
+                    .into_iter()
+                    .filter_map(|(collection, matches)| {
+                        matches.into_iter().next().map(|m| NearestName {
+                            collection,
+                            code: m.entry.metadata.code,
+                            name: m.entry.metadata.name,
+                            distance: m.distance,
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    } else {
+        Vec::new()
+    }
+    .into_iter();
+
+    let conversions = parsed
+        .into_iter()
+        .map(|(input, lab)| ConversionRow {
+            output: FormatUtils::format_color_as(lab, &args.to, args.lab_variant),
+            input,
+            nearest: nearest.next().unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(ConversionTableOutput {
+        metadata: ProgramMetadata::without_distance(),
+        to: format!("{:?}", args.to).to_lowercase(),
+        conversions,
+    })
+}
+
+/// Execute batch conversion of a color list
+///
+/// # Errors
+/// Returns an error if an input cannot be converted, serialization fails, or
+/// the output file cannot be written.
+pub fn execute_convert(args: &crate::cli::ConvertArgs) -> Result<ExecutionResult> {
+    use crate::cli::OutputFormat;
+    use crate::color_report_formatting::display;
+
+    let output = convert_colors(args)?;
+    let format = args.output_format.clone().unwrap_or_default();
+    let content = match format {
+        OutputFormat::Yaml => output
+            .to_yaml()
+            .map_err(|e| ColorError::ParseError(format!("YAML serialization failed: {e}")))?,
+        OutputFormat::Toml => output
+            .to_toml()
+            .map_err(|e| ColorError::ParseError(format!("TOML serialization failed: {e}")))?,
+        OutputFormat::Markdown => output.to_markdown(),
+        format @ (OutputFormat::Env | OutputFormat::Rust | OutputFormat::CssVars) => {
+            return Err(format.unsupported_by(
+                "the convert command",
+                crate::cli::ConvertArgs::OUTPUT_FORMATS,
+            ));
+        }
+    };
+
+    display::display_terminal_output(&content, &format);
+
+    if let Some(file_path) = &args.output_file {
+        write_output_file(file_path, &content, args.dry_run)?;
+    }
+
+    Ok(ExecutionResult::success(String::new()))
+}
+
+/// Execute seeded random palette generation
+///
+/// # Errors
+/// Returns an error if no palette fits the constraints, serialization fails,
+/// or the output file cannot be written.
+pub fn execute_random(args: &crate::cli::RandomArgs) -> Result<ExecutionResult> {
+    use crate::cli::OutputFormat;
+    use crate::color_report_formatting::display;
+
+    let output = random_palette(args)?;
+    let format = args.output_format.clone().unwrap_or_default();
+    let content = match format {
+        OutputFormat::Yaml => output
+            .to_yaml()
+            .map_err(|e| ColorError::ParseError(format!("YAML serialization failed: {e}")))?,
+        OutputFormat::Toml => output
+            .to_toml()
+            .map_err(|e| ColorError::ParseError(format!("TOML serialization failed: {e}")))?,
+        format @ (OutputFormat::Env
+        | OutputFormat::Rust
+        | OutputFormat::Markdown
+        | OutputFormat::CssVars) => {
+            return Err(format.unsupported_by("the random command", OutputFormat::STRUCTURED));
+        }
+    };
+
+    display::display_terminal_output(&content, &format);
+
+    if let Some(file_path) = &args.output_file {
+        write_output_file(file_path, &content, args.dry_run)?;
+    }
+
+    Ok(ExecutionResult::success(String::new()))
+}
+
+/// Execute plain-English color description
+///
+/// # Errors
+/// Returns an error if the color cannot be parsed or JSON serialization fails.
+pub fn execute_describe(args: &crate::cli::DescribeArgs) -> Result<ExecutionResult> {
+    use crate::cli::DescribeFormat;
+    use palette::Srgb;
+
+    let lab = crate::color::parse_color_input(&args.color)?;
+    let srgb: Srgb = lab.into_color();
+    let description = crate::color_ops::analysis::describe_color(srgb);
+
+    let output = match args.format {
+        DescribeFormat::Phrase => description.description,
+        DescribeFormat::Json => serde_json::to_string_pretty(&description)
+            .map_err(|e| ColorError::ParseError(format!("JSON serialization failed: {e}")))?,
+    };
+
+    Ok(ExecutionResult::success(output))
+}
+
+/// Build the export listing for every entry of a collection
+#[must_use]
+pub fn collection_export(
+    collection: &dyn crate::color_parser::collections::ColorCollection,
+) -> crate::output_formats::CollectionExportOutput {
+    use crate::output_formats::{CollectionExportEntry, CollectionExportOutput};
+
+    let colors = collection
+        .colors()
+        .iter()
+        .map(|entry| {
+            let [r, g, b] = entry.color.rgb;
+            CollectionExportEntry {
+                name: entry.metadata.name.clone(),
+                hex: format!("#{r:02X}{g:02X}{b:02X}"),
+                rgb: entry.color.rgb,
+                code: entry.metadata.code.clone(),
+            }
+        })
+        .collect();
+
+    CollectionExportOutput {
+        collection: collection.name().to_string(),
+        colors,
+    }
+}
+
+/// Execute export-collection command: write every collection entry to a file
+/// # Errors
+/// Returns error if the arguments are invalid, the collection cannot be loaded,
+/// or serialization or writing the file fails
+pub fn execute_export_collection(
+    args: &crate::cli::ExportCollectionArgs,
+) -> Result<ExecutionResult> {
+    use crate::cli::ExportFormat;
+
+    args.validate()?;
+    let collection = load_collection(&args.collection)?;
+    let export = collection_export(collection.as_ref());
+
+    let content = match args.export_format()? {
+        ExportFormat::Json => export
+            .to_json()
+            .map_err(|e| ColorError::ParseError(format!("JSON serialization failed: {e}")))?,
+        ExportFormat::Yaml => export
+            .to_yaml()
+            .map_err(|e| ColorError::ParseError(format!("YAML serialization failed: {e}")))?,
+    };
+    if args.dry_run {
+        return Ok(ExecutionResult::success(format!(
+            "Dry run: would export {} colors from {} ({} bytes) to: {}",
+            export.colors.len(),
+            export.collection,
+            content.len(),
+            args.output
+        )));
+    }
+    std::fs::write(&args.output, content).map_err(ColorError::from)?;
+
+    Ok(ExecutionResult::success(format!(
+        "Exported {} colors from {} to {}",
+        export.colors.len(),
+        export.collection,
+        args.output
+    )))
+}
+
+/// Classify parsed palette colors against a gamut
+///
+/// `colors` pairs each input string with its parsed LAB value.
+#[must_use]
+pub fn gamut_report(
+    colors: &[(String, palette::Lab)],
+    gamut: crate::color_ops::Gamut,
+) -> crate::output_formats::GamutReportOutput {
+    use crate::output_formats::{GamutReportEntry, GamutReportOutput, ProgramMetadata};
+
+    let entries: Vec<GamutReportEntry> = colors
+        .iter()
+        .map(|(input, lab)| GamutReportEntry {
+            input: input.clone(),
+            lab: crate::format_utils::FormatUtils::lab_to_lab(*lab),
+            in_gamut: gamut.contains(*lab),
+        })
+        .collect();
+
+    let out_of_gamut = entries.iter().filter(|entry| !entry.in_gamut).count();
+    let percent_out_of_gamut = if entries.is_empty() {
+        0.0
+    } else {
+        out_of_gamut as f64 / entries.len() as f64 * 100.0
+    };
+
+    GamutReportOutput {
+        metadata: ProgramMetadata::without_distance(),
+        gamut: gamut.name().to_string(),
+        total_colors: entries.len(),
+        out_of_gamut,
+        percent_out_of_gamut,
+        colors: entries,
+    }
+}
+
+/// Execute gamut-report command: classify every color of a palette file
+///
+/// Blank lines are skipped; every other line must hold one color.
+///
+/// # Errors
+/// Returns error if the file cannot be read, a line is not a valid color, or
+/// serialization fails
+pub fn execute_gamut_report(args: &crate::cli::GamutReportArgs) -> Result<ExecutionResult> {
+    use crate::cli::OutputFormat;
+
+    let content = read_color_list(&args.input_file)?;
+    let colors = args
+        .stdin_format
+        .tokenize(&content)
+        .into_iter()
+        .map(|(line_number, color)| {
+            crate::color::parse_color_input(&color)
+                .map(|lab| (color, lab))
+                .map_err(|e| {
+                    ColorError::ParseError(format!("{}: line {line_number}: {e}", args.input_file))
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let report = gamut_report(&colors, args.gamut);
+    let output = match args.output_format.clone().unwrap_or_default() {
+        OutputFormat::Yaml => report
+            .to_yaml()
+            .map_err(|e| ColorError::ParseError(format!("YAML serialization failed: {e}")))?,
+        OutputFormat::Toml => report
+            .to_toml()
+            .map_err(|e| ColorError::ParseError(format!("TOML serialization failed: {e}")))?,
+        format @ (OutputFormat::Env
+        | OutputFormat::Rust
+        | OutputFormat::Markdown
+        | OutputFormat::CssVars) => {
+            return Err(format.unsupported_by("the gamut-report command", OutputFormat::STRUCTURED));
+        }
+    };
+
+    Ok(ExecutionResult::success(output))
+}
+
+/// Execute palette command: render a palette file as a contact-sheet PNG
+///
+/// # Errors
+/// Returns error if the arguments are invalid, the file cannot be read, a line
+/// is not a valid color, or the PNG cannot be written
+pub fn execute_palette(args: &crate::cli::PaletteArgs) -> Result<ExecutionResult> {
+    use crate::image::ImageGenerator;
+
+    args.validate()?;
+    let content = read_color_list(&args.input_file)?;
+    let colors = args
+        .stdin_format
+        .tokenize(&content)
+        .into_iter()
+        .map(|(line_number, color)| {
+            crate::color::parse_color_input(&color)
+                .map(|lab| (color, lab))
+                .map_err(|e| {
+                    ColorError::ParseError(format!("{}: line {line_number}: {e}", args.input_file))
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if colors.is_empty() {
+        return Err(ColorError::InvalidArguments(format!(
+            "{} contains no colors",
+            args.input_file
+        )));
+    }
+
+    let columns = colors.len().min(args.columns as usize);
+    let rows = colors.len().div_ceil(columns);
+    if args.dry_run {
+        let (width, height) = ImageGenerator::contact_sheet_size(colors.len(), args);
+        return Ok(ExecutionResult::success(format!(
+            "Dry run: would save contact sheet with {} colors ({columns}x{rows}, {width}x{height}) to: {}",
+            colors.len(),
+            args.sheet
+        )));
+    }
+    ImageGenerator::new().generate_contact_sheet(args, &colors)?;
+    Ok(ExecutionResult::success(format!(
+        "Contact sheet with {} colors ({columns}x{rows}) saved to: {}",
+        colors.len(),
+        args.sheet
+    )))
+}
+
+/// Rewrite a single color in `notation`
+///
+/// # Errors
+/// Returns error if `input` is not a valid color, or is translucent, since no
+/// canonical notation carries alpha
+pub fn canonicalize_color(input: &str, notation: crate::cli::Notation) -> Result<String> {
+    use crate::cli::Notation;
+    use crate::color_report_formatting::{lab_to_hsl_tuple, lab_to_rgb};
+    use crate::precision_utils::PrecisionUtils;
+    use clap::ValueEnum;
+
+    let lab = crate::color::parse_color_input(input.trim())?;
+    crate::color::reject_alpha(
+        input,
+        &format!(
+            "the {} notation",
+            notation
+                .to_possible_value()
+                .map_or_else(String::new, |value| value.get_name().to_string())
+        ),
+    )?;
+    let (r, g, b) = lab_to_rgb(lab);
+    Ok(match notation {
+        Notation::Hex6 => format!("#{r:02X}{g:02X}{b:02X}"),
+        Notation::HexLower => format!("#{r:02x}{g:02x}{b:02x}"),
+        Notation::Rgb => crate::utils::Utils::rgb_to_string(r, g, b),
+        Notation::Hsl => {
+            let (h, s, l) = lab_to_hsl_tuple(lab);
+            let round = |value: f64| PrecisionUtils::format_f64((value * 10.0).round() / 10.0);
+            format!(
+                "hsl({}, {}%, {}%)",
+                round(h % 360.0),
+                round(s * 100.0),
+                round(l * 100.0)
+            )
+        }
+    })
+}
+
+/// Read a color list from `path`, or from stdin when `path` is `-`
+fn read_color_list(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut content = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut content)?;
+        Ok(content)
+    } else {
+        Ok(std::fs::read_to_string(path)?)
+    }
+}
+
+/// Write `content` to `path`, or with `dry_run` only report what would be written
+fn write_output_file(path: &str, content: &str, dry_run: bool) -> Result<()> {
+    if dry_run {
+        println!("Dry run: would save {} bytes to: {path}", content.len());
+        Ok(())
+    } else {
+        std::fs::write(path, content).map_err(ColorError::from)
+    }
+}
+
+/// Execute canonicalize command: rewrite every color, one per output line
+///
+/// Argument colors come first, then the colors of `--input-file` in order,
+/// split according to `--stdin-format`; empty items are skipped.
+///
+/// # Errors
+/// Returns error if the file cannot be read or any input is not a valid color
+pub fn execute_canonicalize(args: &crate::cli::CanonicalizeArgs) -> Result<ExecutionResult> {
+    let mut lines = args
+        .colors
+        .iter()
+        .map(|color| {
+            canonicalize_color(color, args.notation)
+                .map_err(|e| ColorError::ParseError(format!("{color}: {e}")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if let Some(path) = &args.input_file {
+        let content = read_color_list(path)?;
+        for (line_number, color) in args.stdin_format.tokenize(&content) {
+            lines.push(
+                canonicalize_color(&color, args.notation).map_err(|e| {
+                    ColorError::ParseError(format!("{path}: line {line_number}: {e}"))
+                })?,
+            );
+        }
+    }
+
+    Ok(ExecutionResult::success(lines.join("\n")))
+}
+
+/// Execute equal command: compare two colors within a tolerance
+///
+/// The result's `equal` metadata entry is `true` or `false`; the output is a
+/// one-line verdict with the measured distance.
+///
+/// # Errors
+/// Returns error if a color cannot be parsed, the distance method is unknown,
+/// or the tolerance is negative
+pub fn execute_equal(args: &crate::cli::EqualArgs) -> Result<ExecutionResult> {
+    use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+    use crate::precision_utils::PrecisionUtils;
+
+    if args.tolerance.is_nan() || args.tolerance < 0.0 {
+        return Err(ColorError::InvalidArguments(format!(
+            "Tolerance must be a non-negative number, got {}",
+            args.tolerance
+        )));
+    }
+    let algorithm: DistanceAlgorithm = args
+        .distance_method
+        .parse()
+        .map_err(|e| ColorError::InvalidArguments(format!("{e}")))?;
+
+    let first = crate::color::parse_color_input(&args.first)?;
+    let second = crate::color::parse_color_input(&args.second)?;
+    let equal = crate::color_ops::distance::colors_equal(first, second, args.tolerance, algorithm);
+    let distance = calculate_distance(algorithm, first, second);
+
+    let output = format!(
+        "{}: {} {} {} {}",
+        if equal { "equal" } else { "different" },
+        algorithm.name(),
+        PrecisionUtils::format_f64(distance),
+        if equal { "<=" } else { ">" },
+        PrecisionUtils::format_f64(args.tolerance)
+    );
+    let metadata = std::collections::HashMap::from([
+        ("equal".to_string(), equal.to_string()),
+        ("distance".to_string(), distance.to_string()),
+    ]);
+
+    Ok(ExecutionResult::success_with_metadata(output, metadata))
+}
+
+/// Execute mix command: print the mixed color as `#RRGGBB`
+///
+/// With `perceptual_midpoint` the colors are mixed at the point of the LAB
+/// path with equal ΔE2000 to both, otherwise `amount` of the way from the
+/// first to the second in `color_space`.
+///
+/// # Errors
+/// Returns error if the amount is outside 0.0-1.0 or a color cannot be parsed
+pub fn execute_mix(args: &crate::cli::MixArgs) -> Result<ExecutionResult> {
+    use crate::color_ops::mixing::{mix_toward, perceptual_midpoint};
+    use crate::format_utils::FormatUtils;
+    use palette::Srgb;
+
+    args.validate()?;
+    let first = crate::color::parse_color_input(&args.first)?;
+    let second = crate::color::parse_color_input(&args.second)?;
+    let mixed = if args.perceptual_midpoint {
+        perceptual_midpoint(first, second)
+    } else {
+        let first: Srgb = first.into_color();
+        let second: Srgb = second.into_color();
+        mix_toward(first, second, args.amount, args.color_space).into_color()
+    };
+
+    Ok(ExecutionResult::success(FormatUtils::lab_to_hex(mixed)))
+}
+
+/// Execute search command: list collection entries under a code prefix as YAML
+///
+/// # Errors
+/// Returns error if the collection is unknown, the collections cannot be
+/// loaded, or serialization fails
+pub fn execute_search(args: &crate::cli::SearchArgs) -> Result<ExecutionResult> {
+    use crate::color_parser::unified_manager::UnifiedColorManager;
+    use crate::output_formats::{CollectionExportEntry, CollectionExportOutput};
+
+    let collection = load_collection(&args.collection)?;
+    let manager = UnifiedColorManager::new();
+
+    let colors = manager
+        .find_by_code_prefix(&args.collection, &args.code)
+        .into_iter()
+        .map(|m| {
+            let [r, g, b] = m.entry.color.rgb;
+            CollectionExportEntry {
+                name: m.entry.metadata.name,
+                hex: format!("#{r:02X}{g:02X}{b:02X}"),
+                rgb: m.entry.color.rgb,
+                code: m.entry.metadata.code,
+            }
+        })
+        .collect();
+    let output = CollectionExportOutput {
+        collection: collection.name().to_string(),
+        colors,
+    }
+    .to_yaml()
+    .map_err(|e| ColorError::ParseError(format!("YAML serialization failed: {e}")))?;
+
+    Ok(ExecutionResult::success(output))
+}
+
 /// Export hue collection display to file in specified format
 fn export_hue_collection_display(
     hue_output: &crate::output_formats::HueCollectionOutput,
     format: crate::cli::OutputFormat,
     file_path: &str,
+    dry_run: bool,
 ) -> crate::error::Result<()> {
-    use std::fs;
-
     let content = match format {
         crate::cli::OutputFormat::Yaml => hue_output.to_yaml().map_err(|e| {
             crate::error::ColorError::ParseError(format!("YAML serialization failed: {e}"))
@@ -443,9 +1416,29 @@ fn export_hue_collection_display(
         crate::cli::OutputFormat::Toml => hue_output.to_toml().map_err(|e| {
             crate::error::ColorError::ParseError(format!("TOML serialization failed: {e}"))
         })?,
+        crate::cli::OutputFormat::Env
+        | crate::cli::OutputFormat::Rust
+        | crate::cli::OutputFormat::Markdown
+        | crate::cli::OutputFormat::CssVars => {
+            return Err(
+                format.unsupported_by("the hue command", crate::cli::OutputFormat::STRUCTURED)
+            );
+        }
     };
 
-    fs::write(file_path, content).map_err(crate::error::ColorError::from)?;
+    write_output_file(file_path, &content, dry_run)
+}
+
+/// Execute info command: list the supported formats, one per line
+///
+/// # Errors
+/// Currently infallible; returns `Result` like the other commands
+pub fn execute_info(args: &crate::cli::InfoArgs) -> Result<ExecutionResult> {
+    let output = match args.list {
+        crate::cli::InfoTopic::Formats => {
+            crate::color_parser::ColorParser::supported_formats().join("\n")
+        }
+    };
 
-    Ok(())
+    Ok(ExecutionResult::success(output))
 }
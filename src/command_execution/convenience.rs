@@ -13,32 +13,21 @@ use crate::error::Result;
 
 /// Create gradient generation command
 #[must_use]
-pub const fn create_gradient_command(
+pub fn create_gradient_command(
     start_color: String,
     end_color: String,
     stops: usize,
 ) -> CommandType {
-    use crate::cli::GradientArgs;
+    use crate::cli::{GradientArgs, ImageWidth};
 
     // Create GradientArgs manually with actual fields
     let args = GradientArgs {
         start_color,
         end_color,
-        start_position: 0,
-        end_position: 100,
-        ease_in: 0.65,
-        ease_out: 0.35,
-        svg: None,
-        png: None,
-        no_legend: false,
-        width: 1000,
-        step: None,
+        width: ImageWidth::Pixels(1000),
         stops,
-        stops_simple: false,
-        output_format: None,
-        output_file: None,
-        func_filter: None,
         vectorized_text: false,
+        ..Default::default()
     };
 
     CommandType::GenerateGradient {
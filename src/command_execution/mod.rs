@@ -21,8 +21,12 @@ pub use execution::{
 };
 
 pub use commands::{
-    execute_analyze_color, execute_convert_color, execute_find_closest_color,
-    execute_generate_gradient, execute_hue_analysis,
+    canonicalize_color, collection_export, convert_colors, execute_analyze_color,
+    execute_canonicalize, execute_convert, execute_convert_color, execute_describe, execute_equal,
+    execute_export_collection, execute_find_closest_color, execute_gamut_report,
+    execute_generate_gradient, execute_harmony_gradient, execute_hue_analysis, execute_info,
+    execute_mix, execute_palette, execute_ramp, execute_random, execute_scale_position,
+    execute_search, gamut_report, random_palette, scale_position,
 };
 
 pub use convenience::{
@@ -92,21 +96,9 @@ mod tests {
         let args = crate::cli::GradientArgs {
             start_color: "".to_string(),
             end_color: "blue".to_string(),
-            start_position: 0,
-            end_position: 100,
-            ease_in: 0.65,
-            ease_out: 0.35,
-            svg: None,
-            png: None,
-            no_legend: false,
-            width: 1000,
-            step: None,
-            stops: 5,
-            stops_simple: false,
-            output_format: None,
-            output_file: None,
-            func_filter: None,
+            width: crate::cli::ImageWidth::Pixels(1000),
             vectorized_text: false,
+            ..Default::default()
         };
 
         let cmd = CommandType::GenerateGradient {
@@ -189,4 +181,469 @@ mod tests {
         assert!(result2.is_ok());
         assert!(result3.is_ok());
     }
+
+    #[test]
+    fn test_min_chroma_excludes_near_white() {
+        use crate::color_parser::{ColorEntry, UniversalColor};
+
+        let colors = vec![
+            ColorEntry::new(
+                UniversalColor::from_rgb([250, 250, 248]),
+                "Near White".into(),
+            ),
+            ColorEntry::new(UniversalColor::from_rgb([220, 40, 40]), "Red".into()),
+        ];
+
+        let unfiltered = commands::filter_collection_colors(&colors, None, None, None, None);
+        assert_eq!(unfiltered.len(), 2);
+
+        let chromatic = commands::filter_collection_colors(&colors, None, None, None, Some(5.0));
+        assert_eq!(chromatic.len(), 1);
+        assert_eq!(chromatic[0].0.metadata.name, "Red");
+    }
+
+    #[test]
+    fn test_exclusive_hue_bound_excludes_boundary_color() {
+        use crate::cli::Range;
+        use crate::color_parser::{ColorEntry, UniversalColor};
+
+        let colors = vec![ColorEntry::new(
+            UniversalColor::from_rgb([40, 60, 200]),
+            "Blue".into(),
+        )];
+        let (_, lch) = commands::filter_collection_colors(&colors, None, None, None, None)[0];
+        let hue = crate::color_ops::analysis::hue::normalize_hue(f64::from(lch.hue.into_degrees()));
+
+        let matching = |range: &str| {
+            let range = Range::parse(range).unwrap();
+            commands::filter_collection_colors(&colors, Some(&range), None, None, None).len()
+        };
+        let below = format!("[{}...{hue}", hue - 30.0);
+        let above = format!("{hue}...{}", hue + 30.0);
+        assert_eq!(matching(&format!("{below}]")), 1);
+        assert_eq!(matching(&format!("{below})")), 0);
+        assert_eq!(matching(&format!("[{above})")), 1);
+        assert_eq!(matching(&format!("({above})")), 0);
+    }
+
+    #[test]
+    fn test_complementary_harmony_gradient_ends_at_complement() {
+        use crate::cli::{HarmonyGradientArgs, HarmonyRelationship};
+        use crate::format_utils::FormatUtils;
+
+        let args = HarmonyGradientArgs {
+            base: "#3366CC".to_string(),
+            relationship: HarmonyRelationship::Complementary,
+            scheme_strategy: "lab".to_string(),
+            ease_in: 0.65,
+            ease_out: 0.35,
+            stops: 5,
+            output_format: None,
+            output_file: None,
+            dry_run: false,
+        };
+        let output = commands::harmony_gradients(&args).unwrap();
+
+        let base = crate::color::parse_color_input("#3366CC").unwrap();
+        let complement = crate::color_schemes::complementary_lab(base);
+        assert_eq!(output.gradients.len(), 1);
+        let gradient = &output.gradients[0];
+        assert_eq!(gradient.target, "complementary");
+        assert_eq!(gradient.start, "#3366CC");
+        assert_eq!(gradient.end, FormatUtils::lab_to_hex(complement));
+        assert_eq!(gradient.stops.last().unwrap().hex, gradient.end);
+
+        let split = HarmonyGradientArgs {
+            relationship: HarmonyRelationship::SplitComplementary,
+            ..args
+        };
+        assert_eq!(
+            commands::harmony_gradients(&split).unwrap().gradients.len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_export_css_collection_contains_tomato() {
+        use crate::cli::ExportCollectionArgs;
+
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("names.json");
+        let args = ExportCollectionArgs {
+            collection: "css".to_string(),
+            output: output.to_string_lossy().into_owned(),
+            dry_run: false,
+        };
+
+        execute_export_collection(&args).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+        let tomato = json["colors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| {
+                entry["name"]
+                    .as_str()
+                    .unwrap()
+                    .eq_ignore_ascii_case("tomato")
+            })
+            .expect("tomato entry");
+        assert_eq!(tomato["hex"], "#FF6347");
+        assert_eq!(tomato["rgb"], serde_json::json!([255, 99, 71]));
+
+        let bad_extension = ExportCollectionArgs {
+            output: "names.txt".to_string(),
+            ..args
+        };
+        assert!(execute_export_collection(&bad_extension).is_err());
+    }
+
+    #[test]
+    fn test_gamut_report_counts_out_of_gamut_colors() {
+        use crate::cli::{GamutReportArgs, OutputFormat};
+        use crate::color_ops::Gamut;
+        use std::io::Write;
+
+        let mut palette = tempfile::NamedTempFile::new().unwrap();
+        writeln!(palette, "#FF0000").unwrap();
+        writeln!(palette, "lab(85, -95, 80)").unwrap();
+        writeln!(palette).unwrap();
+        writeln!(palette, "lch(60, 130, 140)").unwrap();
+        writeln!(palette, "lab(50, 0, 0)").unwrap();
+
+        let args = GamutReportArgs {
+            input_file: palette.path().to_string_lossy().into_owned(),
+            stdin_format: crate::cli::StdinFormat::Lines,
+            gamut: Gamut::Srgb,
+            output_format: Some(OutputFormat::Yaml),
+        };
+        let srgb: serde_yml::Value =
+            serde_yml::from_str(&execute_gamut_report(&args).unwrap().output).unwrap();
+        assert_eq!(srgb["total_colors"], 4);
+        assert_eq!(srgb["out_of_gamut"], 2);
+        assert_eq!(srgb["percent_out_of_gamut"], 50.0);
+        assert_eq!(srgb["colors"][1]["in_gamut"], false);
+
+        let p3 = GamutReportArgs {
+            gamut: Gamut::DisplayP3,
+            ..args
+        };
+        let p3: serde_yml::Value =
+            serde_yml::from_str(&execute_gamut_report(&p3).unwrap().output).unwrap();
+        assert_eq!(p3["gamut"], "Display P3");
+        assert_eq!(p3["out_of_gamut"], 1);
+        assert_eq!(p3["percent_out_of_gamut"], 25.0);
+        assert_eq!(p3["colors"][1]["in_gamut"], true);
+    }
+
+    #[test]
+    fn test_csv_stdin_format_yields_one_match_per_color() {
+        use crate::cli::StdinFormat;
+        use crate::color_parser::unified_manager::UnifiedColorManager;
+
+        let colors = StdinFormat::Csv.tokenize("red, blue, #00FF00\n");
+        assert_eq!(colors.len(), 3);
+
+        let rgbs: Vec<[u8; 3]> = colors
+            .iter()
+            .map(|(_, color)| {
+                let lab = crate::color::parse_color_input(color).unwrap();
+                let (r, g, b) = crate::color_report_formatting::lab_to_rgb(lab);
+                [r, g, b]
+            })
+            .collect();
+        let manager = UnifiedColorManager::new();
+        let matches = manager.find_closest_batch(&rgbs, 1);
+        assert_eq!(matches.len(), 3);
+
+        let css_names: Vec<String> = matches
+            .iter()
+            .map(|collections| collections[0].1[0].entry.metadata.name.to_lowercase())
+            .collect();
+        assert_eq!(css_names, ["red", "blue", "lime"]);
+
+        assert_eq!(
+            StdinFormat::Whitespace.tokenize("red  rgb(0, 0, 255)\n\t#0f0"),
+            [
+                (1, "red".to_string()),
+                (1, "rgb(0, 0, 255)".to_string()),
+                (2, "#0f0".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_to_lowercase_hex() {
+        use crate::cli::{CanonicalizeArgs, Notation};
+        use std::io::Write;
+
+        let mut tokens = tempfile::NamedTempFile::new().unwrap();
+        writeln!(tokens, "rgb(255,0,0)").unwrap();
+        writeln!(tokens).unwrap();
+        writeln!(tokens, "  Blue  ").unwrap();
+
+        let args = CanonicalizeArgs {
+            colors: vec!["RED".to_string(), "#f00".to_string()],
+            input_file: Some(tokens.path().to_string_lossy().into_owned()),
+            notation: Notation::HexLower,
+            stdin_format: crate::cli::StdinFormat::Lines,
+        };
+        let output = execute_canonicalize(&args).unwrap().output;
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            ["#ff0000", "#ff0000", "#ff0000", "#0000ff"]
+        );
+
+        assert_eq!(
+            canonicalize_color("red", Notation::Hex6).unwrap(),
+            "#FF0000"
+        );
+        assert_eq!(
+            canonicalize_color("#f00", Notation::Rgb).unwrap(),
+            "rgb(255, 0, 0)"
+        );
+        assert_eq!(
+            canonicalize_color("#0000ff", Notation::Hsl).unwrap(),
+            "hsl(240, 100%, 50%)"
+        );
+        // Alpha is rejected, not silently dropped
+        assert!(canonicalize_color("#FF000080", Notation::Hex6).is_err());
+        assert!(canonicalize_color("rgba(255, 0, 0, 0.5)", Notation::Rgb).is_err());
+        assert!(canonicalize_color("#FF0000FF", Notation::Hex6).is_ok());
+    }
+
+    #[test]
+    fn test_convert_maps_each_input_to_one_row_in_order() {
+        use crate::cli::{ConvertArgs, LabVariant, OutputFormat};
+        use crate::format_utils::ColorFormat;
+        use std::io::Write;
+
+        let mut colors = tempfile::NamedTempFile::new().unwrap();
+        writeln!(colors, "#FF0000").unwrap();
+        writeln!(colors).unwrap();
+        writeln!(colors, "rgb(0, 128, 255)").unwrap();
+        writeln!(colors, "  navy ").unwrap();
+
+        let args = ConvertArgs {
+            colors: Vec::new(),
+            input_file: Some(colors.path().to_string_lossy().into_owned()),
+            stdin_format: crate::cli::StdinFormat::Lines,
+            to: ColorFormat::Oklch,
+            lab_variant: LabVariant::D65,
+            names: false,
+            #[cfg(feature = "parallel")]
+            parallel: false,
+            output_format: Some(OutputFormat::Markdown),
+            output_file: None,
+            dry_run: false,
+        };
+        let table = convert_colors(&args).unwrap();
+        assert_eq!(table.to, "oklch");
+
+        // Reference OKLCH values, not derived from the conversion under test
+        let expected = [
+            ("#FF0000", "oklch(0.628, 0.258, 29.2)"),
+            ("rgb(0, 128, 255)", "oklch(0.615, 0.211, 256.1)"),
+            ("navy", "oklch(0.271, 0.188, 264.1)"),
+        ];
+        let rows: Vec<(&str, &str)> = table
+            .conversions
+            .iter()
+            .map(|row| (row.input.as_str(), row.output.as_str()))
+            .collect();
+        assert_eq!(rows, expected);
+
+        let markdown = table.to_markdown();
+        assert_eq!(markdown.lines().count(), 5);
+        assert!(markdown.starts_with("| Input | oklch |\n"));
+        assert!(markdown.contains("| navy | oklch(0.271, 0.188, 264.1) |"));
+
+        let hex = convert_colors(&ConvertArgs {
+            colors: vec!["white".to_string()],
+            to: ColorFormat::Hex,
+            ..args
+        })
+        .unwrap();
+        let outputs: Vec<&str> = hex
+            .conversions
+            .iter()
+            .map(|row| row.output.as_str())
+            .collect();
+        assert_eq!(outputs, ["#FFFFFF", "#FF0000", "#0080FF", "#000080"]);
+        // Conversion measures no distance, so the metadata names none
+        assert_eq!(hex.metadata.distance_strategy, None);
+    }
+
+    #[test]
+    fn test_convert_names_keep_input_file_order() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+        use std::io::Write;
+
+        let inputs = [
+            "navy",
+            "#FF0000",
+            "rgb(0, 255, 0)",
+            "gold",
+            "#000000",
+            "teal",
+        ];
+        let mut colors = tempfile::NamedTempFile::new().unwrap();
+        for color in inputs {
+            writeln!(colors, "{color}").unwrap();
+        }
+        let path = colors.path().to_string_lossy().into_owned();
+        let css_names = |extra: &[&str]| {
+            let mut argv = vec!["color-rs", "convert", "--input-file", &path, "--to", "hex"];
+            argv.extend_from_slice(extra);
+            let Commands::Convert(args) = Cli::try_parse_from(argv).unwrap().into_command() else {
+                panic!("expected the convert command");
+            };
+            convert_colors(&args)
+                .unwrap()
+                .conversions
+                .into_iter()
+                .map(|row| {
+                    let css = &row.nearest[0];
+                    assert_eq!(css.collection, "CSS Named Colors");
+                    css.name.to_lowercase()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let expected = ["navy", "red", "lime", "gold", "black", "teal"];
+        assert_eq!(css_names(&["--names"]), expected);
+        #[cfg(feature = "parallel")]
+        assert_eq!(css_names(&["--names", "--parallel"]), expected);
+    }
+
+    #[test]
+    fn test_scale_position_metadata_names_delta_e_2000() {
+        use crate::cli::{Cli, Commands};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["color-rs", "scale-position", "navy"]).unwrap();
+        let Commands::ScalePosition(args) = cli.into_command() else {
+            panic!("expected the scale-position command");
+        };
+        let output = scale_position(&args).unwrap();
+        assert_eq!(
+            output.metadata.distance_strategy.as_deref(),
+            Some(DistanceAlgorithm::DeltaE2000.name())
+        );
+    }
+
+    #[test]
+    fn test_output_formats_are_checked_per_command() {
+        use crate::cli::{Cli, Commands, OutputFormat, RampArgs};
+        use clap::{CommandFactory, Parser};
+
+        // Ramp writes env, the formats only other commands write are parse errors
+        let cli = Cli::try_parse_from(["color-rs", "ramp", "red", "-o", "e"]).unwrap();
+        let Commands::Ramp(args) = cli.command else {
+            panic!("expected ramp command");
+        };
+        assert_eq!(args.output_format, Some(OutputFormat::Env));
+        assert!(Cli::try_parse_from(["color-rs", "ramp", "red", "-o", "rust"]).is_err());
+        assert!(Cli::try_parse_from(["color-rs", "random", "-o", "md"]).is_err());
+
+        // Help lists only what the command accepts
+        let mut command = Cli::command();
+        let help = command
+            .find_subcommand_mut("ramp")
+            .unwrap()
+            .render_help()
+            .to_string();
+        assert!(
+            help.contains("[possible values: toml, yaml, env]"),
+            "{help}"
+        );
+
+        // Arguments built in code get the same check with a matching message
+        let error = OutputFormat::CssVars
+            .check_supported("the ramp command", RampArgs::OUTPUT_FORMATS)
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid arguments: css-vars output is not supported by the ramp command (supported: toml, yaml, env)"
+        );
+    }
+
+    #[test]
+    fn test_global_dry_run_writes_no_files() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = |name: &str| dir.path().join(name).to_str().unwrap().to_string();
+        let run = |arguments: &[&str]| {
+            let cli = Cli::try_parse_from(arguments).unwrap();
+            match cli.into_command() {
+                Commands::Ramp(args) => execute_ramp(&args).map(|_| ()),
+                Commands::ExportCollection(args) => {
+                    let result = execute_export_collection(&args).unwrap();
+                    assert!(result.output.starts_with("Dry run:"), "{}", result.output);
+                    Ok(())
+                }
+                Commands::Palette(args) => {
+                    let result = execute_palette(&args).unwrap();
+                    assert!(result.output.starts_with("Dry run:"), "{}", result.output);
+                    Ok(())
+                }
+                _ => panic!("unexpected command"),
+            }
+        };
+
+        // The flag is global, so it may come before or after the subcommand
+        let ramp = path("ramp.yaml");
+        run(&["color-rs", "--dry-run", "ramp", "red", "--file", &ramp]).unwrap();
+        let export = path("css.json");
+        let collection = ["color-rs", "export-collection", "--collection", "css"];
+        run(&[&collection[..], &["--output", &export, "--dry-run"]].concat()).unwrap();
+        let list = path("colors.txt");
+        std::fs::write(&list, "red\nblue\n").unwrap();
+        let sheet = path("sheet.png");
+        run(&["color-rs", "palette", &list, "--sheet", &sheet, "--dry-run"]).unwrap();
+
+        let written: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(written, ["colors.txt"]);
+    }
+
+    #[test]
+    fn test_mix_perceptual_midpoint_is_equidistant() {
+        use crate::cli::{Cli, Commands};
+        use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+        use clap::Parser;
+
+        let parse = |arguments: &[&str]| {
+            Cli::try_parse_from([&["color-rs", "mix"], arguments].concat()).map(Cli::into_command)
+        };
+        let mix = |arguments: &[&str]| {
+            let Ok(Commands::Mix(args)) = parse(arguments) else {
+                panic!("expected mix command");
+            };
+            execute_mix(&args).unwrap().output
+        };
+        let delta_e = |a: &str, b: &str| {
+            let lab = |input: &str| crate::color::parse_color_input(input).unwrap();
+            calculate_distance(DistanceAlgorithm::DeltaE2000, lab(a), lab(b))
+        };
+
+        let midpoint = mix(&["#1E3A8A", "#FDE047", "--perceptual-midpoint"]);
+        let (to_first, to_second) = (delta_e(&midpoint, "#1E3A8A"), delta_e(&midpoint, "#FDE047"));
+        // Within the rounding of the #RRGGBB output
+        assert!(
+            (to_first - to_second).abs() < 1.0,
+            "{to_first} vs {to_second}"
+        );
+
+        assert_eq!(mix(&["red", "blue", "--amount", "0"]), "#FF0000");
+        assert_eq!(mix(&["red", "blue", "--amount", "1"]), "#0000FF");
+        assert!(parse(&["red", "blue", "--amount", "0.3", "--perceptual-midpoint"]).is_err());
+    }
 }
@@ -8,6 +8,7 @@ use std::collections::HashMap;
 
 /// Command type using enum dispatch (replaces trait objects)
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // Commands are built once per invocation
 pub enum CommandType {
     /// Generate color gradient between two colors
     GenerateGradient {
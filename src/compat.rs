@@ -98,7 +98,7 @@ pub mod legacy {
 #[allow(deprecated)]
 mod tests {
     use super::{CommandType, create_parser, execute_legacy_command};
-    use crate::cli::GradientArgs;
+    use crate::cli::{GradientArgs, ImageWidth};
     use crate::color_parsing::ParserType;
 
     #[test]
@@ -121,21 +121,11 @@ mod tests {
         let args = GradientArgs {
             start_color: "#FF0000".to_string(),
             end_color: "#0000FF".to_string(),
-            start_position: 0,
-            end_position: 100,
-            ease_in: 0.65,
-            ease_out: 0.35,
-            svg: None,
-            png: None,
-            no_legend: false,
-            width: 1000,
+            width: ImageWidth::Pixels(1000),
             step: Some(10),
-            stops: 5,
-            stops_simple: false,
             output_format: Some(crate::cli::OutputFormat::Yaml),
-            output_file: None,
-            func_filter: None,
             vectorized_text: false,
+            ..Default::default()
         };
 
         let cmd = CommandType::GenerateGradient {
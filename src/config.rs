@@ -18,10 +18,17 @@ pub const DEFAULT_GRAD_STEP: &str = "5";
 pub const DEFAULT_FONT_SIZE: &str = "12";
 pub const DEFAULT_BORDER_WIDTH: &str = "0";
 pub const DEFAULT_BORDER_COLOR: &str = "white";
+pub const DEFAULT_STOP_SAMPLES: &str = "10000";
+pub const DEFAULT_RAMP_TINTS: &str = "5";
+pub const DEFAULT_RAMP_SHADES: &str = "5";
 
 /// Gradient calculation parameters
 /// Number of sample points for intelligent stop calculation
 pub const INTELLIGENT_STOP_SAMPLE_POINTS: usize = 10000;
+/// Minimum accepted sample count for intelligent stop calculation
+pub const MIN_STOP_SAMPLE_POINTS: usize = 100;
+/// Most stops a gradient may grow to through `--refine-threshold`
+pub const MAX_REFINED_STOPS: usize = 256;
 
 /// Numerical constants for calculations
 pub const EPSILON: f64 = 1e-7;
@@ -113,6 +120,9 @@ pub mod algorithm_constants {
     /// Luminance convergence tolerance for binary search
     pub const LUMINANCE_TOLERANCE: f64 = 0.001;
 
+    /// Maximum binary search iterations when matching a target luminance
+    pub const LUMINANCE_MAX_ITERATIONS: usize = 50;
+
     /// Geometric position calculation tolerance
     pub const GEOMETRIC_TOLERANCE: f64 = 0.001;
 
@@ -152,22 +162,23 @@ pub mod algorithm_constants {
 
     /// Delta E 2000 algorithm constants
     /// Lightness weighting factor for average lightness calculation
-    pub const DELTA_E_LIGHTNESS_FACTOR: f64 = 0.015;
+    pub const DELTA_E_LIGHTNESS_FACTOR: f64 = crate::color_math::DELTA_E_LIGHTNESS_FACTOR;
 
     /// Lightness weighting base offset
-    pub const DELTA_E_LIGHTNESS_OFFSET: f64 = 50.0;
+    pub const DELTA_E_LIGHTNESS_OFFSET: f64 = crate::color_math::DELTA_E_LIGHTNESS_OFFSET;
 
     /// Lightness weighting denominator offset
-    pub const DELTA_E_LIGHTNESS_DENOMINATOR_OFFSET: f64 = 20.0;
+    pub const DELTA_E_LIGHTNESS_DENOMINATOR_OFFSET: f64 =
+        crate::color_math::DELTA_E_LIGHTNESS_DENOMINATOR_OFFSET;
 
     /// Chroma weighting factor
-    pub const DELTA_E_CHROMA_FACTOR: f64 = 0.045;
+    pub const DELTA_E_CHROMA_FACTOR: f64 = crate::color_math::DELTA_E_CHROMA_FACTOR;
 
     /// Hue weighting factor  
-    pub const DELTA_E_HUE_FACTOR: f64 = 0.015;
+    pub const DELTA_E_HUE_FACTOR: f64 = crate::color_math::DELTA_E_HUE_FACTOR;
 
     /// Standard parametric factor (kL, kC, kH default values)
-    pub const DELTA_E_PARAMETRIC_FACTOR: f64 = 1.0;
+    pub const DELTA_E_PARAMETRIC_FACTOR: f64 = crate::color_math::DELTA_E_PARAMETRIC_FACTOR;
 
     /// Gradient calculation parameters
     /// Binary search division factor
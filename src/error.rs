@@ -24,6 +24,21 @@ pub enum ColorError {
     ParseError(String),
     /// Invalid operation error
     InvalidOperation(String),
+    /// Malformed floating-point number
+    ParseFloatError(std::num::ParseFloatError),
+    /// Malformed number in one component of a color such as `lab(50, abc, 10)`
+    InvalidComponent {
+        /// Color input as given
+        input: String,
+        /// Component that failed to parse, e.g. `LAB a`
+        component: &'static str,
+        /// Underlying number error
+        source: std::num::ParseFloatError,
+    },
+    /// Malformed integer
+    ParseIntError(std::num::ParseIntError),
+    /// CSV reading error
+    CsvError(csv::Error),
     /// General error
     General(String),
 }
@@ -39,6 +54,17 @@ impl fmt::Display for ColorError {
             Self::InvalidArguments(msg) => write!(f, "Invalid arguments: {msg}"),
             Self::ParseError(msg) => write!(f, "Color parse error: {msg}"),
             Self::InvalidOperation(msg) => write!(f, "Invalid operation: {msg}"),
+            Self::ParseFloatError(err) => write!(f, "Invalid number: {err}"),
+            Self::InvalidComponent {
+                input,
+                component,
+                source,
+            } => write!(
+                f,
+                "Failed to parse color '{input}': invalid {component} value: {source}"
+            ),
+            Self::ParseIntError(err) => write!(f, "Invalid integer: {err}"),
+            Self::CsvError(err) => write!(f, "CSV error: {err}"),
             Self::General(msg) => write!(f, "Error: {msg}"),
         }
     }
@@ -48,6 +74,9 @@ impl std::error::Error for ColorError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::IoError(err) => Some(err),
+            Self::ParseFloatError(err) | Self::InvalidComponent { source: err, .. } => Some(err),
+            Self::ParseIntError(err) => Some(err),
+            Self::CsvError(err) => Some(err),
             _ => None,
         }
     }
@@ -61,7 +90,19 @@ impl From<std::io::Error> for ColorError {
 
 impl From<std::num::ParseIntError> for ColorError {
     fn from(err: std::num::ParseIntError) -> Self {
-        Self::InvalidColor(format!("Parse error: {err}"))
+        Self::ParseIntError(err)
+    }
+}
+
+impl From<std::num::ParseFloatError> for ColorError {
+    fn from(err: std::num::ParseFloatError) -> Self {
+        Self::ParseFloatError(err)
+    }
+}
+
+impl From<csv::Error> for ColorError {
+    fn from(err: csv::Error) -> Self {
+        Self::CsvError(err)
     }
 }
 
@@ -96,8 +137,8 @@ impl From<crate::scheme_config::ConfigError> for ColorError {
 pub trait IoResultExt<T> {
     /// Convert `std::io::Result<T>` to `crate::error::Result<T>`
     ///
-    /// This provides a convenient way to convert IO errors to `ColorError`
-    /// without the verbose `.map_err(|e| ColorError::InvalidColor(format!("IO error: {}", e)))`
+    /// Wraps the error in `ColorError::IoError`, the same variant `?` produces
+    /// through `From<std::io::Error>`
     ///
     /// # Example
     /// ```rust
@@ -105,10 +146,6 @@ pub trait IoResultExt<T> {
     /// use std::fs;
     ///
     /// # fn main() -> Result<()> {
-    /// // Instead of:
-    /// // fs::read_to_string("file.txt").map_err(|e| ColorError::InvalidColor(format!("IO error: {}", e)))?;
-    ///
-    /// // You can write:
     /// match fs::read_to_string("nonexistent.txt").to_err() {
     ///     Ok(_content) => println!("File found"),
     ///     Err(e) => println!("Expected error: {}", e),
@@ -121,7 +158,7 @@ pub trait IoResultExt<T> {
 
 impl<T> IoResultExt<T> for std::io::Result<T> {
     fn to_err(self) -> Result<T> {
-        self.map_err(|e| ColorError::InvalidColor(format!("IO error: {e}")))
+        self.map_err(ColorError::IoError)
     }
 }
 
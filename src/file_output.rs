@@ -115,7 +115,7 @@ impl FileOutputService {
             .to_toml()
             .map_err(|e| ColorError::General(format!("Failed to serialize to TOML: {e}")))?;
 
-        fs::write(&filename, toml_string).map_err(ColorError::IoError)?;
+        fs::write(&filename, toml_string)?;
 
         Ok(())
     }
@@ -138,7 +138,7 @@ impl FileOutputService {
             .to_yaml()
             .map_err(|e| ColorError::General(format!("Failed to serialize to YAML: {e}")))?;
 
-        fs::write(&filename, yaml_string).map_err(ColorError::IoError)?;
+        fs::write(&filename, yaml_string)?;
 
         Ok(())
     }
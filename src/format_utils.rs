@@ -3,10 +3,11 @@
 //! Provides consistent color format conversions with standardized precision
 //! for both console output and file export, eliminating code duplication.
 
+use crate::color_ops::analysis::hue::normalize_hue;
 use crate::color_ops::conversion;
 use crate::precision_utils::PrecisionUtils;
 use crate::utils::Utils;
-use palette::{Hsl, Hsv, IntoColor, Lab, Lch, Srgb, Xyz};
+use palette::{Hsl, Hsv, IntoColor, Lab, Lch, Oklch, Srgb, Xyz};
 
 /// Consolidated color format utilities
 pub struct FormatUtils;
@@ -38,6 +39,70 @@ impl FormatUtils {
         conversion::srgb_to_hex(srgb)
     }
 
+    /// Shortest lowercase form of a `#RRGGBB` or `#RRGGBBAA` color
+    ///
+    /// Doubled digit pairs collapse to the CSS shorthand (`#FF0000` becomes
+    /// `#f00`); other colors are only lowercased. Input that is not a hex color
+    /// is returned unchanged.
+    ///
+    /// # Example
+    /// ```rust
+    /// use color_rs::format_utils::FormatUtils;
+    ///
+    /// assert_eq!(FormatUtils::shortest_hex("#FF0000"), "#f00");
+    /// assert_eq!(FormatUtils::shortest_hex("#FF0001"), "#ff0001");
+    /// ```
+    #[must_use]
+    pub fn shortest_hex(hex: &str) -> String {
+        let Some(digits) = hex.strip_prefix('#') else {
+            return hex.to_string();
+        };
+        if !matches!(digits.len(), 6 | 8) || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return hex.to_string();
+        }
+
+        let digits = digits.to_ascii_lowercase();
+        let pairs: Vec<&[u8]> = digits.as_bytes().chunks(2).collect();
+        if pairs.iter().all(|pair| pair[0] == pair[1]) {
+            let short: String = pairs.iter().map(|pair| char::from(pair[0])).collect();
+            format!("#{short}")
+        } else {
+            format!("#{digits}")
+        }
+    }
+
+    /// Convert LAB to RGB with 0.0-1.0 float channels, e.g. `rgb(1.0, 0.0, 0.0)`
+    ///
+    /// Channels are clamped to the sRGB gamut and written with up to three
+    /// decimals, keeping at least one.
+    #[must_use]
+    pub fn lab_to_rgb_float(lab: Lab) -> String {
+        let srgb: Srgb = lab.into_color();
+        let channel = |value: f32| {
+            let formatted = PrecisionUtils::format_f64(f64::from(value.clamp(0.0, 1.0)));
+            if formatted.contains('.') {
+                formatted
+            } else {
+                format!("{formatted}.0")
+            }
+        };
+        format!(
+            "rgb({}, {}, {})",
+            channel(srgb.red),
+            channel(srgb.green),
+            channel(srgb.blue)
+        )
+    }
+
+    /// Convert LAB to RGB in the requested notation
+    #[must_use]
+    pub fn lab_to_rgb_as(lab: Lab, format: crate::cli::RgbFormat) -> String {
+        match format {
+            crate::cli::RgbFormat::U8 => Self::lab_to_rgb(lab),
+            crate::cli::RgbFormat::Float => Self::lab_to_rgb_float(lab),
+        }
+    }
+
     /// Convert LAB to RGB format string using functional conversion
     #[must_use]
     pub fn lab_to_rgb(lab: Lab) -> String {
@@ -54,7 +119,7 @@ impl FormatUtils {
         let srgb: Srgb = lab.into_color();
         let hsl: Hsl = srgb.into_color();
         PrecisionUtils::format_hsl(
-            hsl.hue.into_inner() as f64,
+            normalize_hue(hsl.hue.into_inner() as f64),
             (hsl.saturation * 100.0) as f64,
             (hsl.lightness * 100.0) as f64,
         )
@@ -66,7 +131,7 @@ impl FormatUtils {
         let srgb: Srgb = lab.into_color();
         let hsv: Hsv = srgb.into_color();
         PrecisionUtils::format_hsv(
-            hsv.hue.into_inner() as f64,
+            normalize_hue(hsv.hue.into_inner() as f64),
             (hsv.saturation * 100.0) as f64,
             (hsv.value * 100.0) as f64,
         )
@@ -112,20 +177,38 @@ impl FormatUtils {
         PrecisionUtils::format_lab(f64::from(lab.l), f64::from(lab.a), f64::from(lab.b))
     }
 
+    /// Convert D65 LAB to a LAB string relative to the requested reference white
+    #[must_use]
+    pub fn lab_to_lab_as(lab: Lab, variant: crate::cli::LabVariant) -> String {
+        match variant {
+            crate::cli::LabVariant::D65 => Self::lab_to_lab(lab),
+            crate::cli::LabVariant::D50 => {
+                let lab = conversion::lab_to_lab_d50(lab);
+                PrecisionUtils::format_lab(f64::from(lab.l), f64::from(lab.a), f64::from(lab.b))
+            }
+        }
+    }
+
     /// Convert LAB to LCH format string with standardized precision using functional conversion
     #[must_use]
     pub fn lab_to_lch(lab: Lab) -> String {
         let lch: Lch = lab.into_color();
-        PrecisionUtils::format_lch(lch.l as f64, lch.chroma as f64, lch.hue.into_inner() as f64)
+        PrecisionUtils::format_lch(
+            lch.l as f64,
+            lch.chroma as f64,
+            normalize_hue(lch.hue.into_inner() as f64),
+        )
     }
 
     /// Convert LAB to OKLCH format string with standardized precision using functional conversion
     #[must_use]
     pub fn lab_to_oklch(lab: Lab) -> String {
-        // For now, use LCH as approximation for OKLCH since palette doesn't have native OKLCH
-        // This is a simplified conversion - for true OKLCH, more complex color space conversion would be needed
-        let lch: Lch = lab.into_color();
-        PrecisionUtils::format_oklch(lch.l as f64, lch.chroma as f64, lch.hue.into_inner() as f64)
+        let oklch: Oklch = lab.into_color();
+        PrecisionUtils::format_oklch(
+            f64::from(oklch.l),
+            f64::from(oklch.chroma),
+            normalize_hue(f64::from(oklch.hue.into_inner())),
+        )
     }
 
     /// Get all color format strings - this is the ONLY non-duplicate function in `FormatUtils`
@@ -147,7 +230,7 @@ impl FormatUtils {
 }
 
 /// Enum for selecting the color output type.
-#[derive(PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum ColorFormat {
     Hex,
     Lab,
@@ -176,6 +259,20 @@ impl FormatUtils {
             ColorFormat::Lch => Self::lab_to_lch(lab),
         }
     }
+
+    /// Format a color like [`format_color`](Self::format_color), writing LAB
+    /// relative to the requested reference white
+    #[must_use]
+    pub fn format_color_as(
+        lab: Lab,
+        color_format: &ColorFormat,
+        lab_variant: crate::cli::LabVariant,
+    ) -> String {
+        match color_format {
+            ColorFormat::Lab => Self::lab_to_lab_as(lab, lab_variant),
+            _ => Self::format_color(lab, color_format),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -183,6 +280,19 @@ mod tests {
     use super::*;
     use palette::Lab;
 
+    #[test]
+    fn test_lab_to_rgb_float() {
+        let red: Lab = Srgb::new(1.0_f32, 0.0, 0.0).into_color();
+        assert_eq!(FormatUtils::lab_to_rgb_float(red), "rgb(1.0, 0.0, 0.0)");
+        assert_eq!(
+            FormatUtils::lab_to_rgb_as(red, crate::cli::RgbFormat::U8),
+            "rgb(255, 0, 0)"
+        );
+
+        let gray: Lab = Srgb::new(0.5_f32, 0.5, 0.5).into_color();
+        assert_eq!(FormatUtils::lab_to_rgb_float(gray), "rgb(0.5, 0.5, 0.5)");
+    }
+
     #[test]
     fn test_lab_to_formats() {
         let lab = Lab::new(50.0, 25.0, -15.0);
@@ -216,4 +326,39 @@ mod tests {
         assert!(!formats.lab.is_empty());
         assert!(!formats.lch.is_empty());
     }
+
+    #[test]
+    fn test_hue_output_is_never_negative() {
+        let hue_of = |formatted: &str| -> f64 {
+            let inner = formatted.trim_end_matches(')');
+            inner.rsplit(", ").next().unwrap().parse().unwrap()
+        };
+
+        // Blues, purples and pinks sit at negative atan2 angles; the near-zero
+        // cases straddle the wrap point
+        let samples = [
+            Lab::new(32.3, 79.2, -107.9),
+            Lab::new(50.0, 25.0, -15.0),
+            Lab::new(60.0, 40.0, -0.01),
+            Lab::new(60.0, 40.0, 0.01),
+            Lab::new(60.0, 40.0, 0.0),
+            Lab::new(40.0, -30.0, -0.5),
+        ];
+        for lab in samples {
+            for formatted in [FormatUtils::lab_to_lch(lab), FormatUtils::lab_to_oklch(lab)] {
+                let hue = hue_of(&formatted);
+                assert!((0.0..=360.0).contains(&hue), "{formatted}");
+            }
+
+            let spaces = crate::color_ops::analysis::get_color_spaces(lab.into_color());
+            for hue in [
+                spaces.lch.hue,
+                spaces.oklch.hue,
+                spaces.hsl.hue,
+                spaces.hsv.hue,
+            ] {
+                assert!((0.0..360.0).contains(&hue), "{lab:?}: {hue}");
+            }
+        }
+    }
 }
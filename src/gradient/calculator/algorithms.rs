@@ -3,7 +3,7 @@
 //! This module contains the core algorithms for calculating gradient stops,
 //! including intelligent positioning and binary search implementations.
 
-use crate::config::INTELLIGENT_STOP_SAMPLE_POINTS;
+use crate::config::{INTELLIGENT_STOP_SAMPLE_POINTS, MIN_STOP_SAMPLE_POINTS};
 use kurbo::{CubicBez, ParamCurve, Point};
 
 /// Intelligent stop calculation using easing functions and importance analysis
@@ -11,13 +11,38 @@ use kurbo::{CubicBez, ParamCurve, Point};
 pub struct IntelligentStopCalculator {
     ease_in: f64,
     ease_out: f64,
+    sample_points: usize,
 }
 
 impl IntelligentStopCalculator {
     /// Create new intelligent stop calculator with easing parameters
     #[must_use]
     pub const fn new(ease_in: f64, ease_out: f64) -> Self {
-        Self { ease_in, ease_out }
+        Self {
+            ease_in,
+            ease_out,
+            sample_points: INTELLIGENT_STOP_SAMPLE_POINTS,
+        }
+    }
+
+    /// Set the number of curve samples used for stop placement
+    ///
+    /// Higher counts place stops more precisely on sharp easing curves.
+    /// Values below `MIN_STOP_SAMPLE_POINTS` are raised to that minimum.
+    #[must_use]
+    pub const fn with_sample_points(mut self, sample_points: usize) -> Self {
+        self.sample_points = if sample_points < MIN_STOP_SAMPLE_POINTS {
+            MIN_STOP_SAMPLE_POINTS
+        } else {
+            sample_points
+        };
+        self
+    }
+
+    /// Number of curve samples used for stop placement
+    #[must_use]
+    pub const fn sample_points(&self) -> usize {
+        self.sample_points
     }
 
     /// Calculate gradient stops using intelligent positioning
@@ -29,7 +54,7 @@ impl IntelligentStopCalculator {
 
         let curve = self.create_bezier_curve();
         let cumulative_importance = self.calculate_cumulative_importance(&curve);
-        let total_importance = cumulative_importance[INTELLIGENT_STOP_SAMPLE_POINTS];
+        let total_importance = cumulative_importance[self.sample_points];
 
         if total_importance == 0.0 {
             return self.fallback_to_equal_spacing(num_stops);
@@ -59,10 +84,10 @@ impl IntelligentStopCalculator {
 
     /// Calculate cumulative importance values along the curve
     fn calculate_cumulative_importance(&self, curve: &CubicBez) -> Vec<f64> {
-        let mut cumulative_importance = vec![0.0; INTELLIGENT_STOP_SAMPLE_POINTS + 1];
+        let mut cumulative_importance = vec![0.0; self.sample_points + 1];
 
-        for i in 0..INTELLIGENT_STOP_SAMPLE_POINTS {
-            let derivative_magnitude = Self::calculate_derivative_magnitude(curve, i);
+        for i in 0..self.sample_points {
+            let derivative_magnitude = self.calculate_derivative_magnitude(curve, i);
             cumulative_importance[i + 1] = cumulative_importance[i] + derivative_magnitude;
         }
 
@@ -70,9 +95,9 @@ impl IntelligentStopCalculator {
     }
 
     /// Calculate derivative magnitude at a specific sample point
-    fn calculate_derivative_magnitude(curve: &CubicBez, sample_index: usize) -> f64 {
-        let t = sample_index as f64 / INTELLIGENT_STOP_SAMPLE_POINTS as f64;
-        let dt = 1.0 / INTELLIGENT_STOP_SAMPLE_POINTS as f64;
+    fn calculate_derivative_magnitude(&self, curve: &CubicBez, sample_index: usize) -> f64 {
+        let t = sample_index as f64 / self.sample_points as f64;
+        let dt = 1.0 / self.sample_points as f64;
 
         let current_point = curve.eval(t);
         let next_point = curve.eval((t + dt).min(1.0));
@@ -123,7 +148,7 @@ impl IntelligentStopCalculator {
         target_importance: f64,
     ) -> (usize, usize) {
         let mut low = 0;
-        let mut high = INTELLIGENT_STOP_SAMPLE_POINTS;
+        let mut high = self.sample_points;
 
         while high - low > 1 {
             let mid = usize::midpoint(low, high);
@@ -148,9 +173,9 @@ impl IntelligentStopCalculator {
         if cumulative_importance[high] - cumulative_importance[low] > f64::EPSILON {
             let ratio = (target_importance - cumulative_importance[low])
                 / (cumulative_importance[high] - cumulative_importance[low]);
-            (low as f64 + ratio) / INTELLIGENT_STOP_SAMPLE_POINTS as f64
+            (low as f64 + ratio) / self.sample_points as f64
         } else {
-            low as f64 / INTELLIGENT_STOP_SAMPLE_POINTS as f64
+            low as f64 / self.sample_points as f64
         }
     }
 }
@@ -234,6 +259,45 @@ mod tests {
         assert_eq!(stops[stops.len() - 1], 1.0);
     }
 
+    #[test]
+    fn test_sample_points_improve_accuracy() {
+        // The curve's y(u) = 3(1-u)u^2 + u^3, so stop k of n sits where y(u) = k/(n-1)
+        let expected = |target: f64| {
+            let (mut low, mut high) = (0.0_f64, 1.0_f64);
+            for _ in 0..60 {
+                let mid = (low + high) / 2.0;
+                let y = 3.0 * (1.0 - mid) * mid * mid + mid.powi(3);
+                if y < target {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            (low + high) / 2.0
+        };
+        let max_error = |stops: &[f64]| {
+            stops
+                .iter()
+                .enumerate()
+                .map(|(i, &s)| (s - expected(i as f64 / (stops.len() - 1) as f64)).abs())
+                .fold(0.0, f64::max)
+        };
+
+        let coarse = IntelligentStopCalculator::new(0.95, 0.05).with_sample_points(100);
+        let fine = IntelligentStopCalculator::new(0.95, 0.05).with_sample_points(50_000);
+
+        let coarse_error = max_error(&coarse.calculate_stops(7));
+        let fine_error = max_error(&fine.calculate_stops(7));
+        assert!(fine_error < coarse_error);
+        assert!(fine_error < 1e-4);
+    }
+
+    #[test]
+    fn test_sample_points_minimum() {
+        let calculator = IntelligentStopCalculator::new(0.42, 0.58).with_sample_points(1);
+        assert_eq!(calculator.sample_points(), MIN_STOP_SAMPLE_POINTS);
+    }
+
     #[test]
     fn test_equal_spacing_calculator() {
         let calculator = EqualSpacingCalculator;
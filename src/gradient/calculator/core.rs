@@ -5,12 +5,17 @@
 
 use super::algorithms::{EqualSpacingCalculator, IntelligentStopCalculator, cubic_bezier_ease};
 use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
-use crate::config::algorithm_constants;
+use crate::color_ops::Gamut;
+use crate::color_ops::mixing::{ColorSpace, HueDirection, mix_toward_directed};
+use crate::config::{INTELLIGENT_STOP_SAMPLE_POINTS, MIN_STOP_SAMPLE_POINTS, algorithm_constants};
 use crate::gradient::easing::EasingFunction;
 use crate::utils::Utils;
 use palette::{IntoColor, Lab, Mix, Srgb};
 use tabled::Tabled;
 
+/// Bisection steps when pulling an overshoot endpoint back to the gamut boundary
+const OVERSHOOT_BISECTION_STEPS: usize = 24;
+
 /// Gradient value for display in tables
 #[derive(Tabled, Clone)]
 pub struct GradientValue {
@@ -33,7 +38,11 @@ pub struct GradientCalculator {
 /// Algorithm selection for gradient calculation
 #[derive(Debug, Clone)]
 pub enum CalculationAlgorithm {
-    Intelligent { ease_in: f64, ease_out: f64 },
+    Intelligent {
+        ease_in: f64,
+        ease_out: f64,
+        sample_points: usize,
+    },
     EqualSpacing,
 }
 
@@ -42,8 +51,21 @@ impl GradientCalculator {
     #[must_use]
     pub fn with_intelligent_stops(ease_in: f64, ease_out: f64) -> Self {
         Self {
-            algorithm: CalculationAlgorithm::Intelligent { ease_in, ease_out },
+            algorithm: CalculationAlgorithm::Intelligent {
+                ease_in,
+                ease_out,
+                sample_points: INTELLIGENT_STOP_SAMPLE_POINTS,
+            },
+        }
+    }
+
+    /// Set the curve sample count used by the intelligent stop algorithm
+    #[must_use]
+    pub fn with_stop_samples(mut self, samples: usize) -> Self {
+        if let CalculationAlgorithm::Intelligent { sample_points, .. } = &mut self.algorithm {
+            *sample_points = samples;
         }
+        self
     }
 
     /// Create calculator with equal spacing
@@ -58,8 +80,13 @@ impl GradientCalculator {
     #[must_use]
     pub fn calculate_stops(&self, num_stops: usize) -> Vec<f64> {
         match &self.algorithm {
-            CalculationAlgorithm::Intelligent { ease_in, ease_out } => {
-                let calculator = IntelligentStopCalculator::new(*ease_in, *ease_out);
+            CalculationAlgorithm::Intelligent {
+                ease_in,
+                ease_out,
+                sample_points,
+            } => {
+                let calculator = IntelligentStopCalculator::new(*ease_in, *ease_out)
+                    .with_sample_points(*sample_points);
                 calculator.calculate_stops(num_stops)
             }
             CalculationAlgorithm::EqualSpacing => {
@@ -84,7 +111,27 @@ impl GradientCalculator {
             .collect()
     }
 
+    /// Reject position ranges that are empty or reversed
+    ///
+    /// Stop positions are spread over `end_position - start_position`, so a
+    /// zero-width range would collapse every stop onto one position and the SVG
+    /// offset mapping would divide by zero.
+    ///
+    /// # Errors
+    /// Returns `ColorError::InvalidArguments` unless `start_position < end_position`.
+    pub fn check_position_range(start_position: u8, end_position: u8) -> crate::error::Result<()> {
+        if start_position >= end_position {
+            return Err(crate::error::ColorError::InvalidArguments(format!(
+                "Start position ({start_position}) must be less than end position ({end_position})"
+            )));
+        }
+        Ok(())
+    }
+
     /// Generate gradient values for display
+    ///
+    /// # Errors
+    /// Returns an error if `start_position` is not less than `end_position`.
     pub fn generate_gradient_values(
         &self,
         start_lab: Lab,
@@ -94,6 +141,7 @@ impl GradientCalculator {
         end_position: u8,
         easing_function: &EasingFunction,
     ) -> crate::error::Result<Vec<GradientValue>> {
+        Self::check_position_range(start_position, end_position)?;
         if num_stops == 0 {
             return Ok(Vec::new());
         }
@@ -234,105 +282,673 @@ impl GradientCalculator {
                     bezier_t,
                     lab_color: rgb_lab,
                     rgb_color: (r, g, b),
+                    alpha: 1.0,
                 });
             }
         } else {
-            // Smart mode: Equal distance with geometric position finding using custom algorithm
-            // Calculate total distance between start and end colors using provided algorithm
-            let total_distance = calculate_distance(algorithm, start_lab, end_lab);
-            let step_distance = total_distance / (steps - 1) as f64;
+            // Smart mode: equal distance steps along the LAB path
+            gradient_stops = Self::equal_distance_stops(
+                start_lab,
+                end_lab,
+                start_position,
+                end_position,
+                ease_in,
+                ease_out,
+                steps,
+                algorithm,
+                INTELLIGENT_STOP_SAMPLE_POINTS,
+                |t| start_lab.mix(end_lab, t as f32),
+            );
+        }
 
-            for i in 0..steps {
-                if i == 0 {
-                    // First stop: use start color
-                    let start_srgb: Srgb = start_lab.into_color();
-                    let start_color_rgb = (
-                        (start_srgb.red * 255.0).round() as u8,
-                        (start_srgb.green * 255.0).round() as u8,
-                        (start_srgb.blue * 255.0).round() as u8,
-                    );
-                    gradient_stops.push(UnifiedGradientStop {
-                        position: start_position,
-                        geometric_t: 0.0,
-                        bezier_t: 0.0,
-                        lab_color: start_lab,
-                        rgb_color: start_color_rgb,
-                    });
+        gradient_stops
+    }
+
+    /// Intelligent-stop gradient interpolated in `space` instead of LAB
+    ///
+    /// Stops are still placed at equal ΔE2000 steps from the start color (along
+    /// the path in HSL and HSV, see [`Self::path_length_stops`]); only the colors
+    /// between the endpoints are mixed in `space`, with hue following
+    /// `hue_direction` in HSL and HSV. Each stop is
+    /// located to within `1 / sample_points` of the curve; `ColorSpace::Lab`
+    /// with [`INTELLIGENT_STOP_SAMPLE_POINTS`] gives the same stops as
+    /// [`Self::calculate_unified_gradient`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn calculate_unified_gradient_in_space(
+        start_lab: Lab,
+        end_lab: Lab,
+        start_position: u8,
+        end_position: u8,
+        ease_in: f64,
+        ease_out: f64,
+        steps: usize,
+        space: ColorSpace,
+        hue_direction: HueDirection,
+        sample_points: usize,
+    ) -> Vec<UnifiedGradientStop> {
+        let start_srgb: Srgb = start_lab.into_color();
+        let end_srgb: Srgb = end_lab.into_color();
+        let interpolate = |t: f64| -> Lab {
+            match space {
+                ColorSpace::Lab => start_lab.mix(end_lab, t as f32),
+                _ => mix_toward_directed(start_srgb, end_srgb, t as f32, space, hue_direction)
+                    .into_color(),
+            }
+        };
+        if matches!(space, ColorSpace::Hsl | ColorSpace::Hsv) {
+            return Self::path_length_stops(
+                start_lab,
+                end_lab,
+                start_position,
+                end_position,
+                ease_in,
+                ease_out,
+                steps,
+                sample_points,
+                interpolate,
+            );
+        }
+        Self::equal_distance_stops(
+            start_lab,
+            end_lab,
+            start_position,
+            end_position,
+            ease_in,
+            ease_out,
+            steps,
+            DistanceAlgorithm::DeltaE2000,
+            sample_points,
+            interpolate,
+        )
+    }
+
+    /// Stops at equal ΔE2000 distances travelled along the path traced by `interpolate`
+    ///
+    /// Hue-based paths can swing away from the start color and back (red to
+    /// blue the long way passes yellow, green and cyan), so the distance from
+    /// the start is not monotonic and [`Self::equal_distance_stops`] would skip
+    /// whole stretches. Here the path is walked in `sample_points` steps and
+    /// the stops split its accumulated length evenly.
+    #[allow(clippy::too_many_arguments)]
+    fn path_length_stops(
+        start_lab: Lab,
+        end_lab: Lab,
+        start_position: u8,
+        end_position: u8,
+        ease_in: f64,
+        ease_out: f64,
+        steps: usize,
+        sample_points: usize,
+        interpolate: impl Fn(f64) -> Lab,
+    ) -> Vec<UnifiedGradientStop> {
+        let samples = sample_points.max(MIN_STOP_SAMPLE_POINTS);
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut travelled = 0.0;
+        let mut previous = start_lab;
+        lengths.push(travelled);
+        for k in 1..=samples {
+            let bezier_t = cubic_bezier_ease(k as f64 / samples as f64, ease_in, ease_out);
+            let color = interpolate(bezier_t);
+            travelled += calculate_distance(DistanceAlgorithm::DeltaE2000, previous, color);
+            lengths.push(travelled);
+            previous = color;
+        }
+
+        (0..steps)
+            .map(|i| {
+                let (geometric_t, bezier_t, lab_color) = if i == 0 {
+                    (0.0, 0.0, start_lab)
                 } else if i == steps - 1 {
-                    // Last stop: use end color
-                    let end_srgb: Srgb = end_lab.into_color();
-                    let end_color_rgb = (
-                        (end_srgb.red * 255.0).round() as u8,
-                        (end_srgb.green * 255.0).round() as u8,
-                        (end_srgb.blue * 255.0).round() as u8,
-                    );
-                    gradient_stops.push(UnifiedGradientStop {
-                        position: end_position,
-                        geometric_t: 1.0,
-                        bezier_t: 1.0,
-                        lab_color: end_lab,
-                        rgb_color: end_color_rgb,
-                    });
+                    (1.0, 1.0, end_lab)
                 } else {
-                    // Middle stops: find geometric position that produces target Delta E distance
-                    let target_distance = step_distance * i as f64;
-
-                    // Binary search to find geometric_t that produces target distance
-                    let mut low = 0.0;
-                    let mut high = 1.0;
-                    let mut best_t = 0.5;
-
-                    for _ in 0..50 {
-                        // Binary search with 50 iterations for precision
-                        let mid_t =
-                            (low + high) / algorithm_constants::BINARY_SEARCH_DIVISION_FACTOR;
-                        let bezier_t = cubic_bezier_ease(mid_t, ease_in, ease_out);
-                        let test_color = start_lab.mix(end_lab, bezier_t as f32);
-                        let actual_distance = calculate_distance(algorithm, start_lab, test_color);
-
-                        if (actual_distance - target_distance).abs()
-                            < algorithm_constants::GRADIENT_DISTANCE_TOLERANCE
-                        {
-                            best_t = mid_t;
-                            break;
-                        }
-
-                        if actual_distance < target_distance {
-                            low = mid_t;
-                        } else {
-                            high = mid_t;
-                        }
+                    let target = travelled * i as f64 / (steps - 1) as f64;
+                    let k = lengths
+                        .partition_point(|&length| length < target)
+                        .clamp(1, samples);
+                    let (below, above) = (lengths[k - 1], lengths[k]);
+                    let fraction = if above > below {
+                        (target - below) / (above - below)
+                    } else {
+                        0.0
+                    };
+                    let geometric_t = ((k - 1) as f64 + fraction) / samples as f64;
+                    let bezier_t = cubic_bezier_ease(geometric_t, ease_in, ease_out);
+                    (geometric_t, bezier_t, interpolate(bezier_t))
+                };
+                let srgb: Srgb = lab_color.into_color();
+                UnifiedGradientStop {
+                    position: (start_position as f64
+                        + geometric_t * (end_position - start_position) as f64)
+                        .round() as u8,
+                    geometric_t,
+                    bezier_t,
+                    lab_color,
+                    rgb_color: (
+                        (srgb.red * 255.0).round().clamp(0.0, 255.0) as u8,
+                        (srgb.green * 255.0).round().clamp(0.0, 255.0) as u8,
+                        (srgb.blue * 255.0).round().clamp(0.0, 255.0) as u8,
+                    ),
+                    alpha: 1.0,
+                }
+            })
+            .collect()
+    }
 
+    /// Stops at equal `algorithm` distances along the path traced by `interpolate`
+    ///
+    /// `interpolate` maps an eased position in 0.0-1.0 to a color between the
+    /// endpoints; the first and last stops are always the exact endpoints. The
+    /// search for each middle stop ends once it has narrowed the geometric
+    /// position to `1 / sample_points`, so more samples place stops closer to
+    /// their target distance.
+    #[allow(clippy::too_many_arguments)]
+    fn equal_distance_stops(
+        start_lab: Lab,
+        end_lab: Lab,
+        start_position: u8,
+        end_position: u8,
+        ease_in: f64,
+        ease_out: f64,
+        steps: usize,
+        algorithm: DistanceAlgorithm,
+        sample_points: usize,
+        interpolate: impl Fn(f64) -> Lab,
+    ) -> Vec<UnifiedGradientStop> {
+        let mut gradient_stops = Vec::new();
+        let resolution = 1.0 / sample_points.max(MIN_STOP_SAMPLE_POINTS) as f64;
+        // Smart mode: Equal distance with geometric position finding using custom algorithm
+        // Calculate total distance between start and end colors using provided algorithm
+        let total_distance = calculate_distance(algorithm, start_lab, end_lab);
+        let step_distance = total_distance / (steps - 1) as f64;
+
+        for i in 0..steps {
+            if i == 0 {
+                // First stop: use start color
+                let start_srgb: Srgb = start_lab.into_color();
+                let start_color_rgb = (
+                    (start_srgb.red * 255.0).round() as u8,
+                    (start_srgb.green * 255.0).round() as u8,
+                    (start_srgb.blue * 255.0).round() as u8,
+                );
+                gradient_stops.push(UnifiedGradientStop {
+                    position: start_position,
+                    geometric_t: 0.0,
+                    bezier_t: 0.0,
+                    lab_color: start_lab,
+                    rgb_color: start_color_rgb,
+                    alpha: 1.0,
+                });
+            } else if i == steps - 1 {
+                // Last stop: use end color
+                let end_srgb: Srgb = end_lab.into_color();
+                let end_color_rgb = (
+                    (end_srgb.red * 255.0).round() as u8,
+                    (end_srgb.green * 255.0).round() as u8,
+                    (end_srgb.blue * 255.0).round() as u8,
+                );
+                gradient_stops.push(UnifiedGradientStop {
+                    position: end_position,
+                    geometric_t: 1.0,
+                    bezier_t: 1.0,
+                    lab_color: end_lab,
+                    rgb_color: end_color_rgb,
+                    alpha: 1.0,
+                });
+            } else {
+                // Middle stops: find geometric position that produces target Delta E distance
+                let target_distance = step_distance * i as f64;
+
+                // Binary search to find geometric_t that produces target distance
+                let mut low = 0.0;
+                let mut high = 1.0;
+                let mut best_t = 0.5;
+
+                while high - low > resolution {
+                    let mid_t = (low + high) / algorithm_constants::BINARY_SEARCH_DIVISION_FACTOR;
+                    let bezier_t = cubic_bezier_ease(mid_t, ease_in, ease_out);
+                    let test_color = interpolate(bezier_t);
+                    let actual_distance = calculate_distance(algorithm, start_lab, test_color);
+
+                    if (actual_distance - target_distance).abs()
+                        < algorithm_constants::GRADIENT_DISTANCE_TOLERANCE
+                    {
                         best_t = mid_t;
+                        break;
+                    }
+
+                    if actual_distance < target_distance {
+                        low = mid_t;
+                    } else {
+                        high = mid_t;
                     }
 
-                    // Calculate final bezier_t and actual color using found geometric position
-                    let final_bezier_t = cubic_bezier_ease(best_t, ease_in, ease_out);
-                    let actual_lab = start_lab.mix(end_lab, final_bezier_t as f32);
-                    let actual_srgb: Srgb = actual_lab.into_color();
-                    let rgb_color = (
-                        (actual_srgb.red * 255.0).round() as u8,
-                        (actual_srgb.green * 255.0).round() as u8,
-                        (actual_srgb.blue * 255.0).round() as u8,
-                    );
-
-                    // Calculate position using the found geometric t
-                    let position = (start_position as f64
-                        + best_t * (end_position - start_position) as f64)
-                        .round() as u8;
-
-                    gradient_stops.push(UnifiedGradientStop {
-                        position,
-                        geometric_t: best_t,
-                        bezier_t: final_bezier_t,
-                        lab_color: actual_lab,
+                    best_t = mid_t;
+                }
+
+                // Calculate final bezier_t and actual color using found geometric position
+                let final_bezier_t = cubic_bezier_ease(best_t, ease_in, ease_out);
+                let actual_lab = interpolate(final_bezier_t);
+                let actual_srgb: Srgb = actual_lab.into_color();
+                let rgb_color = (
+                    (actual_srgb.red * 255.0).round() as u8,
+                    (actual_srgb.green * 255.0).round() as u8,
+                    (actual_srgb.blue * 255.0).round() as u8,
+                );
+
+                // Calculate position using the found geometric t
+                let position = (start_position as f64
+                    + best_t * (end_position - start_position) as f64)
+                    .round() as u8;
+
+                gradient_stops.push(UnifiedGradientStop {
+                    position,
+                    geometric_t: best_t,
+                    bezier_t: final_bezier_t,
+                    lab_color: actual_lab,
+                    rgb_color,
+                    alpha: 1.0,
+                });
+            }
+        }
+
+        gradient_stops
+    }
+
+    /// Diverging gradient from `start_lab` through `center_lab` to `end_lab`
+    ///
+    /// Both halves are computed toward the center with the same easing and stop
+    /// count, so they are built symmetrically, and the center stop sits exactly
+    /// halfway between `start_position` and `end_position`. An even `steps` is rounded up
+    /// to the next odd count so the center is always a stop. `sample_points` is
+    /// the stop search resolution, as in [`Self::calculate_unified_gradient_in_space`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn calculate_diverging_gradient(
+        start_lab: Lab,
+        center_lab: Lab,
+        end_lab: Lab,
+        start_position: u8,
+        end_position: u8,
+        ease_in: f64,
+        ease_out: f64,
+        steps: usize,
+        use_simple_mode: bool,
+        sample_points: usize,
+    ) -> Vec<UnifiedGradientStop> {
+        let segment_steps = steps / 2 + 1;
+        let center_position = start_position + (end_position - start_position) / 2;
+        let segment = |from: Lab| {
+            if use_simple_mode {
+                Self::calculate_unified_gradient(
+                    from,
+                    center_lab,
+                    start_position,
+                    center_position,
+                    ease_in,
+                    ease_out,
+                    segment_steps,
+                    true,
+                )
+            } else {
+                Self::calculate_unified_gradient_in_space(
+                    from,
+                    center_lab,
+                    start_position,
+                    center_position,
+                    ease_in,
+                    ease_out,
+                    segment_steps,
+                    ColorSpace::Lab,
+                    HueDirection::Short,
+                    sample_points,
+                )
+            }
+        };
+
+        let mut gradient_stops: Vec<UnifiedGradientStop> = segment(start_lab)
+            .into_iter()
+            .map(|stop| UnifiedGradientStop {
+                geometric_t: stop.geometric_t / 2.0,
+                bezier_t: stop.bezier_t / 2.0,
+                ..stop
+            })
+            .collect();
+
+        // The center stop is the exact center color, not an interpolation result
+        if let Some(center) = gradient_stops.last_mut() {
+            let center_srgb: Srgb = center_lab.into_color();
+            center.position = center_position;
+            center.lab_color = center_lab;
+            center.rgb_color = (
+                (center_srgb.red * 255.0).round() as u8,
+                (center_srgb.green * 255.0).round() as u8,
+                (center_srgb.blue * 255.0).round() as u8,
+            );
+        }
+
+        // Mirror the end half around the center, skipping its copy of the center stop
+        gradient_stops.extend(segment(end_lab).into_iter().rev().skip(1).map(|stop| {
+            UnifiedGradientStop {
+                position: end_position - (stop.position - start_position),
+                geometric_t: 1.0 - stop.geometric_t / 2.0,
+                bezier_t: 1.0 - stop.bezier_t / 2.0,
+                ..stop
+            }
+        }));
+
+        gradient_stops
+    }
+
+    /// Interpolate opacity linearly from `start_alpha` to `end_alpha` across `stops`
+    ///
+    /// Alpha follows each stop's geometric position, independent of the color easing.
+    pub fn apply_alpha(stops: &mut [UnifiedGradientStop], start_alpha: f64, end_alpha: f64) {
+        for stop in stops {
+            stop.alpha = (end_alpha - start_alpha).mul_add(stop.geometric_t, start_alpha);
+        }
+    }
+
+    /// Replace the easing of `stops` with a stepped easing
+    ///
+    /// Each stop keeps its position and takes the color the staircase holds
+    /// at its geometric position, so neighbouring stops within one step share
+    /// a color.
+    pub fn apply_stepped_easing(
+        stops: &mut [UnifiedGradientStop],
+        args: &crate::cli::GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+        easing: &EasingFunction,
+    ) {
+        for stop in stops {
+            stop.bezier_t = easing.ease(stop.geometric_t);
+            (stop.lab_color, stop.rgb_color) =
+                Self::eased_color(args, start_lab, end_lab, stop.bezier_t);
+        }
+    }
+
+    /// Colors at each fraction in `positions`, as `(fraction, color)` pairs
+    ///
+    /// Repeated fractions are sampled once, at their first occurrence; the
+    /// remaining order is kept as given. See [`Self::color_at`].
+    #[must_use]
+    pub fn colors_at(
+        args: &crate::cli::GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+        positions: &[f64],
+    ) -> Vec<(f64, GradientValue)> {
+        let mut samples: Vec<(f64, GradientValue)> = Vec::with_capacity(positions.len());
+        for &position in positions {
+            if !samples.iter().any(|(seen, _)| *seen == position) {
+                samples.push((position, Self::color_at(args, start_lab, end_lab, position)));
+            }
+        }
+        samples
+    }
+
+    /// Color at fraction `position` (0.0-1.0) of the gradient `args` describes
+    ///
+    /// Applies the same easing, interpolation space, simple mode and overshoot
+    /// as the full stop calculation, without generating any stops. Positions
+    /// outside 0.0-1.0 are clamped. The reported position is mapped into the
+    /// start/end position range.
+    #[must_use]
+    pub fn color_at(
+        args: &crate::cli::GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+        position: f64,
+    ) -> GradientValue {
+        let t = position.clamp(0.0, 1.0);
+        let (_, (r, g, b)) = Self::sample(args, start_lab, end_lab, t);
+
+        let range = f64::from(args.end_position) - f64::from(args.start_position);
+        let srgb = Srgb::new(r, g, b).into_format::<f32>();
+        GradientValue {
+            position: format!(
+                "{}%",
+                crate::precision_utils::PrecisionUtils::format_f64(
+                    t.mul_add(range, f64::from(args.start_position))
+                )
+            ),
+            hex: format!("#{r:02X}{g:02X}{b:02X}"),
+            rgb: Utils::rgb_to_string(r, g, b),
+            wcag_luminance: crate::precision_utils::PrecisionUtils::format_wcag_relative_luminance(
+                crate::color_ops::luminance::wcag_relative(srgb),
+            ),
+        }
+    }
+
+    /// LAB color at fraction `position` (0.0-1.0) of the gradient `args` describes
+    ///
+    /// Same sampling as [`Self::color_at`], before conversion to sRGB.
+    #[must_use]
+    pub fn lab_at(
+        args: &crate::cli::GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+        position: f64,
+    ) -> Lab {
+        Self::sample(args, start_lab, end_lab, position.clamp(0.0, 1.0)).0
+    }
+
+    /// Color at unclamped fraction `t`, applying overshoot and easing
+    fn sample(
+        args: &crate::cli::GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+        t: f64,
+    ) -> (Lab, (u8, u8, u8)) {
+        let (start_lab, end_lab) = if args.overshoot > 0.0 {
+            Self::overshoot_endpoints(start_lab, end_lab, args.overshoot / 100.0)
+        } else {
+            (start_lab, end_lab)
+        };
+        Self::eased_color(args, start_lab, end_lab, Self::eased_fraction(args, t))
+    }
+
+    /// Fraction `t` after the cubic-bezier or stepped easing of `args`
+    fn eased_fraction(args: &crate::cli::GradientArgs, t: f64) -> f64 {
+        args.stepped_easing().map_or_else(
+            || cubic_bezier_ease(t, args.ease_in, args.ease_out),
+            |easing| easing.ease(t),
+        )
+    }
+
+    /// Color at eased fraction `bezier_t` between the endpoints, in the
+    /// interpolation space and simple mode of `args`
+    fn eased_color(
+        args: &crate::cli::GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+        bezier_t: f64,
+    ) -> (Lab, (u8, u8, u8)) {
+        let start_srgb: Srgb = start_lab.into_color();
+        let end_srgb: Srgb = end_lab.into_color();
+        if args.stops_simple {
+            // Simple mode interpolates the rounded endpoint channels in RGB
+            let channel = |from: f32, to: f32| {
+                let from = f64::from((from * 255.0).round());
+                let to = f64::from((to * 255.0).round());
+                (to - from).mul_add(bezier_t, from).round() as u8
+            };
+            let (r, g, b) = (
+                channel(start_srgb.red, end_srgb.red),
+                channel(start_srgb.green, end_srgb.green),
+                channel(start_srgb.blue, end_srgb.blue),
+            );
+            let lab: Lab = Srgb::new(r, g, b).into_format::<f32>().into_color();
+            (lab, (r, g, b))
+        } else {
+            let lab: Lab = match args.color_space {
+                ColorSpace::Lab => start_lab.mix(end_lab, bezier_t as f32),
+                space => mix_toward_directed(
+                    start_srgb,
+                    end_srgb,
+                    bezier_t as f32,
+                    space,
+                    args.hue_direction,
+                )
+                .into_color(),
+            };
+            let srgb: Srgb = lab.into_color();
+            let rgb = (
+                (srgb.red * 255.0).round().clamp(0.0, 255.0) as u8,
+                (srgb.green * 255.0).round().clamp(0.0, 255.0) as u8,
+                (srgb.blue * 255.0).round().clamp(0.0, 255.0) as u8,
+            );
+            (lab, rgb)
+        }
+    }
+
+    /// Insert midpoint stops until no neighbors are more than `threshold` ΔE2000 apart
+    ///
+    /// Each pass splits every neighbor pair further apart than `threshold` at
+    /// its geometric midpoint, sampled like [`Self::color_at`], so extra stops
+    /// only appear where the curve is steep. Passes repeat until every pair is
+    /// within the threshold or the gradient holds `max_stops` stops. `stops`
+    /// must be in geometric order; `start_lab` and `end_lab` are the endpoints
+    /// before any overshoot.
+    #[must_use]
+    pub fn refine_stops(
+        mut stops: Vec<UnifiedGradientStop>,
+        args: &crate::cli::GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+        threshold: f64,
+        max_stops: usize,
+    ) -> Vec<UnifiedGradientStop> {
+        let start_position = f64::from(args.start_position);
+        let range = f64::from(args.end_position) - start_position;
+
+        loop {
+            let mut budget = max_stops.saturating_sub(stops.len());
+            let mut refined = Vec::with_capacity(stops.len() * 2);
+            for pair in stops.windows(2) {
+                refined.push(pair[0].clone());
+                let distance = calculate_distance(
+                    DistanceAlgorithm::DeltaE2000,
+                    pair[0].lab_color,
+                    pair[1].lab_color,
+                );
+                if budget > 0 && distance > threshold {
+                    let t = f64::midpoint(pair[0].geometric_t, pair[1].geometric_t);
+                    let (lab_color, rgb_color) = Self::sample(args, start_lab, end_lab, t);
+                    refined.push(UnifiedGradientStop {
+                        position: t.mul_add(range, start_position).round() as u8,
+                        geometric_t: t,
+                        bezier_t: Self::eased_fraction(args, t),
+                        lab_color,
                         rgb_color,
+                        alpha: 1.0,
                     });
+                    budget -= 1;
                 }
             }
+            refined.extend(stops.last().cloned());
+
+            if refined.len() == stops.len() {
+                return refined;
+            }
+            stops = refined;
         }
+    }
 
-        gradient_stops
+    /// Extend the LAB path past both endpoints by `fraction` of its length
+    ///
+    /// Each endpoint moves away from the other along the straight LAB line
+    /// through both. An endpoint that would leave sRGB stops at the gamut
+    /// boundary on that line instead, so the extended colors are never clipped
+    /// channel by channel and keep the path's direction.
+    #[must_use]
+    pub fn overshoot_endpoints(start_lab: Lab, end_lab: Lab, fraction: f64) -> (Lab, Lab) {
+        let extend = |from: Lab, away_from: Lab| {
+            let at = |t: f32| {
+                Lab::new(
+                    t.mul_add(from.l - away_from.l, from.l),
+                    t.mul_add(from.a - away_from.a, from.a),
+                    t.mul_add(from.b - away_from.b, from.b),
+                )
+            };
+            let fraction = fraction as f32;
+            if !Gamut::Srgb.contains(from) {
+                return from;
+            }
+            if Gamut::Srgb.contains(at(fraction)) {
+                return at(fraction);
+            }
+
+            // Bisect for the furthest in-gamut point on the extension
+            let (mut inside, mut outside) = (0.0, fraction);
+            for _ in 0..OVERSHOOT_BISECTION_STEPS {
+                let mid = (inside + outside) / 2.0;
+                if Gamut::Srgb.contains(at(mid)) {
+                    inside = mid;
+                } else {
+                    outside = mid;
+                }
+            }
+            at(inside)
+        };
+
+        (extend(start_lab, end_lab), extend(end_lab, start_lab))
+    }
+
+    /// Respace stop positions logarithmically between `start_position` and `end_position`
+    ///
+    /// Each stop moves to `start * (end / start)^t` for its geometric position
+    /// `t`, so stops crowd toward the start. Colors are left as computed.
+    /// `start_position` must be greater than 0.
+    pub fn apply_log_scale(
+        stops: &mut [UnifiedGradientStop],
+        start_position: u8,
+        end_position: u8,
+    ) {
+        let start = f64::from(start_position);
+        let ratio = f64::from(end_position) / start;
+        for stop in stops {
+            stop.position = (start * ratio.powf(stop.geometric_t))
+                .round()
+                .clamp(start, f64::from(end_position)) as u8;
+        }
+    }
+
+    /// Largest stop count whose neighbors stay at least `min_delta_e` apart
+    ///
+    /// `stops_for` builds the gradient for a candidate count, so the count is
+    /// sized from the same stops the caller will output. Grows the count from 2
+    /// until the smallest ΔE2000 between neighboring stops would fall below
+    /// `min_delta_e`, or `max_stops` is reached. Never returns fewer than 2 stops.
+    ///
+    /// # Errors
+    /// Returns the first error from `stops_for`.
+    pub fn auto_stop_count<E>(
+        max_stops: usize,
+        min_delta_e: f64,
+        mut stops_for: impl FnMut(usize) -> std::result::Result<Vec<UnifiedGradientStop>, E>,
+    ) -> std::result::Result<usize, E> {
+        let mut count = 2;
+
+        for candidate in 3..=max_stops {
+            let stops = stops_for(candidate)?;
+            let min_neighbor_distance = stops
+                .windows(2)
+                .map(|pair| {
+                    calculate_distance(
+                        DistanceAlgorithm::DeltaE2000,
+                        pair[0].lab_color,
+                        pair[1].lab_color,
+                    )
+                })
+                .fold(f64::INFINITY, f64::min);
+
+            if min_neighbor_distance < min_delta_e {
+                break;
+            }
+            count = candidate;
+        }
+
+        Ok(count)
     }
 }
 
@@ -345,6 +961,15 @@ pub struct UnifiedGradientStop {
     pub bezier_t: f64,    // Position after bezier easing applied
     pub lab_color: Lab,   // Color in LAB space
     pub rgb_color: (u8, u8, u8), // Color in RGB space
+    pub alpha: f64,       // Opacity in 0-1 range
+}
+
+impl UnifiedGradientStop {
+    /// Alpha scaled to a byte, as used in `#RRGGBBAA`
+    #[must_use]
+    pub fn alpha_byte(&self) -> u8 {
+        (self.alpha * 255.0).round().clamp(0.0, 255.0) as u8
+    }
 }
 
 #[cfg(test)]
@@ -385,6 +1010,91 @@ mod tests {
         assert_eq!(values.len(), 3);
         assert_eq!(values[0].position, "0%");
         assert_eq!(values[2].position, "100%");
+
+        assert!(
+            calculator
+                .generate_gradient_values(start_lab, end_lab, 3, 50, 50, &easing)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_color_at_endpoints_and_midpoint() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["color-rs", "gradient", "#FF0000", "#0000FF"]).unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        let start = crate::color::parse_color_input(&args.start_color).unwrap();
+        let end = crate::color::parse_color_input(&args.end_color).unwrap();
+
+        let first = GradientCalculator::color_at(&args, start, end, 0.0);
+        assert_eq!(first.hex, "#FF0000");
+        assert_eq!(first.position, "0%");
+        let last = GradientCalculator::color_at(&args, start, end, 1.0);
+        assert_eq!(last.hex, "#0000FF");
+        assert_eq!(last.position, "100%");
+
+        let sample = GradientCalculator::color_at(&args, start, end, 0.37);
+        assert_eq!(sample.position, "37%");
+        assert_ne!(sample.hex, first.hex);
+        assert_ne!(sample.hex, last.hex);
+
+        let cli =
+            Cli::try_parse_from(["color-rs", "gradient", "red", "blue", "--at-single", "1.5"])
+                .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        assert!(args.validate().is_err());
+    }
+
+    #[test]
+    fn test_colors_at_dedupes_in_order() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "gradient",
+            "red",
+            "blue",
+            "--sample-at",
+            "0.5,0,0.5,1,0",
+        ])
+        .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        assert!(args.validate().is_ok());
+        let start = crate::color::parse_color_input(&args.start_color).unwrap();
+        let end = crate::color::parse_color_input(&args.end_color).unwrap();
+
+        let samples = GradientCalculator::colors_at(&args, start, end, &args.sample_at);
+        let fractions: Vec<f64> = samples.iter().map(|(t, _)| *t).collect();
+        assert_eq!(fractions, vec![0.5, 0.0, 1.0]);
+        assert_eq!(
+            samples[0].1.hex,
+            GradientCalculator::color_at(&args, start, end, 0.5).hex
+        );
+        assert_eq!(samples[1].1.hex, "#FF0000");
+        assert_eq!(samples[2].1.hex, "#0000FF");
+
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "gradient",
+            "red",
+            "blue",
+            "--sample-at",
+            "0.2,1.1",
+        ])
+        .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        assert!(args.validate().is_err());
     }
 
     #[test]
@@ -400,4 +1110,78 @@ mod tests {
         assert_eq!(stops[0].position, 0);
         assert_eq!(stops[2].position, 100);
     }
+
+    #[test]
+    fn test_auto_stop_count_scales_with_contrast() {
+        let black = Lab::new(0.0, 0.0, 0.0);
+        let white = Lab::new(100.0, 0.0, 0.0);
+        let gray = Lab::new(50.0, 0.0, 0.0);
+        let light_gray = Lab::new(60.0, 0.0, 0.0);
+
+        let count_for = |start: Lab, end: Lab| {
+            GradientCalculator::auto_stop_count(40, 3.0, |steps| {
+                Ok::<_, std::convert::Infallible>(GradientCalculator::calculate_unified_gradient(
+                    start, end, 0, 100, 0.65, 0.35, steps, false,
+                ))
+            })
+            .unwrap()
+        };
+        let high = count_for(black, white);
+        let low = count_for(gray, light_gray);
+
+        assert!(
+            high > low,
+            "high contrast {high} should exceed low contrast {low}"
+        );
+        assert!(low >= 2);
+        assert!(high <= 40);
+    }
+
+    #[test]
+    fn test_diverging_gradient_center_at_midpoint() {
+        let blue: Lab = Srgb::new(0.0_f32, 0.0, 1.0).into_color();
+        let red: Lab = Srgb::new(1.0_f32, 0.0, 0.0).into_color();
+        let white = Lab::new(100.0, 0.0, 0.0);
+
+        for simple in [false, true] {
+            let stops = GradientCalculator::calculate_diverging_gradient(
+                blue,
+                white,
+                red,
+                0,
+                100,
+                0.65,
+                0.35,
+                7,
+                simple,
+                INTELLIGENT_STOP_SAMPLE_POINTS,
+            );
+            assert_eq!(stops.len(), 7);
+
+            let center = stops.iter().find(|stop| stop.position == 50).unwrap();
+            assert_eq!(center.rgb_color, (255, 255, 255));
+            assert_eq!(center.lab_color, white);
+            assert_eq!(stops[3].position, 50);
+            assert!(stops[..3].iter().all(|stop| stop.position < 50));
+            assert!(stops[4..].iter().all(|stop| stop.position > 50));
+            assert_eq!(stops[0].rgb_color, (0, 0, 255));
+            assert_eq!(stops[6].rgb_color, (255, 0, 0));
+        }
+
+        // An even count is rounded up so the center keeps its stop
+        let stops = GradientCalculator::calculate_diverging_gradient(
+            blue,
+            white,
+            red,
+            0,
+            100,
+            0.65,
+            0.35,
+            4,
+            false,
+            INTELLIGENT_STOP_SAMPLE_POINTS,
+        );
+        assert_eq!(stops.len(), 5);
+        assert_eq!(stops[2].position, 50);
+    }
 }
@@ -13,6 +13,36 @@ pub enum EasingType {
     Linear,
     CubicBezier,
     Smooth,
+    /// Staircase with the given number of steps, like CSS `steps()`
+    Steps(u32, JumpMode),
+}
+
+/// Where a stepped easing jumps, matching the CSS `steps()` jump terms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JumpMode {
+    /// Jump at the start of each interval (`jump-start`, `start`)
+    Start,
+    /// Jump at the end of each interval (`jump-end`, `end`)
+    #[default]
+    End,
+    /// No jump at either end; the first and last steps hold 0 and 1 (`jump-none`)
+    None,
+    /// Jump at both ends, adding a step (`jump-both`)
+    Both,
+}
+
+impl JumpMode {
+    /// Parse a CSS jump term
+    #[must_use]
+    pub fn parse(term: &str) -> Option<Self> {
+        match term.trim().to_ascii_lowercase().as_str() {
+            "jump-start" | "start" => Some(Self::Start),
+            "jump-end" | "end" => Some(Self::End),
+            "jump-none" => Some(Self::None),
+            "jump-both" => Some(Self::Both),
+            _ => None,
+        }
+    }
 }
 
 /// Functional easing implementation using enum dispatch for zero-cost abstractions
@@ -22,6 +52,8 @@ pub enum EasingFunction {
     Linear,
     /// Cubic Bezier easing with control points (x1, 0, x2, 1)
     CubicBezier { x1: f64, x2: f64 },
+    /// Discrete easing that holds each value for an interval, like CSS `steps()`
+    Steps { count: u32, jump: JumpMode },
 }
 
 impl Default for EasingFunction {
@@ -70,12 +102,50 @@ impl EasingFunction {
         Self::cubic_bezier(0.0, 0.58)
     }
 
+    /// Parse a CSS `steps(n)` or `steps(n, <jump term>)` function
+    ///
+    /// The jump term defaults to `end`; `jump-none` needs at least two steps.
+    ///
+    /// # Errors
+    /// Returns a message if `spec` is not a valid `steps()` function
+    ///
+    /// # Example
+    /// ```rust
+    /// use color_rs::gradient::{EasingFunction, JumpMode};
+    ///
+    /// let easing = EasingFunction::parse_steps("steps(4, end)").unwrap();
+    /// assert_eq!(easing, EasingFunction::Steps { count: 4, jump: JumpMode::End });
+    /// assert_eq!(easing.ease(0.3), 0.25);
+    /// ```
+    pub fn parse_steps(spec: &str) -> std::result::Result<Self, String> {
+        let invalid =
+            || format!("Invalid steps easing: '{spec}'. Expected steps(N) or steps(N, jump-term)");
+        let inner = spec
+            .trim()
+            .strip_prefix("steps(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(invalid)?;
+        let (count, jump) = match inner.split_once(',') {
+            Some((count, term)) => (count, JumpMode::parse(term).ok_or_else(invalid)?),
+            None => (inner, JumpMode::End),
+        };
+        let count: u32 = count.trim().parse().map_err(|_| invalid())?;
+        if count == 0 || (jump == JumpMode::None && count < 2) {
+            return Err(format!(
+                "Invalid steps easing: '{spec}'. Needs at least {} step(s)",
+                if jump == JumpMode::None { 2 } else { 1 }
+            ));
+        }
+        Ok(Self::Steps { count, jump })
+    }
+
     /// Calculate the eased value for a given time parameter t (0.0 to 1.0)
     #[must_use]
     pub fn ease(&self, t: f64) -> f64 {
         match self {
             Self::Linear => t.clamp(0.0, 1.0),
             Self::CubicBezier { x1, x2 } => self.cubic_bezier_ease(t, *x1, *x2),
+            Self::Steps { count, jump } => Self::steps_ease(t, *count, *jump),
         }
     }
 
@@ -85,9 +155,26 @@ impl EasingFunction {
         match self {
             Self::Linear => "Linear",
             Self::CubicBezier { .. } => "Cubic Bezier",
+            Self::Steps { .. } => "Steps",
         }
     }
 
+    /// Staircase output following the CSS step easing algorithm
+    fn steps_ease(t: f64, count: u32, jump: JumpMode) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        let count = f64::from(count);
+        let mut step = (t * count).floor();
+        if matches!(jump, JumpMode::Start | JumpMode::Both) {
+            step += 1.0;
+        }
+        let jumps = match jump {
+            JumpMode::Start | JumpMode::End => count,
+            JumpMode::None => count - 1.0,
+            JumpMode::Both => count + 1.0,
+        };
+        step.min(jumps) / jumps
+    }
+
     /// Internal cubic bezier calculation
     fn cubic_bezier_ease(&self, t: f64, x1: f64, x2: f64) -> f64 {
         let t = t.clamp(0.0, 1.0);
@@ -141,6 +228,7 @@ impl EasingFactory {
             EasingType::Linear => EasingFunction::Linear,
             EasingType::CubicBezier => EasingFunction::cubic_bezier(ease_in, ease_out),
             EasingType::Smooth => EasingFunction::ease_in_out(),
+            EasingType::Steps(count, jump) => EasingFunction::Steps { count, jump },
         }
     }
 
@@ -245,4 +333,30 @@ mod tests {
             EasingFunction::CubicBezier { x1: 0.0, x2: 0.58 }
         ));
     }
+
+    #[test]
+    fn test_steps_easing_yields_n_distinct_values() {
+        let distinct = |easing: &EasingFunction| {
+            let mut values: Vec<f64> = (0..1000)
+                .map(|i| easing.ease(f64::from(i) / 1000.0))
+                .collect();
+            values.dedup();
+            values
+        };
+
+        let end = EasingFactory::create_easing(EasingType::Steps(4, JumpMode::End), 0.0, 0.0);
+        assert_eq!(distinct(&end), vec![0.0, 0.25, 0.5, 0.75]);
+        assert_eq!(end.ease(1.0), 1.0);
+
+        let start = EasingFunction::parse_steps("steps(4, jump-start)").unwrap();
+        assert_eq!(distinct(&start), vec![0.25, 0.5, 0.75, 1.0]);
+
+        let none = EasingFunction::parse_steps("steps(3,jump-none)").unwrap();
+        assert_eq!(distinct(&none), vec![0.0, 0.5, 1.0]);
+
+        assert!(EasingFunction::parse_steps("steps(0)").is_err());
+        assert!(EasingFunction::parse_steps("steps(1, jump-none)").is_err());
+        assert!(EasingFunction::parse_steps("steps(4, sideways)").is_err());
+        assert!(EasingFunction::parse_steps("ease").is_err());
+    }
 }
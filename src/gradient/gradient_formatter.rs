@@ -14,6 +14,8 @@ pub enum GradientFormat {
     Table,
     /// CSS gradient format
     Css,
+    /// CSS gradient without optional whitespace and with the shortest hex colors
+    MinifiedCss,
     /// JSON format
     Json,
     /// Custom format with provided formatter function
@@ -26,6 +28,7 @@ impl GradientFormat {
         match self {
             Self::Table => format_as_table(values),
             Self::Css => format_as_css(values),
+            Self::MinifiedCss => format_as_minified_css(values),
             Self::Json => format_as_json(values),
             Self::Custom { name } => format_as_custom(values, name),
         }
@@ -36,6 +39,7 @@ impl GradientFormat {
         match self {
             Self::Table => "Table",
             Self::Css => "CSS",
+            Self::MinifiedCss => "Minified CSS",
             Self::Json => "JSON",
             Self::Custom { name } => name,
         }
@@ -67,6 +71,26 @@ fn format_as_css(values: &[GradientValue]) -> Result<String> {
     Ok(format!("linear-gradient({})", css_stops.join(", ")))
 }
 
+/// Pure function for minified CSS formatting
+fn format_as_minified_css(values: &[GradientValue]) -> Result<String> {
+    if values.is_empty() {
+        return Ok(String::new());
+    }
+
+    let css_stops: Vec<String> = values
+        .iter()
+        .map(|value| {
+            format!(
+                "{} {}",
+                crate::format_utils::FormatUtils::shortest_hex(&value.hex),
+                value.position
+            )
+        })
+        .collect();
+
+    Ok(format!("linear-gradient({})", css_stops.join(",")))
+}
+
 /// Pure function for JSON formatting
 fn format_as_json(values: &[GradientValue]) -> Result<String> {
     if values.is_empty() {
@@ -186,6 +210,11 @@ impl GradientFormatter {
         Self::with_format(GradientFormat::Css)
     }
 
+    /// Create output manager with minified CSS format
+    pub fn with_minified_css_format() -> Self {
+        Self::with_format(GradientFormat::MinifiedCss)
+    }
+
     /// Create output manager with JSON format
     pub fn with_json_format() -> Self {
         Self::with_format(GradientFormat::Json)
@@ -291,6 +320,17 @@ mod tests {
         assert!(result.contains("100%"));
     }
 
+    #[test]
+    fn test_minified_css_formatting() {
+        let values = create_test_values();
+        let result = GradientFormatter::with_minified_css_format()
+            .format_gradient(&values)
+            .unwrap();
+
+        assert!(!result.contains('\n'));
+        assert_eq!(result, "linear-gradient(#f00 0%,#00f 100%)");
+    }
+
     #[test]
     fn test_css_formatting() {
         let values = create_test_values();
@@ -162,6 +162,7 @@ impl GradientStopCalculator {
         end_position: u8,
         easing_function: &crate::gradient::easing::EasingFunction,
     ) -> Result<Vec<GradientValue>> {
+        super::GradientCalculator::check_position_range(start_position, end_position)?;
         if num_stops == 0 {
             return Ok(Vec::new());
         }
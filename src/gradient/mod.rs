@@ -18,7 +18,7 @@ pub use calculator::{
     CalculationAlgorithm, EqualSpacingCalculator, GradientCalculator, GradientValue,
     IntelligentStopCalculator, UnifiedGradientStop, cubic_bezier_ease,
 };
-pub use easing::{EasingFactory, EasingFunction, EasingType};
+pub use easing::{EasingFactory, EasingFunction, EasingType, JumpMode};
 
 // Functional re-exports
 pub use gradient_formatter::{EventCallbacks, GradientFormat, GradientFormatter};
@@ -33,9 +33,9 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
     use crate::color_report_formatting::lab_to_rgb;
     use crate::image::ImageGenerator;
     use crate::output_formats::{
-        ColorCollectionMatches, ColorInfo, ContrastAnalysis, EnhancedGradientAnalysisOutput,
-        EnhancedGradientStop, GradientAnalysisOutput, GradientColors, GradientConfiguration,
-        GradientStop, NestedColorInfo, ProgramMetadata,
+        ColorInfo, ContrastAnalysis, EnhancedGradientAnalysisOutput, EnhancedGradientStop,
+        GradientAnalysisOutput, GradientColors, GradientConfiguration, GradientStop,
+        NestedColorInfo, ProgramMetadata,
     };
     use palette::{IntoColor, Lab, Srgb};
 
@@ -88,6 +88,12 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
         (ratio as f32, level.to_string())
     };
 
+    // Metric for naming stops after their nearest collection colors
+    let naming_algorithm: DistanceAlgorithm = args
+        .distance_method
+        .parse()
+        .map_err(|e| crate::error::ColorError::InvalidArguments(format!("{e}")))?;
+
     // Parse colors using unified parser to support all color formats (CSS, RAL, etc.)
     let parser = ColorParser::new();
     let (start_lab, _start_format) = parser.parse(&args.start_color)?;
@@ -116,26 +122,93 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
     );
     let end_lab: Lab = end_srgb.into_color();
 
+    // Single-sample query: skip stops, images and the report entirely
+    if let Some(t) = args.at_single {
+        let value = GradientCalculator::color_at(&args, start_lab, end_lab, t);
+        println!("{}: {} {}", value.position, value.hex, value.rgb);
+        return Ok(());
+    }
+
+    // Keyframe query: the colors at each requested fraction, nothing else
+    if !args.sample_at.is_empty() {
+        return print_gradient_samples(&args, start_lab, end_lab);
+    }
+
+    // Inline SVG for CSS: print the data URI and nothing else
+    if args.data_uri {
+        println!(
+            "{}",
+            ImageGenerator::new().svg_data_uri(&args, start_lab, end_lab)?
+        );
+        return Ok(());
+    }
+
     // Generate images if requested
     let image_gen = ImageGenerator::new();
-    if args.should_generate_svg() {
-        image_gen.generate_svg(&args, start_lab, end_lab)?;
-        println!("SVG gradient saved to: {}", args.svg_name());
-    }
-    if args.should_generate_png() {
-        image_gen.generate_png(&args, start_lab, end_lab)?;
-        println!("PNG gradient saved to: {}", args.png_name());
+    if args.dry_run {
+        let (width, height) = ImageGenerator::gradient_image_size(&args);
+        if args.should_generate_svg() {
+            println!(
+                "Dry run: would save SVG gradient ({width}x{height}) to: {}",
+                args.svg_name()
+            );
+        }
+        if args.should_generate_png() {
+            println!(
+                "Dry run: would save PNG gradient ({width}x{height}) to: {}",
+                args.png_name()
+            );
+        }
+        if let Some(strip) = &args.lightness_strip {
+            let (strip_width, strip_height) = ImageGenerator::gradient_band_size(&args);
+            println!(
+                "Dry run: would save lightness strip ({strip_width}x{strip_height}) to: {strip}"
+            );
+        }
+        if let Some(pdf) = &args.pdf {
+            let (pdf_width, pdf_height) = ImageGenerator::gradient_band_size(&args);
+            println!("Dry run: would save PDF shading ({pdf_width}x{pdf_height}) to: {pdf}");
+        }
+    } else {
+        if args.should_generate_svg() {
+            image_gen.generate_svg(&args, start_lab, end_lab)?;
+            println!("SVG gradient saved to: {}", args.svg_name());
+        }
+        if args.should_generate_png() {
+            image_gen.generate_png(&args, start_lab, end_lab)?;
+            println!("PNG gradient saved to: {}", args.png_name());
+        }
+        if let Some(strip) = &args.lightness_strip {
+            image_gen.generate_lightness_strip(&args, start_lab, end_lab, strip)?;
+            println!("Lightness strip saved to: {strip}");
+        }
+        if let Some(pdf) = &args.pdf {
+            image_gen.generate_pdf(&args, start_lab, end_lab, pdf)?;
+            println!("PDF shading saved to: {pdf}");
+        }
     }
 
     // Calculate gradient steps
-    let steps = if let Some(step_percent) = args.step {
+    let steps = if let Some(auto) = args.auto_stops {
+        GradientCalculator::auto_stop_count(auto.max, auto.min_delta_e, |count| {
+            stops_for_args(&args, start_lab, end_lab, count)
+        })?
+    } else if let Some(step_percent) = args.step {
         (100 / step_percent as usize).max(2)
     } else {
         args.stops
     };
 
     // Create unified color manager for color name lookups
-    let color_manager = UnifiedColorManager::new()?;
+    let color_manager = UnifiedColorManager::new();
+
+    // Endpoint RGB strings in the requested notation
+    let endpoint_rgb = |lab: Lab, (r, g, b): (u8, u8, u8)| match args.rgb_format {
+        crate::cli::RgbFormat::U8 => format!("rgb({r}, {g}, {b})"),
+        crate::cli::RgbFormat::Float => crate::format_utils::FormatUtils::lab_to_rgb_float(lab),
+    };
+    let start_rgb = endpoint_rgb(start_lab, start_color);
+    let end_rgb = endpoint_rgb(end_lab, end_color);
 
     // Calculate distance between start and end colors using Delta-E 2000
     let start_end_distance = calculate_distance(DistanceAlgorithm::DeltaE2000, start_lab, end_lab);
@@ -151,90 +224,38 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
         wcag_relative_luminance_rgb((start_color.0, start_color.1, start_color.2));
     let end_luminance = wcag_relative_luminance_rgb((end_color.0, end_color.1, end_color.2));
 
-    // Helper function to find color collections for a given RGB color
-    let find_color_collections = |rgb: [u8; 3]| -> ColorCollectionMatches {
-        let css_matches = color_manager.find_closest_css_colors(rgb, 1);
-        let ral_classic_matches = color_manager.find_closest_ral_classic(rgb, 1);
-        let ral_design_matches = color_manager.find_closest_ral_design(rgb, 1);
-
-        let css = if css_matches.is_empty() {
-            "Unknown | Unknown | #000000".to_string()
-        } else {
-            let m = &css_matches[0];
-            let hex = format!(
-                "#{:02X}{:02X}{:02X}",
-                m.entry.color.rgb[0], m.entry.color.rgb[1], m.entry.color.rgb[2]
-            );
-            format!(
-                "{} | {} | {}",
-                m.entry.metadata.code.as_deref().unwrap_or("unknown"),
-                m.entry.metadata.name,
-                hex
-            )
-        };
-
-        let ralc = if ral_classic_matches.is_empty() {
-            "Unknown | Unknown | #000000".to_string()
-        } else {
-            let m = &ral_classic_matches[0];
-            let hex = format!(
-                "#{:02X}{:02X}{:02X}",
-                m.entry.color.rgb[0], m.entry.color.rgb[1], m.entry.color.rgb[2]
-            );
-            format!(
-                "{} | {} | {}",
-                m.entry.metadata.code.as_deref().unwrap_or("unknown"),
-                m.entry.metadata.name,
-                hex
-            )
-        };
-
-        let raldsp = if ral_design_matches.is_empty() {
-            "Unknown | Unknown | #000000".to_string()
-        } else {
-            let m = &ral_design_matches[0];
-            let hex = format!(
-                "#{:02X}{:02X}{:02X}",
-                m.entry.color.rgb[0], m.entry.color.rgb[1], m.entry.color.rgb[2]
-            );
-            format!(
-                "{} | {} | {}",
-                m.entry.metadata.code.as_deref().unwrap_or("unknown"),
-                m.entry.metadata.name,
-                hex
-            )
-        };
-
-        ColorCollectionMatches {
-            css,
-            css_distance: css_matches.first().map_or(999.0, |m| m.distance),
-            ralc,
-            ralc_distance: ral_classic_matches.first().map_or(999.0, |m| m.distance),
-            raldsp,
-            raldsp_distance: ral_design_matches.first().map_or(999.0, |m| m.distance),
-        }
-    };
-
     // Find color collections for start and end colors
-    let start_collections = find_color_collections([start_color.0, start_color.1, start_color.2]);
-    let end_collections = find_color_collections([end_color.0, end_color.1, end_color.2]);
+    // (endpoints use the same nearest-name lookup as the gradient stops)
+    let start_collections = find_color_collections(
+        &color_manager,
+        [start_color.0, start_color.1, start_color.2],
+        naming_algorithm,
+    );
+    let end_collections = find_color_collections(
+        &color_manager,
+        [end_color.0, end_color.1, end_color.2],
+        naming_algorithm,
+    );
 
     // Generate gradient stops using unified calculation
-    let unified_stops = GradientCalculator::calculate_unified_gradient(
-        start_lab,
-        end_lab,
-        args.start_position,
-        args.end_position,
-        args.ease_in,
-        args.ease_out,
-        steps,
-        args.stops_simple,
+    let unified_stops = emission_order(
+        sort_stops(
+            stops_for_args(&args, start_lab, end_lab, steps)?,
+            args.sort_stops_by,
+        ),
+        args.descending,
     );
+    let translucent = unified_stops.iter().any(|stop| stop.alpha < 1.0);
 
     // Convert unified stops to old format for YAML output
     let mut gradient_stops = Vec::new();
     for stop in &unified_stops {
-        let hex = lab_to_hex(stop.lab_color);
+        let (hex, rgb) = stop_color_strings(
+            stop,
+            lab_to_hex(stop.lab_color),
+            translucent,
+            args.rgb_format,
+        );
         let luminance = wcag_relative_luminance_rgb(stop.rgb_color);
 
         // Calculate color distance from start_color using Delta E 2000
@@ -242,8 +263,12 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
             calculate_distance(DistanceAlgorithm::DeltaE2000, start_lab, stop.lab_color) as f32;
 
         // Find closest color names
-        let closest_css = color_manager
-            .find_closest_css_colors([stop.rgb_color.0, stop.rgb_color.1, stop.rgb_color.2], 1);
+        let closest_css = color_manager.find_closest_css_colors_with_algorithm(
+            [stop.rgb_color.0, stop.rgb_color.1, stop.rgb_color.2],
+            1,
+            None,
+            naming_algorithm,
+        );
         let color_name = if closest_css.is_empty() {
             None
         } else {
@@ -261,20 +286,12 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
         let gradient_stop = GradientStop {
             position: stop.position as u32,
             hex: hex.clone(),
-            rgb: format!(
-                "rgb({}, {}, {})",
-                stop.rgb_color.0, stop.rgb_color.1, stop.rgb_color.2
-            ),
+            rgb,
             lab: format!(
                 "lab({:.2}, {:.2}, {:.2})",
                 stop.lab_color.l, stop.lab_color.a, stop.lab_color.b
             ),
-            lch: format!(
-                "lch({:.2}, {:.2}, {:.1})",
-                stop.lab_color.l,
-                stop.lab_color.a.hypot(stop.lab_color.b),
-                stop.lab_color.b.atan2(stop.lab_color.a).to_degrees()
-            ),
+            lch: lch_string(stop.lab_color),
             wcag21_relative_luminance: luminance,
             distance,
             color_name,
@@ -286,7 +303,12 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
     // Generate enhanced gradient stops with nested color structure using unified data
     let mut enhanced_gradient_stops = Vec::new();
     for stop in &unified_stops {
-        let hex = lab_to_hex(stop.lab_color);
+        let (hex, rgb) = stop_color_strings(
+            stop,
+            lab_to_hex(stop.lab_color),
+            translucent,
+            args.rgb_format,
+        );
         let luminance = wcag_relative_luminance_rgb(stop.rgb_color);
 
         // Calculate color distance from start_color using Delta E 2000
@@ -294,27 +316,22 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
             calculate_distance(DistanceAlgorithm::DeltaE2000, start_lab, stop.lab_color) as f32;
 
         // Get color collections for this stop
-        let stop_collections =
-            find_color_collections([stop.rgb_color.0, stop.rgb_color.1, stop.rgb_color.2]);
+        let stop_collections = find_color_collections(
+            &color_manager,
+            [stop.rgb_color.0, stop.rgb_color.1, stop.rgb_color.2],
+            naming_algorithm,
+        );
 
         let enhanced_stop = EnhancedGradientStop {
             position: stop.position as u32,
             color: NestedColorInfo {
                 hex: hex.clone(),
-                rgb: format!(
-                    "rgb({}, {}, {})",
-                    stop.rgb_color.0, stop.rgb_color.1, stop.rgb_color.2
-                ),
+                rgb,
                 lab: format!(
                     "lab({:.2}, {:.2}, {:.2})",
                     stop.lab_color.l, stop.lab_color.a, stop.lab_color.b
                 ),
-                lch: format!(
-                    "lch({:.2}, {:.2}, {:.1})",
-                    stop.lab_color.l,
-                    stop.lab_color.a.hypot(stop.lab_color.b),
-                    stop.lab_color.b.atan2(stop.lab_color.a).to_degrees()
-                ),
+                lch: lch_string(stop.lab_color),
                 wcag21_relative_luminance: luminance,
                 distance,
             },
@@ -326,7 +343,7 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
 
     // Create enhanced gradient analysis
     let enhanced_gradient_analysis = EnhancedGradientAnalysisOutput {
-        metadata: ProgramMetadata::new(Some("Delta E 2000")),
+        metadata: ProgramMetadata::new(Some(naming_algorithm.name())),
         configuration: GradientConfiguration {
             start_color: args.start_color.clone(),
             end_color: args.end_color.clone(),
@@ -339,20 +356,12 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
         colors: GradientColors {
             start: ColorInfo {
                 hex: lab_to_hex(start_lab),
-                rgb: format!(
-                    "rgb({}, {}, {})",
-                    start_color.0, start_color.1, start_color.2
-                ),
+                rgb: start_rgb.clone(),
                 lab: format!(
                     "lab({:.2}, {:.2}, {:.2})",
                     start_lab.l, start_lab.a, start_lab.b
                 ),
-                lch: format!(
-                    "lch({:.2}, {:.2}, {:.1})",
-                    start_lab.l,
-                    start_lab.a.hypot(start_lab.b),
-                    start_lab.b.atan2(start_lab.a).to_degrees()
-                ),
+                lch: lch_string(start_lab),
                 contrast: Some(ContrastAnalysis {
                     distance: start_end_distance,
                     wcag21_relative_luminance: start_luminance,
@@ -362,14 +371,9 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
             },
             end: ColorInfo {
                 hex: lab_to_hex(end_lab),
-                rgb: format!("rgb({}, {}, {})", end_color.0, end_color.1, end_color.2),
+                rgb: end_rgb.clone(),
                 lab: format!("lab({:.2}, {:.2}, {:.2})", end_lab.l, end_lab.a, end_lab.b),
-                lch: format!(
-                    "lch({:.2}, {:.2}, {:.1})",
-                    end_lab.l,
-                    end_lab.a.hypot(end_lab.b),
-                    end_lab.b.atan2(end_lab.a).to_degrees()
-                ),
+                lch: lch_string(end_lab),
                 contrast: Some(ContrastAnalysis {
                     distance: start_end_distance,
                     wcag21_relative_luminance: end_luminance,
@@ -379,11 +383,16 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
             },
         },
         gradient_stops: enhanced_gradient_stops,
+        text_contrast: args
+            .text_contrast
+            .as_deref()
+            .map(|text| text_contrast_report(&unified_stops, text))
+            .transpose()?,
     };
 
     // Create complete gradient analysis (legacy format for compatibility)
     let _gradient_analysis = GradientAnalysisOutput {
-        metadata: ProgramMetadata::new(Some("Delta E 2000")),
+        metadata: ProgramMetadata::new(Some(naming_algorithm.name())),
         configuration: GradientConfiguration {
             start_color: args.start_color.clone(),
             end_color: args.end_color.clone(),
@@ -396,20 +405,12 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
         colors: GradientColors {
             start: ColorInfo {
                 hex: lab_to_hex(start_lab),
-                rgb: format!(
-                    "rgb({}, {}, {})",
-                    start_color.0, start_color.1, start_color.2
-                ),
+                rgb: start_rgb.clone(),
                 lab: format!(
                     "lab({:.2}, {:.2}, {:.2})",
                     start_lab.l, start_lab.a, start_lab.b
                 ),
-                lch: format!(
-                    "lch({:.2}, {:.2}, {:.1})",
-                    start_lab.l,
-                    start_lab.a.hypot(start_lab.b),
-                    start_lab.b.atan2(start_lab.a).to_degrees()
-                ),
+                lch: lch_string(start_lab),
                 contrast: Some(ContrastAnalysis {
                     distance: start_end_distance,
                     wcag21_relative_luminance: start_luminance,
@@ -419,14 +420,9 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
             },
             end: ColorInfo {
                 hex: lab_to_hex(end_lab),
-                rgb: format!("rgb({}, {}, {})", end_color.0, end_color.1, end_color.2),
+                rgb: end_rgb.clone(),
                 lab: format!("lab({:.2}, {:.2}, {:.2})", end_lab.l, end_lab.a, end_lab.b),
-                lch: format!(
-                    "lch({:.2}, {:.2}, {:.1})",
-                    end_lab.l,
-                    end_lab.a.hypot(end_lab.b),
-                    end_lab.b.atan2(end_lab.a).to_degrees()
-                ),
+                lch: lch_string(end_lab),
                 contrast: Some(ContrastAnalysis {
                     distance: start_end_distance,
                     wcag21_relative_luminance: end_luminance,
@@ -450,12 +446,115 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
         crate::cli::OutputFormat::Yaml => enhanced_gradient_analysis.to_yaml().map_err(|e| {
             crate::error::ColorError::InvalidArguments(format!("Failed to serialize to YAML: {e}"))
         })?,
+        crate::cli::OutputFormat::Env => enhanced_gradient_analysis.to_env().map_err(|e| {
+            crate::error::ColorError::InvalidArguments(format!("Failed to serialize to env: {e}"))
+        })?,
+        crate::cli::OutputFormat::Rust => enhanced_gradient_analysis.to_rust_const(),
+        crate::cli::OutputFormat::Markdown => enhanced_gradient_analysis.to_markdown(),
+        format @ crate::cli::OutputFormat::CssVars => {
+            return Err(format.unsupported_by(
+                "the gradient command",
+                crate::cli::GradientArgs::OUTPUT_FORMATS,
+            ));
+        }
     };
+    let output =
+        crate::output_formats::localize_decimal_separator(&output, format, args.decimal_separator);
 
-    // Display to terminal with colorization (like color command)
-    display_colorized_gradient_output(&output, format);
+    // Display to terminal: a quick-look table, or colorized structured output
+    if args.table {
+        println!("{}", gradient_table(&unified_stops)?);
+        if let Some(report) = &enhanced_gradient_analysis.text_contrast {
+            println!(
+                "Minimum contrast against {}: {:.2}:1 at {} ({})",
+                report.text, report.min_ratio, report.worst_position, report.worst_hex
+            );
+        }
+    } else {
+        display_colorized_gradient_output(&output, format);
+    }
 
     // Save to file if requested
+    save_structured_output(&args, format, &output, "Gradient analysis")
+}
+
+/// Worst-case contrast of the emitted stops against the `--text-contrast` color
+fn text_contrast_report(
+    stops: &[UnifiedGradientStop],
+    text: &str,
+) -> crate::error::Result<crate::output_formats::GradientTextContrast> {
+    use crate::config::algorithm_constants;
+    use palette::{IntoColor, Srgb};
+
+    let text_srgb: Srgb = crate::color::parse_color_input(text)?.into_color();
+    let values = gradient_values(stops);
+    let (worst, ratio) = worst_contrast_against(&values, text_srgb).ok_or_else(|| {
+        crate::error::ColorError::InvalidArguments("Gradient has no stops".to_string())
+    })?;
+
+    Ok(crate::output_formats::GradientTextContrast {
+        text: text.to_string(),
+        hex: crate::color_ops::conversion::srgb_to_hex(text_srgb),
+        min_ratio: ratio,
+        worst_position: worst.position.clone(),
+        worst_hex: worst.hex.clone(),
+        aa_normal_text: ratio >= algorithm_constants::WCAG_AA_THRESHOLD,
+        aa_large_text: ratio >= algorithm_constants::WCAG_AA_LARGE_THRESHOLD,
+    })
+}
+
+/// Print and optionally save the colors at each `--sample-at` fraction
+fn print_gradient_samples(
+    args: &crate::cli::GradientArgs,
+    start_lab: palette::Lab,
+    end_lab: palette::Lab,
+) -> crate::error::Result<()> {
+    use crate::cli::OutputFormat;
+    use crate::error::ColorError;
+    use crate::output_formats::{GradientSample, GradientSamplesOutput, ProgramMetadata};
+
+    let samples = GradientSamplesOutput {
+        metadata: ProgramMetadata::without_distance(),
+        samples: GradientCalculator::colors_at(args, start_lab, end_lab, &args.sample_at)
+            .into_iter()
+            .map(|(t, value)| GradientSample {
+                t,
+                position: value.position,
+                hex: value.hex,
+                rgb: value.rgb,
+            })
+            .collect(),
+    };
+
+    let format = args.output_format.as_ref().unwrap_or(&OutputFormat::Yaml);
+    let output = match format {
+        OutputFormat::Yaml => samples.to_yaml().map_err(|e| {
+            ColorError::InvalidArguments(format!("Failed to serialize to YAML: {e}"))
+        })?,
+        OutputFormat::Toml => samples.to_toml().map_err(|e| {
+            ColorError::InvalidArguments(format!("Failed to serialize to TOML: {e}"))
+        })?,
+        OutputFormat::Env | OutputFormat::Rust | OutputFormat::Markdown | OutputFormat::CssVars => {
+            return Err(format.unsupported_by("gradient --sample-at", OutputFormat::STRUCTURED));
+        }
+    };
+    let output =
+        crate::output_formats::localize_decimal_separator(&output, format, args.decimal_separator);
+
+    display_colorized_gradient_output(&output, format);
+    save_structured_output(args, format, &output, "Gradient samples")
+}
+
+/// Write structured gradient output to the `--file` name, if one was given
+///
+/// The format's extension is added when the name has none; `label` names the
+/// content in the status line.
+fn save_structured_output(
+    args: &crate::cli::GradientArgs,
+    format: &crate::cli::OutputFormat,
+    output: &str,
+    label: &str,
+) -> crate::error::Result<()> {
     if let Some(filename) = &args.output_file {
         use std::fs::File;
         use std::io::Write;
@@ -463,6 +562,10 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
         let extension = match format {
             crate::cli::OutputFormat::Toml => "toml",
             crate::cli::OutputFormat::Yaml => "yaml",
+            crate::cli::OutputFormat::Env => "env",
+            crate::cli::OutputFormat::Rust => "rs",
+            crate::cli::OutputFormat::Markdown => "md",
+            crate::cli::OutputFormat::CssVars => "css",
         };
 
         let full_filename = if filename.contains('.') {
@@ -471,14 +574,342 @@ pub fn generate_gradient(args: crate::cli::GradientArgs) -> crate::error::Result
             format!("{filename}.{extension}")
         };
 
-        let mut file = File::create(&full_filename)?;
-        file.write_all(output.as_bytes())?;
-        println!("Gradient analysis saved to: {full_filename}");
+        if args.dry_run {
+            println!(
+                "Dry run: would save {} ({} bytes) to: {full_filename}",
+                label.to_lowercase(),
+                output.len()
+            );
+        } else {
+            let mut file = File::create(&full_filename)?;
+            file.write_all(output.as_bytes())?;
+            println!("{label} saved to: {full_filename}");
+        }
     }
 
     Ok(())
 }
 
+/// Find the nearest CSS, RAL Classic and RAL Design names for an RGB color
+///
+/// Shared by the gradient endpoints and every gradient stop so all entries in the
+/// structured output carry the same self-describing collection information.
+/// Matches are ranked and their distances reported under `algorithm`.
+fn find_color_collections(
+    color_manager: &crate::color_parser::UnifiedColorManager,
+    rgb: [u8; 3],
+    algorithm: crate::color_distance_strategies::DistanceAlgorithm,
+) -> crate::output_formats::ColorCollectionMatches {
+    let css_matches = color_manager.find_closest_css_colors_with_algorithm(rgb, 1, None, algorithm);
+    let ral_classic_matches =
+        color_manager.find_closest_ral_classic_with_algorithm(rgb, 1, None, algorithm);
+    let ral_design_matches =
+        color_manager.find_closest_ral_design_with_algorithm(rgb, 1, None, algorithm);
+
+    crate::output_formats::ColorCollectionMatches {
+        css: format_collection_match(&css_matches),
+        css_distance: css_matches.first().map_or(999.0, |m| m.distance),
+        ralc: format_collection_match(&ral_classic_matches),
+        ralc_distance: ral_classic_matches.first().map_or(999.0, |m| m.distance),
+        raldsp: format_collection_match(&ral_design_matches),
+        raldsp_distance: ral_design_matches.first().map_or(999.0, |m| m.distance),
+    }
+}
+
+/// Format the best collection match as `code | name | #RRGGBB`
+fn format_collection_match(matches: &[crate::color_parser::ColorMatch]) -> String {
+    matches.first().map_or_else(
+        || "Unknown | Unknown | #000000".to_string(),
+        |m| {
+            format!(
+                "{} | {} | #{:02X}{:02X}{:02X}",
+                m.entry.metadata.code.as_deref().unwrap_or("unknown"),
+                m.entry.metadata.name,
+                m.entry.color.rgb[0],
+                m.entry.color.rgb[1],
+                m.entry.color.rgb[2]
+            )
+        },
+    )
+}
+
+/// Center color of a `--diverging` gradient, or `None` for a plain gradient
+///
+/// Without `--center`, the center is the neutral gray at the mean LAB lightness
+/// of the endpoints.
+///
+/// # Errors
+/// Returns an error if the `--center` color cannot be parsed.
+pub fn diverging_center(
+    args: &crate::cli::GradientArgs,
+    start_lab: palette::Lab,
+    end_lab: palette::Lab,
+) -> crate::error::Result<Option<palette::Lab>> {
+    if !args.diverging {
+        return Ok(None);
+    }
+    match &args.center {
+        Some(center) => crate::color::parse_color_input(center).map(Some),
+        None => Ok(Some(palette::Lab::new(
+            f32::midpoint(start_lab.l, end_lab.l),
+            0.0,
+            0.0,
+        ))),
+    }
+}
+
+/// Calculate the stops `args` asks for, diverging through a center color if requested
+///
+/// When either endpoint is translucent (`#RRGGBBAA`, `rgba()`, `hsla()`), alpha is
+/// interpolated linearly across the stops. With `--overshoot`, the endpoints are
+/// first pushed outward along the LAB path (see
+/// [`GradientCalculator::overshoot_endpoints`]).
+///
+/// # Errors
+/// Returns an error if the position range is empty or the `--center` color
+/// cannot be parsed.
+pub fn stops_for_args(
+    args: &crate::cli::GradientArgs,
+    start_lab: palette::Lab,
+    end_lab: palette::Lab,
+    steps: usize,
+) -> crate::error::Result<Vec<UnifiedGradientStop>> {
+    GradientCalculator::check_position_range(args.start_position, args.end_position)?;
+    let given_endpoints = (start_lab, end_lab);
+    let (start_lab, end_lab) = if args.overshoot > 0.0 {
+        GradientCalculator::overshoot_endpoints(start_lab, end_lab, args.overshoot / 100.0)
+    } else {
+        (start_lab, end_lab)
+    };
+    let mut stops = match diverging_center(args, start_lab, end_lab)? {
+        Some(center_lab) => GradientCalculator::calculate_diverging_gradient(
+            start_lab,
+            center_lab,
+            end_lab,
+            args.start_position,
+            args.end_position,
+            args.ease_in,
+            args.ease_out,
+            steps,
+            args.stops_simple,
+            args.stop_samples,
+        ),
+        None if args.stops_simple && args.color_space == crate::color_ops::ColorSpace::Lab => {
+            GradientCalculator::calculate_unified_gradient(
+                start_lab,
+                end_lab,
+                args.start_position,
+                args.end_position,
+                args.ease_in,
+                args.ease_out,
+                steps,
+                true,
+            )
+        }
+        None => GradientCalculator::calculate_unified_gradient_in_space(
+            start_lab,
+            end_lab,
+            args.start_position,
+            args.end_position,
+            args.ease_in,
+            args.ease_out,
+            steps,
+            args.color_space,
+            args.hue_direction,
+            args.stop_samples,
+        ),
+    };
+
+    if let Some(easing) = args.stepped_easing() {
+        GradientCalculator::apply_stepped_easing(&mut stops, args, start_lab, end_lab, &easing);
+    }
+
+    if let Some(threshold) = args.refine_threshold {
+        stops = GradientCalculator::refine_stops(
+            stops,
+            args,
+            given_endpoints.0,
+            given_endpoints.1,
+            threshold,
+            crate::config::MAX_REFINED_STOPS,
+        );
+    }
+
+    if args.scale == crate::cli::PositionScale::Log {
+        GradientCalculator::apply_log_scale(&mut stops, args.start_position, args.end_position);
+    }
+
+    let parser = crate::color_parser::CssColorParser::new();
+    let alpha = |color: &str| parser.parse(color).map_or(1.0, |parsed| parsed.a);
+    let (start_alpha, end_alpha) = (alpha(&args.start_color), alpha(&args.end_color));
+    if start_alpha < 1.0 || end_alpha < 1.0 {
+        GradientCalculator::apply_alpha(&mut stops, start_alpha, end_alpha);
+    }
+
+    Ok(stops)
+}
+
+/// `lch()` string for a gradient color, with the hue in [0, 360)
+///
+/// The hue is rounded to the printed precision before normalizing, so hues
+/// just below 360° read `0.0` rather than `360.0`.
+fn lch_string(lab: palette::Lab) -> String {
+    let hue = f64::from(lab.b.atan2(lab.a).to_degrees());
+    format!(
+        "lch({:.2}, {:.2}, {:.1})",
+        lab.l,
+        lab.a.hypot(lab.b),
+        crate::color_ops::analysis::hue::normalize_hue((hue * 10.0).round() / 10.0)
+    )
+}
+
+/// Hex and RGB strings for a stop: `#rrggbbaa` and `rgba()` in translucent gradients
+fn stop_color_strings(
+    stop: &UnifiedGradientStop,
+    hex: String,
+    translucent: bool,
+    rgb_format: crate::cli::RgbFormat,
+) -> (String, String) {
+    let (r, g, b) = stop.rgb_color;
+    let channels = match rgb_format {
+        crate::cli::RgbFormat::U8 => format!("{r}, {g}, {b}"),
+        crate::cli::RgbFormat::Float => {
+            let rgb = crate::format_utils::FormatUtils::lab_to_rgb_float(stop.lab_color);
+            rgb["rgb(".len()..rgb.len() - 1].to_string()
+        }
+    };
+    if translucent {
+        (
+            format!("{hex}{:02x}", stop.alpha_byte()),
+            format!(
+                "rgba({channels}, {})",
+                crate::precision_utils::PrecisionUtils::format_f64(stop.alpha)
+            ),
+        )
+    } else {
+        (hex, format!("rgb({channels})"))
+    }
+}
+
+/// Reorder stops by a color property, ascending
+///
+/// Only the listing order changes; each stop keeps its position and color.
+/// Ties keep their position order.
+#[must_use]
+pub fn sort_stops(
+    mut stops: Vec<UnifiedGradientStop>,
+    key: crate::cli::StopSortKey,
+) -> Vec<UnifiedGradientStop> {
+    use crate::cli::StopSortKey;
+    use palette::{IntoColor, Lch};
+
+    let property = |stop: &UnifiedGradientStop| -> f32 {
+        let lch: Lch = stop.lab_color.into_color();
+        match key {
+            StopSortKey::Position => f32::from(stop.position),
+            StopSortKey::Lightness => stop.lab_color.l,
+            StopSortKey::Hue => lch.hue.into_positive_degrees(),
+            StopSortKey::Chroma => lch.chroma,
+        }
+    };
+    if key != StopSortKey::Position {
+        stops.sort_by(|a, b| property(a).total_cmp(&property(b)));
+    }
+    stops
+}
+
+/// Order stops for output: start-to-end, or end-to-start with `descending`
+///
+/// Only the listing order changes; each stop keeps its position and color.
+#[must_use]
+pub fn emission_order(
+    mut stops: Vec<UnifiedGradientStop>,
+    descending: bool,
+) -> Vec<UnifiedGradientStop> {
+    if descending {
+        stops.reverse();
+    }
+    stops
+}
+
+/// Render gradient stops as a position/hex/RGB/WCAG luminance table
+///
+/// # Errors
+/// Returns an error if table formatting fails.
+pub fn gradient_table(stops: &[UnifiedGradientStop]) -> crate::error::Result<String> {
+    GradientFormat::Table.format_gradient(&gradient_values(stops))
+}
+
+/// Stops as display values; HEX gains an alpha byte when any stop is translucent
+fn gradient_values(stops: &[UnifiedGradientStop]) -> Vec<GradientValue> {
+    let translucent = stops.iter().any(|stop| stop.alpha < 1.0);
+    stops
+        .iter()
+        .map(|stop| {
+            let (r, g, b) = stop.rgb_color;
+            let srgb = palette::Srgb::new(r, g, b).into_format::<f32>();
+            GradientValue {
+                position: format!("{}%", stop.position),
+                hex: if translucent {
+                    format!("#{r:02X}{g:02X}{b:02X}{:02X}", stop.alpha_byte())
+                } else {
+                    format!("#{r:02X}{g:02X}{b:02X}")
+                },
+                rgb: crate::utils::Utils::rgb_to_string(r, g, b),
+                wcag_luminance:
+                    crate::precision_utils::PrecisionUtils::format_wcag_relative_luminance(
+                        crate::color_ops::luminance::wcag_relative(srgb),
+                    ),
+            }
+        })
+        .collect()
+}
+
+/// Lowest WCAG contrast ratio of any stop against `text`
+///
+/// Returns `f64::INFINITY` when there are no stops. Stops whose HEX cannot be
+/// parsed are skipped; an alpha byte (`#RRGGBBAA`) is ignored.
+///
+/// # Example
+/// ```rust
+/// use color_rs::gradient::{GradientValue, min_contrast_against};
+/// use palette::Srgb;
+///
+/// let stop = |hex: &str| GradientValue {
+///     position: String::new(),
+///     hex: hex.to_string(),
+///     rgb: String::new(),
+///     wcag_luminance: String::new(),
+/// };
+/// let ratio = min_contrast_against(&[stop("#FFFFFF"), stop("#767676")], Srgb::new(1.0, 1.0, 1.0));
+/// assert!(ratio < 1.01);
+/// ```
+#[must_use]
+pub fn min_contrast_against(stops: &[GradientValue], text: palette::Srgb) -> f64 {
+    worst_contrast_against(stops, text).map_or(f64::INFINITY, |(_, ratio)| ratio)
+}
+
+/// Stop with the lowest WCAG contrast ratio against `text`, and that ratio
+///
+/// The first stop wins ties. Returns `None` when no stop has a parseable HEX.
+#[must_use]
+pub fn worst_contrast_against(
+    stops: &[GradientValue],
+    text: palette::Srgb,
+) -> Option<(&GradientValue, f64)> {
+    stops
+        .iter()
+        .filter_map(|stop| {
+            let hex = stop.hex.get(..7).unwrap_or(&stop.hex);
+            let color = crate::color_ops::conversion::hex_to_srgb(hex).ok()?;
+            Some((stop, crate::color_ops::contrast::wcag_ratio(color, text)))
+        })
+        .fold(None, |worst, (stop, ratio)| match worst {
+            Some((_, lowest)) if lowest <= ratio => worst,
+            _ => Some((stop, ratio)),
+        })
+}
+
 /// Display TOML/YAML output to terminal with colorization (copied from color.rs)
 fn display_colorized_gradient_output(content: &str, format: &crate::cli::OutputFormat) {
     for line in content.lines() {
@@ -527,6 +958,13 @@ fn colorize_structured_line(line: &str, format: &crate::cli::OutputFormat) -> St
                 line.to_string()
             }
         }
+        crate::cli::OutputFormat::Env => trimmed.split_once('=').map_or_else(
+            || line.to_string(),
+            |(key, value)| format!("{}={}", key.green(), value),
+        ),
+        crate::cli::OutputFormat::Rust
+        | crate::cli::OutputFormat::Markdown
+        | crate::cli::OutputFormat::CssVars => line.to_string(),
     }
 }
 
@@ -540,4 +978,686 @@ mod tests {
         let _easing_type = EasingType::Linear;
         // Test passes if compilation succeeds
     }
+
+    #[test]
+    fn test_endpoint_collection_names() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("gradient.yaml");
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "gradient",
+            "red",
+            "blue",
+            "--output",
+            "yaml",
+            "--file",
+            data.to_str().unwrap(),
+        ])
+        .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        generate_gradient(args).unwrap();
+
+        let yaml: serde_yml::Value =
+            serde_yml::from_str(&std::fs::read_to_string(&data).unwrap()).unwrap();
+        let start = &yaml["colors"]["start"]["collections"];
+        let end = &yaml["colors"]["end"]["collections"];
+        assert!(
+            start["css"].as_str().unwrap().starts_with("red |"),
+            "{start:?}"
+        );
+        assert!(start["ralc"].as_str().unwrap().starts_with("RAL "));
+        assert!(
+            end["css"].as_str().unwrap().starts_with("blue |"),
+            "{end:?}"
+        );
+    }
+
+    #[test]
+    fn test_naming_method_changes_borderline_stop() {
+        use crate::color_distance_strategies::DistanceAlgorithm;
+
+        // #000066 sits between Navy and Midnight Blue
+        let manager = crate::color_parser::UnifiedColorManager::new();
+        let perceptual =
+            find_color_collections(&manager, [0, 0, 102], DistanceAlgorithm::DeltaE2000);
+        let euclidean = find_color_collections(&manager, [0, 0, 102], DistanceAlgorithm::DeltaE76);
+
+        assert!(perceptual.css.contains("Navy"));
+        assert!(euclidean.css.contains("Midnight Blue"));
+    }
+
+    #[test]
+    fn test_dry_run_writes_no_files() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let svg = dir.path().join("gradient.svg");
+        let png = dir.path().join("gradient.png");
+        let data = dir.path().join("gradient");
+
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "gradient",
+            "red",
+            "blue",
+            "--svg",
+            svg.to_str().unwrap(),
+            "--png",
+            png.to_str().unwrap(),
+            "--file",
+            data.to_str().unwrap(),
+            "--dry-run",
+        ])
+        .unwrap();
+        let Commands::Gradient(args) = cli.into_command() else {
+            panic!("expected gradient command");
+        };
+
+        assert!(generate_gradient(args).is_ok());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_gradient_table_rows() {
+        use palette::Lab;
+
+        let stops = GradientCalculator::calculate_unified_gradient(
+            Lab::new(0.0, 0.0, 0.0),
+            Lab::new(100.0, 0.0, 0.0),
+            0,
+            100,
+            0.65,
+            0.35,
+            6,
+            false,
+        );
+        let table = gradient_table(&stops).unwrap();
+
+        let rows: Vec<&str> = table.lines().filter(|line| line.contains('│')).collect();
+        assert!(rows[0].contains("Position"));
+        assert!(rows[0].contains("WCAG Luminance"));
+        assert_eq!(rows.len(), 1 + stops.len());
+        assert!(rows[1].contains("#000000"));
+        assert!(rows[6].contains("#FFFFFF"));
+    }
+
+    #[test]
+    fn test_min_contrast_against_mid_gray_text() {
+        use palette::{Lab, Srgb};
+
+        let stops = GradientCalculator::calculate_unified_gradient(
+            Lab::new(100.0, 0.0, 0.0),
+            Lab::new(0.0, 0.0, 0.0),
+            0,
+            100,
+            0.0,
+            1.0,
+            11,
+            false,
+        );
+        let values = gradient_values(&stops);
+
+        // Mid gray text is lost where the gradient passes its lightness
+        let gray = Srgb::new(0.5, 0.5, 0.5);
+        let ratio = min_contrast_against(&values, gray);
+        assert!(ratio < 1.5, "min contrast {ratio}");
+        let (worst, _) = worst_contrast_against(&values, gray).unwrap();
+        assert!(worst.position != "0%" && worst.position != "100%");
+
+        // Black text is worst over the black end, where contrast is 1:1
+        let black = Srgb::new(0.0, 0.0, 0.0);
+        assert!((min_contrast_against(&values, black) - 1.0).abs() < 1e-6);
+        assert_eq!(
+            worst_contrast_against(&values, black).unwrap().0.position,
+            "100%"
+        );
+        assert!(min_contrast_against(&[], black).is_infinite());
+    }
+
+    #[test]
+    fn test_descending_emits_end_position_first() {
+        use palette::Lab;
+
+        let stops = GradientCalculator::calculate_unified_gradient(
+            Lab::new(0.0, 0.0, 0.0),
+            Lab::new(100.0, 0.0, 0.0),
+            10,
+            90,
+            0.65,
+            0.35,
+            5,
+            false,
+        );
+        let descending = emission_order(stops.clone(), true);
+        assert_eq!(descending[0].position, 90);
+        assert_eq!(descending.last().unwrap().position, 10);
+        assert_eq!(descending[0].rgb_color, stops.last().unwrap().rgb_color);
+
+        let table = gradient_table(&descending).unwrap();
+        let rows: Vec<&str> = table.lines().filter(|line| line.contains('│')).collect();
+        assert!(rows[1].contains("90%"));
+        assert!(rows.last().unwrap().contains("10%"));
+    }
+
+    #[test]
+    fn test_sort_stops_by_lightness() {
+        use crate::cli::StopSortKey;
+        use palette::Lab;
+
+        let red = Lab::new(53.24, 80.09, 67.2);
+        let blue = Lab::new(32.3, 79.19, -107.86);
+        let stops =
+            GradientCalculator::calculate_unified_gradient(red, blue, 0, 100, 0.65, 0.35, 7, true);
+
+        let sorted = sort_stops(stops.clone(), StopSortKey::Lightness);
+        assert_eq!(sorted.len(), stops.len());
+        assert!(
+            sorted
+                .windows(2)
+                .all(|pair| pair[0].lab_color.l <= pair[1].lab_color.l)
+        );
+        let mut positions: Vec<u8> = sorted.iter().map(|stop| stop.position).collect();
+        positions.sort_unstable();
+        assert_eq!(
+            positions,
+            stops.iter().map(|stop| stop.position).collect::<Vec<_>>()
+        );
+        assert_ne!(sorted[0].position, 0);
+
+        let unchanged = sort_stops(stops.clone(), StopSortKey::Position);
+        assert!(
+            unchanged
+                .iter()
+                .zip(&stops)
+                .all(|(a, b)| a.position == b.position)
+        );
+    }
+
+    #[test]
+    fn test_log_scale_crowds_stops_toward_start() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let stops_for = |scale: &str| {
+            let cli = Cli::try_parse_from([
+                "color-rs", "gradient", "red", "blue", "-s", "1", "--stops", "6", "--scale", scale,
+            ])
+            .unwrap();
+            let Commands::Gradient(args) = cli.command else {
+                panic!("expected gradient command");
+            };
+            args.validate().unwrap();
+            let start = crate::color::parse_color_input(&args.start_color).unwrap();
+            let end = crate::color::parse_color_input(&args.end_color).unwrap();
+            stops_for_args(&args, start, end, args.stops).unwrap()
+        };
+        let linear = stops_for("linear");
+        let log = stops_for("log");
+
+        assert_eq!(log[0].position, 1);
+        assert_eq!(log.last().unwrap().position, 100);
+        assert!(log[1].position - log[0].position < linear[1].position - linear[0].position);
+        for (log_stop, linear_stop) in log.iter().zip(&linear) {
+            assert!(log_stop.position <= linear_stop.position);
+            assert_eq!(log_stop.rgb_color, linear_stop.rgb_color);
+        }
+
+        let cli =
+            Cli::try_parse_from(["color-rs", "gradient", "red", "blue", "--scale", "log"]).unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        assert!(args.validate().is_err());
+    }
+
+    #[test]
+    fn test_refine_threshold_bounds_neighbor_delta_e() {
+        use crate::cli::{Cli, Commands};
+        use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+        use clap::Parser;
+
+        let stops_for = |extra: &[&str]| {
+            let mut argv = vec!["color-rs", "gradient", "red", "blue", "--stops", "3"];
+            argv.extend(extra);
+            let Commands::Gradient(args) = Cli::try_parse_from(argv).unwrap().command else {
+                panic!("expected gradient command");
+            };
+            args.validate().unwrap();
+            let start = crate::color::parse_color_input(&args.start_color).unwrap();
+            let end = crate::color::parse_color_input(&args.end_color).unwrap();
+            stops_for_args(&args, start, end, args.stops).unwrap()
+        };
+        let max_neighbor_delta_e = |stops: &[UnifiedGradientStop]| {
+            stops
+                .windows(2)
+                .map(|pair| {
+                    calculate_distance(
+                        DistanceAlgorithm::DeltaE2000,
+                        pair[0].lab_color,
+                        pair[1].lab_color,
+                    )
+                })
+                .fold(0.0, f64::max)
+        };
+
+        let coarse = stops_for(&[]);
+        let refined = stops_for(&["--refine-threshold", "5"]);
+        assert!(max_neighbor_delta_e(&coarse) > 5.0);
+        assert!(max_neighbor_delta_e(&refined) <= 5.0);
+
+        // The original stops are kept, in order, with midpoints between them
+        assert!(refined.len() > coarse.len());
+        assert_eq!(refined[0].rgb_color, coarse[0].rgb_color);
+        assert_eq!(
+            refined.last().unwrap().rgb_color,
+            coarse.last().unwrap().rgb_color
+        );
+        assert!(
+            refined
+                .windows(2)
+                .all(|pair| pair[0].geometric_t < pair[1].geometric_t)
+        );
+
+        // A tiny threshold stops at the cap instead of looping forever
+        let capped = stops_for(&["--refine-threshold", "0.001"]);
+        assert_eq!(capped.len(), crate::config::MAX_REFINED_STOPS);
+    }
+
+    #[test]
+    fn test_stop_samples_sets_stop_search_resolution() {
+        use crate::cli::{Cli, Commands};
+        use crate::color_distance_strategies::{DistanceAlgorithm, calculate_distance};
+        use clap::Parser;
+
+        let stops_for = |samples: &str| {
+            let cli = Cli::try_parse_from([
+                "color-rs",
+                "gradient",
+                "red",
+                "blue",
+                "--ease-in",
+                "0.95",
+                "--ease-out",
+                "0.05",
+                "--stop-samples",
+                samples,
+            ])
+            .unwrap();
+            let Commands::Gradient(args) = cli.command else {
+                panic!("expected gradient command");
+            };
+            args.validate().unwrap();
+            let start = crate::color::parse_color_input(&args.start_color).unwrap();
+            let end = crate::color::parse_color_input(&args.end_color).unwrap();
+            stops_for_args(&args, start, end, args.stops).unwrap()
+        };
+        // Largest miss between a stop's ΔE from the start and its equal-step target
+        let max_error = |stops: &[UnifiedGradientStop]| {
+            let start = stops[0].lab_color;
+            let total = calculate_distance(
+                DistanceAlgorithm::DeltaE2000,
+                start,
+                stops.last().unwrap().lab_color,
+            );
+            let step = total / (stops.len() - 1) as f64;
+            stops
+                .iter()
+                .enumerate()
+                .map(|(i, stop)| {
+                    let distance =
+                        calculate_distance(DistanceAlgorithm::DeltaE2000, start, stop.lab_color);
+                    (distance - step * i as f64).abs()
+                })
+                .fold(0.0, f64::max)
+        };
+
+        let coarse = stops_for("100");
+        let fine = stops_for("100000");
+        assert_ne!(
+            coarse
+                .iter()
+                .map(|stop| stop.geometric_t)
+                .collect::<Vec<_>>(),
+            fine.iter().map(|stop| stop.geometric_t).collect::<Vec<_>>()
+        );
+        assert!(max_error(&fine) < max_error(&coarse));
+        assert!(max_error(&fine) < 0.05, "{}", max_error(&fine));
+    }
+
+    #[test]
+    fn test_hue_direction_picks_arc_in_hsl_gradient() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let colors_for = |direction: &str| {
+            let cli = Cli::try_parse_from([
+                "color-rs",
+                "gradient",
+                "red",
+                "blue",
+                "--color-space",
+                "hsl",
+                "--hue-direction",
+                direction,
+                "--stops",
+                "9",
+            ])
+            .unwrap();
+            let Commands::Gradient(args) = cli.command else {
+                panic!("expected gradient command");
+            };
+            args.validate().unwrap();
+            let start = crate::color::parse_color_input(&args.start_color).unwrap();
+            let end = crate::color::parse_color_input(&args.end_color).unwrap();
+            stops_for_args(&args, start, end, args.stops)
+                .unwrap()
+                .into_iter()
+                .map(|stop| stop.rgb_color)
+                .collect::<Vec<_>>()
+        };
+        let passes_green =
+            |colors: &[(u8, u8, u8)]| colors.iter().any(|&(r, g, _)| g > 200 && r < 120);
+
+        let long = colors_for("long");
+        let short = colors_for("short");
+        assert!(passes_green(&long), "{long:?}");
+        assert!(!passes_green(&short), "{short:?}");
+        assert_eq!(colors_for("cw"), long);
+        assert_eq!(colors_for("ccw"), short);
+    }
+
+    #[test]
+    fn test_overshoot_extends_endpoints_within_gamut() {
+        use crate::cli::{Cli, Commands};
+        use crate::color_ops::Gamut;
+        use clap::Parser;
+
+        let stops_for = |colors: [&str; 2], overshoot: &str| {
+            let cli = Cli::try_parse_from([
+                "color-rs",
+                "gradient",
+                colors[0],
+                colors[1],
+                "--overshoot",
+                overshoot,
+            ])
+            .unwrap();
+            let Commands::Gradient(args) = cli.command else {
+                panic!("expected gradient command");
+            };
+            args.validate().unwrap();
+            let start = crate::color::parse_color_input(&args.start_color).unwrap();
+            let end = crate::color::parse_color_input(&args.end_color).unwrap();
+            stops_for_args(&args, start, end, 5).unwrap()
+        };
+
+        let plain = stops_for(["#606060", "#A0A0A0"], "0");
+        let extended = stops_for(["#606060", "#A0A0A0"], "20");
+        assert!(extended[0].lab_color.l < plain[0].lab_color.l - 1.0);
+        assert!(extended[4].lab_color.l > plain[4].lab_color.l + 1.0);
+        assert_eq!(extended[0].position, plain[0].position);
+        assert_eq!(extended[4].position, plain[4].position);
+        let with_sign = stops_for(["#606060", "#A0A0A0"], "20%");
+        assert_eq!(with_sign[0].rgb_color, extended[0].rgb_color);
+        assert_eq!(with_sign[4].rgb_color, extended[4].rgb_color);
+
+        // Black and white are already on the gamut boundary, so they cannot move
+        let clamped = stops_for(["black", "white"], "50");
+        assert_eq!(clamped[0].rgb_color, (0, 0, 0));
+        assert_eq!(clamped[4].rgb_color, (255, 255, 255));
+
+        let vivid = stops_for(["#3366CC", "#CC9933"], "100");
+        for stop in [&vivid[0], &vivid[4]] {
+            assert!(Gamut::Srgb.contains(stop.lab_color));
+        }
+    }
+
+    #[test]
+    fn test_auto_stops_follow_the_color_space_path() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let count_for = |extra: &[&str]| {
+            let mut argv = vec![
+                "color-rs",
+                "gradient",
+                "red",
+                "blue",
+                "--auto-stops",
+                "max=40,min-delta-e=10",
+            ];
+            argv.extend_from_slice(extra);
+            let Commands::Gradient(args) = Cli::try_parse_from(argv).unwrap().into_command() else {
+                panic!("expected gradient command");
+            };
+            let start = crate::color::parse_color_input(&args.start_color).unwrap();
+            let end = crate::color::parse_color_input(&args.end_color).unwrap();
+            GradientCalculator::auto_stop_count(40, 10.0, |count| {
+                stops_for_args(&args, start, end, count)
+            })
+            .unwrap()
+        };
+
+        // The long HSL arc passes through yellow, green and cyan, so it fits
+        // far more stops ΔE 10 apart than the direct LAB path does
+        let lab = count_for(&[]);
+        let hsl_long = count_for(&["--color-space", "hsl", "--hue-direction", "long"]);
+        assert!(hsl_long > lab, "hsl long arc {hsl_long} vs lab {lab}");
+    }
+
+    #[test]
+    fn test_zero_width_position_range_is_rejected() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "gradient",
+            "red",
+            "blue",
+            "--start-position",
+            "50",
+            "--end-position",
+            "50",
+        ])
+        .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        assert!(args.validate().is_err());
+
+        // Callers that skip validation get an error rather than NaN offsets
+        let red = palette::Lab::new(53.24, 80.09, 67.2);
+        let blue = palette::Lab::new(32.3, 79.19, -107.86);
+        let error = stops_for_args(&args, red, blue, 5).unwrap_err();
+        assert!(error.to_string().contains("must be less than end position"));
+    }
+
+    #[test]
+    fn test_alpha_interpolates_between_endpoints() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["color-rs", "gradient", "#FF000000", "#FF0000FF"]).unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        let red = palette::Lab::new(53.24, 80.09, 67.2);
+        let stops = stops_for_args(&args, red, red, 5).unwrap();
+
+        assert!(stops[0].alpha.abs() < 1e-9);
+        assert!((stops.last().unwrap().alpha - 1.0).abs() < 1e-9);
+        assert!(stops.windows(2).all(|pair| pair[0].alpha <= pair[1].alpha));
+        assert!(
+            stops
+                .iter()
+                .any(|stop| stop.alpha > 0.0 && stop.alpha < 1.0)
+        );
+
+        let table = gradient_table(&stops).unwrap();
+        assert!(table.contains("#FF000000"));
+        assert!(table.contains("#FF0000FF"));
+    }
+
+    #[test]
+    fn test_markdown_table_output() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("gradient.md");
+
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "gradient",
+            "red",
+            "blue",
+            "--stops",
+            "4",
+            "--output",
+            "markdown",
+            "--file",
+            data.to_str().unwrap(),
+        ])
+        .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        assert!(generate_gradient(args).is_ok());
+
+        let table = std::fs::read_to_string(&data).unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].starts_with("| Position |"));
+        assert!(lines[1].starts_with("| --- |"));
+        assert_eq!(lines.len(), 2 + 4);
+        assert!(lines[2].starts_with("| 0% |"));
+    }
+
+    #[test]
+    fn test_lch_hues_in_gradient_output_are_normalized() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("gradient.yaml");
+
+        // Blue sits at a negative atan2 angle, and the path crosses 0°
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "gradient",
+            "red",
+            "blue",
+            "--stops",
+            "6",
+            "--output",
+            "yaml",
+            "--file",
+            data.to_str().unwrap(),
+        ])
+        .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        generate_gradient(args).unwrap();
+
+        let yaml = std::fs::read_to_string(&data).unwrap();
+        let hues: Vec<f64> = yaml
+            .lines()
+            .filter_map(|line| line.split_once("lch("))
+            .map(|(_, rest)| rest.trim_end_matches([')', '\'', '"']))
+            .map(|values| values.rsplit(", ").next().unwrap().parse().unwrap())
+            .collect();
+        assert!(hues.len() >= 8, "{yaml}");
+        assert!(
+            hues.iter().all(|hue| (0.0..360.0).contains(hue)),
+            "{hues:?}"
+        );
+        assert!(hues.contains(&306.3));
+    }
+
+    #[test]
+    fn test_metadata_names_selected_distance_method() {
+        use crate::cli::{Cli, Commands};
+        use crate::color_distance_strategies::DistanceAlgorithm;
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("gradient.yaml");
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "gradient",
+            "red",
+            "blue",
+            "--distance-method",
+            "lch",
+            "--output",
+            "yaml",
+            "--file",
+            data.to_str().unwrap(),
+        ])
+        .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        generate_gradient(args).unwrap();
+
+        let yaml = std::fs::read_to_string(&data).unwrap();
+        let strategy = format!("distance_strategy: {}", DistanceAlgorithm::Lch.name());
+        assert!(yaml.contains(&strategy), "{yaml}");
+        assert!(!yaml.contains("Delta E 2000"));
+    }
+
+    #[test]
+    fn test_rust_const_output() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("gradient.rs");
+
+        let cli = Cli::try_parse_from([
+            "color-rs",
+            "gradient",
+            "red",
+            "blue",
+            "--stops",
+            "4",
+            "--output",
+            "rust",
+            "--file",
+            data.to_str().unwrap(),
+        ])
+        .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+        assert!(generate_gradient(args).is_ok());
+
+        let code = std::fs::read_to_string(&data).unwrap();
+        let declared = code
+            .split("]); ")
+            .nth(1)
+            .and_then(|rest| rest.split(']').next())
+            .and_then(|count| count.parse::<usize>().ok())
+            .unwrap();
+        let elements = code
+            .lines()
+            .filter(|l| l.trim_start().starts_with('('))
+            .count();
+        assert_eq!(declared, elements);
+        assert!(elements >= 2);
+
+        // Token check: declaration shape and balanced delimiters
+        assert!(code.contains("const GRADIENT: [(f32, [u8; 3]); "));
+        assert!(code.trim_end().ends_with("];"));
+        for (open, close) in [('(', ')'), ('[', ']')] {
+            assert_eq!(code.matches(open).count(), code.matches(close).count());
+        }
+    }
 }
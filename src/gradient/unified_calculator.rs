@@ -63,6 +63,7 @@ fn create_gradient_stop(
         bezier_t,
         lab_color,
         rgb_color,
+        alpha: 1.0,
     }
 }
 
@@ -4,7 +4,7 @@
 //! immutable configuration methods and CLI integration.
 
 use super::types::*;
-use crate::cli::GradientArgs;
+use crate::cli::{GradientArgs, ImageWidth};
 use crate::error::{ColorError, Result};
 
 impl GradientConfig {
@@ -193,14 +193,46 @@ impl GradientConfig {
             svg: self.image_output.svg_filename.clone(),
             png: self.image_output.png_filename.clone(),
             no_legend: !self.image_output.show_legend,
-            width: self.image_output.width,
+            width: ImageWidth::Pixels(self.image_output.width),
             step,
             stops,
             stops_simple,
             output_format: self.file_output.as_ref().map(|f| f.format.clone()),
             output_file: self.file_output.map(|f| f.filename),
-            func_filter: None,
             vectorized_text: self.image_output.vectorized_text,
+            ..Default::default()
+        }
+    }
+
+    /// Apply this configuration onto existing `GradientArgs`
+    ///
+    /// Fields modelled by `GradientConfig` are taken from the configuration;
+    /// CLI-only options it does not represent are preserved from `args`.
+    pub fn apply_to_gradient_args(self, args: GradientArgs) -> GradientArgs {
+        let configured = self.to_gradient_args();
+
+        GradientArgs {
+            start_color: configured.start_color,
+            end_color: configured.end_color,
+            start_position: configured.start_position,
+            end_position: configured.end_position,
+            ease_in: configured.ease_in,
+            ease_out: configured.ease_out,
+            svg: configured.svg,
+            png: configured.png,
+            no_legend: configured.no_legend,
+            // The configuration models pixel widths only; keep a percentage width as given
+            width: match args.width {
+                ImageWidth::Percent(_) => args.width,
+                ImageWidth::Pixels(_) => configured.width,
+            },
+            step: configured.step,
+            stops: configured.stops,
+            stops_simple: configured.stops_simple,
+            output_format: configured.output_format,
+            output_file: configured.output_file,
+            vectorized_text: configured.vectorized_text,
+            ..args
         }
     }
 
@@ -229,8 +261,15 @@ impl GradientConfig {
     }
 
     /// Create easing configuration from CLI arguments
+    ///
+    /// A named `--easing` keyword overrides the numeric `--ease-in`/`--ease-out` values.
     fn create_easing_config(args: &GradientArgs) -> Result<EasingConfig> {
-        EasingConfig::new(args.ease_in, args.ease_out)
+        args.easing
+            .as_deref()
+            .map_or_else(
+                || EasingConfig::new(args.ease_in, args.ease_out),
+                EasingConfig::from_keyword,
+            )
             .map_err(|e| ColorError::InvalidGradient(e.to_string()))
     }
 
@@ -268,6 +307,10 @@ impl GradientConfig {
         match format {
             crate::cli::OutputFormat::Toml => "gradient.toml",
             crate::cli::OutputFormat::Yaml => "gradient.yaml",
+            crate::cli::OutputFormat::Env => "gradient.env",
+            crate::cli::OutputFormat::Rust => "gradient.rs",
+            crate::cli::OutputFormat::Markdown => "gradient.md",
+            crate::cli::OutputFormat::CssVars => "gradient.css",
         }
     }
 
@@ -284,7 +327,7 @@ impl GradientConfig {
         let positioned_config = base_config.with_position_range(position_range)?;
         let stop_configured = positioned_config.with_stop_config(stop_config);
         let image_configured = Self::apply_image_output(stop_configured, args)?;
-        let sized_config = image_configured.with_width(args.width)?;
+        let sized_config = image_configured.with_width(args.width.pixels())?;
         let legend_config = sized_config.with_legend(!args.no_legend);
 
         let final_config = if let Some(file_out) = file_output {
@@ -47,11 +47,11 @@ pub fn positioned_gradient(
 /// Generate gradient using modern functional approach (Assignment 6 Milestone 6.1)
 pub fn generate_gradient(args: GradientArgs) -> Result<()> {
     // Create gradient configuration from CLI arguments
-    let config = GradientConfig::from_gradient_args(args)?;
+    let config = GradientConfig::from_gradient_args(args.clone())?;
 
-    // Convert config to GradientArgs for the actual generation
-    // This uses the validated and normalized configuration
-    let gradient_args = config.to_gradient_args();
+    // Apply the validated and normalized configuration back onto the CLI arguments,
+    // keeping options the configuration does not model
+    let gradient_args = config.apply_to_gradient_args(args);
 
     // Delegate to the proven gradient generation implementation
     // This approach maintains backward compatibility while using
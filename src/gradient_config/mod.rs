@@ -59,6 +59,47 @@ mod tests {
         assert_eq!(ease_in_out.ease_out_value(), 0.58);
     }
 
+    #[test]
+    fn test_easing_keyword() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let cli = Cli::try_parse_from(["color-rs", "gradient", "red", "blue", "--easing", "ease"])
+            .unwrap();
+        let Commands::Gradient(args) = cli.command else {
+            panic!("expected gradient command");
+        };
+
+        let config = GradientConfig::from_gradient_args(args).unwrap();
+        assert_eq!(
+            config.easing().ease_in_value(),
+            crate::config::bezier_presets::EASE.0
+        );
+        assert_eq!(
+            config.easing().ease_out_value(),
+            crate::config::bezier_presets::EASE.1
+        );
+
+        assert!(EasingConfig::from_keyword("ease-in-out").is_ok());
+        assert!(EasingConfig::from_keyword("bounce").is_err());
+        assert!(EasingConfig::from_keyword("steps(4, end)").is_ok());
+        assert!(
+            Cli::try_parse_from([
+                "color-rs",
+                "gradient",
+                "red",
+                "blue",
+                "--easing",
+                "steps(4,end)"
+            ])
+            .is_ok()
+        );
+        assert!(
+            Cli::try_parse_from(["color-rs", "gradient", "red", "blue", "--easing", "bounce"])
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_easing_validation() {
         assert!(EasingConfig::new(-0.1, 0.5).is_err());
@@ -148,7 +189,7 @@ mod tests {
         assert_eq!(args.end_color, "#0000FF");
         assert_eq!(args.svg, Some("test.svg".to_string()));
         assert_eq!(args.step, Some(10));
-        assert_eq!(args.width, 800);
+        assert_eq!(args.width, crate::cli::ImageWidth::Pixels(800));
     }
 
     #[test]
@@ -74,6 +74,7 @@ pub struct FileOutput {
 pub enum GradientValidationError {
     InvalidColorFormat(String),
     InvalidEasingValue(f64),
+    UnknownEasingKeyword(String),
     InvalidPositionRange(u8, u8),
     InvalidStepValue(u8),
     InvalidWidth(u32),
@@ -90,6 +91,11 @@ impl std::fmt::Display for GradientValidationError {
                 f,
                 "Invalid easing value: {val}. Must be between 0.0 and 1.0"
             ),
+            GradientValidationError::UnknownEasingKeyword(keyword) => write!(
+                f,
+                "Unknown easing keyword: '{keyword}'. Must be one of: {}",
+                EasingConfig::KEYWORDS.join(", ")
+            ),
             GradientValidationError::InvalidPositionRange(start, end) => write!(
                 f,
                 "Invalid position range: start={start}, end={end}. Start must be less than end and both must be 0-100"
@@ -98,6 +98,32 @@ impl EasingConfig {
         }
     }
 
+    /// Easing keywords accepted by [`Self::from_keyword`] (CSS timing-function names)
+    pub const KEYWORDS: [&'static str; 5] =
+        ["linear", "ease", "ease-in", "ease-out", "ease-in-out"];
+
+    /// Create easing configuration from a CSS timing-function keyword
+    ///
+    /// # Errors
+    /// Returns `GradientValidationError::UnknownEasingKeyword` for keywords not in
+    /// [`Self::KEYWORDS`] that are not a valid `steps()` function
+    pub fn from_keyword(keyword: &str) -> std::result::Result<Self, GradientValidationError> {
+        match keyword.trim().to_ascii_lowercase().as_str() {
+            "linear" => Ok(Self::linear()),
+            "ease" => Ok(Self::ease()),
+            "ease-in" => Ok(Self::ease_in()),
+            "ease-out" => Ok(Self::ease_out()),
+            "ease-in-out" => Ok(Self::ease_in_out()),
+            // Stepped easing replaces the curve; the bezier values stay linear
+            steps if crate::gradient::EasingFunction::parse_steps(steps).is_ok() => {
+                Ok(Self::linear())
+            }
+            _ => Err(GradientValidationError::UnknownEasingKeyword(
+                keyword.to_string(),
+            )),
+        }
+    }
+
     /// Default easing configuration
     pub fn default_config() -> Self {
         Self::linear()
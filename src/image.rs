@@ -4,10 +4,11 @@ use image::{ImageBuffer, Rgba, RgbaImage};
 use palette::{IntoColor, Lab, Lch, Srgb};
 use resvg;
 use std::fs;
+use std::io::{BufWriter, Write};
 use tiny_skia::{Pixmap, Transform};
 use usvg::{Options, Tree, fontdb};
 
-use crate::cli::{GradientArgs, HueArgs};
+use crate::cli::{GradientArgs, HueArgs, ImageWidth, PaletteArgs};
 use crate::color_ops::analysis::hue::HueAnalysisResult;
 use crate::config::{algorithm_constants, display_constants, math_constants};
 use crate::error::{ColorError, Result};
@@ -59,6 +60,164 @@ pub enum ImageFormat {
     Png,
 }
 
+/// Strip whitespace between SVG elements and shorten hex colors in attributes
+///
+/// Expects one element or text run per line, as the SVG writers here produce.
+/// Only quoted attribute values are shortened, so hex codes in legend text
+/// stay as written.
+#[must_use]
+pub fn minify_svg(svg: &str) -> String {
+    let joined: String = svg
+        .lines()
+        .map(str::trim)
+        .collect::<String>()
+        .replace(" />", "/>");
+    joined
+        .split('"')
+        .enumerate()
+        .map(|(i, piece)| {
+            if i % 2 == 1 {
+                crate::format_utils::FormatUtils::shortest_hex(piece)
+            } else {
+                piece.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\"")
+}
+
+/// Stops sampled for rendered gradients, dense enough for smooth easing
+const RENDER_STEPS: usize = 400;
+
+/// Light and dark cells of the checkerboard behind translucent swatches
+const CHECKER_LIGHT: Srgb = Srgb::new(1.0, 1.0, 1.0);
+const CHECKER_DARK: Srgb = Srgb::new(0.8, 0.8, 0.8);
+
+/// Checker cells along each side of a translucent swatch
+const CHECKER_CELLS: u32 = 4;
+
+/// Build a single-page PDF that paints an axial (type 2) RGB shading
+///
+/// `stops` are `(offset, [r, g, b])` pairs with offsets increasing from 0.0 to
+/// 1.0 and channels in 0.0-1.0; neighbouring stops are joined by linear
+/// (type 2) functions inside one stitching (type 3) function. The shading runs
+/// horizontally from `x0` to `x1` on a `width` x `height` point page and is
+/// extended flat beyond both ends.
+#[must_use]
+pub fn axial_shading_pdf(
+    stops: &[(f64, [f64; 3])],
+    width: f64,
+    height: f64,
+    x0: f64,
+    x1: f64,
+) -> Vec<u8> {
+    let number = |value: f64| {
+        let text = format!("{value:.4}");
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        if text == "-0" {
+            "0".to_string()
+        } else {
+            text.to_string()
+        }
+    };
+    let color = |[r, g, b]: [f64; 3]| format!("[{} {} {}]", number(r), number(g), number(b));
+
+    // A shading needs at least one segment; a lone stop becomes a flat one
+    let segments: Vec<([f64; 3], [f64; 3])> = match stops {
+        [(_, single)] => vec![(*single, *single)],
+        _ => stops
+            .windows(2)
+            .map(|pair| (pair[0].1, pair[1].1))
+            .collect(),
+    };
+    let functions: Vec<String> = segments
+        .iter()
+        .map(|(c0, c1)| {
+            format!(
+                "<< /FunctionType 2 /Domain [0 1] /C0 {} /C1 {} /N 1 >>",
+                color(*c0),
+                color(*c1)
+            )
+        })
+        .collect();
+    let bounds: Vec<String> = stops
+        .iter()
+        .skip(1)
+        .take(segments.len().saturating_sub(1))
+        .map(|(offset, _)| number(*offset))
+        .collect();
+    let encode = vec!["0 1"; segments.len()].join(" ");
+
+    let content = "/Sh0 sh\n";
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Shading << /Sh0 5 0 R >> >> /Contents 4 0 R >>",
+            number(width),
+            number(height)
+        ),
+        format!(
+            "<< /Length {} >>\nstream\n{content}endstream",
+            content.len()
+        ),
+        format!(
+            "<< /ShadingType 2 /ColorSpace /DeviceRGB /Coords [{} 0 {} 0] /Function 6 0 R /Extend [true true] >>",
+            number(x0),
+            number(x1)
+        ),
+        format!(
+            "<< /FunctionType 3 /Domain [0 1] /Functions [{}] /Bounds [{}] /Encode [{encode}] >>",
+            functions.join(" "),
+            bounds.join(" ")
+        ),
+    ];
+
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", index + 1).as_bytes());
+    }
+
+    let xref = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+/// Wrap an SVG document in a percent-encoded `data:image/svg+xml` URI
+///
+/// Letters, digits and characters that are safe inside a URI and a CSS
+/// `url()` pass through; everything else, including `#`, `<`, `>`, quotes,
+/// spaces and line breaks, is percent-encoded byte by byte.
+#[must_use]
+pub fn svg_to_data_uri(svg: &str) -> String {
+    const SAFE: &[u8] = b"-_.~!$&*+,;=:@/?";
+
+    let mut uri = String::with_capacity(svg.len() * 2);
+    uri.push_str("data:image/svg+xml,");
+    for byte in svg.bytes() {
+        if byte.is_ascii_alphanumeric() || SAFE.contains(&byte) {
+            uri.push(char::from(byte));
+        } else {
+            uri.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    uri
+}
+
 /// Image generation and processing
 pub struct ImageGenerator;
 
@@ -69,10 +228,43 @@ impl ImageGenerator {
         Self
     }
 
+    /// Gradient image dimensions in pixels (width, total height including legend)
+    #[must_use]
+    pub fn gradient_image_size(args: &GradientArgs) -> (u32, u32) {
+        let (gradient_height, legend_height) = Self::gradient_image_heights(args);
+        (args.width.pixels(), gradient_height + legend_height)
+    }
+
+    /// Gradient band dimensions in pixels (width, height without the legend)
+    ///
+    /// The size of the lightness strip and of the PDF shading page.
+    #[must_use]
+    pub fn gradient_band_size(args: &GradientArgs) -> (u32, u32) {
+        (args.width.pixels(), Self::gradient_image_heights(args).0)
+    }
+
+    /// Heights of the gradient band and the legend below it
+    fn gradient_image_heights(args: &GradientArgs) -> (u32, u32) {
+        let gradient_height =
+            (f64::from(args.width.pixels()) * display_constants::HEIGHT_RATIO) as u32;
+        let legend_height = if args.no_legend {
+            0
+        } else {
+            (f64::from(gradient_height) * display_constants::DEFAULT_LEGEND_HEIGHT_RATIO)
+                .max(display_constants::MIN_LEGEND_HEIGHT) as u32
+        };
+        (gradient_height, legend_height)
+    }
+
     /// Generate SVG gradient
     pub fn generate_svg(&self, args: &GradientArgs, start_lab: Lab, end_lab: Lab) -> Result<()> {
-        let svg_content = self.create_svg_content(args, start_lab, end_lab)?;
-        fs::write(args.svg_name(), svg_content)?;
+        if args.minify {
+            let svg_content = self.create_svg_content(args, start_lab, end_lab)?;
+            fs::write(args.svg_name(), minify_svg(&svg_content))?;
+        } else {
+            let file = fs::File::create(args.svg_name())?;
+            self.write_svg(BufWriter::new(file), args, start_lab, end_lab)?;
+        }
 
         // Generate vectorized SVG if requested
         if args.vectorized_text {
@@ -82,6 +274,25 @@ impl ImageGenerator {
         Ok(())
     }
 
+    /// Gradient SVG as a percent-encoded data URI, minified when requested
+    ///
+    /// # Errors
+    /// Returns an error if the SVG cannot be rendered.
+    pub fn svg_data_uri(
+        &self,
+        args: &GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+    ) -> Result<String> {
+        let svg_content = self.create_svg_content(args, start_lab, end_lab)?;
+        let svg_content = if args.minify {
+            minify_svg(&svg_content)
+        } else {
+            svg_content
+        };
+        Ok(svg_to_data_uri(&svg_content))
+    }
+
     /// Generate PNG gradient
     pub fn generate_png(&self, args: &GradientArgs, start_lab: Lab, end_lab: Lab) -> Result<()> {
         // Create SVG content first
@@ -97,14 +308,8 @@ impl ImageGenerator {
         let tree = Tree::from_str(&svg_content, &options)
             .map_err(|e| ColorError::SvgError(format!("Failed to parse SVG: {e}")))?;
 
-        let width = args.width;
-        let gradient_height = (f64::from(width) * display_constants::HEIGHT_RATIO) as u32;
-        let legend_height = if args.no_legend {
-            0
-        } else {
-            (f64::from(gradient_height) * display_constants::DEFAULT_LEGEND_HEIGHT_RATIO)
-                .max(display_constants::MIN_LEGEND_HEIGHT) as u32
-        };
+        let width = args.width.pixels();
+        let (gradient_height, legend_height) = Self::gradient_image_heights(args);
         let total_height = gradient_height + legend_height;
 
         // Create pixmap
@@ -127,56 +332,52 @@ impl ImageGenerator {
         Ok(())
     }
 
-    /// Create SVG content string
+    /// Stream the SVG gradient to any writer
+    ///
+    /// Writes the document piece by piece instead of building it in memory, so
+    /// very wide gradients can go straight to a file or socket.
     ///
     /// # Errors
-    /// This function currently cannot fail but returns Result for future extensibility
-    /// when error conditions may be added (e.g., invalid color spaces, malformed arguments).
-    fn create_svg_content(
+    /// Returns an error if writing to `writer` fails.
+    pub fn write_svg<W: Write>(
         &self,
+        mut writer: W,
         args: &GradientArgs,
         start_lab: Lab,
         end_lab: Lab,
-    ) -> Result<String> {
-        let width = args.width;
-        let gradient_height = (f64::from(width) * display_constants::HEIGHT_RATIO) as u32;
-        let legend_height = if args.no_legend {
-            0
-        } else {
-            (f64::from(gradient_height) * display_constants::DEFAULT_LEGEND_HEIGHT_RATIO)
-                .max(display_constants::MIN_LEGEND_HEIGHT) as u32
-        };
+    ) -> Result<()> {
+        let width = args.width.pixels();
+        let (gradient_height, legend_height) = Self::gradient_image_heights(args);
         let total_height = gradient_height + legend_height;
 
         let start_hex = lab_to_hex(start_lab);
         let end_hex = lab_to_hex(end_lab);
 
-        let mut svg = String::new();
-        svg.push_str(&format!(
-            r#"<svg width="{width}" height="{total_height}" xmlns="http://www.w3.org/2000/svg">"#
-        ));
-        svg.push('\n');
+        match args.width {
+            ImageWidth::Pixels(_) => writeln!(
+                writer,
+                r#"<svg width="{width}" height="{total_height}" xmlns="http://www.w3.org/2000/svg">"#
+            )?,
+            // Percentage widths scale with the container; the viewBox keeps the aspect ratio
+            ImageWidth::Percent(_) => writeln!(
+                writer,
+                r#"<svg width="{}" viewBox="0 0 {width} {total_height}" xmlns="http://www.w3.org/2000/svg">"#,
+                args.width.svg_attribute()
+            )?,
+        }
 
         // Add gradient definition that maps start_position to end_position
-        svg.push_str("  <defs>\n");
-        svg.push_str(&format!(
-            "    <linearGradient id=\"grad\" x1=\"{}%\" y1=\"0%\" x2=\"{}%\" y2=\"0%\">\n",
+        writer.write_all(b"  <defs>\n")?;
+        writeln!(
+            writer,
+            "    <linearGradient id=\"grad\" x1=\"{}%\" y1=\"0%\" x2=\"{}%\" y2=\"0%\">",
             args.start_position, args.end_position
-        ));
+        )?;
 
         // Use unified gradient calculation for consistent results with YAML output
-        // Generate many stops (400) for smooth bezier rendering in SVG
-        let svg_steps = 400; // High resolution for smooth gradients
-        let unified_stops = GradientCalculator::calculate_unified_gradient(
-            start_lab,
-            end_lab,
-            args.start_position,
-            args.end_position,
-            args.ease_in,
-            args.ease_out,
-            svg_steps,
-            args.stops_simple, // Use same mode as YAML output
-        );
+        // Same stop calculation (and mode) as the YAML output
+        let unified_stops =
+            crate::gradient::stops_for_args(args, start_lab, end_lab, RENDER_STEPS)?;
 
         // Convert unified stops to SVG stops with proper offset mapping
         // Map stop positions from [start_position, end_position] to [0%, 100%]
@@ -201,6 +402,15 @@ impl ImageGenerator {
 
             last_offset = Some(relative_offset);
 
+            let opacity = if stop.alpha < 1.0 {
+                format!(
+                    " stop-opacity=\"{}\"",
+                    crate::precision_utils::PrecisionUtils::format_f64(stop.alpha)
+                )
+            } else {
+                String::new()
+            };
+
             // Format offset with proper precision (show .5 when needed, hide .0)
             let offset_str = if relative_offset.fract() == 0.0 {
                 format!("{}%", relative_offset.round() as u8)
@@ -208,18 +418,20 @@ impl ImageGenerator {
                 format!("{relative_offset:.1}%")
             };
 
-            svg.push_str(&format!(
-                "      <stop offset=\"{offset_str}\" stop-color=\"{hex_color}\" />\n"
-            ));
+            writeln!(
+                writer,
+                "      <stop offset=\"{offset_str}\" stop-color=\"{hex_color}\"{opacity} />"
+            )?;
         }
 
-        svg.push_str("    </linearGradient>\n");
-        svg.push_str("  </defs>\n");
+        writer.write_all(b"    </linearGradient>\n")?;
+        writer.write_all(b"  </defs>\n")?;
 
         // Create full-width gradient rectangle
-        svg.push_str(&format!(
-            "  <rect x=\"0\" y=\"0\" width=\"{width}\" height=\"{gradient_height}\" fill=\"url(#grad)\" />\n"
-        ));
+        writeln!(
+            writer,
+            "  <rect x=\"0\" y=\"0\" width=\"{width}\" height=\"{gradient_height}\" fill=\"url(#grad)\" />"
+        )?;
 
         // Add legend if not disabled
         if !args.no_legend {
@@ -228,31 +440,48 @@ impl ImageGenerator {
             let text_y = gradient_height
                 + (f64::from(legend_height) * display_constants::DEFAULT_TEXT_Y_RATIO) as u32;
 
-            svg.push_str(&format!(
-                "  <rect x=\"0\" y=\"{gradient_height}\" width=\"100%\" height=\"{legend_height}\" fill=\"rgb(0,0,0)\" />\n"
-            ));
-            svg.push_str(&format!(
-                "  <text x=\"{}\" y=\"{}\" font-family=\"{}\" font-size=\"{}\" fill=\"white\">\n",
+            writeln!(
+                writer,
+                "  <rect x=\"0\" y=\"{gradient_height}\" width=\"100%\" height=\"{legend_height}\" fill=\"rgb(0,0,0)\" />"
+            )?;
+            writeln!(
+                writer,
+                "  <text x=\"{}\" y=\"{}\" font-family=\"{}\" font-size=\"{}\" fill=\"white\">",
                 width / 100,
                 text_y,
                 display_constants::FONT_FAMILY,
                 font_size
-            ));
-            svg.push_str(&format!(
-                "    cubic-bezier({}, 0, {}, 1) | positions: {}%-{}% | colors: {}-{}\n",
+            )?;
+            writeln!(
+                writer,
+                "    cubic-bezier({}, 0, {}, 1) | positions: {}%-{}% | colors: {}-{}",
                 args.ease_in,
                 args.ease_out,
                 args.start_position,
                 args.end_position,
                 start_hex,
                 end_hex
-            ));
-            svg.push_str("  </text>\n");
+            )?;
+            writer.write_all(b"  </text>\n")?;
         }
 
-        svg.push_str("</svg>");
+        writer.write_all(b"</svg>")?;
 
-        Ok(svg)
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Create SVG content string
+    fn create_svg_content(
+        &self,
+        args: &GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+    ) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.write_svg(&mut buffer, args, start_lab, end_lab)?;
+        String::from_utf8(buffer)
+            .map_err(|e| ColorError::SvgError(format!("SVG is not valid UTF-8: {e}")))
     }
 
     /// Validate image generation parameters
@@ -262,13 +491,13 @@ impl ImageGenerator {
     /// Returns error if filenames don't have proper extensions or if validation fails
     /// for any required parameter values.
     pub fn validate_image_params(&self, args: &GradientArgs) -> Result<()> {
-        if args.width == 0 {
+        if args.width.pixels() == 0 {
             return Err(ColorError::InvalidArguments(
                 "Image width must be greater than 0".to_string(),
             ));
         }
 
-        if args.width > 10000 {
+        if args.width.pixels() > 10000 {
             return Err(ColorError::InvalidArguments(
                 "Image width should not exceed 10000 pixels for performance reasons".to_string(),
             ));
@@ -523,14 +752,12 @@ impl ImageGenerator {
 
                 // Extract LCH components from the color
                 let lch = color.color;
-                let hue_str = format!("{:.0}", lch.hue.into_degrees());
+                let hue = crate::color_ops::analysis::hue::normalize_hue(f64::from(
+                    lch.hue.into_degrees(),
+                ));
+                let hue_str = format!("{hue:.0}");
                 let hex_str = hex_color.to_uppercase();
-                let lch_str = format!(
-                    "lch({:.1}, {:.1}, {:.1})",
-                    lch.l,
-                    lch.chroma,
-                    lch.hue.into_degrees()
-                );
+                let lch_str = format!("lch({:.1}, {:.1}, {:.1})", lch.l, lch.chroma, hue);
                 let code_str = color.code.as_deref().unwrap_or("Unknown");
                 let name_str = color.name.as_deref().unwrap_or("Unknown");
 
@@ -575,12 +802,244 @@ impl ImageGenerator {
         Ok(svg)
     }
 
+    /// Height of the label strip under each contact sheet swatch (two text lines)
+    const fn contact_sheet_label_height(font_size: u32) -> u32 {
+        font_size * 3
+    }
+
+    /// Contact sheet dimensions in pixels (width, height) for `count` colors
+    ///
+    /// The grid is at most `columns` swatches wide and grows by one row per
+    /// `columns` colors, so large palettes produce a taller image.
+    #[must_use]
+    pub fn contact_sheet_size(count: usize, args: &PaletteArgs) -> (u32, u32) {
+        let columns = (args.columns.max(1) as usize).min(count.max(1));
+        let rows = count.div_ceil(columns);
+        let cell_height = args.swatch_size + Self::contact_sheet_label_height(args.font_size);
+        (columns as u32 * args.swatch_size, rows as u32 * cell_height)
+    }
+
+    /// Render `colors` as a grid of labeled swatches and save it as PNG
+    ///
+    /// Each swatch is labeled with its hex value and, when it differs, the
+    /// color as written in the input.
+    ///
+    /// # Errors
+    /// Returns error if the sheet is too large to allocate or the PNG cannot be written
+    pub fn generate_contact_sheet(
+        &self,
+        args: &PaletteArgs,
+        colors: &[(String, Lab)],
+    ) -> Result<()> {
+        let svg_content = self.create_contact_sheet_svg(args, colors);
+        self.render_svg_to_png(&svg_content, &args.sheet)
+    }
+
+    /// Create the contact sheet SVG
+    fn create_contact_sheet_svg(&self, args: &PaletteArgs, colors: &[(String, Lab)]) -> String {
+        let (width, height) = Self::contact_sheet_size(colors.len(), args);
+        let columns = (args.columns.max(1) as usize).min(colors.len().max(1));
+        let swatch = args.swatch_size;
+        let cell_height = swatch + Self::contact_sheet_label_height(args.font_size);
+        let font_size = args.font_size;
+
+        let mut svg = String::new();
+        svg.push_str(&format!(
+            "<svg width=\"{width}\" height=\"{height}\" xmlns=\"http://www.w3.org/2000/svg\">\n"
+        ));
+        svg.push_str(&format!(
+            "  <rect x=\"0\" y=\"0\" width=\"{width}\" height=\"{height}\" fill=\"white\" />\n"
+        ));
+
+        let parser = crate::color_parser::CssColorParser::new();
+        for (i, (input, lab)) in colors.iter().enumerate() {
+            let x = (i % columns) as u32 * swatch;
+            let y = (i / columns) as u32 * cell_height;
+            let hex_color = lab_to_hex(*lab).to_uppercase();
+            let alpha = parser.parse(input).map_or(1.0, |parsed| parsed.a);
+
+            let fill = if alpha < 1.0 {
+                Self::push_checker_swatch(&mut svg, (x, y), swatch, *lab, alpha as f32);
+                "none".to_string()
+            } else {
+                hex_color.clone()
+            };
+            svg.push_str(&format!(
+                "  <rect x=\"{x}\" y=\"{y}\" width=\"{swatch}\" height=\"{swatch}\" fill=\"{fill}\" stroke=\"{}\" stroke-width=\"{}\" />\n",
+                args.border_color, args.border_width
+            ));
+
+            let mut labels = vec![hex_color.clone()];
+            if !input.trim().eq_ignore_ascii_case(&hex_color) {
+                labels.push(input.trim().to_string());
+            }
+            for (line, label) in labels.iter().enumerate() {
+                let text_y = y + swatch + font_size * (3 * line as u32 + 3) / 2;
+                let label = label
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;");
+                svg.push_str(&format!(
+                    "  <text x=\"{}\" y=\"{text_y}\" font-family=\"{}\" font-size=\"{font_size}\" fill=\"black\" text-anchor=\"middle\">{label}</text>\n",
+                    x + swatch / 2,
+                    display_constants::FONT_FAMILY
+                ));
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Draw a translucent swatch as the color flattened over a checkerboard
+    fn push_checker_swatch(
+        svg: &mut String,
+        (x, y): (u32, u32),
+        swatch: u32,
+        lab: Lab,
+        alpha: f32,
+    ) {
+        let color: Srgb = lab.into_color();
+        let (over_light, over_dark) = crate::color_ops::mixing::flatten_over_checker(
+            palette::Srgba::new(color.red, color.green, color.blue, alpha),
+            CHECKER_LIGHT,
+            CHECKER_DARK,
+        );
+        let [over_light, over_dark] = [over_light, over_dark].map(|flat| {
+            let flat: Srgb<u8> = flat.into_format();
+            format!("#{:02X}{:02X}{:02X}", flat.red, flat.green, flat.blue)
+        });
+
+        let cell = swatch / CHECKER_CELLS;
+        for row in 0..CHECKER_CELLS {
+            for column in 0..CHECKER_CELLS {
+                // The last row and column absorb the remainder of the division
+                let width = if column + 1 == CHECKER_CELLS {
+                    swatch - cell * column
+                } else {
+                    cell
+                };
+                let height = if row + 1 == CHECKER_CELLS {
+                    swatch - cell * row
+                } else {
+                    cell
+                };
+                let fill = if (row + column) % 2 == 0 {
+                    &over_light
+                } else {
+                    &over_dark
+                };
+                svg.push_str(&format!(
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{width}\" height=\"{height}\" fill=\"{fill}\" />\n",
+                    x + cell * column,
+                    y + cell * row
+                ));
+            }
+        }
+    }
+
+    /// Write the gradient as a single-page PDF with an axial shading
+    ///
+    /// The page has the size of the gradient band (the legend is not drawn)
+    /// and uses the same RGB stops as the SVG output; transparency is dropped.
+    ///
+    /// # Errors
+    /// Returns an error if the stops cannot be calculated or the file cannot be written.
+    pub fn generate_pdf(
+        &self,
+        args: &GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+        pdf_path: &str,
+    ) -> Result<()> {
+        fs::write(pdf_path, self.create_pdf_content(args, start_lab, end_lab)?)?;
+        Ok(())
+    }
+
+    /// Create the PDF shading from the gradient's RGB stops
+    fn create_pdf_content(
+        &self,
+        args: &GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+    ) -> Result<Vec<u8>> {
+        let range = f64::from(args.end_position.saturating_sub(args.start_position)).max(1.0);
+        let mut stops: Vec<(f64, [f64; 3])> = Vec::new();
+        for stop in crate::gradient::stops_for_args(args, start_lab, end_lab, RENDER_STEPS)? {
+            let offset = f64::from(stop.position.saturating_sub(args.start_position)) / range;
+            if stops.last().is_some_and(|(last, _)| offset <= *last) {
+                continue;
+            }
+            let (r, g, b) = stop.rgb_color;
+            stops.push((offset.min(1.0), [r, g, b].map(|c| f64::from(c) / 255.0)));
+        }
+
+        let (width, height) = Self::gradient_band_size(args);
+        let width = f64::from(width);
+        Ok(axial_shading_pdf(
+            &stops,
+            width,
+            f64::from(height),
+            width * f64::from(args.start_position) / 100.0,
+            width * f64::from(args.end_position) / 100.0,
+        ))
+    }
+
+    /// Render the gradient's lightness profile as a grayscale PNG strip
+    ///
+    /// The strip has the width and band height of the gradient image, without
+    /// a legend. Each pixel column is gray at the LAB L of the gradient at that
+    /// column's position, scaled so L 0 is 0 and L 100 is 255. Columns before
+    /// the start position or after the end position hold the endpoint values.
+    pub fn generate_lightness_strip(
+        &self,
+        args: &GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+        png_path: &str,
+    ) -> Result<()> {
+        let svg_content = self.create_lightness_strip_svg(args, start_lab, end_lab);
+        self.render_svg_to_png(&svg_content, png_path)
+    }
+
+    /// Create the lightness strip SVG, one 1px rectangle per column
+    fn create_lightness_strip_svg(
+        &self,
+        args: &GradientArgs,
+        start_lab: Lab,
+        end_lab: Lab,
+    ) -> String {
+        let (width, height) = Self::gradient_band_size(args);
+        let start = f64::from(args.start_position) / 100.0;
+        let span = (f64::from(args.end_position) / 100.0 - start).max(f64::EPSILON);
+
+        let mut svg = String::new();
+        svg.push_str(&format!(
+            "<svg width=\"{width}\" height=\"{height}\" xmlns=\"http://www.w3.org/2000/svg\" shape-rendering=\"crispEdges\">\n"
+        ));
+        for x in 0..width {
+            let position = f64::from(x) / f64::from(width.saturating_sub(1).max(1));
+            let lab =
+                GradientCalculator::lab_at(args, start_lab, end_lab, (position - start) / span);
+            let gray = (f64::from(lab.l) / 100.0 * 255.0).round().clamp(0.0, 255.0) as u8;
+            svg.push_str(&format!(
+                "  <rect x=\"{x}\" y=\"0\" width=\"1\" height=\"{height}\" fill=\"#{gray:02X}{gray:02X}{gray:02X}\" />\n"
+            ));
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
     /// Convert SVG file to PNG
     fn svg_to_png(&self, svg_path: &str, png_path: &str, _width: u32) -> Result<()> {
         // Read SVG content
         let svg_content = fs::read_to_string(svg_path)
             .map_err(|e| ColorError::SvgError(format!("Failed to read SVG file: {e}")))?;
+        self.render_svg_to_png(&svg_content, png_path)
+    }
 
+    /// Render SVG content to a PNG file at the SVG's own size
+    fn render_svg_to_png(&self, svg_content: &str, png_path: &str) -> Result<()> {
         // Configure usvg options
         let mut options = Options::default();
         let mut fontdb = fontdb::Database::new();
@@ -588,7 +1047,7 @@ impl ImageGenerator {
         options.fontdb = std::sync::Arc::new(fontdb);
 
         // Parse SVG
-        let tree = Tree::from_str(&svg_content, &options)
+        let tree = Tree::from_str(svg_content, &options)
             .map_err(|e| ColorError::SvgError(format!("Failed to parse SVG: {e}")))?;
 
         // Get actual dimensions from the tree or use width with calculated height
@@ -627,7 +1086,7 @@ impl ImageGenerator {
         let svg_content = self.create_svg_content(args, start_lab, end_lab)?;
 
         // Convert text to paths using usvg
-        let vectorized_svg = self.convert_text_to_paths(&svg_content)?;
+        let vectorized_svg = self.convert_text_to_paths(&svg_content, args.minify)?;
 
         // Save with _vectorized suffix
         let vectorized_filename = args.svg_name().replace(".svg", "_vectorized.svg");
@@ -646,7 +1105,7 @@ impl ImageGenerator {
         let svg_content = self.create_hue_gradient_svg(args, colors)?;
 
         // Convert text to paths using usvg
-        let vectorized_svg = self.convert_text_to_paths(&svg_content)?;
+        let vectorized_svg = self.convert_text_to_paths(&svg_content, false)?;
 
         // Save with _vectorized suffix
         let vectorized_filename = args.svg_name().replace(".svg", "_vectorized.svg");
@@ -665,7 +1124,7 @@ impl ImageGenerator {
         let svg_content = self.create_hue_palette_svg(args, colors)?;
 
         // Convert text to paths using usvg
-        let vectorized_svg = self.convert_text_to_paths(&svg_content)?;
+        let vectorized_svg = self.convert_text_to_paths(&svg_content, false)?;
 
         // Save with _vectorized suffix
         let vectorized_filename = args.svg_name().replace(".svg", "_vectorized.svg");
@@ -675,7 +1134,7 @@ impl ImageGenerator {
     }
 
     /// Convert text elements in SVG to vector paths using usvg
-    fn convert_text_to_paths(&self, svg_content: &str) -> Result<String> {
+    fn convert_text_to_paths(&self, svg_content: &str, minify: bool) -> Result<String> {
         // Configure usvg options with font loading
         let mut options = Options::default();
         let mut fontdb = fontdb::Database::new();
@@ -688,10 +1147,17 @@ impl ImageGenerator {
         })?;
 
         // Convert tree back to SVG string with WriteOptions for proper formatting
-        let write_options = usvg::WriteOptions::default();
+        let mut write_options = usvg::WriteOptions::default();
+        if minify {
+            write_options.indent = usvg::Indent::None;
+        }
         let vectorized_svg = tree.to_string(&write_options);
 
-        Ok(vectorized_svg)
+        Ok(if minify {
+            minify_svg(&vectorized_svg)
+        } else {
+            vectorized_svg
+        })
     }
 }
 
@@ -733,21 +1199,12 @@ mod tests {
         GradientArgs {
             start_color: "FF0000".to_string(),
             end_color: "0000FF".to_string(),
-            start_position: 0,
-            end_position: 100,
             ease_in: 0.25,
             ease_out: 0.75,
             svg: Some("test.svg".to_string()),
-            png: None,
-            no_legend: false,
-            width: 1000,
-            step: None,
-            stops: 5,
-            stops_simple: false,
-            output_format: None,
-            output_file: None,
-            func_filter: None,
+            width: ImageWidth::Pixels(1000),
             vectorized_text: false,
+            ..Default::default()
         }
     }
 
@@ -770,6 +1227,57 @@ mod tests {
         assert!(svg_content.contains("</svg>"));
     }
 
+    #[test]
+    fn test_write_svg_streams_valid_svg() {
+        let generator = ImageGenerator::new();
+        let args = create_test_args();
+        let start_lab: Lab = Srgb::new(1.0, 0.0, 0.0).into_color();
+        let end_lab: Lab = Srgb::new(0.0, 0.0, 1.0).into_color();
+
+        let mut buffer: Vec<u8> = Vec::new();
+        generator
+            .write_svg(&mut buffer, &args, start_lab, end_lab)
+            .unwrap();
+
+        let svg = String::from_utf8(buffer.clone()).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert_eq!(
+            svg,
+            generator
+                .create_svg_content(&args, start_lab, end_lab)
+                .unwrap()
+        );
+        assert!(Tree::from_data(&buffer, &Options::default()).is_ok());
+    }
+
+    #[test]
+    fn test_percentage_width_svg_uses_viewbox() {
+        let generator = ImageGenerator::new();
+        let mut args = create_test_args();
+        args.width = ImageWidth::parse("100%").unwrap();
+        let start_lab: Lab = Srgb::new(1.0, 0.0, 0.0).into_color();
+        let end_lab: Lab = Srgb::new(0.0, 0.0, 1.0).into_color();
+
+        let svg = generator
+            .create_svg_content(&args, start_lab, end_lab)
+            .unwrap();
+        let header = svg.lines().next().unwrap();
+        let (width, height) = ImageGenerator::gradient_image_size(&args);
+        assert!(header.contains(r#"width="100%""#));
+        assert!(header.contains(&format!(r#"viewBox="0 0 {width} {height}""#)));
+        assert!(Tree::from_str(&svg, &Options::default()).is_ok());
+
+        // Pixel widths keep fixed dimensions and no viewBox
+        args.width = ImageWidth::parse("800px").unwrap();
+        let svg = generator
+            .create_svg_content(&args, start_lab, end_lab)
+            .unwrap();
+        assert!(svg.starts_with(r#"<svg width="800" height="#));
+        assert!(!svg.contains("viewBox"));
+        assert!(ImageWidth::parse("150%").is_err());
+    }
+
     #[test]
     fn test_image_params_validation() {
         let generator = ImageGenerator::new();
@@ -779,11 +1287,154 @@ mod tests {
         assert!(generator.validate_image_params(&args).is_ok());
 
         // Invalid width
-        args.width = 0;
+        args.width = ImageWidth::Pixels(0);
         assert!(generator.validate_image_params(&args).is_err());
 
         // Too large width
-        args.width = 15000;
+        args.width = ImageWidth::Pixels(15000);
         assert!(generator.validate_image_params(&args).is_err());
     }
+
+    #[test]
+    fn test_contact_sheet_dimensions() {
+        let dir = tempfile::tempdir().unwrap();
+        let sheet = dir.path().join("sheet.png");
+        let args = PaletteArgs {
+            input_file: "-".to_string(),
+            stdin_format: crate::cli::StdinFormat::Lines,
+            sheet: sheet.to_string_lossy().into_owned(),
+            columns: 8,
+            swatch_size: 50,
+            font_size: 10,
+            border_width: 1,
+            border_color: "white".to_string(),
+            dry_run: false,
+        };
+
+        // 20 colors in 8 columns need 3 rows of 50px swatches plus 30px labels
+        assert_eq!(ImageGenerator::contact_sheet_size(20, &args), (400, 240));
+        // Fewer colors than columns shrink the sheet to one row of that many
+        assert_eq!(ImageGenerator::contact_sheet_size(3, &args), (150, 80));
+
+        let colors: Vec<(String, Lab)> = (0..20)
+            .map(|i| {
+                let lab: Lab = Srgb::new(i as f32 / 19.0, 0.5, 0.2).into_color();
+                (format!("color {i} & co"), lab)
+            })
+            .collect();
+        ImageGenerator::new()
+            .generate_contact_sheet(&args, &colors)
+            .unwrap();
+        assert_eq!(image::image_dimensions(&sheet).unwrap(), (400, 240));
+    }
+
+    #[test]
+    fn test_minified_svg() {
+        let generator = ImageGenerator::new();
+        let args = create_test_args();
+        let start_lab: Lab = Srgb::new(1.0, 0.0, 0.0).into_color();
+        let end_lab: Lab = Srgb::new(0.0, 0.0, 1.0).into_color();
+
+        let svg = generator
+            .create_svg_content(&args, start_lab, end_lab)
+            .unwrap();
+        let minified = minify_svg(&svg);
+        assert!(!minified.contains('\n'));
+        assert!(minified.contains(r##"stop-color="#f00"/>"##));
+        // Legend text keeps the full hex codes
+        assert!(minified.contains("colors: #FF0000-#0000FF"));
+        assert!(Tree::from_str(&minified, &Options::default()).is_ok());
+    }
+
+    #[test]
+    fn test_pdf_axial_shading() {
+        let generator = ImageGenerator::new();
+        let args = create_test_args();
+        let start_lab: Lab = Srgb::new(1.0, 0.0, 0.0).into_color();
+        let end_lab: Lab = Srgb::new(0.0, 0.0, 1.0).into_color();
+
+        let pdf = generator
+            .create_pdf_content(&args, start_lab, end_lab)
+            .unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+        let text = String::from_utf8(pdf).unwrap();
+        assert!(text.contains("/ShadingType 2 /ColorSpace /DeviceRGB"));
+        assert!(text.contains("/C0 [1 0 0]"));
+        assert!(text.contains("/C1 [0 0 1]"));
+
+        // startxref must point at the cross-reference table
+        let xref: usize = text
+            .lines()
+            .skip_while(|line| *line != "startxref")
+            .nth(1)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(text[xref..].starts_with("xref"));
+    }
+
+    #[test]
+    fn test_svg_data_uri_round_trip() {
+        let generator = ImageGenerator::new();
+        let args = create_test_args();
+        let start_lab: Lab = Srgb::new(1.0, 0.0, 0.0).into_color();
+        let end_lab: Lab = Srgb::new(0.0, 0.0, 1.0).into_color();
+
+        let uri = generator.svg_data_uri(&args, start_lab, end_lab).unwrap();
+        let payload = uri.strip_prefix("data:image/svg+xml,").unwrap();
+        for raw in ['#', '<', '>', '"', '\'', ' ', '\n'] {
+            assert!(!payload.contains(raw), "unencoded {raw:?}");
+        }
+
+        let mut decoded = Vec::new();
+        let mut bytes = payload.bytes();
+        while let Some(byte) = bytes.next() {
+            if byte == b'%' {
+                let hex = [bytes.next().unwrap(), bytes.next().unwrap()];
+                decoded.push(u8::from_str_radix(std::str::from_utf8(&hex).unwrap(), 16).unwrap());
+            } else {
+                decoded.push(byte);
+            }
+        }
+        let svg = String::from_utf8(decoded).unwrap();
+        assert_eq!(
+            svg,
+            generator
+                .create_svg_content(&args, start_lab, end_lab)
+                .unwrap()
+        );
+        assert!(Tree::from_str(&svg, &Options::default()).is_ok());
+    }
+
+    #[test]
+    fn test_lightness_strip_increases_for_dark_to_light() {
+        let generator = ImageGenerator::new();
+        let mut args = create_test_args();
+        args.width = ImageWidth::Pixels(200);
+        let start_lab: Lab = Srgb::new(0.0, 0.0, 0.0).into_color();
+        let end_lab: Lab = Srgb::new(1.0, 1.0, 1.0).into_color();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("strip.png");
+        generator
+            .generate_lightness_strip(&args, start_lab, end_lab, path.to_str().unwrap())
+            .unwrap();
+
+        let strip = image::open(&path).unwrap().to_rgba8();
+        assert_eq!(
+            strip.dimensions(),
+            ImageGenerator::gradient_band_size(&args)
+        );
+        let grays: Vec<u8> = (0..strip.width())
+            .map(|x| {
+                let pixel = strip.get_pixel(x, strip.height() / 2);
+                assert_eq!(pixel[0], pixel[1]);
+                assert_eq!(pixel[1], pixel[2]);
+                pixel[0]
+            })
+            .collect();
+        assert_eq!(grays[0], 0);
+        assert_eq!(grays[grays.len() - 1], 255);
+        assert!(grays.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
 }
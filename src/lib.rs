@@ -9,6 +9,8 @@ pub mod clock;
 pub mod color;
 pub mod color_distance_strategies;
 pub mod color_formatter;
+// Core numeric color math, usable without std
+pub mod color_math;
 pub mod color_report_formatting;
 // Template Method Pattern Migration (Milestone 1.2) - Modern Alternative
 pub mod color_matching;
@@ -34,6 +36,7 @@ pub mod output_formats;
 // Performance validation for Milestone 7.2
 pub mod performance_validation;
 pub mod precision_utils;
+pub mod profiles;
 pub mod utils;
 
 // Functional Programming Modules
@@ -42,7 +45,11 @@ pub mod command_execution;
 pub mod parsing_chain;
 
 // Re-export main types for convenience
-pub use cli::{Cli, ColorArgs, Commands, GradientArgs, HueArgs};
+pub use cli::{
+    CanonicalizeArgs, Cli, ColorArgs, Commands, ConvertArgs, DescribeArgs, EqualArgs,
+    ExportCollectionArgs, GamutReportArgs, GradientArgs, HarmonyGradientArgs, HueArgs, InfoArgs,
+    MixArgs, PaletteArgs, RampArgs, RandomArgs, ScalePositionArgs, SearchArgs,
+};
 pub use color::{ColorInfo, ColorSpace};
 pub use color_distance_strategies::{
     ALens,
@@ -76,8 +83,8 @@ pub use color_ops::analysis::hue::{
 // Color Matching - Functional pattern matching across collections
 pub use color_matching::{
     CollectionType, MatchingConfig, extract_hue_from_code, match_across_all_collections,
-    match_color, match_color_by_type, post_process_ral_design, validate_lab_basic,
-    validate_ral_classic, validate_ral_design,
+    match_color, match_color_by_type, match_confidence, post_process_ral_design,
+    validate_lab_basic, validate_ral_classic, validate_ral_design,
 };
 // Color Operations - Comprehensive functional color operations
 pub use color_ops::{
@@ -199,12 +206,7 @@ pub use utils::Utils;
 /// let args = ColorArgs {
 ///     color: "#FF6B35".to_string(),
 ///     distance_method: "lab".to_string(),
-///     scheme_strategy: "lab".to_string(),
-///     relative_luminance: None,
-///     luminance: None,
-///     output_format: None,
-///     output_file: None,
-///     func_filter: None,
+///     ..Default::default()
 /// };
 ///
 /// let matches = color_rs.color_match(&args)?;
@@ -213,27 +215,17 @@ pub use utils::Utils;
 ///
 /// ## Gradient Generation
 /// ```rust
-/// use color_rs::{ColorRs, cli::GradientArgs};
+/// use color_rs::{ColorRs, cli::{GradientArgs, ImageWidth}};
 ///
 /// let color_rs = ColorRs::new();
 /// let args = GradientArgs {
 ///     start_color: "#FF0000".to_string(),
 ///     end_color: "#0000FF".to_string(),
-///     start_position: 0,
-///     end_position: 100,
 ///     ease_in: 0.4,
 ///     ease_out: 0.6,
-///     svg: None,
-///     png: None,
-///     vectorized_text: true,
-///     no_legend: false,
-///     width: 1000,
-///     step: None,
-///     stops: 5,
-///     stops_simple: false,
-///     output_format: None,
+///     width: ImageWidth::Pixels(1000),
 ///     output_file: Some("gradient.svg".to_string()),
-///     func_filter: None,
+///     ..Default::default()
 /// };
 ///
 /// color_rs.generate_gradient(args)?;
@@ -264,6 +256,10 @@ pub use utils::Utils;
 ///     border_width: 0, // No borders for analysis-only mode
 ///     border_color: "white".to_string(),
 ///     header_text: None,
+///     find_duplicates: None,
+///     min_chroma: None,
+///     sort: vec![color_rs::cli::CollectionSortKey::Hue],
+///     dry_run: false,
 /// };
 ///
 /// color_rs.analyze_hue(&args)?;
@@ -300,27 +296,17 @@ impl ColorRs {
     ///
     /// # Examples
     /// ```rust
-    /// use color_rs::{ColorRs, cli::GradientArgs};
+    /// use color_rs::{ColorRs, cli::{GradientArgs, ImageWidth}};
     ///
     /// let color_rs = ColorRs::new();
     /// let args = GradientArgs {
     ///     start_color: "#FF0000".to_string(),
     ///     end_color: "#0000FF".to_string(),
-    ///     start_position: 0,
-    ///     end_position: 100,
     ///     ease_in: 0.4,
     ///     ease_out: 0.6,
-    ///     svg: None,
-    ///     png: None,
-    ///     vectorized_text: true,
-    ///     no_legend: false,
-    ///     width: 800,
-    ///     step: None,
-    ///     stops: 5,
-    ///     stops_simple: false,
-    ///     output_format: None,
+    ///     width: ImageWidth::Pixels(800),
     ///     output_file: Some("gradient.svg".to_string()),
-    ///     func_filter: None,
+    ///     ..Default::default()
     /// };
     ///
     /// color_rs.generate_gradient(args)?;
@@ -358,12 +344,7 @@ impl ColorRs {
     /// let args = ColorArgs {
     ///     color: "#FF5733".to_string(),
     ///     distance_method: "lab".to_string(),
-    ///     scheme_strategy: "lab".to_string(),
-    ///     relative_luminance: None,
-    ///     luminance: None,
-    ///     output_format: None,
-    ///     output_file: None,
-    ///     func_filter: None,
+    ///     ..Default::default()
     /// };
     ///
     /// let matches = color_rs.color_match(&args)?;
@@ -423,6 +404,10 @@ impl ColorRs {
     ///     border_width: 0, // No borders for analysis-only mode
     ///     border_color: "white".to_string(),
     ///     header_text: None,
+    ///     find_duplicates: None,
+    ///     min_chroma: None,
+    ///     sort: vec![color_rs::cli::CollectionSortKey::Hue],
+    ///     dry_run: false,
     /// };
     ///
     /// color_rs.analyze_hue(&args)?;
@@ -436,6 +421,209 @@ impl ColorRs {
         println!("{}", result.output);
         Ok(())
     }
+
+    /// Generate a tints-and-shades ramp around a base color
+    ///
+    /// Mixes the base toward white and black in LAB space and prints the ramp,
+    /// ordered from the lightest tint to the darkest shade, in the selected
+    /// structured format.
+    ///
+    /// # Errors
+    /// Returns an error if arguments are invalid, the base color cannot be parsed,
+    /// or the output file cannot be written.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use color_rs::{ColorRs, cli::RampArgs};
+    ///
+    /// let color_rs = ColorRs::new();
+    /// let args = RampArgs {
+    ///     color: "#3366CC".to_string(),
+    ///     tints: 5,
+    ///     shades: 5,
+    ///     preserve_hue: false,
+    ///     output_format: None,
+    ///     output_file: None,
+    ///     dry_run: false,
+    /// };
+    ///
+    /// color_rs.generate_ramp(&args)?;
+    /// # Ok::<(), color_rs::error::ColorError>(())
+    /// ```
+    pub fn generate_ramp(&self, args: &RampArgs) -> Result<()> {
+        args.validate()?;
+        command_execution::execute_ramp(args)?;
+        Ok(())
+    }
+
+    /// Describe a color in plain English
+    ///
+    /// Returns a phrase such as "a light, moderately saturated, warm red", or a
+    /// JSON object with each descriptor when `args.format` is `Json`.
+    ///
+    /// # Errors
+    /// Returns an error if the color cannot be parsed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use color_rs::{ColorRs, cli::{DescribeArgs, DescribeFormat}};
+    ///
+    /// let color_rs = ColorRs::new();
+    /// let args = DescribeArgs {
+    ///     color: "#FF9999".to_string(),
+    ///     format: DescribeFormat::Phrase,
+    /// };
+    ///
+    /// let phrase = color_rs.describe(&args)?;
+    /// assert!(phrase.contains("red"));
+    /// # Ok::<(), color_rs::error::ColorError>(())
+    /// ```
+    pub fn describe(&self, args: &DescribeArgs) -> Result<String> {
+        Ok(command_execution::execute_describe(args)?.output)
+    }
+
+    /// Export every entry of a built-in collection to a JSON or YAML file
+    ///
+    /// Each entry carries its name, hex, RGB and code (when the collection has
+    /// codes). Returns a one-line summary of what was written.
+    ///
+    /// # Errors
+    /// Returns an error if the collection is unknown, the output extension is not
+    /// .json/.yaml/.yml, or the file cannot be written.
+    pub fn export_collection(&self, args: &ExportCollectionArgs) -> Result<String> {
+        Ok(command_execution::execute_export_collection(args)?.output)
+    }
+
+    /// Classify every color of a palette file against a display gamut
+    ///
+    /// Returns a YAML (or TOML) report listing each color with `in_gamut` and
+    /// summarizing the share of the palette outside the gamut.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, a line is not a valid color,
+    /// or the requested output format is not YAML or TOML.
+    pub fn gamut_report(&self, args: &GamutReportArgs) -> Result<String> {
+        Ok(command_execution::execute_gamut_report(args)?.output)
+    }
+
+    /// Rewrite colors in one canonical notation, one per line in input order
+    ///
+    /// # Errors
+    /// Returns an error if the input file cannot be read or any input is not a
+    /// valid color.
+    pub fn canonicalize(&self, args: &CanonicalizeArgs) -> Result<String> {
+        Ok(command_execution::execute_canonicalize(args)?.output)
+    }
+
+    /// Compare two colors within a tolerance
+    ///
+    /// Returns whether they are equal and a one-line verdict with the measured
+    /// distance.
+    ///
+    /// # Errors
+    /// Returns an error if a color cannot be parsed, the distance method is
+    /// unknown, or the tolerance is negative.
+    pub fn equal(&self, args: &EqualArgs) -> Result<(bool, String)> {
+        let result = command_execution::execute_equal(args)?;
+        let equal = result.metadata.get("equal").is_some_and(|v| v == "true");
+        Ok((equal, result.output))
+    }
+
+    /// List the entries of a collection whose code starts with a prefix
+    ///
+    /// Returns YAML with the collection name and each entry's name, hex, RGB
+    /// and code, in collection order.
+    ///
+    /// # Errors
+    /// Returns an error if the collection is unknown or cannot be loaded.
+    pub fn search(&self, args: &SearchArgs) -> Result<String> {
+        Ok(command_execution::execute_search(args)?.output)
+    }
+
+    /// Render the colors of a palette file as a labeled contact-sheet PNG
+    ///
+    /// Returns a one-line summary with the grid size and output path.
+    ///
+    /// # Errors
+    /// Returns an error if the arguments are invalid, the file cannot be read,
+    /// a line is not a valid color, or the PNG cannot be written.
+    pub fn palette(&self, args: &PaletteArgs) -> Result<String> {
+        Ok(command_execution::execute_palette(args)?.output)
+    }
+
+    /// List supported features, such as the accepted input formats
+    ///
+    /// # Errors
+    /// Currently infallible; returns `Result` like the other commands.
+    pub fn info(&self, args: &InfoArgs) -> Result<String> {
+        Ok(command_execution::execute_info(args)?.output)
+    }
+
+    /// Generate gradients from a base color to each color of a harmony scheme
+    ///
+    /// Displays the gradients as YAML (or TOML) and saves them when an output
+    /// file is given.
+    ///
+    /// # Errors
+    /// Returns an error if the arguments are invalid, the base color cannot be
+    /// parsed, or the output cannot be serialized or written.
+    pub fn harmony_gradient(&self, args: &HarmonyGradientArgs) -> Result<()> {
+        args.validate()?;
+        command_execution::execute_harmony_gradient(args)?;
+        Ok(())
+    }
+
+    /// Generate a seeded random palette of distinct colors
+    ///
+    /// Displays the palette as YAML (or TOML), including the seed that
+    /// reproduces it, and saves it when an output file is given.
+    ///
+    /// # Errors
+    /// Returns an error if the arguments are invalid, no palette fits the
+    /// constraints, or the output cannot be serialized or written.
+    pub fn random(&self, args: &RandomArgs) -> Result<()> {
+        args.validate()?;
+        command_execution::execute_random(args)?;
+        Ok(())
+    }
+
+    /// Find where a color falls on a named colormap
+    ///
+    /// Displays the position (0-1) of the nearest scale color by ΔE2000, that
+    /// color and the distance as YAML (or TOML), and saves it when an output
+    /// file is given.
+    ///
+    /// # Errors
+    /// Returns an error if the arguments are invalid, the color cannot be
+    /// parsed, or the output cannot be serialized or written.
+    pub fn scale_position(&self, args: &ScalePositionArgs) -> Result<()> {
+        args.validate()?;
+        command_execution::execute_scale_position(args)?;
+        Ok(())
+    }
+
+    /// Convert a list of colors to one format
+    ///
+    /// Displays one input-to-output row per color, in input order, as YAML,
+    /// TOML or a Markdown table, and saves it when an output file is given.
+    ///
+    /// # Errors
+    /// Returns an error if the arguments are invalid, an input cannot be read
+    /// or parsed, or the output cannot be serialized or written.
+    pub fn convert(&self, args: &ConvertArgs) -> Result<()> {
+        args.validate()?;
+        command_execution::execute_convert(args)?;
+        Ok(())
+    }
+
+    /// Mix two colors and return the result as `#RRGGBB`
+    ///
+    /// # Errors
+    /// Returns an error if the amount is outside 0.0-1.0 or a color cannot be
+    /// parsed.
+    pub fn mix(&self, args: &MixArgs) -> Result<String> {
+        Ok(command_execution::execute_mix(args)?.output)
+    }
 }
 
 impl Default for ColorRs {
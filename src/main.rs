@@ -6,13 +6,18 @@ use color_rs::{ColorRs, cli};
 
 fn main() -> color_rs::Result<()> {
     // Parse command line arguments
-    let cli_args = cli::Cli::parse();
+    let args = color_rs::profiles::apply_profile(std::env::args_os().collect())?;
+    let cli_args = cli::Cli::parse_from(args);
 
     // Create color-rs instance and process command
     let color_rs = ColorRs::new();
 
-    match cli_args.command {
-        cli::Commands::Gradient(args) => color_rs.generate_gradient(args)?,
+    match cli_args.into_command() {
+        cli::Commands::Gradient(args) => {
+            // Validate arguments before processing
+            args.validate()?;
+            color_rs.generate_gradient(args)?;
+        }
         cli::Commands::Color(args) => {
             // Validate arguments before processing
             args.validate()?;
@@ -24,6 +29,53 @@ fn main() -> color_rs::Result<()> {
             args.validate()?;
             color_rs.analyze_hue(&args)?;
         }
+        cli::Commands::Ramp(args) => {
+            // Validate arguments before processing
+            args.validate()?;
+            color_rs.generate_ramp(&args)?;
+        }
+        cli::Commands::Describe(args) => {
+            println!("{}", color_rs.describe(&args)?);
+        }
+        cli::Commands::ExportCollection(args) => {
+            println!("{}", color_rs.export_collection(&args)?);
+        }
+        cli::Commands::GamutReport(args) => {
+            print!("{}", color_rs.gamut_report(&args)?);
+        }
+        cli::Commands::Canonicalize(args) => {
+            println!("{}", color_rs.canonicalize(&args)?);
+        }
+        cli::Commands::Equal(args) => {
+            // Scripts branch on the exit code, so errors must not look like "different"
+            let code = match color_rs.equal(&args) {
+                Ok((equal, verdict)) => {
+                    println!("{verdict}");
+                    cli::EqualArgs::exit_code(equal)
+                }
+                Err(error) => {
+                    eprintln!("Error: {error}");
+                    cli::EqualArgs::EXIT_ERROR
+                }
+            };
+            std::process::exit(code);
+        }
+        cli::Commands::Search(args) => {
+            print!("{}", color_rs.search(&args)?);
+        }
+        cli::Commands::Palette(args) => {
+            println!("{}", color_rs.palette(&args)?);
+        }
+        cli::Commands::Info(args) => {
+            println!("{}", color_rs.info(&args)?);
+        }
+        cli::Commands::HarmonyGradient(args) => color_rs.harmony_gradient(&args)?,
+        cli::Commands::Random(args) => color_rs.random(&args)?,
+        cli::Commands::ScalePosition(args) => color_rs.scale_position(&args)?,
+        cli::Commands::Convert(args) => color_rs.convert(&args)?,
+        cli::Commands::Mix(args) => {
+            println!("{}", color_rs.mix(&args)?);
+        }
     }
 
     Ok(())
@@ -1,7 +1,8 @@
-//! Output format serialization for TOML and YAML files
+//! Output format serialization for TOML, YAML and env files
 //!
 //! This module implements serializable data structures for exporting color analysis
 //! results to TOML and YAML formats using the builder pattern for different output types.
+//! The flat env format (`KEY=#RRGGBB` lines) is derived from the same structures.
 
 use serde::{Deserialize, Serialize};
 
@@ -54,6 +55,40 @@ pub struct ColorAnalysisOutput {
     pub color_collections: ColorCollections,
     /// Color schemes
     pub color_schemes: ColorSchemes,
+    /// LAB -> sRGB -> LAB precision diagnostic (`--round-trip-check`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub round_trip_check: Option<RoundTripCheck>,
+    /// Effect of clamping the input into sRGB (`--clamp-report`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clamp_report: Option<ClampReport>,
+    /// Report sections to serialize (`--sections`); empty keeps all of them
+    #[serde(skip)]
+    pub sections: Vec<crate::cli::ReportSection>,
+    /// `(--color-<name>, #RRGGBB)` declarations for `--output css-vars`
+    #[serde(skip)]
+    pub css_properties: Vec<(String, String)>,
+}
+
+/// [`ColorAnalysisOutput`] as serialized, with unselected sections left out
+#[derive(Serialize)]
+struct SelectedSections<'a> {
+    metadata: &'a ProgramMetadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    input: Option<&'a InputInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversion: Option<&'a ColorFormats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contrast: Option<&'a ContrastData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    grayscale: Option<&'a GrayscaleData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color_collections: Option<&'a ColorCollections>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color_schemes: Option<&'a ColorSchemes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    round_trip_check: Option<&'a RoundTripCheck>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    clamp_report: Option<&'a ClampReport>,
 }
 
 /// Complete gradient analysis result that can be serialized to TOML/YAML
@@ -80,6 +115,27 @@ pub struct EnhancedGradientAnalysisOutput {
     pub colors: GradientColors,
     /// Enhanced gradient steps/stops with nested structure
     pub gradient_stops: Vec<EnhancedGradientStop>,
+    /// Worst-case contrast of the stops against a text color (`--text-contrast`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_contrast: Option<GradientTextContrast>,
+}
+
+/// Lowest WCAG contrast of any gradient stop against a text color
+#[derive(Debug, Clone, Serialize)]
+pub struct GradientTextContrast {
+    /// Text color as given
+    pub text: String,
+    pub hex: String,
+    /// Lowest contrast ratio over all stops
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub min_ratio: f64,
+    /// Position of the stop with the lowest contrast
+    pub worst_position: String,
+    pub worst_hex: String,
+    /// Whether the lowest ratio meets WCAG AA for normal text (4.5:1)
+    pub aa_normal_text: bool,
+    /// Whether the lowest ratio meets WCAG AA for large text (3:1)
+    pub aa_large_text: bool,
 }
 
 /// Hue collection analysis output with structured color information
@@ -91,6 +147,229 @@ pub struct HueCollectionOutput {
     pub configuration: HueCollectionConfiguration,
     /// Filtered and sorted color entries
     pub colors: Vec<HueColorEntry>,
+    /// Near-identical color pairs (only with --find-duplicates)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<HueDuplicateEntry>,
+}
+
+/// Tints-and-shades ramp around a base color
+#[derive(Debug, Clone, Serialize)]
+pub struct RampOutput {
+    /// Program metadata
+    pub metadata: ProgramMetadata,
+    /// Ramp configuration
+    pub configuration: RampConfiguration,
+    /// Ramp colors ordered from lightest tint to darkest shade
+    pub ramp: Vec<RampEntry>,
+}
+
+/// Ramp configuration information
+#[derive(Debug, Clone, Serialize)]
+pub struct RampConfiguration {
+    pub base_color: String,
+    pub tints: usize,
+    pub shades: usize,
+    pub preserve_hue: bool,
+}
+
+/// Individual ramp color (`tint-N`, `base` or `shade-N`)
+#[derive(Debug, Clone, Serialize)]
+pub struct RampEntry {
+    pub name: String,
+    pub hex: String,
+    pub lch: String,
+}
+
+/// Gradients from a base color to each color of a harmony scheme
+#[derive(Debug, Clone, Serialize)]
+pub struct HarmonyGradientOutput {
+    /// Program metadata
+    pub metadata: ProgramMetadata,
+    /// Harmony gradient configuration
+    pub configuration: HarmonyGradientConfiguration,
+    /// One gradient per scheme color, in scheme order
+    pub gradients: Vec<HarmonyGradient>,
+}
+
+/// Harmony gradient configuration information
+#[derive(Debug, Clone, Serialize)]
+pub struct HarmonyGradientConfiguration {
+    pub base_color: String,
+    pub relationship: String,
+    pub scheme_strategy: String,
+    pub ease_in: f64,
+    pub ease_out: f64,
+    pub stops: usize,
+}
+
+/// Gradient from the base color to one scheme color
+#[derive(Debug, Clone, Serialize)]
+pub struct HarmonyGradient {
+    /// Scheme color name, e.g. `triadic_2`
+    pub target: String,
+    pub start: String,
+    pub end: String,
+    pub stops: Vec<HarmonyGradientStop>,
+}
+
+/// Individual harmony gradient stop
+#[derive(Debug, Clone, Serialize)]
+pub struct HarmonyGradientStop {
+    /// Position as a percentage of the gradient
+    pub position: u8,
+    pub hex: String,
+}
+
+/// Seeded random palette
+#[derive(Debug, Clone, Serialize)]
+pub struct RandomPaletteOutput {
+    /// Program metadata
+    pub metadata: ProgramMetadata,
+    /// Random palette configuration, including the seed to reproduce it
+    pub configuration: RandomPaletteConfiguration,
+    /// Colors in the order they were drawn
+    pub colors: Vec<RandomPaletteColor>,
+}
+
+/// Random palette configuration information
+#[derive(Debug, Clone, Serialize)]
+pub struct RandomPaletteConfiguration {
+    pub count: usize,
+    pub seed: u64,
+    pub min_chroma: f64,
+    pub lightness_range: String,
+    pub min_delta_e: f64,
+}
+
+/// Individual random palette color
+#[derive(Debug, Clone, Serialize)]
+pub struct RandomPaletteColor {
+    /// Nearest CSS color name
+    pub name: String,
+    pub hex: String,
+    pub lch: String,
+}
+
+/// Position of a color on a named colormap
+#[derive(Debug, Clone, Serialize)]
+pub struct ScalePositionOutput {
+    /// Program metadata
+    pub metadata: ProgramMetadata,
+    /// Color as given
+    pub input: String,
+    /// Input color as HEX
+    pub hex: String,
+    /// Colormap searched
+    pub scale: String,
+    /// Position of the nearest scale color (0.0-1.0)
+    pub position: f64,
+    /// Scale color at that position
+    pub scale_color: String,
+    /// ΔE2000 between the input and the scale color
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub delta_e: f64,
+}
+
+/// Colors converted to one format, in input order
+#[derive(Debug, Clone, Serialize)]
+pub struct ConversionTableOutput {
+    /// Program metadata
+    pub metadata: ProgramMetadata,
+    /// Target format
+    pub to: String,
+    /// One row per input color
+    pub conversions: Vec<ConversionRow>,
+}
+
+/// One input color and its converted notation
+#[derive(Debug, Clone, Serialize)]
+pub struct ConversionRow {
+    /// Color as given
+    pub input: String,
+    /// Color in the target format
+    pub output: String,
+    /// Nearest named color of each collection (with `--names`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub nearest: Vec<NearestName>,
+}
+
+/// Closest entry of one color collection
+#[derive(Debug, Clone, Serialize)]
+pub struct NearestName {
+    /// Collection display name
+    pub collection: String,
+    /// Entry code, e.g. `RAL 3020`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Entry name
+    pub name: String,
+    /// ΔE2000 from the input color
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub distance: f64,
+}
+
+/// Gradient colors at requested positions, e.g. animation keyframes
+#[derive(Debug, Clone, Serialize)]
+pub struct GradientSamplesOutput {
+    /// Program metadata
+    pub metadata: ProgramMetadata,
+    /// One sample per distinct requested fraction, in request order
+    pub samples: Vec<GradientSample>,
+}
+
+/// Gradient color at one requested fraction
+#[derive(Debug, Clone, Serialize)]
+pub struct GradientSample {
+    /// Requested fraction of the gradient (0.0-1.0), before easing
+    pub t: f64,
+    /// Position within the start/end position range
+    pub position: String,
+    pub hex: String,
+    pub rgb: String,
+}
+
+/// Every entry of a color collection, for name pickers and autocompletion
+#[derive(Debug, Clone, Serialize)]
+pub struct CollectionExportOutput {
+    /// Collection display name
+    pub collection: String,
+    /// Entries in collection order
+    pub colors: Vec<CollectionExportEntry>,
+}
+
+/// Exported collection entry
+#[derive(Debug, Clone, Serialize)]
+pub struct CollectionExportEntry {
+    pub name: String,
+    pub hex: String,
+    pub rgb: [u8; 3],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+}
+
+/// Classification of a palette against a target gamut
+#[derive(Debug, Clone, Serialize)]
+pub struct GamutReportOutput {
+    /// Program metadata
+    pub metadata: ProgramMetadata,
+    /// Target gamut name
+    pub gamut: String,
+    pub total_colors: usize,
+    pub out_of_gamut: usize,
+    /// Share of the palette outside the gamut (0-100)
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub percent_out_of_gamut: f64,
+    /// Palette colors in input order
+    pub colors: Vec<GamutReportEntry>,
+}
+
+/// Gamut classification of one palette color
+#[derive(Debug, Clone, Serialize)]
+pub struct GamutReportEntry {
+    /// Color as written in the input file
+    pub input: String,
+    pub lab: String,
+    pub in_gamut: bool,
 }
 
 /// Hue collection configuration information
@@ -101,6 +380,17 @@ pub struct HueCollectionConfiguration {
     pub hue_range: Option<String>,
     pub lightness_range: Option<String>,
     pub chroma_range: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_chroma: Option<f64>,
+}
+
+/// Pair of differently named collection entries with near-identical colors
+#[derive(Debug, Clone, Serialize)]
+pub struct HueDuplicateEntry {
+    pub first: String,
+    pub second: String,
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub delta_e: f64,
 }
 
 /// Individual hue color entry formatted as single line: Hue | code | HEX | LCH | name | Hue shift
@@ -220,7 +510,9 @@ pub struct ProgramMetadata {
     pub author: String,
     pub description: String,
     pub generated_at: String,
-    pub distance_strategy: String,
+    /// Color distance metric the command measured with, if it measured any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance_strategy: Option<String>,
 }
 
 /// Input color information
@@ -228,6 +520,12 @@ pub struct ProgramMetadata {
 pub struct InputInfo {
     pub input_color: String,
     pub base_color: String,
+    /// Notation the input was written in (hex, rgb, hsl, named, lab, ...)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_format: Option<String>,
+    /// Input exactly as given, in its source notation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_value: Option<String>,
 }
 
 /// All color format conversions
@@ -244,13 +542,51 @@ pub struct ColorFormats {
     pub oklch: String,
 }
 
+/// Error accrued by converting the input LAB to 8-bit sRGB and back
+#[derive(Debug, Clone, Serialize)]
+pub struct RoundTripCheck {
+    /// LAB value of the parsed input
+    pub lab_in: String,
+    /// 8-bit sRGB the input converts to (after clamping)
+    pub rgb: String,
+    /// LAB value recovered from that sRGB
+    pub lab_out: String,
+    /// ΔE2000 between `lab_in` and `lab_out`
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub delta_e_2000: f64,
+    /// Largest per-channel difference (0-255 scale) between the exact sRGB and `rgb`
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub rgb_delta: f64,
+    /// Whether the input lies inside the sRGB gamut
+    pub in_gamut: bool,
+}
+
+/// Change made by clamping the input into the sRGB gamut
+#[derive(Debug, Clone, Serialize)]
+pub struct ClampReport {
+    /// sRGB channels (0-1) before clamping; out-of-gamut inputs fall below 0 or above 1
+    pub unclamped: String,
+    /// sRGB channels (0-1) after clamping
+    pub clamped: String,
+    /// Channels that were clamped, with their unclamped values
+    pub clamped_channels: Vec<String>,
+    /// ΔE2000 between the input and its clamped color
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub delta_e_2000: f64,
+}
+
 /// Contrast and luminance information
 #[derive(Debug, Clone, Serialize)]
 pub struct ContrastData {
     #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_wcag_luminance")]
     pub wcag21_relative_luminance: f64,
+    /// Rec. 709 luma of the gamma-encoded sRGB values (not for WCAG contrast)
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_wcag_luminance")]
+    pub luma: f64,
     pub contrast_vs_white: ContrastInfo,
     pub contrast_vs_black: ContrastInfo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contrast_vs_target: Option<TargetContrastInfo>,
     pub brightness: BrightnessInfo,
 }
 
@@ -272,6 +608,25 @@ pub struct GrayscaleData {
 pub struct ContrastInfo {
     pub ratio: f64,
     pub assessment: String,
+    /// WCAG level ("AAA", "AA" or "Fail") for the requested text size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance: Option<String>,
+}
+
+/// Contrast against a user-specified color with WCAG pass/fail results
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct TargetContrastInfo {
+    pub against: String,
+    pub hex: String,
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub ratio: f64,
+    pub aa_normal_text: bool,
+    pub aa_large_text: bool,
+    pub aaa_normal_text: bool,
+    pub aaa_large_text: bool,
+    /// WCAG level ("AAA", "AA" or "Fail") for the requested text size
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance: Option<String>,
 }
 
 /// Brightness assessment
@@ -284,8 +639,30 @@ pub struct BrightnessInfo {
 /// Color collection matches
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct ColorCollections {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub css_colors: Vec<ColorMatch>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ral_classic: Vec<ColorMatch>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ral_design: Vec<ColorMatch>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub custom: Vec<ColorMatch>,
+    /// Matches that meet a contrast ratio against a background (only with --accessible-against)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessible: Option<AccessibleMatches>,
+}
+
+/// Closest collection colors restricted to those legible on a background
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct AccessibleMatches {
+    pub background: String,
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub min_ratio: f64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub css_colors: Vec<ColorMatch>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub ral_classic: Vec<ColorMatch>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub ral_design: Vec<ColorMatch>,
 }
 
@@ -298,8 +675,29 @@ pub struct ColorMatch {
     pub code: Option<String>,
     #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
     pub distance: f64,
+    /// How well the name describes the color, 0-1 (see `match_confidence`)
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub confidence: f64,
     #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_wcag_luminance")]
     pub wcag21_relative_luminance: f64,
+    /// Every distance metric for this match (only with --all-distances)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distances: Option<MatchDistances>,
+}
+
+/// Distance from the input color to a match under every supported metric
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct MatchDistances {
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub delta_e_76: f64,
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub delta_e_94: f64,
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub delta_e_2000: f64,
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub euclidean_lab: f64,
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub lch: f64,
 }
 
 /// Color schemes with configurable strategy
@@ -309,6 +707,88 @@ pub struct ColorSchemes {
     pub split_complementary: Vec<EnhancedColorSchemeItem>,
     pub triadic: Vec<EnhancedColorSchemeItem>,
     pub tetradic: Vec<EnhancedColorSchemeItem>,
+    /// Complement with lightness chosen for maximum contrast (`--high-contrast-complement`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub high_contrast_complement: Option<EnhancedColorSchemeItem>,
+    /// Luminance-matched variants (only when luminance preservation is enabled)
+    #[serde(skip_serializing_if = "LuminanceMatchedSchemes::is_omitted")]
+    pub luminance_matched: LuminanceMatchedSchemes,
+}
+
+/// Optional output value that is omitted when `None`, or written as an
+/// explicit null when `emit_null` is set (`--include-null-schemes`)
+///
+/// TOML has no null, so `None` is always omitted from TOML output.
+#[derive(Debug, Clone)]
+pub struct NullableField<T> {
+    pub value: Option<T>,
+    pub emit_null: bool,
+}
+
+impl<T> Default for NullableField<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            emit_null: false,
+        }
+    }
+}
+
+impl<T> NullableField<T> {
+    /// Whether serialization should skip this field
+    #[must_use]
+    pub const fn is_omitted(&self) -> bool {
+        self.value.is_none() && !self.emit_null
+    }
+}
+
+impl<T> From<Option<T>> for NullableField<T> {
+    fn from(value: Option<T>) -> Self {
+        Self {
+            value,
+            emit_null: false,
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for NullableField<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+/// Luminance-matched variants of the selected scheme strategy
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct LuminanceMatchedSchemes {
+    #[serde(skip_serializing_if = "NullableField::is_omitted")]
+    pub complementary: NullableField<EnhancedColorSchemeItem>,
+    #[serde(skip_serializing_if = "NullableField::is_omitted")]
+    pub split_complementary: NullableField<Vec<EnhancedColorSchemeItem>>,
+    #[serde(skip_serializing_if = "NullableField::is_omitted")]
+    pub triadic: NullableField<Vec<EnhancedColorSchemeItem>>,
+    #[serde(skip_serializing_if = "NullableField::is_omitted")]
+    pub tetradic: NullableField<Vec<EnhancedColorSchemeItem>>,
+}
+
+impl LuminanceMatchedSchemes {
+    /// Whether every variant is omitted, so the whole block can be skipped
+    #[must_use]
+    pub const fn is_omitted(&self) -> bool {
+        self.complementary.is_omitted()
+            && self.split_complementary.is_omitted()
+            && self.triadic.is_omitted()
+            && self.tetradic.is_omitted()
+    }
+
+    /// Write missing variants as explicit nulls for a stable schema
+    #[must_use]
+    pub const fn with_nulls(mut self) -> Self {
+        self.complementary.emit_null = true;
+        self.split_complementary.emit_null = true;
+        self.triadic.emit_null = true;
+        self.tetradic.emit_null = true;
+        self
+    }
 }
 
 /// Enhanced color scheme item with direct collection matches
@@ -317,9 +797,25 @@ pub struct EnhancedColorSchemeItem {
     pub hex: String,
     pub hsl: String,
     pub lch: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub css: Option<CollectionMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ral_classic: Option<CollectionMatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ral_design: Option<CollectionMatch>,
+    /// Full analysis of this color (`--analyze-schemes`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis: Option<SchemeColorAnalysis>,
+}
+
+/// Analysis of a single scheme color relative to the base color
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemeColorAnalysis {
+    /// WCAG contrast ratio against the base color
+    #[serde(serialize_with = "crate::precision_utils::PrecisionUtils::serialize_f64_3")]
+    pub contrast_vs_base: f64,
+    /// Properties, color spaces, perception and accessibility of the scheme color
+    pub details: crate::color_ops::analysis::ColorAnalysis,
 }
 
 /// Simplified collection match with essential data
@@ -351,6 +847,10 @@ impl ColorAnalysisOutput {
             grayscale: GrayscaleData::default(),
             color_collections: ColorCollections::default(),
             color_schemes: ColorSchemes::default(),
+            round_trip_check: None,
+            clamp_report: None,
+            sections: Vec::new(),
+            css_properties: Vec::new(),
         }
     }
 
@@ -360,10 +860,23 @@ impl ColorAnalysisOutput {
         self.input = InputInfo {
             input_color,
             base_color,
+            ..InputInfo::default()
         };
         self
     }
 
+    /// Record the notation the input was written in, for round-tripping tools
+    #[must_use]
+    pub fn with_input_format(
+        mut self,
+        format: crate::color_parser::ColorFormat,
+        input_value: &str,
+    ) -> Self {
+        self.input.input_format = Some(format.name().to_string());
+        self.input.input_value = Some(input_value.to_string());
+        self
+    }
+
     /// Set color formats
     #[must_use]
     pub fn with_conversion(mut self, conversion: ColorFormats) -> Self {
@@ -399,13 +912,63 @@ impl ColorAnalysisOutput {
         self
     }
 
+    /// Serialize only these report sections; an empty list keeps all of them
+    #[must_use]
+    pub fn with_sections(mut self, sections: &[crate::cli::ReportSection]) -> Self {
+        self.sections = sections.to_vec();
+        self
+    }
+
+    /// Set the CSS custom properties rendered by [`to_css_vars`](Self::to_css_vars)
+    #[must_use]
+    pub fn with_css_properties(mut self, css_properties: Vec<(String, String)>) -> Self {
+        self.css_properties = css_properties;
+        self
+    }
+
+    /// Whether `section` is serialized
+    #[must_use]
+    pub fn includes(&self, section: crate::cli::ReportSection) -> bool {
+        self.sections.is_empty() || self.sections.contains(&section)
+    }
+
+    /// Borrowed view of the report without the sections left out by `--sections`
+    ///
+    /// Metadata and the opt-in diagnostics (`round_trip_check`, `clamp_report`)
+    /// are always kept.
+    fn selected_sections(&self) -> SelectedSections<'_> {
+        use crate::cli::ReportSection;
+
+        SelectedSections {
+            metadata: &self.metadata,
+            input: self.includes(ReportSection::Input).then_some(&self.input),
+            conversion: self
+                .includes(ReportSection::Conversion)
+                .then_some(&self.conversion),
+            contrast: self
+                .includes(ReportSection::Contrast)
+                .then_some(&self.contrast),
+            grayscale: self
+                .includes(ReportSection::Grayscale)
+                .then_some(&self.grayscale),
+            color_collections: self
+                .includes(ReportSection::Collections)
+                .then_some(&self.color_collections),
+            color_schemes: self
+                .includes(ReportSection::Schemes)
+                .then_some(&self.color_schemes),
+            round_trip_check: self.round_trip_check.as_ref(),
+            clamp_report: self.clamp_report.as_ref(),
+        }
+    }
+
     /// Serialize to TOML format
     ///
     /// # Errors
     /// Returns `toml::ser::Error` if TOML serialization fails due to invalid data structure
     /// or unsupported data types.
     pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
-        toml::to_string_pretty(self)
+        toml::to_string_pretty(&self.selected_sections())
     }
 
     /// Serialize to YAML format
@@ -414,7 +977,85 @@ impl ColorAnalysisOutput {
     /// Returns `serde_yml::Error` if YAML serialization fails due to invalid data structure
     /// or unsupported data types.
     pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
-        serde_yml::to_string(self)
+        serde_yml::to_string(&self.selected_sections())
+    }
+
+    /// Serialize to flat env format (`KEY=#RRGGBB` lines)
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if the data cannot be converted to a serializable value.
+    pub fn to_env(&self) -> Result<String, serde_yml::Error> {
+        to_env_string(&self.selected_sections())
+    }
+
+    /// Render the base and scheme colors as a CSS `:root` block
+    #[must_use]
+    pub fn to_css_vars(&self) -> String {
+        to_css_custom_properties(&self.css_properties)
+    }
+
+    /// Render the conversions, luminance and nearest collection names as a Markdown table
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        use crate::cli::ReportSection;
+
+        let conversion = &self.conversion;
+        let nearest = |matches: &[ColorMatch]| {
+            matches.first().map_or_else(String::new, |m| {
+                format!(
+                    "{} ({}, ΔE {})",
+                    m.name,
+                    m.hex,
+                    crate::precision_utils::PrecisionUtils::format_f64(m.distance)
+                )
+            })
+        };
+        let collections = &self.color_collections;
+
+        let rows: Vec<Vec<String>> = [
+            (
+                ReportSection::Input,
+                "Input",
+                self.input.input_color.clone(),
+            ),
+            (ReportSection::Conversion, "HEX", conversion.hex.clone()),
+            (ReportSection::Conversion, "RGB", conversion.rgb.clone()),
+            (ReportSection::Conversion, "HSL", conversion.hsl.clone()),
+            (ReportSection::Conversion, "HSB", conversion.hsb.clone()),
+            (ReportSection::Conversion, "LAB", conversion.lab.clone()),
+            (ReportSection::Conversion, "LCH", conversion.lch.clone()),
+            (ReportSection::Conversion, "OKLCH", conversion.oklch.clone()),
+            (ReportSection::Conversion, "CMYK", conversion.cmyk.clone()),
+            (ReportSection::Conversion, "XYZ", conversion.xyz.clone()),
+            (
+                ReportSection::Contrast,
+                "WCAG Luminance",
+                crate::precision_utils::PrecisionUtils::format_wcag_relative_luminance(
+                    self.contrast.wcag21_relative_luminance,
+                ),
+            ),
+            (
+                ReportSection::Collections,
+                "CSS",
+                nearest(&collections.css_colors),
+            ),
+            (
+                ReportSection::Collections,
+                "RAL Classic",
+                nearest(&collections.ral_classic),
+            ),
+            (
+                ReportSection::Collections,
+                "RAL Design",
+                nearest(&collections.ral_design),
+            ),
+        ]
+        .into_iter()
+        .filter(|(section, _, _)| self.includes(*section))
+        .map(|(_, field, value)| vec![field.to_string(), value])
+        .collect();
+
+        to_markdown_table(&["Field", "Value"], &rows)
     }
 }
 
@@ -439,6 +1080,23 @@ impl GradientAnalysisOutput {
 }
 
 impl EnhancedGradientAnalysisOutput {
+    /// Render the gradient stops as a Rust `const` array
+    ///
+    /// See [`to_rust_const_array`]; stops whose hex value cannot be parsed are skipped.
+    #[must_use]
+    pub fn to_rust_const(&self) -> String {
+        let stops: Vec<(f32, [u8; 3])> = self
+            .gradient_stops
+            .iter()
+            .filter_map(|stop| {
+                let rgb =
+                    crate::color_parser::csv_loader::CsvLoader::hex_to_rgb(&stop.color.hex).ok()?;
+                Some((stop.position as f32 / 100.0, rgb))
+            })
+            .collect();
+        to_rust_const_array(&stops)
+    }
+
     /// Serialize to TOML format
     ///
     /// # Errors
@@ -456,6 +1114,51 @@ impl EnhancedGradientAnalysisOutput {
     pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
         serde_yml::to_string(self)
     }
+
+    /// Serialize to flat env format (`KEY=#RRGGBB` lines)
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if the data cannot be converted to a serializable value.
+    pub fn to_env(&self) -> Result<String, serde_yml::Error> {
+        to_env_string(self)
+    }
+
+    /// Render one Markdown table row per gradient stop
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .gradient_stops
+            .iter()
+            .map(|stop| {
+                vec![
+                    format!("{}%", stop.position),
+                    stop.color.hex.clone(),
+                    stop.color.rgb.clone(),
+                    stop.color.lab.clone(),
+                    crate::precision_utils::PrecisionUtils::format_wcag_relative_luminance(
+                        stop.color.wcag21_relative_luminance,
+                    ),
+                    stop.collections.css.clone(),
+                    stop.collections.ralc.clone(),
+                    stop.collections.raldsp.clone(),
+                ]
+            })
+            .collect();
+
+        to_markdown_table(
+            &[
+                "Position",
+                "Hex",
+                "RGB",
+                "LAB",
+                "WCAG Luminance",
+                "CSS",
+                "RAL Classic",
+                "RAL Design",
+            ],
+            &rows,
+        )
+    }
 }
 
 impl Default for HueCollectionOutput {
@@ -472,6 +1175,7 @@ impl HueCollectionOutput {
             metadata: ProgramMetadata::new(None),
             configuration: HueCollectionConfiguration::default(),
             colors: Vec::new(),
+            duplicates: Vec::new(),
         }
     }
 
@@ -489,6 +1193,101 @@ impl HueCollectionOutput {
         self
     }
 
+    /// Set duplicate color pairs
+    #[must_use]
+    pub fn with_duplicates(mut self, duplicates: Vec<HueDuplicateEntry>) -> Self {
+        self.duplicates = duplicates;
+        self
+    }
+
+    /// Serialize to TOML format
+    ///
+    /// # Errors
+    /// Returns `toml::ser::Error` if TOML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Serialize to YAML format
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if YAML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
+        serde_yml::to_string(self)
+    }
+}
+
+impl RampOutput {
+    /// Serialize to TOML format
+    ///
+    /// # Errors
+    /// Returns `toml::ser::Error` if TOML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Serialize to YAML format
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if YAML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
+        serde_yml::to_string(self)
+    }
+
+    /// Serialize to flat env format (`KEY=#RRGGBB` lines)
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if the data cannot be converted to a serializable value.
+    pub fn to_env(&self) -> Result<String, serde_yml::Error> {
+        to_env_string(self)
+    }
+}
+
+impl HarmonyGradientOutput {
+    /// Serialize to TOML format
+    ///
+    /// # Errors
+    /// Returns `toml::ser::Error` if TOML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Serialize to YAML format
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if YAML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
+        serde_yml::to_string(self)
+    }
+}
+
+impl RandomPaletteOutput {
+    /// Serialize to TOML format
+    ///
+    /// # Errors
+    /// Returns `toml::ser::Error` if TOML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Serialize to YAML format
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if YAML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
+        serde_yml::to_string(self)
+    }
+}
+
+impl ScalePositionOutput {
     /// Serialize to TOML format
     ///
     /// # Errors
@@ -508,6 +1307,113 @@ impl HueCollectionOutput {
     }
 }
 
+impl ConversionTableOutput {
+    /// Serialize to TOML format
+    ///
+    /// # Errors
+    /// Returns `toml::ser::Error` if TOML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Serialize to YAML format
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if YAML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
+        serde_yml::to_string(self)
+    }
+
+    /// Render the conversions as a Markdown table, input then output, plus a
+    /// nearest-names column when any row has one
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let with_names = self.conversions.iter().any(|row| !row.nearest.is_empty());
+        let rows: Vec<Vec<String>> = self
+            .conversions
+            .iter()
+            .map(|row| {
+                let mut cells = vec![row.input.clone(), row.output.clone()];
+                if with_names {
+                    let names: Vec<String> = row
+                        .nearest
+                        .iter()
+                        .map(|nearest| format!("{} ({})", nearest.name, nearest.collection))
+                        .collect();
+                    cells.push(names.join(", "));
+                }
+                cells
+            })
+            .collect();
+        if with_names {
+            to_markdown_table(&["Input", &self.to, "Nearest"], &rows)
+        } else {
+            to_markdown_table(&["Input", &self.to], &rows)
+        }
+    }
+}
+
+impl GradientSamplesOutput {
+    /// Serialize to TOML format
+    ///
+    /// # Errors
+    /// Returns `toml::ser::Error` if TOML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Serialize to YAML format
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if YAML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
+        serde_yml::to_string(self)
+    }
+}
+
+impl GamutReportOutput {
+    /// Serialize to TOML format
+    ///
+    /// # Errors
+    /// Returns `toml::ser::Error` if TOML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Serialize to YAML format
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if YAML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
+        serde_yml::to_string(self)
+    }
+}
+
+impl CollectionExportOutput {
+    /// Serialize to pretty-printed JSON
+    ///
+    /// # Errors
+    /// Returns `serde_json::Error` if JSON serialization fails.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Serialize to YAML format
+    ///
+    /// # Errors
+    /// Returns `serde_yml::Error` if YAML serialization fails due to invalid data structure
+    /// or unsupported data types.
+    pub fn to_yaml(&self) -> Result<String, serde_yml::Error> {
+        serde_yml::to_string(self)
+    }
+}
+
 impl ProgramMetadata {
     #[must_use]
     /// Create new execution metadata with current timestamp
@@ -539,7 +1445,16 @@ impl ProgramMetadata {
             author: crate::config::APP_AUTHOR.to_string(),
             description: crate::config::APP_DESCRIPTION.to_string(),
             generated_at,
-            distance_strategy: distance_strategy.unwrap_or("LAB Delta E").to_string(),
+            distance_strategy: Some(distance_strategy.unwrap_or("LAB Delta E").to_string()),
+        }
+    }
+
+    #[must_use]
+    /// Create execution metadata for a command that measures no color distance
+    pub fn without_distance() -> Self {
+        Self {
+            distance_strategy: None,
+            ..Self::new(None)
         }
     }
 }
@@ -548,8 +1463,10 @@ impl Default for ContrastData {
     fn default() -> Self {
         Self {
             wcag21_relative_luminance: 0.0,
+            luma: 0.0,
             contrast_vs_white: ContrastInfo::default(),
             contrast_vs_black: ContrastInfo::default(),
+            contrast_vs_target: None,
             brightness: BrightnessInfo::default(),
         }
     }
@@ -560,6 +1477,201 @@ impl Default for ContrastInfo {
         Self {
             ratio: 0.0,
             assessment: String::new(),
+            compliance: None,
+        }
+    }
+}
+
+/// Flatten serializable output into `KEY=#RRGGBB` lines
+///
+/// Only hex color values are emitted. Keys are the uppercase snake-case path of
+/// field names (array items get a 1-based index); a trailing `hex` field or
+/// `_hex` suffix is dropped, so `color_schemes.complementary.hex` becomes
+/// `COLOR_SCHEMES_COMPLEMENTARY`.
+///
+/// # Errors
+/// Returns `serde_yml::Error` if the data cannot be converted to a serializable value.
+pub fn to_env_string<T: Serialize>(data: &T) -> Result<String, serde_yml::Error> {
+    fn is_hex_color(value: &str) -> bool {
+        value.strip_prefix('#').is_some_and(|digits| {
+            matches!(digits.len(), 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit())
+        })
+    }
+
+    fn env_key(path: &[String]) -> String {
+        let mut segments: Vec<&str> = path.iter().map(String::as_str).collect();
+        if segments.len() > 1
+            && segments
+                .last()
+                .is_some_and(|s| s.eq_ignore_ascii_case("hex"))
+        {
+            segments.pop();
+        }
+        let key = segments.join("_");
+        let key = key.strip_suffix("_hex").unwrap_or(&key);
+        key.chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+
+    // YAML values keep struct field order, unlike JSON maps
+    fn collect(value: &serde_yml::Value, path: &mut Vec<String>, lines: &mut Vec<String>) {
+        match value {
+            serde_yml::Value::Mapping(map) => {
+                for (key, child) in map {
+                    path.push(key.as_str().unwrap_or_default().to_string());
+                    collect(child, path, lines);
+                    path.pop();
+                }
+            }
+            serde_yml::Value::Sequence(items) => {
+                for (index, child) in items.iter().enumerate() {
+                    path.push((index + 1).to_string());
+                    collect(child, path, lines);
+                    path.pop();
+                }
+            }
+            serde_yml::Value::String(text) if is_hex_color(text) && !path.is_empty() => {
+                lines.push(format!("{}={}", env_key(path), text.to_ascii_uppercase()));
+            }
+            _ => {}
+        }
+    }
+
+    let value = serde_yml::to_value(data)?;
+    let mut lines = Vec::new();
+    collect(&value, &mut Vec::new(), &mut lines);
+
+    let mut output = lines.join("\n");
+    output.push('\n');
+    Ok(output)
+}
+
+/// Render `(position, [r, g, b])` stops as `const GRADIENT: [(f32, [u8; 3]); N] = [...];`
+///
+/// Positions are normalized to 0.0-1.0, so the array can be embedded in a
+/// binary and sampled without allocation.
+#[must_use]
+pub fn to_rust_const_array(stops: &[(f32, [u8; 3])]) -> String {
+    use std::fmt::Write;
+
+    let mut output = format!(
+        "// Generated by {} {}\nconst GRADIENT: [(f32, [u8; 3]); {}] = [\n",
+        crate::config::APP_NAME,
+        crate::config::APP_VERSION,
+        stops.len()
+    );
+    for (position, [r, g, b]) in stops {
+        let _ = writeln!(output, "    ({position:?}, [{r}, {g}, {b}]),");
+    }
+    output.push_str("];\n");
+    output
+}
+
+/// Render `(name, value)` pairs as CSS custom properties in a `:root` block
+///
+/// # Example
+/// ```rust
+/// use color_rs::output_formats::to_css_custom_properties;
+///
+/// let css = to_css_custom_properties(&[("--color-base".to_string(), "#FF0000".to_string())]);
+/// assert_eq!(css, ":root {\n  --color-base: #FF0000;\n}\n");
+/// ```
+#[must_use]
+pub fn to_css_custom_properties(properties: &[(String, String)]) -> String {
+    use std::fmt::Write;
+
+    let mut output = String::from(":root {\n");
+    for (name, value) in properties {
+        let _ = writeln!(output, "  {name}: {value};");
+    }
+    output.push_str("}\n");
+    output
+}
+
+/// Render a GitHub-flavored Markdown table with a header separator row
+///
+/// Pipes inside cells are escaped so they do not split columns.
+#[must_use]
+pub fn to_markdown_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let row_line = |cells: &mut dyn Iterator<Item = String>| {
+        let cells: Vec<String> = cells.map(|cell| cell.replace('|', "\\|")).collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+
+    let mut output = row_line(&mut headers.iter().map(|header| (*header).to_string()));
+    output.push_str(&row_line(&mut headers.iter().map(|_| "---".to_string())));
+    for row in rows {
+        output.push_str(&row_line(&mut row.iter().cloned()));
+    }
+    output
+}
+
+/// Swap the decimal point of standalone numeric values for another separator
+///
+/// Works on serialized YAML or TOML text line by line. Only values that are a
+/// bare number (`key: 0.5`, `key = 0.5`, `- 0.5`) are changed; strings such as
+/// `lab(53.24, 80.09, 67.2)` keep their points, since their commas already
+/// separate components. Digits are left untouched. YAML values become plain
+/// strings (`0,5`); TOML values are quoted (`"0,5"`) so the file stays valid.
+/// Env, Rust, Markdown and CSS output are returned unchanged.
+#[must_use]
+pub fn localize_decimal_separator(
+    content: &str,
+    format: &crate::cli::OutputFormat,
+    separator: crate::cli::DecimalSeparator,
+) -> String {
+    use crate::cli::OutputFormat;
+
+    let separator = separator.as_char();
+    let delimiter = match format {
+        OutputFormat::Yaml => ": ",
+        OutputFormat::Toml => " = ",
+        OutputFormat::Env | OutputFormat::Rust | OutputFormat::Markdown | OutputFormat::CssVars => {
+            return content.to_string();
         }
+    };
+    if separator == '.' {
+        return content.to_string();
+    }
+
+    let is_decimal = |value: &str| value.contains('.') && value.parse::<f64>().is_ok();
+    let localize_line = |line: &str| -> String {
+        let trimmed = line.trim_start();
+        let value_start = line
+            .find(delimiter)
+            .map(|i| i + delimiter.len())
+            .or_else(|| {
+                trimmed
+                    .starts_with("- ")
+                    .then(|| line.len() - trimmed.len() + 2)
+            });
+        match value_start.map(|i| line.split_at(i)) {
+            Some((prefix, value)) if is_decimal(value) => {
+                let localized = value.replace('.', &separator.to_string());
+                if *format == OutputFormat::Toml {
+                    format!("{prefix}\"{localized}\"")
+                } else {
+                    format!("{prefix}{localized}")
+                }
+            }
+            _ => line.to_string(),
+        }
+    };
+
+    let mut output = content
+        .lines()
+        .map(localize_line)
+        .collect::<Vec<_>>()
+        .join("\n");
+    if content.ends_with('\n') {
+        output.push('\n');
     }
+    output
 }
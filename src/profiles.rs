@@ -0,0 +1,303 @@
+//! Named option profiles
+//!
+//! A profile is a table in `profiles.toml` whose keys name command options and
+//! whose values are their defaults:
+//!
+//! ```toml
+//! [print]
+//! distance_method = "deltae2000"
+//! collections = "ral-classic,ral-design"
+//! lab_variant = "d50"
+//! output = "toml"
+//! ```
+//!
+//! `--profile print` merges the table into the command line before it is
+//! parsed, so profile values go through the same validation as typed flags.
+//! Keys are long flag names, with `_` or `-`, or argument ids such as
+//! `output_format`. A flag given explicitly always wins, and keys the running
+//! command does not have are skipped so one profile can serve several commands.
+
+use crate::cli::Cli;
+use crate::error::{ColorError, Result};
+use clap::CommandFactory;
+use std::ffi::OsString;
+use std::path::Path;
+
+/// File profiles are read from, relative to the working directory
+pub const PROFILES_FILE: &str = "profiles.toml";
+
+/// Apply the profile named by `--profile` to raw command-line arguments
+///
+/// Arguments without `--profile` are returned unchanged and no file is read.
+///
+/// # Errors
+/// Returns an error if `--profile` has no value, [`PROFILES_FILE`] cannot be
+/// read or parsed, the profile does not exist, or one of its entries is
+/// invalid (see [`merge_profile`]).
+pub fn apply_profile(args: Vec<OsString>) -> Result<Vec<OsString>> {
+    let Some(name) = profile_name(&args)? else {
+        return Ok(args);
+    };
+    let profile = load_profile(Path::new(PROFILES_FILE), &name)?;
+    merge_profile(args, &profile)
+}
+
+/// Read the profile `name` from a profiles file
+///
+/// # Errors
+/// Returns an error if the file cannot be read or parsed, or has no table
+/// called `name`.
+pub fn load_profile(path: &Path, name: &str) -> Result<toml::Table> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        ColorError::InvalidArguments(format!(
+            "Failed to read profiles file {}: {e}",
+            path.display()
+        ))
+    })?;
+    let mut profiles: toml::Table = toml::from_str(&content).map_err(|e| {
+        ColorError::InvalidArguments(format!("Invalid profiles file {}: {e}", path.display()))
+    })?;
+
+    match profiles.remove(name) {
+        Some(toml::Value::Table(profile)) => Ok(profile),
+        Some(_) => Err(ColorError::InvalidArguments(format!(
+            "Profile '{name}' in {} must be a table",
+            path.display()
+        ))),
+        None => Err(ColorError::InvalidArguments(format!(
+            "No profile named '{name}' in {}",
+            path.display()
+        ))),
+    }
+}
+
+/// Insert a profile's options after the subcommand, unless given explicitly
+///
+/// Strings and numbers become a single `--flag=value` token, so options taking
+/// several values cannot swallow the positional arguments that follow. `true`
+/// becomes a bare `--flag`, `false` is left out, and arrays are joined with
+/// commas.
+///
+/// # Errors
+/// Returns `ColorError::InvalidArguments` if a key matches no option of any
+/// command, or a value is a table or an array of tables.
+///
+/// # Example
+/// ```rust
+/// use color_rs::profiles::merge_profile;
+///
+/// let profile: toml::Table = toml::from_str(r#"distance_method = "deltae2000""#).unwrap();
+/// let args = merge_profile(vec!["color-rs".into(), "color".into(), "red".into()], &profile)?;
+/// assert_eq!(args, ["color-rs", "color", "--distance-method=deltae2000", "red"]);
+/// # Ok::<(), color_rs::error::ColorError>(())
+/// ```
+pub fn merge_profile(args: Vec<OsString>, profile: &toml::Table) -> Result<Vec<OsString>> {
+    let cli = Cli::command();
+    let Some(position) = subcommand_position(&args) else {
+        return Ok(args);
+    };
+    let Some(command) = args[position]
+        .to_str()
+        .and_then(|name| cli.find_subcommand(name))
+    else {
+        return Ok(args);
+    };
+
+    let mut inserted = Vec::new();
+    for (key, value) in profile {
+        let Some(arg) = find_option(command, key) else {
+            if cli
+                .get_subcommands()
+                .any(|other| find_option(other, key).is_some())
+            {
+                continue;
+            }
+            return Err(ColorError::InvalidArguments(format!(
+                "Unknown profile option '{key}'"
+            )));
+        };
+        let long = arg.get_long().unwrap_or_default();
+        if is_given(&args[position + 1..], arg) {
+            continue;
+        }
+
+        match value {
+            toml::Value::Boolean(true) => inserted.push(OsString::from(format!("--{long}"))),
+            toml::Value::Boolean(false) => {}
+            value => inserted.push(OsString::from(format!(
+                "--{long}={}",
+                option_value(key, value)?
+            ))),
+        }
+    }
+
+    let mut merged = args;
+    merged.splice(position + 1..position + 1, inserted);
+    Ok(merged)
+}
+
+/// Value of `--profile NAME` or `--profile=NAME`, if present
+fn profile_name(args: &[OsString]) -> Result<Option<String>> {
+    let mut iter = args.iter().skip(1).filter_map(|arg| arg.to_str());
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            break;
+        }
+        if arg == "--profile" {
+            return iter
+                .next()
+                .map(|name| Some(name.to_string()))
+                .ok_or_else(|| {
+                    ColorError::InvalidArguments("--profile requires a profile name".to_string())
+                });
+        }
+        if let Some(name) = arg.strip_prefix("--profile=") {
+            return Ok(Some(name.to_string()));
+        }
+    }
+    Ok(None)
+}
+
+/// Index of the subcommand name, skipping the global `--profile NAME`
+fn subcommand_position(args: &[OsString]) -> Option<usize> {
+    let mut skip_value = false;
+    for (index, arg) in args.iter().enumerate().skip(1) {
+        let arg = arg.to_str()?;
+        if skip_value {
+            skip_value = false;
+        } else if arg == "--profile" {
+            skip_value = true;
+        } else if !arg.starts_with('-') {
+            return Some(index);
+        }
+    }
+    None
+}
+
+/// Option of `command` named by a profile key
+fn find_option<'a>(command: &'a clap::Command, key: &str) -> Option<&'a clap::Arg> {
+    let long = key.replace('_', "-");
+    command.get_arguments().find(|arg| {
+        arg.get_long().is_some()
+            && (arg.get_id() == key
+                || arg.get_long() == Some(long.as_str())
+                || arg
+                    .get_all_aliases()
+                    .is_some_and(|aliases| aliases.contains(&long.as_str())))
+    })
+}
+
+/// Whether `arg` appears among the explicit arguments before any `--`
+///
+/// A short flag counts as `-x`, `-x=VALUE`, or `-xVALUE` for options that take
+/// a value.
+fn is_given(args: &[OsString], arg: &clap::Arg) -> bool {
+    let mut longs: Vec<&str> = arg.get_all_aliases().unwrap_or_default();
+    longs.extend(arg.get_long());
+    let short = arg.get_short().map(|short| format!("-{short}"));
+    let takes_value = arg.get_action().takes_values();
+
+    args.iter()
+        .filter_map(|given| given.to_str())
+        .take_while(|given| *given != "--")
+        .any(|given| {
+            if let Some(name) = given.strip_prefix("--") {
+                let name = name.split_once('=').map_or(name, |(name, _)| name);
+                return longs.contains(&name);
+            }
+            short.as_deref().is_some_and(|short| {
+                given
+                    .strip_prefix(short)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('=') || takes_value)
+            })
+        })
+}
+
+/// Command-line text for a profile value
+fn option_value(key: &str, value: &toml::Value) -> Result<String> {
+    match value {
+        toml::Value::String(text) => Ok(text.clone()),
+        toml::Value::Array(items) => items
+            .iter()
+            .map(|item| option_value(key, item))
+            .collect::<Result<Vec<_>>>()
+            .map(|items| items.join(",")),
+        toml::Value::Table(_) => Err(ColorError::InvalidArguments(format!(
+            "Profile option '{key}' must be a string, number, boolean or array"
+        ))),
+        other => Ok(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Commands;
+    use crate::color_distance_strategies::DistanceAlgorithm;
+    use clap::Parser;
+
+    fn parse_color(args: &[&str], profile: &str) -> crate::cli::ColorArgs {
+        let profile: toml::Table = toml::from_str(profile).unwrap();
+        let args = args.iter().map(OsString::from).collect();
+        let merged = merge_profile(args, &profile).unwrap();
+        let Commands::Color(args) = Cli::try_parse_from(merged).unwrap().command else {
+            panic!("expected color command");
+        };
+        args
+    }
+
+    #[test]
+    fn test_profile_sets_defaults_that_explicit_flags_override() {
+        let profile = r#"
+            distance_method = "deltae2000"
+            output = "toml"
+            all_distances = true
+            stops = 12
+        "#;
+
+        let args = parse_color(&["color-rs", "--profile", "print", "color", "red"], profile);
+        assert_eq!(args.distance_method, "deltae2000");
+        assert_eq!(
+            args.distance_method.parse::<DistanceAlgorithm>().unwrap(),
+            DistanceAlgorithm::DeltaE2000
+        );
+        assert_eq!(args.output_format, Some(crate::cli::OutputFormat::Toml));
+        assert!(args.all_distances);
+
+        let args = parse_color(
+            &[
+                "color-rs",
+                "color",
+                "red",
+                "--distance-method=lch",
+                "-o",
+                "yaml",
+            ],
+            profile,
+        );
+        assert_eq!(args.distance_method, "lch");
+        assert_eq!(args.output_format, Some(crate::cli::OutputFormat::Yaml));
+
+        // Multi-value options cannot consume the positional colors
+        let profile: toml::Table = toml::from_str("sample_at = [0, 0.5]").unwrap();
+        let args = ["color-rs", "gradient", "red", "blue"].map(OsString::from);
+        let merged = merge_profile(args.to_vec(), &profile).unwrap();
+        let Commands::Gradient(args) = Cli::try_parse_from(merged).unwrap().command else {
+            panic!("expected gradient command");
+        };
+        assert_eq!(
+            (args.start_color.as_str(), args.end_color.as_str()),
+            ("red", "blue")
+        );
+        assert_eq!(args.sample_at, [0.0, 0.5]);
+
+        // Flags after `--` and look-alike tokens are not explicit options
+        let args = ["color-rs", "color", "--", "-oyaml"].map(OsString::from);
+        let profile: toml::Table = toml::from_str(r#"output = "toml""#).unwrap();
+        let merged = merge_profile(args.to_vec(), &profile).unwrap();
+        assert!(merged.contains(&OsString::from("--output=toml")));
+
+        let unknown: toml::Table = toml::from_str("no_such_option = 1").unwrap();
+        assert!(merge_profile(vec!["color-rs".into(), "color".into()], &unknown).is_err());
+    }
+}
@@ -3,22 +3,64 @@
 //! This module breaks down the large calculate method into focused, composable functions.
 
 use super::types::{BasicColorSchemes, ColorSchemeConfig, LuminanceConfig};
+use crate::color_ops::Gamut;
 use crate::color_ops::luminance::wcag_relative;
 use crate::color_schemes::{
     ColorSchemeResult, ColorSchemeStrategy, HslColorSchemeStrategy, LabColorSchemeStrategy,
     adjust_color_lab_luminance, preserve_lab_luminance, preserve_wcag_relative_luminance,
 };
 use crate::config::algorithm_constants;
-use crate::error::Result;
-use palette::{IntoColor, Lab, Srgb};
+use crate::error::{ColorError, Result};
+use palette::{IntoColor, Lab, Lch, Srgb};
+
+/// Bisection steps when reducing a target chroma to fit sRGB
+const CHROMA_GAMUT_SEARCH_STEPS: usize = 24;
+
+/// Chroma below which a color is treated as achromatic and has no hue to boost
+const ACHROMATIC_CHROMA: f32 = 1e-3;
+
+/// LCH chroma below which a base color's hue is too unstable for schemes
+pub const NEAR_GRAY_CHROMA: f32 = 2.0;
+
+/// Chroma of the stand-in color derived from a gray base with a nominal hue
+pub const GRAY_HUE_CHROMA: f32 = 30.0;
 
 /// Local implementation of relative luminance adjustment since the original is private
 fn adjust_color_relative_luminance(color: Lab, target_luminance: f64) -> Result<Lab> {
+    adjust_color_relative_luminance_with(
+        color,
+        target_luminance,
+        algorithm_constants::LUMINANCE_TOLERANCE,
+        algorithm_constants::LUMINANCE_MAX_ITERATIONS,
+    )
+}
+
+/// Adjust a color's Lab lightness until its WCAG relative luminance is within
+/// `tolerance` of the target, trying at most `max_iter` binary search steps
+///
+/// Tighter tolerances and more iterations give a closer match at the cost of
+/// more conversions; when the search runs out of iterations the last candidate
+/// is returned.
+///
+/// # Errors
+/// Returns error if `tolerance` is not greater than 0
+pub fn adjust_color_relative_luminance_with(
+    color: Lab,
+    target_luminance: f64,
+    tolerance: f64,
+    max_iter: usize,
+) -> Result<Lab> {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return Err(ColorError::InvalidArguments(format!(
+            "Luminance tolerance must be greater than 0, got {tolerance}"
+        )));
+    }
+
     // Convert to RGB, check current luminance
     let srgb: Srgb = color.into_color();
     let current_luminance = wcag_relative(srgb);
 
-    if (current_luminance - target_luminance).abs() < algorithm_constants::LUMINANCE_TOLERANCE {
+    if (current_luminance - target_luminance).abs() < tolerance {
         return Ok(color);
     }
 
@@ -27,13 +69,13 @@ fn adjust_color_relative_luminance(color: Lab, target_luminance: f64) -> Result<
     let mut high = algorithm_constants::BINARY_SEARCH_HIGH_LUMINANCE;
     let mut best_color = color;
 
-    for _ in 0..50 {
+    for _ in 0..max_iter {
         let mid = (low + high) / algorithm_constants::BINARY_SEARCH_DIVISION_FACTOR as f32;
         let test_color = Lab::new(mid, color.a, color.b);
         let test_srgb: Srgb = test_color.into_color();
         let test_luminance = wcag_relative(test_srgb);
 
-        if (test_luminance - target_luminance).abs() < algorithm_constants::LUMINANCE_TOLERANCE {
+        if (test_luminance - target_luminance).abs() < tolerance {
             return Ok(test_color);
         }
 
@@ -59,6 +101,87 @@ pub fn apply_target_luminance(config: ColorSchemeConfig, mut base_color: Lab) ->
     Ok(base_color)
 }
 
+/// Set a color's LCH chroma to `target_chroma`, keeping lightness and hue
+///
+/// When sRGB cannot hold the target at that lightness and hue, the largest
+/// in-gamut chroma below it is used. Achromatic colors are returned unchanged
+/// since they have no hue to saturate.
+#[must_use]
+pub fn apply_target_chroma(color: Lab, target_chroma: f32) -> Lab {
+    let lch: Lch = color.into_color();
+    if lch.chroma < ACHROMATIC_CHROMA {
+        return color;
+    }
+
+    let with_chroma = |chroma: f32| -> Lab { Lch::new(lch.l, chroma, lch.hue).into_color() };
+    let target = with_chroma(target_chroma);
+    if Gamut::Srgb.contains(target) {
+        return target;
+    }
+
+    let (mut low, mut high) = (0.0_f32, target_chroma);
+    for _ in 0..CHROMA_GAMUT_SEARCH_STEPS {
+        let mid = (low + high) / 2.0;
+        if Gamut::Srgb.contains(with_chroma(mid)) {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    with_chroma(low)
+}
+
+/// Whether `color` is too close to gray for its hue to mean anything
+#[must_use]
+pub fn is_near_gray(color: Lab) -> bool {
+    let lch: Lch = color.into_color();
+    lch.chroma < NEAR_GRAY_CHROMA
+}
+
+/// Color that schemes are derived from, given the configured gray hue
+///
+/// A near-gray base has no usable hue, so every rotation of it is gray too.
+/// With a gray hue configured, such a base is replaced by a color of the same
+/// lightness at that hue and [`GRAY_HUE_CHROMA`], reduced to fit sRGB.
+/// Colors with real chroma, or any color without a gray hue, are returned
+/// unchanged.
+#[must_use]
+pub fn apply_gray_hue(base_color: Lab, config: ColorSchemeConfig) -> Lab {
+    match config.gray_hue {
+        Some(hue) if is_near_gray(base_color) => {
+            let hued: Lab = Lch::new(base_color.l, NEAR_GRAY_CHROMA, hue).into_color();
+            apply_target_chroma(hued, GRAY_HUE_CHROMA)
+        }
+        _ => base_color,
+    }
+}
+
+/// Apply the configured target chroma to every derived color of `schemes`
+#[must_use]
+pub fn apply_target_chroma_to_schemes(
+    schemes: BasicColorSchemes,
+    config: ColorSchemeConfig,
+) -> BasicColorSchemes {
+    let Some(target) = config.target_chroma else {
+        return schemes;
+    };
+    let boost = |color| apply_target_chroma(color, target);
+
+    BasicColorSchemes {
+        hsl_complementary: boost(schemes.hsl_complementary),
+        lab_complementary: boost(schemes.lab_complementary),
+        analogous_warm: boost(schemes.analogous_warm),
+        analogous_cool: boost(schemes.analogous_cool),
+        triadic_1: boost(schemes.triadic_1),
+        triadic_2: boost(schemes.triadic_2),
+        split_complementary_1: boost(schemes.split_complementary_1),
+        split_complementary_2: boost(schemes.split_complementary_2),
+        tetradic_1: boost(schemes.tetradic_1),
+        tetradic_2: boost(schemes.tetradic_2),
+        tetradic_3: boost(schemes.tetradic_3),
+    }
+}
+
 /// Calculate basic color schemes using both HSL and Lab strategies
 pub fn calculate_basic_schemes(base_color: Lab) -> BasicColorSchemes {
     let hsl_strategy = HslColorSchemeStrategy;
@@ -142,8 +265,12 @@ pub fn calculate_color_schemes(
     // Apply target luminance adjustments
     let adjusted_base_color = apply_target_luminance(config, base_color)?;
 
-    // Calculate basic color schemes
-    let basic_schemes = calculate_basic_schemes(adjusted_base_color);
+    // Calculate basic color schemes, normalizing their chroma if requested; a
+    // gray base is first given its nominal hue, if one was configured
+    let basic_schemes = apply_target_chroma_to_schemes(
+        calculate_basic_schemes(apply_gray_hue(adjusted_base_color, config)),
+        config,
+    );
     let luminance_config = LuminanceConfig::from(config);
 
     // Build the result using the original ColorSchemeResult structure
@@ -29,6 +29,11 @@ impl ColorSchemeCalculator {
         let mut builder = crate::color_schemes::ColorSchemeBuilder::new();
 
         let config = self.config();
+        // The builder has no chroma option, so use the functional path instead
+        if config.target_chroma.is_some() {
+            return super::calculate_color_schemes(config, base_color);
+        }
+
         if config.preserve_relative_luminance {
             builder = builder.preserve_relative_luminance();
         }
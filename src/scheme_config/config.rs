@@ -72,4 +72,28 @@ impl ColorSchemeConfig {
             ..self
         })
     }
+
+    /// Configuration combinator to set the LCH chroma of derived colors
+    pub fn set_target_chroma(self, chroma: f32) -> std::result::Result<Self, ConfigError> {
+        if !chroma.is_finite() || chroma < 0.0 {
+            return Err(ConfigError::InvalidTargetChroma { value: chroma });
+        }
+        Ok(Self {
+            target_chroma: Some(chroma),
+            ..self
+        })
+    }
+
+    /// Configuration combinator to give a near-gray base a nominal hue
+    ///
+    /// The angle is normalized to 0-360 degrees.
+    pub fn set_gray_hue(self, hue: f32) -> std::result::Result<Self, ConfigError> {
+        if !hue.is_finite() {
+            return Err(ConfigError::InvalidGrayHue { value: hue });
+        }
+        Ok(Self {
+            gray_hue: Some(hue.rem_euclid(360.0)),
+            ..self
+        })
+    }
 }
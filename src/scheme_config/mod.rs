@@ -27,14 +27,16 @@ pub mod types;
 pub mod validation;
 
 // Re-export all public types and functions
-pub use calculation::calculate_color_schemes;
+pub use calculation::{
+    adjust_color_relative_luminance_with, calculate_color_schemes, is_near_gray,
+};
 pub use presets::*;
 pub use types::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use palette::{IntoColor, Lab, Srgb};
+    use palette::{FromColor, IntoColor, Lab, Srgb};
 
     #[test]
     fn test_default_config() {
@@ -238,4 +240,110 @@ mod tests {
         let result = calculation::apply_luminance_matching(color, base_color, config).unwrap();
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_target_chroma_normalizes_derived_colors() {
+        use palette::Lch;
+
+        let chroma = |lab: Lab| Lch::from_color(lab).chroma;
+        let muted = Lab::new(55.0, 8.0, 6.0);
+        let config = ColorSchemeConfig::default()
+            .set_target_chroma(30.0)
+            .unwrap();
+
+        let schemes = calculate_color_schemes(config, muted).unwrap();
+        assert_eq!(schemes.base_color, muted);
+        for derived in [
+            schemes.hsl_complementary,
+            schemes.lab_complementary,
+            schemes.lab_triadic.0,
+            schemes.lab_triadic.1,
+            schemes.lab_tetradic.2,
+        ] {
+            assert!((chroma(derived) - 30.0).abs() < 0.01, "{derived:?}");
+        }
+        assert_eq!(
+            ColorSchemeCalculator::new(config)
+                .calculate(muted)
+                .unwrap()
+                .lab_complementary,
+            schemes.lab_complementary
+        );
+
+        // Chroma beyond sRGB is reduced to the gamut boundary
+        let vivid = calculation::apply_target_chroma(muted, 500.0);
+        assert!(crate::color_ops::Gamut::Srgb.contains(vivid));
+        assert!(chroma(vivid) > 30.0);
+
+        assert!(matches!(
+            ColorSchemeConfig::default().set_target_chroma(-1.0),
+            Err(ConfigError::InvalidTargetChroma { .. })
+        ));
+    }
+
+    #[test]
+    fn test_luminance_tolerance_controls_precision() {
+        use crate::color_ops::luminance::wcag_relative;
+
+        let blue: Lab = Srgb::new(0.1, 0.3, 0.9).into_color();
+        let target = 0.3;
+        let error = |lab: Lab| (wcag_relative(lab.into_color()) - target).abs();
+
+        let loose = adjust_color_relative_luminance_with(blue, target, 0.05, 50).unwrap();
+        let tight = adjust_color_relative_luminance_with(blue, target, 1e-6, 50).unwrap();
+        assert!(error(tight) < error(loose));
+        assert!(error(tight) < 1e-5);
+
+        assert!(adjust_color_relative_luminance_with(blue, target, 0.0, 50).is_err());
+    }
+
+    #[test]
+    fn test_gray_hue_gives_gray_base_colored_schemes() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+        use palette::Lch;
+
+        let cli =
+            Cli::try_parse_from(["color-rs", "color", "#808080", "--gray-hue", "200"]).unwrap();
+        let Commands::Color(args) = cli.command else {
+            panic!("expected color command");
+        };
+        assert_eq!(args.gray_hue, Some(200.0));
+
+        let gray: Lab = Srgb::new(0.5, 0.5, 0.5).into_color();
+        assert!(is_near_gray(gray));
+        let chroma = |lab: Lab| Lch::from_color(lab).chroma;
+
+        let plain = calculate_color_schemes(ColorSchemeConfig::default(), gray).unwrap();
+        assert!(
+            plain
+                .iter_relationships()
+                .all(|(_, color)| is_near_gray(color))
+        );
+
+        let config = ColorSchemeConfig::default()
+            .set_gray_hue(args.gray_hue.unwrap())
+            .unwrap();
+        let hued = calculate_color_schemes(config, gray).unwrap();
+        assert_eq!(hued.base_color, gray);
+        for (name, color) in hued.iter_relationships() {
+            assert!(chroma(color) > 10.0, "{name} is gray: {color:?}");
+        }
+
+        // A chromatic base keeps its own hue
+        let red: Lab = Srgb::new(1.0, 0.0, 0.0).into_color();
+        assert_eq!(
+            calculate_color_schemes(config, red)
+                .unwrap()
+                .lab_complementary,
+            calculate_color_schemes(ColorSchemeConfig::default(), red)
+                .unwrap()
+                .lab_complementary
+        );
+
+        assert!(matches!(
+            ColorSchemeConfig::default().set_gray_hue(f32::NAN),
+            Err(ConfigError::InvalidGrayHue { .. })
+        ));
+    }
 }
@@ -12,6 +12,10 @@ pub struct ColorSchemeConfig {
     pub preserve_lab_luminance: bool,
     pub target_relative_luminance: Option<f64>,
     pub target_lab_luminance: Option<f64>,
+    /// LCH chroma every derived color is set to, reduced where sRGB cannot hold it
+    pub target_chroma: Option<f32>,
+    /// LCH hue assumed for a near-gray base, whose own hue is undefined
+    pub gray_hue: Option<f32>,
 }
 
 /// Validation errors for color scheme configuration
@@ -23,6 +27,10 @@ pub enum ConfigError {
     InvalidTargetLuminance { value: f64, min: f64, max: f64 },
     /// Multiple target luminance values cannot be specified
     ConflictingTargetValues,
+    /// Target chroma must be a non-negative number
+    InvalidTargetChroma { value: f32 },
+    /// Gray hue must be a finite angle
+    InvalidGrayHue { value: f32 },
 }
 
 impl std::fmt::Display for ConfigError {
@@ -46,6 +54,12 @@ impl std::fmt::Display for ConfigError {
                     "Cannot specify both relative and lab target luminance values"
                 )
             }
+            Self::InvalidTargetChroma { value } => {
+                write!(f, "Target chroma {value} must be a non-negative number")
+            }
+            Self::InvalidGrayHue { value } => {
+                write!(f, "Gray hue {value} must be a finite angle in degrees")
+            }
         }
     }
 }
@@ -10,6 +10,8 @@ impl ColorSchemeConfig {
         preserve_lab_luminance: false,
         target_relative_luminance: None,
         target_lab_luminance: None,
+        target_chroma: None,
+        gray_hue: None,
     };
 
     /// Create a validated configuration
@@ -60,6 +62,8 @@ impl ColorSchemeConfig {
             preserve_lab_luminance,
             target_relative_luminance,
             target_lab_luminance,
+            target_chroma: None,
+            gray_hue: None,
         })
     }
 
@@ -70,6 +74,8 @@ impl ColorSchemeConfig {
             preserve_lab_luminance: false,
             target_relative_luminance: None,
             target_lab_luminance: None,
+            target_chroma: None,
+            gray_hue: None,
         }
     }
 
@@ -80,6 +86,8 @@ impl ColorSchemeConfig {
             preserve_lab_luminance: true,
             target_relative_luminance: None,
             target_lab_luminance: None,
+            target_chroma: None,
+            gray_hue: None,
         }
     }
 
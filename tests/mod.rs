@@ -54,6 +54,10 @@ mod test_suite_runner {
             border_color: "white".to_string(),
             header_text: None,
             vectorized_text: false,
+            find_duplicates: None,
+            min_chroma: None,
+            sort: vec![color_rs::cli::CollectionSortKey::Hue],
+            dry_run: false,
         };
 
         assert_eq!(args.collection, "css");
@@ -91,4 +95,60 @@ mod test_suite_runner {
 
         println!("✅ Range parsing tests passed!");
     }
+
+    /// Test the exit codes scripts rely on for the equal command
+    #[test]
+    fn test_equal_exit_codes() {
+        use color_rs::cli::EqualArgs;
+        use std::process::Command;
+
+        let run = |args: &[&str]| {
+            Command::new(env!("CARGO_BIN_EXE_color-rs"))
+                .arg("equal")
+                .args(args)
+                .output()
+                .expect("Should run the color-rs binary")
+        };
+
+        let equal = run(&["#FF0000", "#FE0000", "--tolerance", "2"]);
+        assert_eq!(equal.status.code(), Some(EqualArgs::EXIT_EQUAL));
+
+        let different = run(&["red", "blue"]);
+        assert_eq!(different.status.code(), Some(EqualArgs::EXIT_DIFFERENT));
+        assert!(String::from_utf8_lossy(&different.stdout).starts_with("different"));
+
+        let invalid = run(&["red", "not-a-color"]);
+        assert_eq!(invalid.status.code(), Some(EqualArgs::EXIT_ERROR));
+        assert!(invalid.stdout.is_empty());
+
+        let usage = run(&["red"]);
+        assert_eq!(usage.status.code(), Some(EqualArgs::EXIT_ERROR));
+    }
+
+    /// Test that the built-in collections work without the color-table directory
+    #[test]
+    fn test_color_without_color_tables_uses_bundled_copies() {
+        use std::process::Command;
+
+        let dir = std::env::temp_dir().join(format!("color-rs-no-tables-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("Should create a scratch directory");
+
+        let output = Command::new(env!("CARGO_BIN_EXE_color-rs"))
+            .current_dir(&dir)
+            .args(["color", "red"])
+            .output()
+            .expect("Should run the color-rs binary");
+        std::fs::remove_dir_all(&dir).ok();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(output.status.success(), "{stderr}");
+        assert!(stdout.contains("hex: '#FF0000'"));
+        assert!(
+            stdout.contains("RAL 3026"),
+            "RAL matches come from the bundled table"
+        );
+        assert_eq!(stderr.matches("css-colors.csv").count(), 1);
+        assert!(stderr.contains("using the bundled copy"));
+    }
 }
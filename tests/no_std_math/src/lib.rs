@@ -0,0 +1,33 @@
+//! `#![no_std]` compile check for `color_rs::color_math`
+//!
+//! Builds the crate's core color math source in a `no_std` context with the
+//! `libm` float backend. Run from the repository root with:
+//!
+//! ```text
+//! cargo test --manifest-path tests/no_std_math/Cargo.toml
+//! ```
+
+#![no_std]
+
+#[path = "../../../src/color_math.rs"]
+pub mod color_math;
+
+/// Delta E 2000 between two sRGB colors, computed entirely without `std`
+#[must_use]
+pub fn srgb_delta_e_2000(rgb1: [f64; 3], rgb2: [f64; 3]) -> f64 {
+    color_math::delta_e_2000(color_math::srgb_to_lab(rgb1), color_math::srgb_to_lab(rgb2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delta_e_2000_without_std() {
+        let identical = srgb_delta_e_2000([0.2, 0.4, 0.6], [0.2, 0.4, 0.6]);
+        assert!(identical.abs() < 1e-12);
+
+        let black_white = srgb_delta_e_2000([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        assert!((black_white - 100.0).abs() < 0.01);
+    }
+}
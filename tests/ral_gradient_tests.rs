@@ -1,7 +1,10 @@
 // Sprint 0.19.4 - RAL Color Resolution Test Cases
 // This file contains test cases to validate RAL color support in gradient command
 
-use color_rs::{ColorRs, cli::GradientArgs};
+use color_rs::{
+    ColorRs,
+    cli::{GradientArgs, ImageWidth},
+};
 
 #[cfg(test)]
 mod ral_gradient_tests {
@@ -16,21 +19,10 @@ mod ral_gradient_tests {
         let args = GradientArgs {
             start_color: "RAL 5019".to_string(), // Capri blue
             end_color: "RAL 1004".to_string(),   // Golden yellow
-            start_position: 0,
-            end_position: 100,
-            ease_in: 0.65,
-            ease_out: 0.35,
-            svg: None,
-            png: None,
-            no_legend: false,
-            width: 1000,
-            step: None,
+            width: ImageWidth::Pixels(1000),
             stops: 5,
-            stops_simple: false,
-            output_format: None,
-            output_file: None,
-            func_filter: None,
             vectorized_text: false,
+            ..Default::default()
         };
 
         // This should NOT panic or return an error
@@ -58,21 +50,10 @@ mod ral_gradient_tests {
             let args = GradientArgs {
                 start_color: start.to_string(),
                 end_color: end.to_string(),
-                start_position: 0,
-                end_position: 100,
-                ease_in: 0.65,
-                ease_out: 0.35,
-                svg: None,
-                png: None,
-                no_legend: false,
-                width: 1000,
-                step: None,
+                width: ImageWidth::Pixels(1000),
                 stops: 5,
-                stops_simple: false,
-                output_format: None,
-                output_file: None,
-                func_filter: None,
                 vectorized_text: false,
+                ..Default::default()
             };
 
             let result = color_rs.generate_gradient(args);
@@ -94,21 +75,10 @@ mod ral_gradient_tests {
         let args = GradientArgs {
             start_color: "RAL 010 40 30".to_string(), // Deep red
             end_color: "RAL 270 30 40".to_string(),   // Deep purple
-            start_position: 0,
-            end_position: 100,
-            ease_in: 0.65,
-            ease_out: 0.35,
-            svg: None,
-            png: None,
-            no_legend: false,
-            width: 1000,
-            step: None,
+            width: ImageWidth::Pixels(1000),
             stops: 5,
-            stops_simple: false,
-            output_format: None,
-            output_file: None,
-            func_filter: None,
             vectorized_text: false,
+            ..Default::default()
         };
 
         let result = color_rs.generate_gradient(args);
@@ -128,21 +98,10 @@ mod ral_gradient_tests {
         let invalid_args = GradientArgs {
             start_color: "RAL 9999".to_string(), // Invalid RAL code
             end_color: "blue".to_string(),
-            start_position: 0,
-            end_position: 100,
-            ease_in: 0.65,
-            ease_out: 0.35,
-            svg: None,
-            png: None,
-            no_legend: false,
-            width: 1000,
-            step: None,
+            width: ImageWidth::Pixels(1000),
             stops: 5,
-            stops_simple: false,
-            output_format: None,
-            output_file: None,
-            func_filter: None,
             vectorized_text: false,
+            ..Default::default()
         };
 
         let result = color_rs.generate_gradient(invalid_args);
@@ -169,21 +128,10 @@ mod ral_gradient_tests {
             let args = GradientArgs {
                 start_color: "RAL 3020".to_string(),
                 end_color: "RAL 5005".to_string(),
-                start_position: 0,
-                end_position: 100,
-                ease_in: 0.65,
-                ease_out: 0.35,
-                svg: None,
-                png: None,
-                no_legend: false,
-                width: 1000,
-                step: None,
+                width: ImageWidth::Pixels(1000),
                 stops: 10,
-                stops_simple: false,
-                output_format: None,
-                output_file: None,
-                func_filter: None,
                 vectorized_text: false,
+                ..Default::default()
             };
 
             let result = color_rs.generate_gradient(args);
@@ -224,21 +172,10 @@ mod ral_gradient_tests {
             let args = GradientArgs {
                 start_color: ral_color.to_string(),
                 end_color: "white".to_string(),
-                start_position: 0,
-                end_position: 100,
-                ease_in: 0.65,
-                ease_out: 0.35,
-                svg: None,
-                png: None,
-                no_legend: false,
-                width: 1000,
-                step: None,
+                width: ImageWidth::Pixels(1000),
                 stops: 5,
-                stops_simple: false,
-                output_format: None,
-                output_file: None,
-                func_filter: None,
                 vectorized_text: false,
+                ..Default::default()
             };
 
             let result = color_rs.generate_gradient(args);